@@ -10,6 +10,9 @@ export namespace base {
   export import CompoundStateSpace = oxmpl.CompoundStateSpace;
   export import CompoundStateSpaceBuilder = oxmpl.CompoundStateSpaceBuilder;
   export import Goal = oxmpl.Goal;
+  export import GoalSpace = oxmpl.GoalSpace;
+  export import GoalState = oxmpl.GoalState;
+  export import GoalStates = oxmpl.GoalStates;
   export import Path = oxmpl.Path;
   export import PlannerConfig = oxmpl.PlannerConfig;
   export import ProblemDefinition = oxmpl.ProblemDefinition;
@@ -19,14 +22,17 @@ export namespace base {
   export import SO2StateSpace = oxmpl.SO2StateSpace;
   export import SO3State = oxmpl.SO3State;
   export import SO3StateSpace = oxmpl.SO3StateSpace;
+  export import SE2PoseGoal = oxmpl.SE2PoseGoal;
   export import SE2State = oxmpl.SE2State;
   export import SE2StateSpace = oxmpl.SE2StateSpace;
+  export import SE3PoseGoal = oxmpl.SE3PoseGoal;
   export import SE3State = oxmpl.SE3State;
   export import SE3StateSpace = oxmpl.SE3StateSpace;
   export import StateValidityChecker = oxmpl.StateValidityChecker;
 }
 
 export namespace geometric {
+  export import Planner = oxmpl.Planner;
   export import PRM = oxmpl.PRM;
   export import RRT = oxmpl.RRT;
   export import RRTConnect = oxmpl.RRTConnect;
@@ -10,6 +10,9 @@ export const base = {
   CompoundStateSpace: oxmpl.CompoundStateSpace,
   CompoundStateSpaceBuilder: oxmpl.CompoundStateSpaceBuilder,
   Goal: oxmpl.Goal,
+  GoalSpace: oxmpl.GoalSpace,
+  GoalState: oxmpl.GoalState,
+  GoalStates: oxmpl.GoalStates,
   Path: oxmpl.Path,
   PlannerConfig: oxmpl.PlannerConfig,
   ProblemDefinition: oxmpl.ProblemDefinition,
@@ -19,14 +22,17 @@ export const base = {
   SO2StateSpace: oxmpl.SO2StateSpace,
   SO3State: oxmpl.SO3State,
   SO3StateSpace: oxmpl.SO3StateSpace,
+  SE2PoseGoal: oxmpl.SE2PoseGoal,
   SE2State: oxmpl.SE2State,
   SE2StateSpace: oxmpl.SE2StateSpace,
+  SE3PoseGoal: oxmpl.SE3PoseGoal,
   SE3State: oxmpl.SE3State,
   SE3StateSpace: oxmpl.SE3StateSpace,
   StateValidityChecker: oxmpl.StateValidityChecker,
 };
 
 export const geometric = {
+  Planner: oxmpl.Planner,
   PRM: oxmpl.PRM,
   RRT: oxmpl.RRT,
   RRTConnect: oxmpl.RRTConnect,
@@ -0,0 +1,113 @@
+// Copyright (c) 2025 Junior Sundar
+//
+// SPDX-License-Identifier: BSD-3-Clause
+
+use std::{cell::Cell, marker::PhantomData, mem};
+
+use oxmpl::base::{
+    goal::{GoalSampleableRegion, GoalStates},
+    space::StateSpace,
+    state::State,
+};
+use rand::RngCore;
+use wasm_bindgen::prelude::*;
+
+use crate::base::js_state_convert::JsStateConvert;
+
+thread_local! {
+    /// The random number generator of the planner sampling a goal, while it calls `sampleGoal`.
+    static PLANNER_RNG: Cell<Option<*mut (dyn RngCore + 'static)>> = const { Cell::new(None) };
+}
+
+/// Calls `f` with `rng` lent to the `sampleGoal` methods of the built-in goals, so that they draw
+/// from a planner's random number generator and seeded planners find the same paths as in Rust.
+pub fn with_planner_rng<R>(rng: &mut dyn RngCore, f: impl FnOnce() -> R) -> R {
+    // SAFETY: Only the lifetime is erased. The pointer is dereferenced by `with_goal_rng` while
+    // `f` runs, when `rng` is borrowed by this function, and is removed before it returns.
+    let rng: *mut (dyn RngCore + 'static) = unsafe { mem::transmute(rng as *mut dyn RngCore) };
+    let previous = PLANNER_RNG.replace(Some(rng));
+    let result = f();
+    PLANNER_RNG.set(previous);
+    result
+}
+
+/// Calls `f` with the random number generator lent by `with_planner_rng`, or with the thread's
+/// generator if no planner is sampling the goal.
+fn with_goal_rng<R>(f: impl FnOnce(&mut dyn RngCore) -> R) -> R {
+    match PLANNER_RNG.take() {
+        Some(rng) => {
+            // SAFETY: `with_planner_rng` is still running, and the pointer was taken out of the
+            // slot, so this is the only reference to the generator.
+            let result = f(unsafe { &mut *rng });
+            PLANNER_RNG.set(Some(rng));
+            result
+        }
+        None => f(&mut rand::rng()),
+    }
+}
+
+/// A built-in Rust goal seen through JavaScript values, independent of its state type.
+pub trait JsDynGoal {
+    fn is_satisfied(&self, state: JsValue) -> Result<bool, String>;
+
+    fn distance_goal(&self, state: JsValue) -> Result<f64, String>;
+
+    fn sample_goal(&self) -> Result<JsValue, String>;
+}
+
+/// A `JsDynGoal` whose set of goal states can be added to.
+pub trait JsDynGoalStates: JsDynGoal {
+    fn add_state(&mut self, state: JsValue) -> Result<(), String>;
+
+    fn state_count(&self) -> usize;
+}
+
+/// Implements the `JsDynGoal` traits for a goal `G` over states of type `S`.
+pub struct TypedGoal<G, S> {
+    pub goal: G,
+    _phantom: PhantomData<S>,
+}
+
+impl<G, S> TypedGoal<G, S> {
+    pub fn new(goal: G) -> Self {
+        Self {
+            goal,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<G, S> JsDynGoal for TypedGoal<G, S>
+where
+    S: JsStateConvert + State,
+    G: GoalSampleableRegion<S>,
+{
+    fn is_satisfied(&self, state: JsValue) -> Result<bool, String> {
+        Ok(self.goal.is_satisfied(&S::from_js_value(state)?))
+    }
+
+    fn distance_goal(&self, state: JsValue) -> Result<f64, String> {
+        Ok(self.goal.distance_goal(&S::from_js_value(state)?))
+    }
+
+    fn sample_goal(&self) -> Result<JsValue, String> {
+        with_goal_rng(|rng| self.goal.sample_goal(rng))
+            .map(|state| state.to_js_value())
+            .map_err(|e| e.to_string())
+    }
+}
+
+impl<SP> JsDynGoalStates for TypedGoal<GoalStates<SP>, SP::StateType>
+where
+    SP: StateSpace,
+    SP::StateType: JsStateConvert + Clone,
+{
+    fn add_state(&mut self, state: JsValue) -> Result<(), String> {
+        self.goal.add_state(SP::StateType::from_js_value(state)?);
+        Ok(())
+    }
+
+    fn state_count(&self) -> usize {
+        self.goal.states.len()
+    }
+}
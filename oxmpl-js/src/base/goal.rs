@@ -2,12 +2,13 @@
 //
 // SPDX-License-Identifier: BSD-3-Clause
 
-use crate::base::js_state_convert::*;
+use crate::base::{builtin_goal::with_planner_rng, js_state_convert::*};
 use oxmpl::base::{
     error::StateSamplingError,
     goal::{Goal, GoalRegion, GoalSampleableRegion},
     state::{CompoundState, RealVectorState, SE2State, SE3State, SO2State, SO3State, State},
 };
+use rand::RngCore;
 use wasm_bindgen::prelude::*;
 use web_sys::console;
 
@@ -17,6 +18,13 @@ pub struct JsGoal {
     pub(crate) instance: JsValue,
 }
 
+// SAFETY: Planners that share the goal between threads require it to be `Send + Sync`.
+// WebAssembly modules built by `wasm-bindgen` run on a single thread, so the wrapped JS object is
+// never actually sent to or shared with another thread. `lib.rs` refuses to build with the
+// `atomics` target feature, which would allow a threaded module.
+unsafe impl Send for JsGoal {}
+unsafe impl Sync for JsGoal {}
+
 #[wasm_bindgen(js_class = Goal)]
 impl JsGoal {
     #[wasm_bindgen(constructor)]
@@ -67,11 +75,14 @@ impl JsGoal {
         }
     }
 
-    fn call_sample_goal<S: JsStateConvert + State>(&self) -> Result<S, StateSamplingError> {
+    fn call_sample_goal<S: JsStateConvert + State>(
+        &self,
+        rng: &mut dyn RngCore,
+    ) -> Result<S, StateSamplingError> {
         match js_sys::Reflect::get(&self.instance, &JsValue::from_str("sampleGoal")) {
             Ok(func_val) => {
                 if let Ok(func) = func_val.dyn_into::<js_sys::Function>() {
-                    match func.call0(&self.instance) {
+                    match with_planner_rng(rng, || func.call0(&self.instance)) {
                         Ok(result) => S::from_js_value(result)
                             .map_err(|_| StateSamplingError::GoalRegionUnsatisfiable),
                         Err(e) => {
@@ -100,11 +111,8 @@ impl GoalRegion<RealVectorState> for JsGoal {
     }
 }
 impl GoalSampleableRegion<RealVectorState> for JsGoal {
-    fn sample_goal(
-        &self,
-        _rng: &mut impl rand::Rng,
-    ) -> Result<RealVectorState, StateSamplingError> {
-        self.call_sample_goal()
+    fn sample_goal(&self, rng: &mut dyn RngCore) -> Result<RealVectorState, StateSamplingError> {
+        self.call_sample_goal(rng)
     }
 }
 
@@ -119,8 +127,8 @@ impl GoalRegion<SO2State> for JsGoal {
     }
 }
 impl GoalSampleableRegion<SO2State> for JsGoal {
-    fn sample_goal(&self, _rng: &mut impl rand::Rng) -> Result<SO2State, StateSamplingError> {
-        self.call_sample_goal()
+    fn sample_goal(&self, rng: &mut dyn RngCore) -> Result<SO2State, StateSamplingError> {
+        self.call_sample_goal(rng)
     }
 }
 
@@ -135,8 +143,8 @@ impl GoalRegion<SO3State> for JsGoal {
     }
 }
 impl GoalSampleableRegion<SO3State> for JsGoal {
-    fn sample_goal(&self, _rng: &mut impl rand::Rng) -> Result<SO3State, StateSamplingError> {
-        self.call_sample_goal()
+    fn sample_goal(&self, rng: &mut dyn RngCore) -> Result<SO3State, StateSamplingError> {
+        self.call_sample_goal(rng)
     }
 }
 
@@ -151,8 +159,8 @@ impl GoalRegion<SE2State> for JsGoal {
     }
 }
 impl GoalSampleableRegion<SE2State> for JsGoal {
-    fn sample_goal(&self, _rng: &mut impl rand::Rng) -> Result<SE2State, StateSamplingError> {
-        self.call_sample_goal()
+    fn sample_goal(&self, rng: &mut dyn RngCore) -> Result<SE2State, StateSamplingError> {
+        self.call_sample_goal(rng)
     }
 }
 
@@ -167,8 +175,8 @@ impl GoalRegion<SE3State> for JsGoal {
     }
 }
 impl GoalSampleableRegion<SE3State> for JsGoal {
-    fn sample_goal(&self, _rng: &mut impl rand::Rng) -> Result<SE3State, StateSamplingError> {
-        self.call_sample_goal()
+    fn sample_goal(&self, rng: &mut dyn RngCore) -> Result<SE3State, StateSamplingError> {
+        self.call_sample_goal(rng)
     }
 }
 
@@ -183,7 +191,7 @@ impl GoalRegion<CompoundState> for JsGoal {
     }
 }
 impl GoalSampleableRegion<CompoundState> for JsGoal {
-    fn sample_goal(&self, _rng: &mut impl rand::Rng) -> Result<CompoundState, StateSamplingError> {
-        self.call_sample_goal()
+    fn sample_goal(&self, rng: &mut dyn RngCore) -> Result<CompoundState, StateSamplingError> {
+        self.call_sample_goal(rng)
     }
 }
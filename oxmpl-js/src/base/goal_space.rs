@@ -0,0 +1,88 @@
+// Copyright (c) 2025 Junior Sundar
+//
+// SPDX-License-Identifier: BSD-3-Clause
+
+use oxmpl::base::goal::GoalSpace;
+use wasm_bindgen::prelude::*;
+
+use crate::base::{
+    builtin_goal::{JsDynGoal, TypedGoal},
+    JsCompoundStateSpace, JsRealVectorStateSpace, JsSE2StateSpace, JsSE3StateSpace,
+    JsSO2StateSpace, JsSO3StateSpace,
+};
+
+/// A goal defined by a bounded sub-space of the planning space.
+///
+/// The goal space is a state space of the same type as the planning space, but with tighter
+/// bounds. A state satisfies the goal if it lies within those bounds, and sampling the goal
+/// samples the goal space uniformly. Wrap it in a `Goal` to pass it to a `ProblemDefinition`.
+#[wasm_bindgen(js_name = GoalSpace)]
+pub struct JsGoalSpace {
+    inner: Box<dyn JsDynGoal>,
+}
+
+#[wasm_bindgen(js_class = GoalSpace)]
+impl JsGoalSpace {
+    #[wasm_bindgen(js_name = fromRealVectorStateSpace)]
+    pub fn from_real_vector_state_space(goal_space: &JsRealVectorStateSpace) -> Self {
+        let goal_space = goal_space.inner.lock().unwrap().clone();
+        Self {
+            inner: Box::new(TypedGoal::new(GoalSpace::new(goal_space))),
+        }
+    }
+
+    #[wasm_bindgen(js_name = fromSO2StateSpace)]
+    pub fn from_so2_state_space(goal_space: &JsSO2StateSpace) -> Self {
+        let goal_space = goal_space.inner.lock().unwrap().clone();
+        Self {
+            inner: Box::new(TypedGoal::new(GoalSpace::new(goal_space))),
+        }
+    }
+
+    #[wasm_bindgen(js_name = fromSO3StateSpace)]
+    pub fn from_so3_state_space(goal_space: &JsSO3StateSpace) -> Self {
+        let goal_space = goal_space.inner.lock().unwrap().clone();
+        Self {
+            inner: Box::new(TypedGoal::new(GoalSpace::new(goal_space))),
+        }
+    }
+
+    #[wasm_bindgen(js_name = fromSE2StateSpace)]
+    pub fn from_se2_state_space(goal_space: &JsSE2StateSpace) -> Self {
+        let goal_space = goal_space.inner.lock().unwrap().clone();
+        Self {
+            inner: Box::new(TypedGoal::new(GoalSpace::new(goal_space))),
+        }
+    }
+
+    #[wasm_bindgen(js_name = fromSE3StateSpace)]
+    pub fn from_se3_state_space(goal_space: &JsSE3StateSpace) -> Self {
+        let goal_space = goal_space.inner.lock().unwrap().clone();
+        Self {
+            inner: Box::new(TypedGoal::new(GoalSpace::new(goal_space))),
+        }
+    }
+
+    #[wasm_bindgen(js_name = fromCompoundStateSpace)]
+    pub fn from_compound_state_space(goal_space: &JsCompoundStateSpace) -> Self {
+        let goal_space = goal_space.inner.lock().unwrap().clone();
+        Self {
+            inner: Box::new(TypedGoal::new(GoalSpace::new(goal_space))),
+        }
+    }
+
+    #[wasm_bindgen(js_name = isSatisfied)]
+    pub fn is_satisfied(&self, state: JsValue) -> Result<bool, String> {
+        self.inner.is_satisfied(state)
+    }
+
+    #[wasm_bindgen(js_name = distanceGoal)]
+    pub fn distance_goal(&self, state: JsValue) -> Result<f64, String> {
+        self.inner.distance_goal(state)
+    }
+
+    #[wasm_bindgen(js_name = sampleGoal)]
+    pub fn sample_goal(&self) -> Result<JsValue, String> {
+        self.inner.sample_goal()
+    }
+}
@@ -0,0 +1,122 @@
+// Copyright (c) 2025 Junior Sundar
+//
+// SPDX-License-Identifier: BSD-3-Clause
+
+use std::sync::Arc;
+
+use oxmpl::base::goal::GoalState;
+use wasm_bindgen::prelude::*;
+
+use crate::base::{
+    builtin_goal::{JsDynGoal, TypedGoal},
+    JsCompoundState, JsCompoundStateSpace, JsRealVectorState, JsRealVectorStateSpace, JsSE2State,
+    JsSE2StateSpace, JsSE3State, JsSE3StateSpace, JsSO2State, JsSO2StateSpace, JsSO3State,
+    JsSO3StateSpace,
+};
+
+/// A goal defined by a single state and a tolerance around it.
+///
+/// A state satisfies the goal if its distance to the goal state, as measured by the space, is no
+/// more than `threshold`. Wrap it in a `Goal` to pass it to a `ProblemDefinition`.
+#[wasm_bindgen(js_name = GoalState)]
+pub struct JsGoalState {
+    inner: Box<dyn JsDynGoal>,
+}
+
+#[wasm_bindgen(js_class = GoalState)]
+impl JsGoalState {
+    #[wasm_bindgen(js_name = fromRealVectorState)]
+    pub fn from_real_vector_state(
+        space: &JsRealVectorStateSpace,
+        state: &JsRealVectorState,
+        threshold: f64,
+    ) -> Self {
+        let space = Arc::new(space.inner.lock().unwrap().clone());
+        Self {
+            inner: Box::new(TypedGoal::new(GoalState::new(
+                space,
+                (*state.inner).clone(),
+                threshold,
+            ))),
+        }
+    }
+
+    #[wasm_bindgen(js_name = fromSO2State)]
+    pub fn from_so2_state(space: &JsSO2StateSpace, state: &JsSO2State, threshold: f64) -> Self {
+        let space = Arc::new(space.inner.lock().unwrap().clone());
+        Self {
+            inner: Box::new(TypedGoal::new(GoalState::new(
+                space,
+                (*state.inner).clone(),
+                threshold,
+            ))),
+        }
+    }
+
+    #[wasm_bindgen(js_name = fromSO3State)]
+    pub fn from_so3_state(space: &JsSO3StateSpace, state: &JsSO3State, threshold: f64) -> Self {
+        let space = Arc::new(space.inner.lock().unwrap().clone());
+        Self {
+            inner: Box::new(TypedGoal::new(GoalState::new(
+                space,
+                (*state.inner).clone(),
+                threshold,
+            ))),
+        }
+    }
+
+    #[wasm_bindgen(js_name = fromSE2State)]
+    pub fn from_se2_state(space: &JsSE2StateSpace, state: &JsSE2State, threshold: f64) -> Self {
+        let space = Arc::new(space.inner.lock().unwrap().clone());
+        Self {
+            inner: Box::new(TypedGoal::new(GoalState::new(
+                space,
+                (*state.inner).clone(),
+                threshold,
+            ))),
+        }
+    }
+
+    #[wasm_bindgen(js_name = fromSE3State)]
+    pub fn from_se3_state(space: &JsSE3StateSpace, state: &JsSE3State, threshold: f64) -> Self {
+        let space = Arc::new(space.inner.lock().unwrap().clone());
+        Self {
+            inner: Box::new(TypedGoal::new(GoalState::new(
+                space,
+                (*state.inner).clone(),
+                threshold,
+            ))),
+        }
+    }
+
+    #[wasm_bindgen(js_name = fromCompoundState)]
+    pub fn from_compound_state(
+        space: &JsCompoundStateSpace,
+        state: &JsCompoundState,
+        threshold: f64,
+    ) -> Self {
+        let space = Arc::new(space.inner.lock().unwrap().clone());
+        Self {
+            inner: Box::new(TypedGoal::new(GoalState::new(
+                space,
+                (*state.inner).clone(),
+                threshold,
+            ))),
+        }
+    }
+
+    #[wasm_bindgen(js_name = isSatisfied)]
+    pub fn is_satisfied(&self, state: JsValue) -> Result<bool, String> {
+        self.inner.is_satisfied(state)
+    }
+
+    #[wasm_bindgen(js_name = distanceGoal)]
+    pub fn distance_goal(&self, state: JsValue) -> Result<f64, String> {
+        self.inner.distance_goal(state)
+    }
+
+    #[wasm_bindgen(js_name = sampleGoal)]
+    pub fn sample_goal(&self) -> Result<JsValue, String> {
+        self.inner.sample_goal()
+    }
+}
@@ -0,0 +1,125 @@
+// Copyright (c) 2025 Junior Sundar
+//
+// SPDX-License-Identifier: BSD-3-Clause
+
+use std::sync::Arc;
+
+use oxmpl::base::goal::GoalStates;
+use wasm_bindgen::prelude::*;
+
+use crate::base::{
+    builtin_goal::{JsDynGoalStates, TypedGoal},
+    JsCompoundStateSpace, JsRealVectorStateSpace, JsSE2StateSpace, JsSE3StateSpace,
+    JsSO2StateSpace, JsSO3StateSpace,
+};
+
+/// A goal defined by a set of states and a tolerance around each of them.
+///
+/// A state satisfies the goal if it is within `threshold` of any of the goal states, which are
+/// added with `addState`. Sampling the goal picks one of the goal states at random. Wrap it in a
+/// `Goal` to pass it to a `ProblemDefinition`.
+#[wasm_bindgen(js_name = GoalStates)]
+pub struct JsGoalStates {
+    inner: Box<dyn JsDynGoalStates>,
+}
+
+#[wasm_bindgen(js_class = GoalStates)]
+impl JsGoalStates {
+    #[wasm_bindgen(js_name = fromRealVectorStateSpace)]
+    pub fn from_real_vector_state_space(space: &JsRealVectorStateSpace, threshold: f64) -> Self {
+        let space = Arc::new(space.inner.lock().unwrap().clone());
+        Self {
+            inner: Box::new(TypedGoal::new(GoalStates::new(
+                space,
+                Vec::new(),
+                threshold,
+            ))),
+        }
+    }
+
+    #[wasm_bindgen(js_name = fromSO2StateSpace)]
+    pub fn from_so2_state_space(space: &JsSO2StateSpace, threshold: f64) -> Self {
+        let space = Arc::new(space.inner.lock().unwrap().clone());
+        Self {
+            inner: Box::new(TypedGoal::new(GoalStates::new(
+                space,
+                Vec::new(),
+                threshold,
+            ))),
+        }
+    }
+
+    #[wasm_bindgen(js_name = fromSO3StateSpace)]
+    pub fn from_so3_state_space(space: &JsSO3StateSpace, threshold: f64) -> Self {
+        let space = Arc::new(space.inner.lock().unwrap().clone());
+        Self {
+            inner: Box::new(TypedGoal::new(GoalStates::new(
+                space,
+                Vec::new(),
+                threshold,
+            ))),
+        }
+    }
+
+    #[wasm_bindgen(js_name = fromSE2StateSpace)]
+    pub fn from_se2_state_space(space: &JsSE2StateSpace, threshold: f64) -> Self {
+        let space = Arc::new(space.inner.lock().unwrap().clone());
+        Self {
+            inner: Box::new(TypedGoal::new(GoalStates::new(
+                space,
+                Vec::new(),
+                threshold,
+            ))),
+        }
+    }
+
+    #[wasm_bindgen(js_name = fromSE3StateSpace)]
+    pub fn from_se3_state_space(space: &JsSE3StateSpace, threshold: f64) -> Self {
+        let space = Arc::new(space.inner.lock().unwrap().clone());
+        Self {
+            inner: Box::new(TypedGoal::new(GoalStates::new(
+                space,
+                Vec::new(),
+                threshold,
+            ))),
+        }
+    }
+
+    #[wasm_bindgen(js_name = fromCompoundStateSpace)]
+    pub fn from_compound_state_space(space: &JsCompoundStateSpace, threshold: f64) -> Self {
+        let space = Arc::new(space.inner.lock().unwrap().clone());
+        Self {
+            inner: Box::new(TypedGoal::new(GoalStates::new(
+                space,
+                Vec::new(),
+                threshold,
+            ))),
+        }
+    }
+
+    /// Adds a state to the set of goal states.
+    #[wasm_bindgen(js_name = addState)]
+    pub fn add_state(&mut self, state: JsValue) -> Result<(), String> {
+        self.inner.add_state(state)
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn length(&self) -> usize {
+        self.inner.state_count()
+    }
+
+    #[wasm_bindgen(js_name = isSatisfied)]
+    pub fn is_satisfied(&self, state: JsValue) -> Result<bool, String> {
+        self.inner.is_satisfied(state)
+    }
+
+    #[wasm_bindgen(js_name = distanceGoal)]
+    pub fn distance_goal(&self, state: JsValue) -> Result<f64, String> {
+        self.inner.distance_goal(state)
+    }
+
+    #[wasm_bindgen(js_name = sampleGoal)]
+    pub fn sample_goal(&self) -> Result<JsValue, String> {
+        self.inner.sample_goal()
+    }
+}
@@ -2,12 +2,18 @@
 //
 // SPDX-License-Identifier: BSD-3-Clause
 
+pub mod builtin_goal;
 pub mod compound_state;
 pub mod compound_state_space;
 pub mod goal;
+pub mod goal_space;
+pub mod goal_state;
+pub mod goal_states;
 pub mod js_state_convert;
 pub mod path;
 pub mod planner;
+pub mod planner_observer;
+pub mod pose_goals;
 pub mod problem_definition;
 pub mod real_vector_state;
 pub mod real_vector_state_space;
@@ -24,8 +30,13 @@ pub mod state_validity_checker;
 pub use compound_state::{JsCompoundState, JsCompoundStateBuilder};
 pub use compound_state_space::{JsCompoundStateSpace, JsCompoundStateSpaceBuilder};
 pub use goal::JsGoal;
+pub use goal_space::JsGoalSpace;
+pub use goal_state::JsGoalState;
+pub use goal_states::JsGoalStates;
 pub use path::JsPath;
 pub use planner::JsPlannerConfig;
+pub use planner_observer::JsPlannerObserver;
+pub use pose_goals::{JsSE2PoseGoal, JsSE3PoseGoal};
 pub use problem_definition::JsProblemDefinition;
 pub use real_vector_state::JsRealVectorState;
 pub use real_vector_state_space::JsRealVectorStateSpace;
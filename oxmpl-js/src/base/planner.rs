@@ -2,7 +2,10 @@
 //
 // SPDX-License-Identifier: BSD-3-Clause
 
-use oxmpl::base::planner::PlannerConfig;
+use oxmpl::base::{
+    param::ParamSet,
+    planner::{PlannerConfig, PlannerStats},
+};
 use wasm_bindgen::prelude::*;
 
 #[wasm_bindgen(js_name = PlannerConfig)]
@@ -10,13 +13,27 @@ pub struct JsPlannerConfig {
     seed: Option<u64>,
 }
 
+/// The largest integer a JavaScript number holds exactly, `Number.MAX_SAFE_INTEGER`.
+const MAX_SAFE_INTEGER: f64 = 9_007_199_254_740_991.0;
+
 #[wasm_bindgen(js_class = PlannerConfig)]
 impl JsPlannerConfig {
+    /// Creates a planner configuration. `seed` must be an integer from 0 to
+    /// `Number.MAX_SAFE_INTEGER`, so that it seeds the planner exactly as in Rust and Python.
     #[wasm_bindgen(constructor)]
-    pub fn new(seed: Option<f64>) -> Self {
-        Self {
-            seed: seed.map(|s| s as u64),
-        }
+    pub fn new(seed: Option<f64>) -> Result<JsPlannerConfig, String> {
+        let seed = seed
+            .map(|seed| {
+                if seed.fract() == 0.0 && (0.0..=MAX_SAFE_INTEGER).contains(&seed) {
+                    Ok(seed as u64)
+                } else {
+                    Err(format!(
+                        "The seed must be an integer from 0 to {MAX_SAFE_INTEGER}, got {seed}."
+                    ))
+                }
+            })
+            .transpose()?;
+        Ok(Self { seed })
     }
 }
 
@@ -27,3 +44,25 @@ impl From<&JsPlannerConfig> for PlannerConfig {
         }
     }
 }
+
+/// Converts `PlannerStats` into a plain object, with the phase times in seconds.
+pub fn stats_to_js(stats: &PlannerStats) -> JsValue {
+    js_sys::JSON::parse(&stats.to_json()).unwrap_or(JsValue::NULL)
+}
+
+/// Converts a `ParamSet` into a plain object mapping each parameter's name to an object of its
+/// `value`, `description`, `min` and `max`. Infinite bounds are `null`.
+pub fn params_to_js(params: &ParamSet) -> JsValue {
+    js_sys::JSON::parse(&params.to_json()).unwrap_or(JsValue::NULL)
+}
+
+/// Rejects parameter values the WebAssembly build cannot honour. WebAssembly modules built by
+/// `wasm-bindgen` run on a single thread, so `num_threads` must stay at 1.
+pub fn check_js_param(name: &str, value: &str) -> Result<(), String> {
+    if name == "num_threads" && value.trim() != "1" {
+        return Err(
+            "num_threads cannot be changed from JavaScript, which runs on one thread.".to_string(),
+        );
+    }
+    Ok(())
+}
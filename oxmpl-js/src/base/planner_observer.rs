@@ -0,0 +1,84 @@
+// Copyright (c) 2025 Ross Gardiner, Junior Sundar
+//
+// SPDX-License-Identifier: BSD-3-Clause
+
+use crate::base::{js_state_convert::JsStateConvert, path::JsPath};
+use js_sys::{Array, Function, Reflect};
+use oxmpl::base::{observer::PlannerObserver, planner::Path as OxmplPath, state::State};
+use wasm_bindgen::prelude::*;
+use web_sys::console;
+
+/// Implements `PlannerObserver` by calling the methods of a JS object.
+///
+/// Only the methods the object defines are called, so it can implement as few of `onIteration`,
+/// `onNodeAdded`, `onEdgeAdded`, `onEdgeRemoved`, `onSampleRejected` and `onSolution` as it likes.
+pub struct JsPlannerObserver {
+    observer: JsValue,
+}
+
+// SAFETY: `PlannerObserver` requires `Send + Sync` so that observers can be shared between
+// planner threads. WebAssembly modules built by `wasm-bindgen` run on a single thread, so the
+// wrapped JS object is never actually sent to or shared with another thread. `lib.rs` refuses to
+// build with the `atomics` target feature, which would allow a threaded module.
+unsafe impl Send for JsPlannerObserver {}
+unsafe impl Sync for JsPlannerObserver {}
+
+impl JsPlannerObserver {
+    pub fn new(observer: JsValue) -> Self {
+        Self { observer }
+    }
+
+    /// Calls `method` on the JS object with `args`, if it defines one.
+    fn notify(&self, method: &str, args: impl FnOnce() -> Array) {
+        let Ok(callback) = Reflect::get(&self.observer, &JsValue::from_str(method)) else {
+            return;
+        };
+        let Some(callback) = callback.dyn_ref::<Function>() else {
+            return;
+        };
+        if let Err(e) = callback.apply(&self.observer, &args()) {
+            console::error_2(&format!("Planner observer {method} failed:").into(), &e);
+        }
+    }
+}
+
+impl<S> PlannerObserver<S> for JsPlannerObserver
+where
+    S: State + Clone + JsStateConvert,
+    OxmplPath<S>: Into<JsPath>,
+{
+    fn on_iteration(&self, iteration: u64) {
+        self.notify("onIteration", || {
+            Array::of1(&JsValue::from_f64(iteration as f64))
+        });
+    }
+
+    fn on_node_added(&self, state: &S) {
+        self.notify("onNodeAdded", || Array::of1(&state.to_js_value()));
+    }
+
+    fn on_edge_added(&self, from: &S, to: &S) {
+        self.notify("onEdgeAdded", || {
+            Array::of2(&from.to_js_value(), &to.to_js_value())
+        });
+    }
+
+    fn on_edge_removed(&self, from: &S, to: &S) {
+        self.notify("onEdgeRemoved", || {
+            Array::of2(&from.to_js_value(), &to.to_js_value())
+        });
+    }
+
+    fn on_sample_rejected(&self, sample: Option<&S>) {
+        self.notify("onSampleRejected", || {
+            Array::of1(&sample.map_or(JsValue::NULL, |state| state.to_js_value()))
+        });
+    }
+
+    fn on_solution(&self, path: &OxmplPath<S>, length: f64) {
+        self.notify("onSolution", || {
+            let path: JsPath = path.clone().into();
+            Array::of2(&JsValue::from(path), &JsValue::from_f64(length))
+        });
+    }
+}
@@ -0,0 +1,138 @@
+// Copyright (c) 2025 Junior Sundar
+//
+// SPDX-License-Identifier: BSD-3-Clause
+
+use oxmpl::base::{
+    goal::{RotationAxis, SE2PoseGoal, SE3PoseGoal},
+    state::{SE2State, SE3State},
+};
+use wasm_bindgen::prelude::*;
+
+use crate::base::{
+    builtin_goal::{JsDynGoal, TypedGoal},
+    JsSE2State, JsSE3State,
+};
+
+/// A goal for `SE2State`s with separate position and orientation tolerances.
+///
+/// A state satisfies the goal if its position is within `positionTolerance` of the target's and
+/// its yaw is within `orientationTolerance` radians of the target's. Wrap it in a `Goal` to pass
+/// it to a `ProblemDefinition`.
+#[wasm_bindgen(js_name = SE2PoseGoal)]
+pub struct JsSE2PoseGoal {
+    inner: TypedGoal<SE2PoseGoal, SE2State>,
+}
+
+#[wasm_bindgen(js_class = SE2PoseGoal)]
+impl JsSE2PoseGoal {
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        target: &JsSE2State,
+        position_tolerance: f64,
+        orientation_tolerance: f64,
+        yaw_free: Option<bool>,
+    ) -> Self {
+        let mut goal = SE2PoseGoal::new(
+            (*target.inner).clone(),
+            position_tolerance,
+            orientation_tolerance,
+        );
+        goal.yaw_free = yaw_free.unwrap_or(false);
+        Self {
+            inner: TypedGoal::new(goal),
+        }
+    }
+
+    #[wasm_bindgen(js_name = positionError)]
+    pub fn position_error(&self, state: &JsSE2State) -> f64 {
+        self.inner.goal.position_error(&state.inner)
+    }
+
+    #[wasm_bindgen(js_name = orientationError)]
+    pub fn orientation_error(&self, state: &JsSE2State) -> f64 {
+        self.inner.goal.orientation_error(&state.inner)
+    }
+
+    #[wasm_bindgen(js_name = isSatisfied)]
+    pub fn is_satisfied(&self, state: JsValue) -> Result<bool, String> {
+        self.inner.is_satisfied(state)
+    }
+
+    #[wasm_bindgen(js_name = distanceGoal)]
+    pub fn distance_goal(&self, state: JsValue) -> Result<f64, String> {
+        self.inner.distance_goal(state)
+    }
+
+    #[wasm_bindgen(js_name = sampleGoal)]
+    pub fn sample_goal(&self) -> Result<JsValue, String> {
+        self.inner.sample_goal()
+    }
+}
+
+/// A goal for `SE3State`s with separate position and orientation tolerances.
+///
+/// A state satisfies the goal if its position is within `positionTolerance` of the target's and
+/// the angle between its orientation and the target's is within `orientationTolerance` radians.
+/// If `freeAxis` is one of "x", "y" or "z", rotation about that axis of the target's frame is not
+/// counted. Only one axis can be free; to free the orientation entirely, give an
+/// `orientationTolerance` of pi. Wrap it in a `Goal` to pass it to a `ProblemDefinition`.
+#[wasm_bindgen(js_name = SE3PoseGoal)]
+pub struct JsSE3PoseGoal {
+    inner: TypedGoal<SE3PoseGoal, SE3State>,
+}
+
+#[wasm_bindgen(js_class = SE3PoseGoal)]
+impl JsSE3PoseGoal {
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        target: &JsSE3State,
+        position_tolerance: f64,
+        orientation_tolerance: f64,
+        free_axis: Option<String>,
+    ) -> Result<JsSE3PoseGoal, String> {
+        let mut goal = SE3PoseGoal::new(
+            (*target.inner).clone(),
+            position_tolerance,
+            orientation_tolerance,
+        );
+        goal.free_axis = match free_axis.as_deref() {
+            None => None,
+            Some("x") => Some(RotationAxis::X),
+            Some("y") => Some(RotationAxis::Y),
+            Some("z") => Some(RotationAxis::Z),
+            Some(other) => {
+                return Err(format!(
+                    "Invalid free axis '{other}': expected 'x', 'y' or 'z'."
+                ))
+            }
+        };
+        Ok(Self {
+            inner: TypedGoal::new(goal),
+        })
+    }
+
+    #[wasm_bindgen(js_name = positionError)]
+    pub fn position_error(&self, state: &JsSE3State) -> f64 {
+        self.inner.goal.position_error(&state.inner)
+    }
+
+    #[wasm_bindgen(js_name = orientationError)]
+    pub fn orientation_error(&self, state: &JsSE3State) -> f64 {
+        self.inner.goal.orientation_error(&state.inner)
+    }
+
+    #[wasm_bindgen(js_name = isSatisfied)]
+    pub fn is_satisfied(&self, state: JsValue) -> Result<bool, String> {
+        self.inner.is_satisfied(state)
+    }
+
+    #[wasm_bindgen(js_name = distanceGoal)]
+    pub fn distance_goal(&self, state: JsValue) -> Result<f64, String> {
+        self.inner.distance_goal(state)
+    }
+
+    #[wasm_bindgen(js_name = sampleGoal)]
+    pub fn sample_goal(&self) -> Result<JsValue, String> {
+        self.inner.sample_goal()
+    }
+}
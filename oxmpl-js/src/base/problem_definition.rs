@@ -8,6 +8,7 @@ use crate::base::{
     se2_state::JsSE2State, se2_state_space::JsSE2StateSpace, se3_state::JsSE3State,
     se3_state_space::JsSE3StateSpace, so2_state::JsSO2State, so2_state_space::JsSO2StateSpace,
     so3_state::JsSO3State, so3_state_space::JsSO3StateSpace,
+    state_validity_checker::JsStateValidityChecker,
 };
 use oxmpl::base::{
     problem_definition::ProblemDefinition,
@@ -20,6 +21,7 @@ use oxmpl::base::{
 use std::sync::Arc;
 use wasm_bindgen::prelude::*;
 
+#[derive(Clone)]
 pub enum ProblemDefinitionVariant {
     RealVector(Arc<ProblemDefinition<RealVectorState, RealVectorStateSpace, JsGoal>>),
     SO2(Arc<ProblemDefinition<SO2State, SO2StateSpace, JsGoal>>),
@@ -115,4 +117,28 @@ impl JsProblemDefinition {
             inner: ProblemDefinitionVariant::Compound(Arc::new(pd)),
         }
     }
+
+    /// Checks the problem for mistakes that would stop any planner from solving it.
+    ///
+    /// Returns a description of each problem found, so an empty array means none were found. Errors
+    /// come first, followed by warnings, which start with "Warning: " and may be false alarms, as
+    /// they are only suspected from a few goal samples.
+    #[wasm_bindgen(js_name = validate)]
+    pub fn validate(&self, validity_checker: &JsStateValidityChecker) -> Vec<String> {
+        let mut rng = rand::rng();
+        let report = match &self.inner {
+            ProblemDefinitionVariant::RealVector(pd) => pd.validate(validity_checker, &mut rng),
+            ProblemDefinitionVariant::SO2(pd) => pd.validate(validity_checker, &mut rng),
+            ProblemDefinitionVariant::SO3(pd) => pd.validate(validity_checker, &mut rng),
+            ProblemDefinitionVariant::SE2(pd) => pd.validate(validity_checker, &mut rng),
+            ProblemDefinitionVariant::SE3(pd) => pd.validate(validity_checker, &mut rng),
+            ProblemDefinitionVariant::Compound(pd) => pd.validate(validity_checker, &mut rng),
+        };
+        report
+            .errors
+            .iter()
+            .map(ToString::to_string)
+            .chain(report.warnings.iter().map(|w| format!("Warning: {w}")))
+            .collect()
+    }
 }
@@ -32,6 +32,13 @@ impl JsStateValidityChecker {
     }
 }
 
+// SAFETY: `StateValidityChecker` requires `Send + Sync` so that checkers can be shared between
+// planner threads. WebAssembly modules built by `wasm-bindgen` run on a single thread, so the
+// wrapped JS function is never actually sent to or shared with another thread. `lib.rs` refuses to
+// build with the `atomics` target feature, which would allow a threaded module.
+unsafe impl Send for JsStateValidityChecker {}
+unsafe impl Sync for JsStateValidityChecker {}
+
 impl JsStateValidityChecker {
     fn call_is_valid<S: JsStateConvert + State>(&self, state: &S) -> bool {
         let js_state = state.to_js_value();
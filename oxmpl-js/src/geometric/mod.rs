@@ -2,11 +2,13 @@
 //
 // SPDX-License-Identifier: BSD-3-Clause
 
+mod planner;
 mod prm;
 mod rrt;
 mod rrt_connect;
 mod rrt_star;
 
+pub use planner::JsPlanner;
 pub use prm::JsPRM;
 pub use rrt::JsRRT;
 pub use rrt_connect::JsRRTConnect;
@@ -0,0 +1,206 @@
+// Copyright (c) 2025 Junior Sundar
+//
+// SPDX-License-Identifier: BSD-3-Clause
+
+use crate::base::{
+    goal::JsGoal,
+    path::JsPath,
+    planner::{check_js_param, params_to_js, stats_to_js, JsPlannerConfig},
+    planner_observer::JsPlannerObserver,
+    problem_definition::{JsProblemDefinition, ProblemDefinitionVariant},
+    state_validity_checker::JsStateValidityChecker,
+};
+use oxmpl::{
+    base::{
+        goal::GoalSampleableRegion,
+        space::{
+            CompoundStateSpace, RealVectorStateSpace, SE2StateSpace, SE3StateSpace, SO2StateSpace,
+            SO3StateSpace, StateSpace,
+        },
+        state::{CompoundState, RealVectorState, SE2State, SE3State, SO2State, SO3State, State},
+    },
+    geometric::{BoxedPlanner, PlannerRegistry},
+};
+use std::sync::Arc;
+use std::time::Duration;
+use wasm_bindgen::prelude::*;
+
+/// Planners that grow trees on their own threads, which WebAssembly cannot spawn.
+const THREADED_PLANNERS: [&str; 1] = ["CForest"];
+
+/// Returns the geometric planners that can be used from JavaScript.
+fn registry<S, SP>() -> PlannerRegistry<S, SP, JsGoal>
+where
+    S: State + Clone,
+    SP: StateSpace<StateType = S> + 'static,
+    JsGoal: GoalSampleableRegion<S>,
+{
+    let mut registry = PlannerRegistry::with_geometric_planners();
+    for name in THREADED_PLANNERS {
+        registry.remove(name);
+    }
+    registry
+}
+
+enum PlannerVariant {
+    RealVector(BoxedPlanner<RealVectorState, RealVectorStateSpace, JsGoal>),
+    SO2(BoxedPlanner<SO2State, SO2StateSpace, JsGoal>),
+    SO3(BoxedPlanner<SO3State, SO3StateSpace, JsGoal>),
+    Compound(BoxedPlanner<CompoundState, CompoundStateSpace, JsGoal>),
+    SE2(BoxedPlanner<SE2State, SE2StateSpace, JsGoal>),
+    SE3(BoxedPlanner<SE3State, SE3StateSpace, JsGoal>),
+}
+
+/// A geometric planner chosen by name, such as `"RRTConnect"` or `"PRM"`.
+#[wasm_bindgen(js_name = Planner)]
+pub struct JsPlanner {
+    planner: PlannerVariant,
+    pd: ProblemDefinitionVariant,
+}
+
+#[wasm_bindgen(js_class = Planner)]
+impl JsPlanner {
+    /// Creates the planner registered under `name`, one of `Planner.names()`.
+    ///
+    /// The planner's ranges and radii start at 0, so they are derived from the state space when
+    /// the planner is set up. Roadmap planners such as PRM build their roadmap on the first call
+    /// to `solve` after `setup`.
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        name: &str,
+        problem_def: &JsProblemDefinition,
+        config: &JsPlannerConfig,
+    ) -> Result<JsPlanner, String> {
+        let planner_config = config.into();
+        let planner = match &problem_def.inner {
+            ProblemDefinitionVariant::RealVector(_) => registry()
+                .create(name, &planner_config)
+                .map(PlannerVariant::RealVector),
+            ProblemDefinitionVariant::SO2(_) => registry()
+                .create(name, &planner_config)
+                .map(PlannerVariant::SO2),
+            ProblemDefinitionVariant::SO3(_) => registry()
+                .create(name, &planner_config)
+                .map(PlannerVariant::SO3),
+            ProblemDefinitionVariant::Compound(_) => registry()
+                .create(name, &planner_config)
+                .map(PlannerVariant::Compound),
+            ProblemDefinitionVariant::SE2(_) => registry()
+                .create(name, &planner_config)
+                .map(PlannerVariant::SE2),
+            ProblemDefinitionVariant::SE3(_) => registry()
+                .create(name, &planner_config)
+                .map(PlannerVariant::SE3),
+        }
+        .map_err(|e| e.to_string())?;
+        Ok(Self {
+            planner,
+            pd: problem_def.inner.clone(),
+        })
+    }
+
+    /// Returns the names of the planners that can be created.
+    pub fn names() -> Vec<String> {
+        registry::<RealVectorState, RealVectorStateSpace>()
+            .names()
+            .map(str::to_string)
+            .collect()
+    }
+
+    pub fn setup(&mut self, validity_checker: &JsStateValidityChecker) {
+        let checker = Arc::new(validity_checker.clone());
+        match (&mut self.planner, &self.pd) {
+            (PlannerVariant::RealVector(p), ProblemDefinitionVariant::RealVector(pd)) => {
+                p.setup(pd.clone(), checker)
+            }
+            (PlannerVariant::SO2(p), ProblemDefinitionVariant::SO2(pd)) => {
+                p.setup(pd.clone(), checker)
+            }
+            (PlannerVariant::SO3(p), ProblemDefinitionVariant::SO3(pd)) => {
+                p.setup(pd.clone(), checker)
+            }
+            (PlannerVariant::Compound(p), ProblemDefinitionVariant::Compound(pd)) => {
+                p.setup(pd.clone(), checker)
+            }
+            (PlannerVariant::SE2(p), ProblemDefinitionVariant::SE2(pd)) => {
+                p.setup(pd.clone(), checker)
+            }
+            (PlannerVariant::SE3(p), ProblemDefinitionVariant::SE3(pd)) => {
+                p.setup(pd.clone(), checker)
+            }
+            _ => unreachable!("the planner is created for the problem definition's state space"),
+        }
+    }
+
+    pub fn solve(&mut self, timeout_secs: f32) -> Result<JsPath, String> {
+        let timeout = Duration::from_secs_f32(timeout_secs);
+        match &mut self.planner {
+            PlannerVariant::RealVector(p) => p.solve(timeout).map(JsPath::from),
+            PlannerVariant::SO2(p) => p.solve(timeout).map(JsPath::from),
+            PlannerVariant::SO3(p) => p.solve(timeout).map(JsPath::from),
+            PlannerVariant::Compound(p) => p.solve(timeout).map(JsPath::from),
+            PlannerVariant::SE2(p) => p.solve(timeout).map(JsPath::from),
+            PlannerVariant::SE3(p) => p.solve(timeout).map(JsPath::from),
+        }
+        .map_err(|e| e.to_string())
+    }
+
+    /// Returns the statistics collected since the planner was last set up, as an object with the
+    /// phase times in seconds.
+    pub fn stats(&self) -> JsValue {
+        let stats = match &self.planner {
+            PlannerVariant::RealVector(p) => p.stats(),
+            PlannerVariant::SO2(p) => p.stats(),
+            PlannerVariant::SO3(p) => p.stats(),
+            PlannerVariant::Compound(p) => p.stats(),
+            PlannerVariant::SE2(p) => p.stats(),
+            PlannerVariant::SE3(p) => p.stats(),
+        };
+        stats_to_js(&stats)
+    }
+
+    /// Returns the planner's parameters, as an object mapping each name to an object of its
+    /// `value`, `description`, `min` and `max`. Infinite bounds are `null`.
+    pub fn params(&self) -> JsValue {
+        let params = match &self.planner {
+            PlannerVariant::RealVector(p) => p.params(),
+            PlannerVariant::SO2(p) => p.params(),
+            PlannerVariant::SO3(p) => p.params(),
+            PlannerVariant::Compound(p) => p.params(),
+            PlannerVariant::SE2(p) => p.params(),
+            PlannerVariant::SE3(p) => p.params(),
+        };
+        params_to_js(&params)
+    }
+
+    /// Sets a parameter by name, parsing `value` as the parameter's type. Takes effect from the
+    /// next call to `setup`. `num_threads` cannot be changed from JavaScript.
+    #[wasm_bindgen(js_name = setParam)]
+    pub fn set_param(&mut self, name: &str, value: &str) -> Result<(), String> {
+        check_js_param(name, value)?;
+        match &mut self.planner {
+            PlannerVariant::RealVector(p) => p.set_param(name, value),
+            PlannerVariant::SO2(p) => p.set_param(name, value),
+            PlannerVariant::SO3(p) => p.set_param(name, value),
+            PlannerVariant::Compound(p) => p.set_param(name, value),
+            PlannerVariant::SE2(p) => p.set_param(name, value),
+            PlannerVariant::SE3(p) => p.set_param(name, value),
+        }
+        .map_err(|e| e.to_string())
+    }
+
+    /// Sets an object to be told about the planner's progress while it plans, as for the
+    /// `setObserver` method of the other planners.
+    #[wasm_bindgen(js_name = setObserver)]
+    pub fn set_observer(&mut self, observer: JsValue) {
+        let observer = Arc::new(JsPlannerObserver::new(observer));
+        match &mut self.planner {
+            PlannerVariant::RealVector(p) => p.set_observer(observer),
+            PlannerVariant::SO2(p) => p.set_observer(observer),
+            PlannerVariant::SO3(p) => p.set_observer(observer),
+            PlannerVariant::Compound(p) => p.set_observer(observer),
+            PlannerVariant::SE2(p) => p.set_observer(observer),
+            PlannerVariant::SE3(p) => p.set_observer(observer),
+        }
+    }
+}
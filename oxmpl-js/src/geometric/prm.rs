@@ -5,7 +5,8 @@
 use crate::base::{
     goal::JsGoal,
     path::JsPath,
-    planner::JsPlannerConfig,
+    planner::{check_js_param, params_to_js, stats_to_js, JsPlannerConfig},
+    planner_observer::JsPlannerObserver,
     problem_definition::{JsProblemDefinition, ProblemDefinitionVariant},
     state_validity_checker::JsStateValidityChecker,
 };
@@ -176,4 +177,77 @@ impl JsPRM {
                 .map_err(|e| e.to_string()),
         }
     }
+
+    /// Returns the statistics collected since the planner was last set up, as an object with the
+    /// phase times in seconds.
+    pub fn stats(&self) -> JsValue {
+        let stats = match &self.planner {
+            PRMVariant::RealVector(p) => p.stats(),
+            PRMVariant::SO2(p) => p.stats(),
+            PRMVariant::SO3(p) => p.stats(),
+            PRMVariant::Compound(p) => p.stats(),
+            PRMVariant::SE2(p) => p.stats(),
+            PRMVariant::SE3(p) => p.stats(),
+        };
+        stats_to_js(&stats)
+    }
+
+    /// Returns the planner's parameters, as an object mapping each name to an object of its
+    /// `value`, `description`, `min` and `max`. Infinite bounds are `null`.
+    pub fn params(&self) -> JsValue {
+        let params = match &self.planner {
+            PRMVariant::RealVector(p) => p.params(),
+            PRMVariant::SO2(p) => p.params(),
+            PRMVariant::SO3(p) => p.params(),
+            PRMVariant::Compound(p) => p.params(),
+            PRMVariant::SE2(p) => p.params(),
+            PRMVariant::SE3(p) => p.params(),
+        };
+        params_to_js(&params)
+    }
+
+    /// Sets a parameter by name, parsing `value` as the parameter's type. Takes effect from the
+    /// next call to `setup`. `num_threads` cannot be changed from JavaScript.
+    #[wasm_bindgen(js_name = setParam)]
+    pub fn set_param(&mut self, name: &str, value: &str) -> Result<(), String> {
+        check_js_param(name, value)?;
+        match &mut self.planner {
+            PRMVariant::RealVector(p) => p.set_param(name, value),
+            PRMVariant::SO2(p) => p.set_param(name, value),
+            PRMVariant::SO3(p) => p.set_param(name, value),
+            PRMVariant::Compound(p) => p.set_param(name, value),
+            PRMVariant::SE2(p) => p.set_param(name, value),
+            PRMVariant::SE3(p) => p.set_param(name, value),
+        }
+        .map_err(|e| e.to_string())
+    }
+
+    /// Sets an object to be told about the planner's progress while it plans.
+    ///
+    /// The object may define any of `onIteration(iteration)`, `onNodeAdded(state)`,
+    /// `onEdgeAdded(from, to)`, `onEdgeRemoved(from, to)`, `onSampleRejected(state)`, where
+    /// `state` is `null` if sampling failed, and `onSolution(path, length)`.
+    #[wasm_bindgen(js_name = setObserver)]
+    pub fn set_observer(&mut self, observer: JsValue) {
+        match &mut self.planner {
+            PRMVariant::RealVector(p) => p.set_observer(Arc::new(JsPlannerObserver::new(observer))),
+            PRMVariant::SO2(p) => p.set_observer(Arc::new(JsPlannerObserver::new(observer))),
+            PRMVariant::SO3(p) => p.set_observer(Arc::new(JsPlannerObserver::new(observer))),
+            PRMVariant::Compound(p) => p.set_observer(Arc::new(JsPlannerObserver::new(observer))),
+            PRMVariant::SE2(p) => p.set_observer(Arc::new(JsPlannerObserver::new(observer))),
+            PRMVariant::SE3(p) => p.set_observer(Arc::new(JsPlannerObserver::new(observer))),
+        }
+    }
+
+    /// Discards the roadmap.
+    pub fn clear(&mut self) {
+        match &mut self.planner {
+            PRMVariant::RealVector(p) => p.clear(),
+            PRMVariant::SO2(p) => p.clear(),
+            PRMVariant::SO3(p) => p.clear(),
+            PRMVariant::Compound(p) => p.clear(),
+            PRMVariant::SE2(p) => p.clear(),
+            PRMVariant::SE3(p) => p.clear(),
+        }
+    }
 }
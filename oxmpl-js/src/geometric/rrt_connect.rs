@@ -5,7 +5,8 @@
 use crate::base::{
     goal::JsGoal,
     path::JsPath,
-    planner::JsPlannerConfig,
+    planner::{check_js_param, params_to_js, stats_to_js, JsPlannerConfig},
+    planner_observer::JsPlannerObserver,
     problem_definition::{JsProblemDefinition, ProblemDefinitionVariant},
     state_validity_checker::JsStateValidityChecker,
 };
@@ -164,4 +165,69 @@ impl JsRRTConnect {
                 .map_err(|e| e.to_string()),
         }
     }
+
+    /// Returns the statistics collected since the planner was last set up, as an object with the
+    /// phase times in seconds.
+    pub fn stats(&self) -> JsValue {
+        let stats = match &self.planner {
+            RRTConnectVariant::RealVector(p) => p.stats(),
+            RRTConnectVariant::SO2(p) => p.stats(),
+            RRTConnectVariant::SO3(p) => p.stats(),
+            RRTConnectVariant::Compound(p) => p.stats(),
+            RRTConnectVariant::SE2(p) => p.stats(),
+            RRTConnectVariant::SE3(p) => p.stats(),
+        };
+        stats_to_js(&stats)
+    }
+
+    /// Returns the planner's parameters, as an object mapping each name to an object of its
+    /// `value`, `description`, `min` and `max`. Infinite bounds are `null`.
+    pub fn params(&self) -> JsValue {
+        let params = match &self.planner {
+            RRTConnectVariant::RealVector(p) => p.params(),
+            RRTConnectVariant::SO2(p) => p.params(),
+            RRTConnectVariant::SO3(p) => p.params(),
+            RRTConnectVariant::Compound(p) => p.params(),
+            RRTConnectVariant::SE2(p) => p.params(),
+            RRTConnectVariant::SE3(p) => p.params(),
+        };
+        params_to_js(&params)
+    }
+
+    /// Sets a parameter by name, parsing `value` as the parameter's type. Takes effect from the
+    /// next call to `setup`. `num_threads` cannot be changed from JavaScript.
+    #[wasm_bindgen(js_name = setParam)]
+    pub fn set_param(&mut self, name: &str, value: &str) -> Result<(), String> {
+        check_js_param(name, value)?;
+        match &mut self.planner {
+            RRTConnectVariant::RealVector(p) => p.set_param(name, value),
+            RRTConnectVariant::SO2(p) => p.set_param(name, value),
+            RRTConnectVariant::SO3(p) => p.set_param(name, value),
+            RRTConnectVariant::Compound(p) => p.set_param(name, value),
+            RRTConnectVariant::SE2(p) => p.set_param(name, value),
+            RRTConnectVariant::SE3(p) => p.set_param(name, value),
+        }
+        .map_err(|e| e.to_string())
+    }
+
+    /// Sets an object to be told about the planner's progress while it plans.
+    ///
+    /// The object may define any of `onIteration(iteration)`, `onNodeAdded(state)`,
+    /// `onEdgeAdded(from, to)`, `onEdgeRemoved(from, to)`, `onSampleRejected(state)`, where
+    /// `state` is `null` if sampling failed, and `onSolution(path, length)`.
+    #[wasm_bindgen(js_name = setObserver)]
+    pub fn set_observer(&mut self, observer: JsValue) {
+        match &mut self.planner {
+            RRTConnectVariant::RealVector(p) => {
+                p.set_observer(Arc::new(JsPlannerObserver::new(observer)))
+            }
+            RRTConnectVariant::SO2(p) => p.set_observer(Arc::new(JsPlannerObserver::new(observer))),
+            RRTConnectVariant::SO3(p) => p.set_observer(Arc::new(JsPlannerObserver::new(observer))),
+            RRTConnectVariant::Compound(p) => {
+                p.set_observer(Arc::new(JsPlannerObserver::new(observer)))
+            }
+            RRTConnectVariant::SE2(p) => p.set_observer(Arc::new(JsPlannerObserver::new(observer))),
+            RRTConnectVariant::SE3(p) => p.set_observer(Arc::new(JsPlannerObserver::new(observer))),
+        }
+    }
 }
@@ -4,8 +4,9 @@
 
 use crate::base::{
     goal::JsGoal,
-    path::JsPath,
-    planner::JsPlannerConfig,
+    path::{JsPath, PathVariant},
+    planner::{check_js_param, params_to_js, stats_to_js, JsPlannerConfig},
+    planner_observer::JsPlannerObserver,
     problem_definition::{JsProblemDefinition, ProblemDefinitionVariant},
     state_validity_checker::JsStateValidityChecker,
 };
@@ -171,4 +172,108 @@ impl JsRRTStar {
                 .map_err(|e| e.to_string()),
         }
     }
+
+    /// Returns the statistics collected since the planner was last set up, as an object with the
+    /// phase times in seconds.
+    pub fn stats(&self) -> JsValue {
+        let stats = match &self.planner {
+            RRTStarVariant::RealVector(p) => p.stats(),
+            RRTStarVariant::SO2(p) => p.stats(),
+            RRTStarVariant::SO3(p) => p.stats(),
+            RRTStarVariant::Compound(p) => p.stats(),
+            RRTStarVariant::SE2(p) => p.stats(),
+            RRTStarVariant::SE3(p) => p.stats(),
+        };
+        stats_to_js(&stats)
+    }
+
+    /// Returns the planner's parameters, as an object mapping each name to an object of its
+    /// `value`, `description`, `min` and `max`. Infinite bounds are `null`.
+    pub fn params(&self) -> JsValue {
+        let params = match &self.planner {
+            RRTStarVariant::RealVector(p) => p.params(),
+            RRTStarVariant::SO2(p) => p.params(),
+            RRTStarVariant::SO3(p) => p.params(),
+            RRTStarVariant::Compound(p) => p.params(),
+            RRTStarVariant::SE2(p) => p.params(),
+            RRTStarVariant::SE3(p) => p.params(),
+        };
+        params_to_js(&params)
+    }
+
+    /// Sets a parameter by name, parsing `value` as the parameter's type. Takes effect from the
+    /// next call to `setup`. `num_threads` cannot be changed from JavaScript.
+    #[wasm_bindgen(js_name = setParam)]
+    pub fn set_param(&mut self, name: &str, value: &str) -> Result<(), String> {
+        check_js_param(name, value)?;
+        match &mut self.planner {
+            RRTStarVariant::RealVector(p) => p.set_param(name, value),
+            RRTStarVariant::SO2(p) => p.set_param(name, value),
+            RRTStarVariant::SO3(p) => p.set_param(name, value),
+            RRTStarVariant::Compound(p) => p.set_param(name, value),
+            RRTStarVariant::SE2(p) => p.set_param(name, value),
+            RRTStarVariant::SE3(p) => p.set_param(name, value),
+        }
+        .map_err(|e| e.to_string())
+    }
+
+    /// Sets an object to be told about the planner's progress while it plans.
+    ///
+    /// The object may define any of `onIteration(iteration)`, `onNodeAdded(state)`,
+    /// `onEdgeAdded(from, to)`, `onEdgeRemoved(from, to)`, `onSampleRejected(state)`, where
+    /// `state` is `null` if sampling failed, and `onSolution(path, length)`.
+    #[wasm_bindgen(js_name = setObserver)]
+    pub fn set_observer(&mut self, observer: JsValue) {
+        match &mut self.planner {
+            RRTStarVariant::RealVector(p) => {
+                p.set_observer(Arc::new(JsPlannerObserver::new(observer)))
+            }
+            RRTStarVariant::SO2(p) => p.set_observer(Arc::new(JsPlannerObserver::new(observer))),
+            RRTStarVariant::SO3(p) => p.set_observer(Arc::new(JsPlannerObserver::new(observer))),
+            RRTStarVariant::Compound(p) => {
+                p.set_observer(Arc::new(JsPlannerObserver::new(observer)))
+            }
+            RRTStarVariant::SE2(p) => p.set_observer(Arc::new(JsPlannerObserver::new(observer))),
+            RRTStarVariant::SE3(p) => p.set_observer(Arc::new(JsPlannerObserver::new(observer))),
+        }
+    }
+
+    /// Discards the tree.
+    pub fn clear(&mut self) {
+        match &mut self.planner {
+            RRTStarVariant::RealVector(p) => p.clear(),
+            RRTStarVariant::SO2(p) => p.clear(),
+            RRTStarVariant::SO3(p) => p.clear(),
+            RRTStarVariant::Compound(p) => p.clear(),
+            RRTStarVariant::SE2(p) => p.clear(),
+            RRTStarVariant::SE3(p) => p.clear(),
+        }
+    }
+
+    /// Adds the states of `path` to the tree, in order, and returns the number added.
+    #[wasm_bindgen(js_name = warmStart)]
+    pub fn warm_start(&mut self, path: &JsPath) -> Result<usize, String> {
+        match (&mut self.planner, &path.inner) {
+            (RRTStarVariant::RealVector(p), PathVariant::RealVector(path)) => {
+                p.warm_start(path.0.iter().cloned())
+            }
+            (RRTStarVariant::SO2(p), PathVariant::SO2(path)) => {
+                p.warm_start(path.0.iter().cloned())
+            }
+            (RRTStarVariant::SO3(p), PathVariant::SO3(path)) => {
+                p.warm_start(path.0.iter().cloned())
+            }
+            (RRTStarVariant::Compound(p), PathVariant::Compound(path)) => {
+                p.warm_start(path.0.iter().cloned())
+            }
+            (RRTStarVariant::SE2(p), PathVariant::SE2(path)) => {
+                p.warm_start(path.0.iter().cloned())
+            }
+            (RRTStarVariant::SE3(p), PathVariant::SE3(path)) => {
+                p.warm_start(path.0.iter().cloned())
+            }
+            _ => return Err("The path is not in the planner's state space.".to_string()),
+        }
+        .map_err(|e| e.to_string())
+    }
 }
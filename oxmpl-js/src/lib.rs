@@ -4,5 +4,14 @@
 
 #![allow(clippy::arc_with_non_send_sync)]
 
+// The wrappers around JavaScript values implement `Send` and `Sync` on the grounds that a
+// WebAssembly module runs on a single thread. A build with shared memory and threads would break
+// that assumption, so it is refused.
+#[cfg(target_feature = "atomics")]
+compile_error!(
+    "oxmpl-js assumes a single-threaded WebAssembly module and cannot be built with the `atomics` \
+     target feature"
+);
+
 pub mod base;
 pub mod geometric;
@@ -0,0 +1,109 @@
+import oxmpl from 'oxmpl-js';
+import { describe, expect, test } from 'vitest';
+
+function isStateValid(state) {
+  const [x, y] = state.values;
+
+  const wallXPos = 5.0;
+  const wallYMin = 2.0;
+  const wallYMax = 8.0;
+  const wallThickness = 0.5;
+
+  const isInWall =
+    x >= wallXPos - wallThickness / 2.0 &&
+    x <= wallXPos + wallThickness / 2.0 &&
+    y >= wallYMin &&
+    y <= wallYMax;
+
+  return !isInWall;
+}
+
+function solveRvss(space, builtinGoal) {
+  const startState = new oxmpl.base.RealVectorState([1.0, 5.0]);
+  const goal = new oxmpl.base.Goal(builtinGoal);
+
+  const problemDef = oxmpl.base.ProblemDefinition.fromRealVectorState(space, startState, goal);
+  const validityChecker = new oxmpl.base.StateValidityChecker(isStateValid);
+  const planner = new oxmpl.geometric.RRTConnect(
+    0.5,
+    0.05,
+    problemDef,
+    new oxmpl.base.PlannerConfig(0)
+  );
+  planner.setup(validityChecker);
+
+  const path = planner.solve(5.0);
+  const states = path.getStates();
+
+  expect(space.distance(states[0], startState)).toBeLessThan(1e-9);
+  expect(builtinGoal.isSatisfied(states[states.length - 1])).toBe(true);
+  for (const state of states) {
+    expect(isStateValid(state)).toBe(true);
+  }
+}
+
+describe('Built-in Goal Tests', () => {
+  test('GoalState in RealVectorStateSpace', () => {
+    const space = new oxmpl.base.RealVectorStateSpace(2, [0.0, 10.0, 0.0, 10.0]);
+    const goalState = oxmpl.base.GoalState.fromRealVectorState(
+      space,
+      new oxmpl.base.RealVectorState([9.0, 5.0]),
+      0.5
+    );
+
+    expect(goalState.isSatisfied(new oxmpl.base.RealVectorState([9.0, 5.2]))).toBe(true);
+    expect(goalState.isSatisfied(new oxmpl.base.RealVectorState([8.0, 5.0]))).toBe(false);
+    expect(goalState.distanceGoal(new oxmpl.base.RealVectorState([7.0, 5.0]))).toBeCloseTo(1.5);
+    expect(goalState.sampleGoal().values).toEqual([9.0, 5.0]);
+
+    solveRvss(space, goalState);
+  });
+
+  test('GoalStates in SE2StateSpace', () => {
+    const space = new oxmpl.base.SE2StateSpace(1.0, [0.0, 10.0, 0.0, 10.0, -Math.PI, Math.PI]);
+    const goalStates = oxmpl.base.GoalStates.fromSE2StateSpace(space, 0.5);
+
+    expect(() => goalStates.sampleGoal()).toThrow();
+
+    goalStates.addState(new oxmpl.base.SE2State(9.0, 9.0, 0.0));
+    goalStates.addState(new oxmpl.base.SE2State(1.0, 9.0, 0.0));
+
+    expect(goalStates.length).toBe(2);
+    expect(goalStates.isSatisfied(new oxmpl.base.SE2State(1.2, 9.0, 0.0))).toBe(true);
+    expect(goalStates.isSatisfied(new oxmpl.base.SE2State(5.0, 5.0, 0.0))).toBe(false);
+    expect(goalStates.sampleGoal().y).toBeCloseTo(9.0);
+  });
+
+  test('GoalSpace in RealVectorStateSpace', () => {
+    const space = new oxmpl.base.RealVectorStateSpace(2, [0.0, 10.0, 0.0, 10.0]);
+    const goalSpace = oxmpl.base.GoalSpace.fromRealVectorStateSpace(
+      new oxmpl.base.RealVectorStateSpace(2, [8.5, 9.5, 4.5, 5.5])
+    );
+
+    expect(goalSpace.isSatisfied(new oxmpl.base.RealVectorState([9.0, 5.0]))).toBe(true);
+    expect(goalSpace.distanceGoal(new oxmpl.base.RealVectorState([7.5, 5.0]))).toBeCloseTo(1.0);
+
+    solveRvss(space, goalSpace);
+  });
+
+  test('SE3PoseGoal with separate tolerances', () => {
+    // SE3State takes ownership of its rotation, so each state gets a fresh one.
+    const identity = () => new oxmpl.base.SO3State(0.0, 0.0, 0.0, 1.0);
+    const yawed = () => new oxmpl.base.SO3State(0.0, 0.0, Math.sin(0.5), Math.cos(0.5));
+    const target = () => new oxmpl.base.SE3State(0.5, 0.0, 0.3, identity());
+
+    // Within 5 mm and 2 degrees.
+    const goal = new oxmpl.base.SE3PoseGoal(target(), 0.005, (2.0 * Math.PI) / 180.0);
+    expect(goal.isSatisfied(new oxmpl.base.SE3State(0.503, 0.0, 0.3, identity()))).toBe(true);
+    expect(goal.isSatisfied(new oxmpl.base.SE3State(0.51, 0.0, 0.3, identity()))).toBe(false);
+    expect(goal.orientationError(new oxmpl.base.SE3State(0.5, 0.0, 0.3, yawed()))).toBeCloseTo(1.0);
+
+    const yawFree = new oxmpl.base.SE3PoseGoal(target(), 0.005, (2.0 * Math.PI) / 180.0, 'z');
+    expect(yawFree.isSatisfied(new oxmpl.base.SE3State(0.5, 0.0, 0.3, yawed()))).toBe(true);
+    for (let i = 0; i < 20; i++) {
+      expect(yawFree.isSatisfied(yawFree.sampleGoal())).toBe(true);
+    }
+
+    expect(() => new oxmpl.base.SE3PoseGoal(target(), 0.005, 0.1, 'w')).toThrow();
+  });
+});
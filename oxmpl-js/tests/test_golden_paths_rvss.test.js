@@ -0,0 +1,84 @@
+import { readFileSync } from 'node:fs';
+import oxmpl from 'oxmpl-js';
+import { describe, expect, test } from 'vitest';
+
+// The paths recorded by the Rust golden test, `oxmpl/tests/golden_paths_rvss_tests.rs`, which
+// seeded planners must find exactly from JavaScript too.
+const GOLDEN_FILE = new URL('../../oxmpl/tests/golden/paths_rvss.json', import.meta.url);
+
+const SEED = 42;
+
+const CASES = [
+  ['RRT', 'GoalState'],
+  ['RRTConnect', 'GoalState'],
+  ['RRTStar', 'GoalState'],
+  ['RRT', 'GoalSpace'],
+  ['RRTConnect', 'GoalSpace'],
+];
+
+function isStateValid(state) {
+  const [x, y] = state.values;
+
+  const wallXPos = 5.0;
+  const wallYMin = 2.0;
+  const wallYMax = 8.0;
+  const wallThickness = 0.5;
+
+  const isInWall =
+    x >= wallXPos - wallThickness / 2.0 &&
+    x <= wallXPos + wallThickness / 2.0 &&
+    y >= wallYMin &&
+    y <= wallYMax;
+
+  return !isInWall;
+}
+
+function plan(plannerName, goalName) {
+  const space = new oxmpl.base.RealVectorStateSpace(2, [0.0, 10.0, 0.0, 10.0]);
+  const builtinGoal =
+    goalName === 'GoalState'
+      ? oxmpl.base.GoalState.fromRealVectorState(
+          space,
+          new oxmpl.base.RealVectorState([9.0, 5.0]),
+          0.5
+        )
+      : oxmpl.base.GoalSpace.fromRealVectorStateSpace(
+          new oxmpl.base.RealVectorStateSpace(2, [8.5, 9.5, 4.5, 5.5])
+        );
+  const problemDef = oxmpl.base.ProblemDefinition.fromRealVectorState(
+    space,
+    new oxmpl.base.RealVectorState([1.0, 5.0]),
+    new oxmpl.base.Goal(builtinGoal)
+  );
+
+  const planner = new oxmpl.geometric.Planner(
+    plannerName,
+    problemDef,
+    new oxmpl.base.PlannerConfig(SEED)
+  );
+  planner.setParam('max_distance', '0.5');
+  planner.setParam('goal_bias', '0.05');
+  planner.setup(new oxmpl.base.StateValidityChecker(isStateValid));
+  return planner
+    .solve(10.0)
+    .getStates()
+    .map((state) => Array.from(state.values));
+}
+
+describe('Golden paths in RealVectorStateSpace', () => {
+  test('seeded planners find the paths found in Rust', () => {
+    const golden = JSON.parse(readFileSync(GOLDEN_FILE, 'utf8'));
+    expect(Object.keys(golden)).toEqual(CASES.map(([planner, goal]) => `${planner}/${goal}`));
+
+    for (const [planner, goal] of CASES) {
+      expect(plan(planner, goal), `${planner}/${goal}`).toEqual(golden[`${planner}/${goal}`]);
+    }
+  });
+
+  test('seeds that JavaScript numbers cannot hold exactly are rejected', () => {
+    expect(() => new oxmpl.base.PlannerConfig(-1)).toThrow();
+    expect(() => new oxmpl.base.PlannerConfig(1.5)).toThrow();
+    expect(() => new oxmpl.base.PlannerConfig(2 ** 53)).toThrow();
+    expect(() => new oxmpl.base.PlannerConfig(Number.MAX_SAFE_INTEGER)).not.toThrow();
+  });
+});
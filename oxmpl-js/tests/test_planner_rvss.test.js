@@ -0,0 +1,104 @@
+import oxmpl from 'oxmpl-js';
+import { describe, expect, test } from 'vitest';
+
+class CircularGoal {
+  constructor(space, x, y, radius) {
+    this.space = space;
+    this.target = [x, y];
+    this.radius = radius;
+    this.rng = Math.random;
+  }
+
+  isSatisfied(state) {
+    const [sx, sy] = state.values;
+    const dx = sx - this.target[0];
+    const dy = sy - this.target[1];
+    const distance = Math.sqrt(dx * dx + dy * dy);
+    return distance <= this.radius;
+  }
+
+  distanceGoal(state) {
+    const [sx, sy] = state.values;
+    const dx = sx - this.target[0];
+    const dy = sy - this.target[1];
+    const distance = Math.sqrt(dx * dx + dy * dy);
+    return Math.max(0, distance - this.radius);
+  }
+
+  sampleGoal() {
+    const angle = this.rng() * 2 * Math.PI;
+    const radius = this.radius * Math.sqrt(this.rng());
+
+    const x = this.target[0] + radius * Math.cos(angle);
+    const y = this.target[1] + radius * Math.sin(angle);
+    return new oxmpl.base.RealVectorState([x, y]);
+  }
+}
+
+function isStateValid(state) {
+  const [x, y] = state.values;
+
+  const wallXPos = 5.0;
+  const wallYMin = 2.0;
+  const wallYMax = 8.0;
+  const wallThickness = 0.5;
+
+  const isInWall =
+    x >= wallXPos - wallThickness / 2.0 &&
+    x <= wallXPos + wallThickness / 2.0 &&
+    y >= wallYMin &&
+    y <= wallYMax;
+
+  return !isInWall;
+}
+
+describe('Planners created by name in RealVectorStateSpace', () => {
+  test('lists the planners that can be created', () => {
+    const names = oxmpl.geometric.Planner.names();
+    expect(names).toContain('RRTConnect');
+    expect(names).toContain('PRM');
+    // CForest grows its trees on other threads, which WebAssembly cannot spawn.
+    expect(names).not.toContain('CForest');
+  });
+
+  test('every listed planner finds a path', () => {
+    const space = new oxmpl.base.RealVectorStateSpace(2, [0.0, 10.0, 0.0, 10.0]);
+    const startState = new oxmpl.base.RealVectorState([1.0, 5.0]);
+    const validityChecker = new oxmpl.base.StateValidityChecker(isStateValid);
+
+    for (const name of oxmpl.geometric.Planner.names()) {
+      const goalRegion = new CircularGoal(space, 9.0, 5.0, 0.5);
+      const goal = new oxmpl.base.Goal(goalRegion);
+      const problemDef = oxmpl.base.ProblemDefinition.fromRealVectorState(space, startState, goal);
+      const planner = new oxmpl.geometric.Planner(name, problemDef, new oxmpl.base.PlannerConfig(3));
+      planner.setup(validityChecker);
+
+      const path = planner.solve(2.0);
+      const states = path.getStates();
+      expect(states.length).toBeGreaterThan(1);
+      expect(goalRegion.isSatisfied(states[states.length - 1])).toBe(true);
+      for (const state of states) {
+        expect(isStateValid(state)).toBe(true);
+      }
+    }
+  });
+
+  test('rejects unknown planners and can be configured by name', () => {
+    const space = new oxmpl.base.RealVectorStateSpace(2, [0.0, 10.0, 0.0, 10.0]);
+    const startState = new oxmpl.base.RealVectorState([1.0, 5.0]);
+    const goal = new oxmpl.base.Goal(new CircularGoal(space, 9.0, 5.0, 0.5));
+    const problemDef = oxmpl.base.ProblemDefinition.fromRealVectorState(space, startState, goal);
+    const config = new oxmpl.base.PlannerConfig(3);
+
+    expect(() => new oxmpl.geometric.Planner('RRTSharp', problemDef, config)).toThrow();
+
+    const planner = new oxmpl.geometric.Planner('PRM', problemDef, config);
+    expect(planner.params().connection_radius.value).toBe(0.0);
+    planner.setParam('connection_radius', '2.0');
+    expect(() => planner.setParam('num_threads', '2')).toThrow();
+
+    planner.setup(new oxmpl.base.StateValidityChecker(isStateValid));
+    expect(planner.params().connection_radius.value).toBeCloseTo(2.0);
+    expect(planner.solve(2.0).getLength()).toBeGreaterThan(1);
+  });
+});
@@ -0,0 +1,49 @@
+import oxmpl from 'oxmpl-js';
+import { describe, expect, test } from 'vitest';
+
+function isStateValid(state) {
+  const [x, y] = state.values;
+  const isInWall = x >= 4.75 && x <= 5.25 && y >= 2.0 && y <= 8.0;
+  return !isInWall;
+}
+
+function createProblem(start, target, threshold) {
+  const space = new oxmpl.base.RealVectorStateSpace(2, [0.0, 10.0, 0.0, 10.0]);
+  const goal = new oxmpl.base.Goal(
+    oxmpl.base.GoalState.fromRealVectorState(
+      space,
+      new oxmpl.base.RealVectorState(target),
+      threshold
+    )
+  );
+  return oxmpl.base.ProblemDefinition.fromRealVectorState(
+    space,
+    new oxmpl.base.RealVectorState(start),
+    goal
+  );
+}
+
+describe('ProblemDefinition.validate', () => {
+  const validityChecker = new oxmpl.base.StateValidityChecker(isStateValid);
+
+  test('reports no problems for a valid problem', () => {
+    const problemDef = createProblem([1.0, 5.0], [9.0, 5.0], 0.5);
+    expect(problemDef.validate(validityChecker)).toEqual([]);
+  });
+
+  test('reports an invalid start state and an unreachable goal', () => {
+    const problemDef = createProblem([5.0, 4.0], [5.0, 5.0], 0.0);
+    const problems = problemDef.validate(validityChecker);
+    expect(problems.length).toBe(2);
+    expect(problems[0]).toContain('Start state 0');
+    expect(problems[1]).toMatch(/^Warning: /);
+    expect(problems[1]).toContain('goal');
+  });
+
+  test('reports a start state out of bounds', () => {
+    const problemDef = createProblem([11.0, 5.0], [9.0, 5.0], 0.5);
+    expect(problemDef.validate(validityChecker)).toEqual([
+      'Start state 0 is outside the bounds of the space.',
+    ]);
+  });
+});
@@ -102,4 +102,121 @@ describe('RRT Integration Tests', () => {
 
     console.log('Path validation successful!');
   });
+
+  test('RRT resumes across calls and warm starts from a path', () => {
+    const space = new oxmpl.base.RealVectorStateSpace(2, [0.0, 10.0, 0.0, 10.0]);
+    const startState = new oxmpl.base.RealVectorState([1.0, 5.0]);
+    const goalRegion = new CircularGoal(space, 9.0, 5.0, 0.5);
+    const goal = new oxmpl.base.Goal(goalRegion);
+    const problemDef = oxmpl.base.ProblemDefinition.fromRealVectorState(space, startState, goal);
+    const validityChecker = new oxmpl.base.StateValidityChecker(isStateValid);
+
+    const planner = new oxmpl.geometric.RRT(0.5, 0.05, problemDef, new oxmpl.base.PlannerConfig(1));
+    planner.setup(validityChecker);
+
+    // Plan in small slices of time; each call carries on growing the same tree.
+    let path;
+    for (let i = 0; i < 10000 && path === undefined; i++) {
+      try {
+        path = planner.solve(0.001);
+      } catch (error) {
+        // Out of time for this slice.
+      }
+    }
+    expect(path).toBeDefined();
+    const states = path.getStates();
+    expect(goalRegion.isSatisfied(states[states.length - 1])).toBe(true);
+
+    // Once the tree reaches the goal, the path is returned straight away.
+    expect(planner.solve(0.0).getLength()).toBe(path.getLength());
+
+    planner.clear();
+    expect(() => planner.solve(0.0)).toThrow();
+
+    const seeded = new oxmpl.geometric.RRT(0.5, 0.05, problemDef, new oxmpl.base.PlannerConfig(2));
+    seeded.setup(validityChecker);
+    expect(seeded.warmStart(path)).toBe(path.getLength() - 1);
+    const seededStates = seeded.solve(0.0).getStates();
+    expect(goalRegion.isSatisfied(seededStates[seededStates.length - 1])).toBe(true);
+  });
+
+  test('RRT reports its statistics', () => {
+    const space = new oxmpl.base.RealVectorStateSpace(2, [0.0, 10.0, 0.0, 10.0]);
+    const startState = new oxmpl.base.RealVectorState([1.0, 5.0]);
+    const goalRegion = new CircularGoal(space, 9.0, 5.0, 0.5);
+    const goal = new oxmpl.base.Goal(goalRegion);
+    const problemDef = oxmpl.base.ProblemDefinition.fromRealVectorState(space, startState, goal);
+    const validityChecker = new oxmpl.base.StateValidityChecker(isStateValid);
+
+    const planner = new oxmpl.geometric.RRT(0.5, 0.05, problemDef, new oxmpl.base.PlannerConfig(3));
+    planner.setup(validityChecker);
+    planner.solve(5.0);
+
+    const stats = planner.stats();
+    expect(stats.iterations).toBeGreaterThan(0);
+    expect(stats.samples_drawn).toBe(stats.iterations);
+    expect(stats.motion_checks).toBeGreaterThan(0);
+    expect(stats.validity_checks).toBeGreaterThanOrEqual(stats.motion_checks);
+    expect(stats.phase_times['motion checking']).toBeGreaterThan(0);
+
+    planner.setup(validityChecker);
+    expect(planner.stats().iterations).toBe(0);
+  });
+
+  test('RRT streams its tree to an observer', () => {
+    const space = new oxmpl.base.RealVectorStateSpace(2, [0.0, 10.0, 0.0, 10.0]);
+    const startState = new oxmpl.base.RealVectorState([1.0, 5.0]);
+    const goalRegion = new CircularGoal(space, 9.0, 5.0, 0.5);
+    const goal = new oxmpl.base.Goal(goalRegion);
+    const problemDef = oxmpl.base.ProblemDefinition.fromRealVectorState(space, startState, goal);
+    const validityChecker = new oxmpl.base.StateValidityChecker(isStateValid);
+
+    const nodes = [];
+    const edges = [];
+    const solutions = [];
+    const planner = new oxmpl.geometric.RRT(0.5, 0.05, problemDef, new oxmpl.base.PlannerConfig(4));
+    planner.setObserver({
+      onNodeAdded: (state) => nodes.push(state),
+      onEdgeAdded: (from, to) => edges.push([from, to]),
+      onSolution: (path, length) => solutions.push([path, length]),
+    });
+    planner.setup(validityChecker);
+    const path = planner.solve(5.0);
+
+    expect(Array.from(nodes[0].values)).toEqual([1.0, 5.0]);
+    expect(edges.length).toBe(nodes.length - 1);
+    expect(solutions.length).toBe(1);
+    expect(solutions[0][0].getLength()).toBe(path.getLength());
+    expect(solutions[0][1]).toBeGreaterThan(0);
+  });
+
+  test('RRT parameters can be listed and set by name', () => {
+    const space = new oxmpl.base.RealVectorStateSpace(2, [0.0, 10.0, 0.0, 10.0]);
+    const startState = new oxmpl.base.RealVectorState([1.0, 5.0]);
+    const goalRegion = new CircularGoal(space, 9.0, 5.0, 0.5);
+    const goal = new oxmpl.base.Goal(goalRegion);
+    const problemDef = oxmpl.base.ProblemDefinition.fromRealVectorState(space, startState, goal);
+    const validityChecker = new oxmpl.base.StateValidityChecker(isStateValid);
+
+    const planner = new oxmpl.geometric.RRT(0.5, 0.05, problemDef, new oxmpl.base.PlannerConfig(7));
+    const params = planner.params();
+    expect(Object.keys(params)).toEqual(['max_distance', 'goal_bias', 'num_threads']);
+    expect(params.goal_bias.value).toBeCloseTo(0.05);
+    expect(params.goal_bias.min).toBe(0.0);
+    expect(params.goal_bias.max).toBe(1.0);
+    expect(params.num_threads.max).toBeNull();
+
+    planner.setParam('goal_bias', '0.2');
+    expect(planner.params().goal_bias.value).toBeCloseTo(0.2);
+    expect(() => planner.setParam('goal_bias', '1.5')).toThrow();
+    expect(() => planner.setParam('step_size', '1.0')).toThrow();
+    // WebAssembly runs on a single thread.
+    expect(() => planner.setParam('num_threads', '2')).toThrow();
+
+    // A max_distance of 0 means a fifth of the space's maximum extent, and is kept as 0.
+    planner.setParam('max_distance', '0');
+    planner.setup(validityChecker);
+    expect(planner.params().max_distance.value).toBe(0);
+    expect(planner.solve(5.0).getLength()).toBeGreaterThan(1);
+  });
 });
@@ -0,0 +1,260 @@
+// Copyright (c) 2025 Junior Sundar
+//
+// SPDX-License-Identifier: BSD-3-Clause
+
+use pyo3::{
+    exceptions::PyTypeError, exceptions::PyValueError, prelude::*, IntoPyObjectExt, PyClass,
+};
+use rand::RngCore;
+use std::marker::PhantomData;
+
+use oxmpl::base::{
+    goal::{GoalLazySamples, GoalSampleableRegion, GoalStates},
+    space::{
+        CompoundStateSpace as OxmplCompoundStateSpace,
+        RealVectorStateSpace as OxmplRealVectorStateSpace, SE2StateSpace as OxmplSE2StateSpace,
+        SE3StateSpace as OxmplSE3StateSpace, SO2StateSpace as OxmplSO2StateSpace,
+        SO3StateSpace as OxmplSO3StateSpace, StateSpace,
+    },
+    state,
+};
+
+use super::{
+    compound_state_space::PyCompoundStateSpace,
+    goal_lazy_samples::PyGoalLazySamples,
+    goal_space::PyGoalSpace,
+    goal_state::PyGoalState,
+    goal_states::PyGoalStates,
+    pose_goals::{PySE2PoseGoal, PySE3PoseGoal},
+    py_state_convert::PyStateConvert,
+    real_vector_state_space::PyRealVectorStateSpace,
+    se2_state_space::PySE2StateSpace,
+    se3_state_space::PySE3StateSpace,
+    so2_state_space::PySO2StateSpace,
+    so3_state_space::PySO3StateSpace,
+};
+
+/// A snapshot of the Rust space behind any of the Python space classes.
+pub enum SpaceSnapshot {
+    RealVector(OxmplRealVectorStateSpace),
+    SO2(OxmplSO2StateSpace),
+    SO3(OxmplSO3StateSpace),
+    Compound(OxmplCompoundStateSpace),
+    SE2(OxmplSE2StateSpace),
+    SE3(OxmplSE3StateSpace),
+}
+
+impl SpaceSnapshot {
+    /// Copies the configuration of a Python space object.
+    pub fn from_py(space: &Bound<'_, PyAny>) -> PyResult<Self> {
+        if let Ok(space) = space.downcast::<PyRealVectorStateSpace>() {
+            return Ok(Self::RealVector(space.borrow().0.lock().unwrap().clone()));
+        }
+        if let Ok(space) = space.downcast::<PySO2StateSpace>() {
+            return Ok(Self::SO2(space.borrow().0.lock().unwrap().clone()));
+        }
+        if let Ok(space) = space.downcast::<PySO3StateSpace>() {
+            return Ok(Self::SO3(space.borrow().0.lock().unwrap().clone()));
+        }
+        if let Ok(space) = space.downcast::<PyCompoundStateSpace>() {
+            return Ok(Self::Compound(space.borrow().0.borrow().clone()));
+        }
+        if let Ok(space) = space.downcast::<PySE2StateSpace>() {
+            return Ok(Self::SE2(space.borrow().0.borrow().clone()));
+        }
+        if let Ok(space) = space.downcast::<PySE3StateSpace>() {
+            return Ok(Self::SE3(space.borrow().0.borrow().clone()));
+        }
+        Err(PyTypeError::new_err(format!(
+            "Unsupported state space type: {}",
+            space.get_type().name()?
+        )))
+    }
+}
+
+/// Evaluates `$body` with `$space` bound to the Rust space inside a `SpaceSnapshot`, whatever its
+/// type.
+macro_rules! with_space {
+    ($snapshot:expr, |$space:ident| $body:expr) => {
+        match $snapshot {
+            $crate::base::builtin_goal::SpaceSnapshot::RealVector($space) => $body,
+            $crate::base::builtin_goal::SpaceSnapshot::SO2($space) => $body,
+            $crate::base::builtin_goal::SpaceSnapshot::SO3($space) => $body,
+            $crate::base::builtin_goal::SpaceSnapshot::Compound($space) => $body,
+            $crate::base::builtin_goal::SpaceSnapshot::SE2($space) => $body,
+            $crate::base::builtin_goal::SpaceSnapshot::SE3($space) => $body,
+        }
+    };
+}
+pub(crate) use with_space;
+
+/// Converts a Python state object into the Rust state type `S`.
+pub fn extract_state<S: PyStateConvert>(state: &Bound<'_, PyAny>) -> PyResult<S> {
+    Ok(S::from_py_wrapper(state.extract::<S::Wrapper>()?))
+}
+
+/// Wraps a Python callable as a goal sampler for `GoalLazySamples`. The callable returning `None`
+/// or raising an exception finishes the sampling thread.
+pub fn py_sampler<S: PyStateConvert>(sampler: PyObject) -> impl FnMut() -> Option<S> + Send {
+    move || {
+        Python::with_gil(|py| {
+            let result = sampler.call0(py).map_err(|e| e.print(py)).ok()?;
+            if result.is_none(py) {
+                return None;
+            }
+            result
+                .extract::<S::Wrapper>(py)
+                .map(S::from_py_wrapper)
+                .map_err(|e| e.print(py))
+                .ok()
+        })
+    }
+}
+
+/// A built-in Rust goal seen through Python objects, independent of its state type.
+pub trait DynGoal: Send + Sync {
+    fn is_satisfied(&self, state: &Bound<'_, PyAny>) -> PyResult<bool>;
+
+    fn distance_goal(&self, state: &Bound<'_, PyAny>) -> PyResult<f64>;
+
+    fn sample_goal<'py>(
+        &self,
+        py: Python<'py>,
+        rng: &mut dyn RngCore,
+    ) -> PyResult<Bound<'py, PyAny>>;
+}
+
+/// A Python goal class backed by a built-in Rust goal.
+pub trait BuiltinGoal {
+    fn dyn_goal(&self) -> &dyn DynGoal;
+}
+
+/// Samples the built-in Rust goal behind `goal` with `rng`, or returns `None` if `goal` is a goal
+/// class written in Python. This lets built-in goals draw from a planner's random number
+/// generator, so that seeded planners find the same paths as in Rust.
+pub fn sample_builtin_goal<'py>(
+    goal: &Bound<'py, PyAny>,
+    rng: &mut dyn RngCore,
+) -> Option<PyResult<Bound<'py, PyAny>>> {
+    fn sample<'py, G: BuiltinGoal + PyClass>(
+        goal: &Bound<'py, PyAny>,
+        rng: &mut dyn RngCore,
+    ) -> Option<PyResult<Bound<'py, PyAny>>> {
+        let goal = goal.downcast::<G>().ok()?;
+        let sample = goal.borrow().dyn_goal().sample_goal(goal.py(), rng);
+        Some(sample)
+    }
+
+    sample::<PyGoalState>(goal, rng)
+        .or_else(|| sample::<PyGoalStates>(goal, rng))
+        .or_else(|| sample::<PyGoalSpace>(goal, rng))
+        .or_else(|| sample::<PyGoalLazySamples>(goal, rng))
+        .or_else(|| sample::<PySE2PoseGoal>(goal, rng))
+        .or_else(|| sample::<PySE3PoseGoal>(goal, rng))
+}
+
+/// Lets a random number generator be used while the GIL is released.
+struct UngilRng<'a>(&'a mut dyn RngCore);
+
+// SAFETY: `Python::allow_threads` requires its closure to be `Send` only so that it cannot hold
+// GIL-bound references. The closure runs on the calling thread, so the generator is never
+// actually sent to another thread.
+unsafe impl Send for UngilRng<'_> {}
+
+impl<'a> UngilRng<'a> {
+    fn get(self) -> &'a mut dyn RngCore {
+        self.0
+    }
+}
+
+/// A `DynGoal` whose set of goal states can be added to.
+pub trait DynGoalStates: DynGoal {
+    fn add_state(&mut self, state: &Bound<'_, PyAny>) -> PyResult<()>;
+
+    fn state_count(&self) -> usize;
+}
+
+/// A `DynGoal` backed by a sampling thread.
+pub trait DynGoalLazySamples: DynGoal {
+    fn stop(&mut self);
+
+    fn is_sampling(&self) -> bool;
+
+    fn sample_count(&self) -> usize;
+}
+
+/// Implements the `DynGoal` traits for a goal `G` over states of type `S`.
+pub struct TypedGoal<G, S> {
+    pub goal: G,
+    _phantom: PhantomData<S>,
+}
+
+impl<G, S> TypedGoal<G, S> {
+    pub fn new(goal: G) -> Self {
+        Self {
+            goal,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<G, S> DynGoal for TypedGoal<G, S>
+where
+    S: PyStateConvert + state::State,
+    G: GoalSampleableRegion<S> + Send + Sync,
+{
+    fn is_satisfied(&self, state: &Bound<'_, PyAny>) -> PyResult<bool> {
+        Ok(self.goal.is_satisfied(&extract_state(state)?))
+    }
+
+    fn distance_goal(&self, state: &Bound<'_, PyAny>) -> PyResult<f64> {
+        Ok(self.goal.distance_goal(&extract_state(state)?))
+    }
+
+    fn sample_goal<'py>(
+        &self,
+        py: Python<'py>,
+        rng: &mut dyn RngCore,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        // The GIL is released while sampling, as `GoalLazySamples` may be waiting on a Python
+        // sampler to produce its first state.
+        let rng = UngilRng(rng);
+        let state = py
+            .allow_threads(move || self.goal.sample_goal(rng.get()))
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+        state.to_py_wrapper().into_bound_py_any(py)
+    }
+}
+
+impl<SP> DynGoalStates for TypedGoal<GoalStates<SP>, SP::StateType>
+where
+    SP: StateSpace,
+    SP::StateType: PyStateConvert,
+{
+    fn add_state(&mut self, state: &Bound<'_, PyAny>) -> PyResult<()> {
+        self.goal.add_state(extract_state(state)?);
+        Ok(())
+    }
+
+    fn state_count(&self) -> usize {
+        self.goal.states.len()
+    }
+}
+
+impl<SP> DynGoalLazySamples for TypedGoal<GoalLazySamples<SP>, SP::StateType>
+where
+    SP: StateSpace,
+    SP::StateType: PyStateConvert,
+{
+    fn stop(&mut self) {
+        self.goal.stop();
+    }
+
+    fn is_sampling(&self) -> bool {
+        self.goal.is_sampling()
+    }
+
+    fn sample_count(&self) -> usize {
+        self.goal.sample_count()
+    }
+}
@@ -3,7 +3,7 @@
 // SPDX-License-Identifier: BSD-3-Clause
 
 use pyo3::prelude::*;
-use rand::Rng;
+use rand::RngCore;
 use std::marker::PhantomData;
 
 use oxmpl::base::{
@@ -12,7 +12,7 @@ use oxmpl::base::{
     state,
 };
 
-use super::py_state_convert::PyStateConvert;
+use super::{builtin_goal::sample_builtin_goal, py_state_convert::PyStateConvert};
 
 pub struct PyGoal<State> {
     pub instance: PyObject,
@@ -54,11 +54,12 @@ impl<State: PyStateConvert + state::State> GoalRegion<State> for PyGoal<State> {
 }
 
 impl<State: PyStateConvert + state::State> GoalSampleableRegion<State> for PyGoal<State> {
-    fn sample_goal(&self, _rng: &mut impl Rng) -> Result<State, StateSamplingError> {
+    fn sample_goal(&self, rng: &mut dyn RngCore) -> Result<State, StateSamplingError> {
         Python::with_gil(|py| {
-            self.instance
-                .call_method0(py, "sample_goal")
-                .and_then(|res| res.extract::<State::Wrapper>(py))
+            let instance = self.instance.bind(py);
+            sample_builtin_goal(instance, rng)
+                .unwrap_or_else(|| instance.call_method0("sample_goal"))
+                .and_then(|res| res.extract::<State::Wrapper>())
                 .map(State::from_py_wrapper)
                 .map_err(|e| {
                     e.print(py);
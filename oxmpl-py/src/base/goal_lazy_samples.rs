@@ -0,0 +1,114 @@
+// Copyright (c) 2025 Junior Sundar
+//
+// SPDX-License-Identifier: BSD-3-Clause
+
+use pyo3::prelude::*;
+use std::sync::Arc;
+
+use oxmpl::base::goal::GoalLazySamples as OxmplGoalLazySamples;
+
+use super::builtin_goal::{
+    py_sampler, with_space, BuiltinGoal, DynGoal, DynGoalLazySamples, SpaceSnapshot, TypedGoal,
+};
+
+/// A goal whose states are produced by a Python function on a background thread.
+///
+/// As soon as the goal is created, a thread starts calling `sampler` and storing the states it
+/// returns, while the planner carries on with the samples found so far. The thread finishes once
+/// `sampler` returns `None` or raises, `max_samples` states have been stored, or `stop` is
+/// called. A state satisfies the goal if it is within `threshold` of any stored sample.
+///
+/// The sampler only runs while the GIL is free, such as while `sample_goal` waits for the first
+/// sample. It must create a new state on every call, as states cannot be shared between threads.
+///
+/// Args:
+///     space: The state space, used to measure distances to the goal samples.
+///     threshold (float): The distance from a goal sample within which a state satisfies the goal.
+///     max_samples (int): The number of samples after which the thread finishes.
+///     sampler (Callable[[], Optional[State]]): Called repeatedly to produce goal states.
+///
+/// Raises:
+///     TypeError: If the space type is not supported.
+#[pyclass(name = "GoalLazySamples", unsendable)]
+pub struct PyGoalLazySamples(Option<Box<dyn DynGoalLazySamples>>);
+
+impl Drop for PyGoalLazySamples {
+    fn drop(&mut self) {
+        // The sampling thread may be waiting for the GIL, so it must be released while joining.
+        if let Some(goal) = self.0.take() {
+            Python::with_gil(|py| py.allow_threads(move || drop(goal)));
+        }
+    }
+}
+
+impl PyGoalLazySamples {
+    fn goal(&self) -> &dyn DynGoalLazySamples {
+        self.0.as_deref().expect("goal is only taken when dropped")
+    }
+}
+
+impl BuiltinGoal for PyGoalLazySamples {
+    fn dyn_goal(&self) -> &dyn DynGoal {
+        self.goal()
+    }
+}
+
+#[pymethods]
+impl PyGoalLazySamples {
+    #[new]
+    fn new(
+        space: &Bound<'_, PyAny>,
+        threshold: f64,
+        max_samples: usize,
+        sampler: PyObject,
+    ) -> PyResult<Self> {
+        let goal: Box<dyn DynGoalLazySamples> =
+            with_space!(SpaceSnapshot::from_py(space)?, |space| {
+                Box::new(TypedGoal::new(OxmplGoalLazySamples::new(
+                    Arc::new(space),
+                    threshold,
+                    max_samples,
+                    py_sampler(sampler),
+                )))
+            });
+        Ok(Self(Some(goal)))
+    }
+
+    /// Asks the sampling thread to finish and waits for it. Samples found so far are kept.
+    fn stop(&mut self, py: Python<'_>) {
+        if let Some(goal) = self.0.as_deref_mut() {
+            py.allow_threads(|| goal.stop());
+        }
+    }
+
+    /// bool: Whether the sampling thread is still running.
+    #[getter]
+    fn is_sampling(&self) -> bool {
+        self.goal().is_sampling()
+    }
+
+    /// int: The number of goal samples found so far.
+    #[getter]
+    fn sample_count(&self) -> usize {
+        self.goal().sample_count()
+    }
+
+    /// Returns `True` if the state is within `threshold` of any goal sample found so far.
+    fn is_satisfied(&self, state: &Bound<'_, PyAny>) -> PyResult<bool> {
+        self.goal().is_satisfied(state)
+    }
+
+    /// Returns the distance from the state to the edge of the nearest goal region.
+    fn distance_goal(&self, state: &Bound<'_, PyAny>) -> PyResult<f64> {
+        self.goal().distance_goal(state)
+    }
+
+    /// Returns one of the goal samples found so far at random, waiting for the first one if
+    /// needed.
+    ///
+    /// Raises:
+    ///     ValueError: If the sampling thread finished without finding any samples.
+    fn sample_goal<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        self.goal().sample_goal(py, &mut rand::rng())
+    }
+}
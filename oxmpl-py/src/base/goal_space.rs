@@ -0,0 +1,55 @@
+// Copyright (c) 2025 Junior Sundar
+//
+// SPDX-License-Identifier: BSD-3-Clause
+
+use pyo3::prelude::*;
+
+use oxmpl::base::goal::GoalSpace as OxmplGoalSpace;
+
+use super::builtin_goal::{with_space, BuiltinGoal, DynGoal, SpaceSnapshot, TypedGoal};
+
+/// A goal defined by a bounded sub-space of the planning space.
+///
+/// `goal_space` is a state space of the same type as the planning space, but with tighter bounds.
+/// A state satisfies the goal if it lies within the bounds of `goal_space`, and sampling the goal
+/// samples `goal_space` uniformly.
+///
+/// Args:
+///     goal_space: The sub-space that makes up the goal.
+///
+/// Raises:
+///     TypeError: If the space type is not supported.
+#[pyclass(name = "GoalSpace", unsendable)]
+pub struct PyGoalSpace(Box<dyn DynGoal>);
+
+impl BuiltinGoal for PyGoalSpace {
+    fn dyn_goal(&self) -> &dyn DynGoal {
+        &*self.0
+    }
+}
+
+#[pymethods]
+impl PyGoalSpace {
+    #[new]
+    fn new(goal_space: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let goal: Box<dyn DynGoal> = with_space!(SpaceSnapshot::from_py(goal_space)?, |space| {
+            Box::new(TypedGoal::new(OxmplGoalSpace::new(space)))
+        });
+        Ok(Self(goal))
+    }
+
+    /// Returns `True` if the state lies within the bounds of the goal space.
+    fn is_satisfied(&self, state: &Bound<'_, PyAny>) -> PyResult<bool> {
+        self.0.is_satisfied(state)
+    }
+
+    /// Returns the distance from the state to the bounds of the goal space.
+    fn distance_goal(&self, state: &Bound<'_, PyAny>) -> PyResult<f64> {
+        self.0.distance_goal(state)
+    }
+
+    /// Returns a state sampled uniformly from the goal space.
+    fn sample_goal<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        self.0.sample_goal(py, &mut rand::rng())
+    }
+}
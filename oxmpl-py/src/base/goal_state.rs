@@ -0,0 +1,64 @@
+// Copyright (c) 2025 Junior Sundar
+//
+// SPDX-License-Identifier: BSD-3-Clause
+
+use pyo3::prelude::*;
+use std::sync::Arc;
+
+use oxmpl::base::goal::GoalState as OxmplGoalState;
+
+use super::builtin_goal::{
+    extract_state, with_space, BuiltinGoal, DynGoal, SpaceSnapshot, TypedGoal,
+};
+
+/// A goal defined by a single state and a tolerance around it.
+///
+/// A state satisfies the goal if its distance to the goal state, as measured by the space, is no
+/// more than `threshold`. It can be passed to any `ProblemDefinition` constructor in place of a
+/// custom goal class.
+///
+/// Args:
+///     space: The state space, used to measure distances to the goal state.
+///     state: The goal state, of the type used by `space`.
+///     threshold (float): The distance from `state` within which a state satisfies the goal.
+///
+/// Raises:
+///     TypeError: If the space type is not supported or `state` does not belong to it.
+#[pyclass(name = "GoalState", unsendable)]
+pub struct PyGoalState(Box<dyn DynGoal>);
+
+impl BuiltinGoal for PyGoalState {
+    fn dyn_goal(&self) -> &dyn DynGoal {
+        &*self.0
+    }
+}
+
+#[pymethods]
+impl PyGoalState {
+    #[new]
+    fn new(space: &Bound<'_, PyAny>, state: &Bound<'_, PyAny>, threshold: f64) -> PyResult<Self> {
+        let goal: Box<dyn DynGoal> = with_space!(SpaceSnapshot::from_py(space)?, |space| {
+            Box::new(TypedGoal::new(OxmplGoalState::new(
+                Arc::new(space),
+                extract_state(state)?,
+                threshold,
+            )))
+        });
+        Ok(Self(goal))
+    }
+
+    /// Returns `True` if the state is within `threshold` of the goal state.
+    fn is_satisfied(&self, state: &Bound<'_, PyAny>) -> PyResult<bool> {
+        self.0.is_satisfied(state)
+    }
+
+    /// Returns the distance from the state to the edge of the goal region.
+    fn distance_goal(&self, state: &Bound<'_, PyAny>) -> PyResult<f64> {
+        self.0.distance_goal(state)
+    }
+
+    /// Returns the goal state.
+    fn sample_goal<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        self.0.sample_goal(py, &mut rand::rng())
+    }
+}
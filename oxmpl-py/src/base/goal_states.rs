@@ -0,0 +1,79 @@
+// Copyright (c) 2025 Junior Sundar
+//
+// SPDX-License-Identifier: BSD-3-Clause
+
+use pyo3::prelude::*;
+use std::sync::Arc;
+
+use oxmpl::base::goal::GoalStates as OxmplGoalStates;
+
+use super::builtin_goal::{
+    extract_state, with_space, BuiltinGoal, DynGoal, DynGoalStates, SpaceSnapshot, TypedGoal,
+};
+
+/// A goal defined by a set of states and a tolerance around each of them.
+///
+/// A state satisfies the goal if it is within `threshold` of any of the goal states. Sampling the
+/// goal picks one of the goal states at random.
+///
+/// Args:
+///     space: The state space, used to measure distances to the goal states.
+///     states (List): The goal states, of the type used by `space`.
+///     threshold (float): The distance from a goal state within which a state satisfies the goal.
+///
+/// Raises:
+///     TypeError: If the space type is not supported or a state does not belong to it.
+#[pyclass(name = "GoalStates", unsendable)]
+pub struct PyGoalStates(Box<dyn DynGoalStates>);
+
+impl BuiltinGoal for PyGoalStates {
+    fn dyn_goal(&self) -> &dyn DynGoal {
+        &*self.0
+    }
+}
+
+#[pymethods]
+impl PyGoalStates {
+    #[new]
+    fn new(
+        space: &Bound<'_, PyAny>,
+        states: Vec<Bound<'_, PyAny>>,
+        threshold: f64,
+    ) -> PyResult<Self> {
+        let goal: Box<dyn DynGoalStates> = with_space!(SpaceSnapshot::from_py(space)?, |space| {
+            Box::new(TypedGoal::new(OxmplGoalStates::new(
+                Arc::new(space),
+                states.iter().map(extract_state).collect::<PyResult<_>>()?,
+                threshold,
+            )))
+        });
+        Ok(Self(goal))
+    }
+
+    /// Adds a state to the set of goal states.
+    fn add_state(&mut self, state: &Bound<'_, PyAny>) -> PyResult<()> {
+        self.0.add_state(state)
+    }
+
+    /// Returns `True` if the state is within `threshold` of any goal state.
+    fn is_satisfied(&self, state: &Bound<'_, PyAny>) -> PyResult<bool> {
+        self.0.is_satisfied(state)
+    }
+
+    /// Returns the distance from the state to the edge of the nearest goal region.
+    fn distance_goal(&self, state: &Bound<'_, PyAny>) -> PyResult<f64> {
+        self.0.distance_goal(state)
+    }
+
+    /// Returns one of the goal states at random.
+    ///
+    /// Raises:
+    ///     ValueError: If there are no goal states.
+    fn sample_goal<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        self.0.sample_goal(py, &mut rand::rng())
+    }
+
+    fn __len__(&self) -> usize {
+        self.0.state_count()
+    }
+}
@@ -4,11 +4,18 @@
 
 use pyo3::prelude::*;
 
+mod builtin_goal;
 mod compound_state;
 mod compound_state_space;
 mod goal;
+mod goal_lazy_samples;
+mod goal_space;
+mod goal_state;
+mod goal_states;
 mod path;
 mod planner;
+mod planner_observer;
+mod pose_goals;
 mod problem_definition;
 mod py_state_convert;
 mod real_vector_state;
@@ -26,10 +33,19 @@ mod state_validity_checker;
 pub use compound_state::PyCompoundState;
 pub use compound_state_space::PyCompoundStateSpace;
 pub use goal::PyGoal;
-pub use path::PyPath;
-pub use planner::PyPlannerConfig;
+pub use goal_lazy_samples::PyGoalLazySamples;
+pub use goal_space::PyGoalSpace;
+pub use goal_state::PyGoalState;
+pub use goal_states::PyGoalStates;
+pub use path::{PathVariant, PyPath};
+pub use planner::{
+    check_py_param, param_error_to_py, params_to_dict, stats_to_dict, PyPlannerConfig,
+};
+pub use planner_observer::PyPlannerObserver;
+pub use pose_goals::{PySE2PoseGoal, PySE3PoseGoal};
 pub use problem_definition::ProblemDefinitionVariant;
 pub use problem_definition::PyProblemDefinition;
+pub use py_state_convert::PyStateConvert;
 pub use real_vector_state::PyRealVectorState;
 pub use real_vector_state_space::PyRealVectorStateSpace;
 pub use se2_state::PySE2State;
@@ -59,5 +75,11 @@ pub fn create_module(_py: Python<'_>) -> PyResult<Bound<'_, PyModule>> {
     base_module.add_class::<PyPath>()?;
     base_module.add_class::<PyPlannerConfig>()?;
     base_module.add_class::<PyProblemDefinition>()?;
+    base_module.add_class::<PyGoalState>()?;
+    base_module.add_class::<PyGoalStates>()?;
+    base_module.add_class::<PyGoalSpace>()?;
+    base_module.add_class::<PyGoalLazySamples>()?;
+    base_module.add_class::<PySE2PoseGoal>()?;
+    base_module.add_class::<PySE3PoseGoal>()?;
     Ok(base_module)
 }
@@ -1,7 +1,11 @@
 use std::rc::Rc;
 
-use oxmpl::base::planner::PlannerConfig as OxmplPlannerConfig;
-use pyo3::prelude::*;
+use oxmpl::base::{
+    error::ParamError,
+    param::{ParamSet, ParamValue},
+    planner::{PlannerConfig as OxmplPlannerConfig, PlannerStats},
+};
+use pyo3::{exceptions::PyValueError, prelude::*, types::PyDict};
 
 #[pyclass(name = "PlannerConfig", unsendable)]
 pub struct PyPlannerConfig(pub Rc<OxmplPlannerConfig>);
@@ -24,3 +28,57 @@ impl PyPlannerConfig {
         format!("<PlannerConfig seed={:?}>", self.0.seed)
     }
 }
+
+/// Converts `PlannerStats` into a dictionary, with the phase times in seconds.
+pub fn stats_to_dict<'py>(py: Python<'py>, stats: &PlannerStats) -> PyResult<Bound<'py, PyDict>> {
+    let dict = PyDict::new(py);
+    dict.set_item("iterations", stats.iterations)?;
+    dict.set_item("samples_drawn", stats.samples_drawn)?;
+    dict.set_item("samples_rejected", stats.samples_rejected)?;
+    dict.set_item("validity_checks", stats.validity_checks)?;
+    dict.set_item("motion_checks", stats.motion_checks)?;
+    dict.set_item("nearest_neighbour_queries", stats.nearest_neighbour_queries)?;
+    let phase_times = PyDict::new(py);
+    for (phase, time) in &stats.phase_times {
+        phase_times.set_item(phase, time.as_secs_f64())?;
+    }
+    dict.set_item("phase_times", phase_times)?;
+    Ok(dict)
+}
+
+/// Converts a `ParamSet` into a dictionary mapping each parameter's name to a dictionary of its
+/// "value", "description", "min" and "max".
+pub fn params_to_dict<'py>(py: Python<'py>, params: &ParamSet) -> PyResult<Bound<'py, PyDict>> {
+    let dict = PyDict::new(py);
+    for param in params.iter() {
+        let entry = PyDict::new(py);
+        match param.value() {
+            ParamValue::Real(value) => entry.set_item("value", value)?,
+            ParamValue::Integer(value) => entry.set_item("value", value)?,
+            ParamValue::Bool(value) => entry.set_item("value", value)?,
+        }
+        entry.set_item("description", param.description())?;
+        entry.set_item("min", *param.range().start())?;
+        entry.set_item("max", *param.range().end())?;
+        dict.set_item(param.name(), entry)?;
+    }
+    Ok(dict)
+}
+
+/// Converts a `ParamError` into a Python `ValueError`.
+pub fn param_error_to_py(error: ParamError) -> PyErr {
+    PyValueError::new_err(error.to_string())
+}
+
+/// Checks that a parameter can be set from Python.
+///
+/// `solve` holds the GIL while it plans, so planner threads could never call a Python validity
+/// checker. `num_threads` must therefore stay at 1.
+pub fn check_py_param(name: &str, value: &str) -> PyResult<()> {
+    if name == "num_threads" && value.trim() != "1" {
+        return Err(PyValueError::new_err(
+            "num_threads cannot be changed from Python, as validity checks need the GIL.",
+        ));
+    }
+    Ok(())
+}
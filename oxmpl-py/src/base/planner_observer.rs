@@ -0,0 +1,85 @@
+// Copyright (c) 2025 Junior Sundar
+//
+// SPDX-License-Identifier: BSD-3-Clause
+
+use pyo3::{prelude::*, types::PyTuple, IntoPyObjectExt};
+
+use oxmpl::base::{observer::PlannerObserver, planner::Path as OxmplPath, state::State};
+
+use super::{path::PyPath, py_state_convert::PyStateConvert};
+
+/// An internal Rust struct that implements the `PlannerObserver` trait by calling the methods of
+/// a user-provided Python object.
+///
+/// Only the methods the object defines are called, so it can implement as few of `on_iteration`,
+/// `on_node_added`, `on_edge_added`, `on_edge_removed`, `on_sample_rejected` and `on_solution` as
+/// it likes.
+pub struct PyPlannerObserver {
+    pub callback: PyObject,
+}
+
+impl PyPlannerObserver {
+    /// Calls `method` on the Python object, if it has one, with the arguments built by `args`.
+    fn notify(&self, method: &str, args: impl FnOnce(Python<'_>) -> PyResult<Vec<PyObject>>) {
+        Python::with_gil(|py| {
+            let result: PyResult<()> = (|| {
+                let callback = self.callback.bind(py);
+                if !callback.hasattr(method)? {
+                    return Ok(());
+                }
+                callback.call_method1(method, PyTuple::new(py, args(py)?)?)?;
+                Ok(())
+            })();
+            if let Err(e) = result {
+                e.print(py);
+            }
+        })
+    }
+}
+
+fn state_to_py<S: PyStateConvert>(py: Python<'_>, state: &S) -> PyResult<PyObject> {
+    state.to_py_wrapper().into_py_any(py)
+}
+
+impl<S> PlannerObserver<S> for PyPlannerObserver
+where
+    S: State + PyStateConvert,
+    OxmplPath<S>: Into<PyPath>,
+{
+    fn on_iteration(&self, iteration: u64) {
+        self.notify("on_iteration", |py| Ok(vec![iteration.into_py_any(py)?]));
+    }
+
+    fn on_node_added(&self, state: &S) {
+        self.notify("on_node_added", |py| Ok(vec![state_to_py(py, state)?]));
+    }
+
+    fn on_edge_added(&self, from: &S, to: &S) {
+        self.notify("on_edge_added", |py| {
+            Ok(vec![state_to_py(py, from)?, state_to_py(py, to)?])
+        });
+    }
+
+    fn on_edge_removed(&self, from: &S, to: &S) {
+        self.notify("on_edge_removed", |py| {
+            Ok(vec![state_to_py(py, from)?, state_to_py(py, to)?])
+        });
+    }
+
+    fn on_sample_rejected(&self, sample: Option<&S>) {
+        self.notify("on_sample_rejected", |py| {
+            let sample = match sample {
+                Some(state) => state_to_py(py, state)?,
+                None => py.None(),
+            };
+            Ok(vec![sample])
+        });
+    }
+
+    fn on_solution(&self, path: &OxmplPath<S>, length: f64) {
+        self.notify("on_solution", |py| {
+            let path: PyPath = path.clone().into();
+            Ok(vec![path.into_py_any(py)?, length.into_py_any(py)?])
+        });
+    }
+}
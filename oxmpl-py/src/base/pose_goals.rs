@@ -0,0 +1,160 @@
+// Copyright (c) 2025 Junior Sundar
+//
+// SPDX-License-Identifier: BSD-3-Clause
+
+use pyo3::{exceptions::PyValueError, prelude::*};
+
+use oxmpl::base::{
+    goal::{RotationAxis, SE2PoseGoal as OxmplSE2PoseGoal, SE3PoseGoal as OxmplSE3PoseGoal},
+    state::{SE2State as OxmplSE2State, SE3State as OxmplSE3State},
+};
+
+use super::{
+    builtin_goal::{BuiltinGoal, DynGoal, TypedGoal},
+    se2_state::PySE2State,
+    se3_state::PySE3State,
+};
+
+/// A goal for `SE2State`s with separate position and orientation tolerances.
+///
+/// A state satisfies the goal if its position is within `position_tolerance` of the target's and
+/// its yaw is within `orientation_tolerance` radians of the target's.
+///
+/// Args:
+///     target (SE2State): The target pose.
+///     position_tolerance (float): The maximum distance from the target position.
+///     orientation_tolerance (float): The maximum angle in radians from the target yaw.
+///     yaw_free (bool): If `True`, the yaw is not checked at all.
+#[pyclass(name = "SE2PoseGoal", unsendable)]
+pub struct PySE2PoseGoal(TypedGoal<OxmplSE2PoseGoal, OxmplSE2State>);
+
+impl BuiltinGoal for PySE2PoseGoal {
+    fn dyn_goal(&self) -> &dyn DynGoal {
+        &self.0
+    }
+}
+
+#[pymethods]
+impl PySE2PoseGoal {
+    #[new]
+    #[pyo3(signature = (target, position_tolerance, orientation_tolerance, yaw_free=false))]
+    fn new(
+        target: &PySE2State,
+        position_tolerance: f64,
+        orientation_tolerance: f64,
+        yaw_free: bool,
+    ) -> Self {
+        let mut goal = OxmplSE2PoseGoal::new(
+            (*target.0).clone(),
+            position_tolerance,
+            orientation_tolerance,
+        );
+        goal.yaw_free = yaw_free;
+        Self(TypedGoal::new(goal))
+    }
+
+    /// Returns the distance between the position of the state and the target's.
+    fn position_error(&self, state: &PySE2State) -> f64 {
+        self.0.goal.position_error(&state.0)
+    }
+
+    /// Returns the angle in radians between the yaw of the state and the target's.
+    fn orientation_error(&self, state: &PySE2State) -> f64 {
+        self.0.goal.orientation_error(&state.0)
+    }
+
+    /// Returns `True` if the state is within both tolerances of the target.
+    fn is_satisfied(&self, state: &Bound<'_, PyAny>) -> PyResult<bool> {
+        self.0.is_satisfied(state)
+    }
+
+    /// Returns how far the position and orientation errors exceed their tolerances.
+    fn distance_goal(&self, state: &Bound<'_, PyAny>) -> PyResult<f64> {
+        self.0.distance_goal(state)
+    }
+
+    /// Returns a pose sampled from within both tolerances of the target.
+    fn sample_goal<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        self.0.sample_goal(py, &mut rand::rng())
+    }
+}
+
+/// A goal for `SE3State`s with separate position and orientation tolerances.
+///
+/// A state satisfies the goal if its position is within `position_tolerance` of the target's and
+/// the angle between its orientation and the target's is within `orientation_tolerance` radians.
+///
+/// Args:
+///     target (SE3State): The target pose.
+///     position_tolerance (float): The maximum distance from the target position.
+///     orientation_tolerance (float): The maximum angle in radians from the target orientation.
+///     free_axis (Optional[str]): One of "x", "y" or "z". If given, rotation about that axis of
+///         the target's frame is not counted. Only one axis can be free; to free the orientation
+///         entirely, give an `orientation_tolerance` of pi.
+///
+/// Raises:
+///     ValueError: If `free_axis` is not one of "x", "y" or "z".
+#[pyclass(name = "SE3PoseGoal", unsendable)]
+pub struct PySE3PoseGoal(TypedGoal<OxmplSE3PoseGoal, OxmplSE3State>);
+
+impl BuiltinGoal for PySE3PoseGoal {
+    fn dyn_goal(&self) -> &dyn DynGoal {
+        &self.0
+    }
+}
+
+#[pymethods]
+impl PySE3PoseGoal {
+    #[new]
+    #[pyo3(signature = (target, position_tolerance, orientation_tolerance, free_axis=None))]
+    fn new(
+        target: &PySE3State,
+        position_tolerance: f64,
+        orientation_tolerance: f64,
+        free_axis: Option<&str>,
+    ) -> PyResult<Self> {
+        let mut goal = OxmplSE3PoseGoal::new(
+            (*target.0).clone(),
+            position_tolerance,
+            orientation_tolerance,
+        );
+        goal.free_axis = match free_axis {
+            None => None,
+            Some("x") => Some(RotationAxis::X),
+            Some("y") => Some(RotationAxis::Y),
+            Some("z") => Some(RotationAxis::Z),
+            Some(other) => {
+                return Err(PyValueError::new_err(format!(
+                    "Invalid free axis '{other}': expected 'x', 'y' or 'z'."
+                )))
+            }
+        };
+        Ok(Self(TypedGoal::new(goal)))
+    }
+
+    /// Returns the distance between the position of the state and the target's.
+    fn position_error(&self, state: &PySE3State) -> f64 {
+        self.0.goal.position_error(&state.0)
+    }
+
+    /// Returns the angle in radians between the orientation of the state and the target's,
+    /// ignoring any rotation about the free axis.
+    fn orientation_error(&self, state: &PySE3State) -> f64 {
+        self.0.goal.orientation_error(&state.0)
+    }
+
+    /// Returns `True` if the state is within both tolerances of the target.
+    fn is_satisfied(&self, state: &Bound<'_, PyAny>) -> PyResult<bool> {
+        self.0.is_satisfied(state)
+    }
+
+    /// Returns how far the position and orientation errors exceed their tolerances.
+    fn distance_goal(&self, state: &Bound<'_, PyAny>) -> PyResult<f64> {
+        self.0.distance_goal(state)
+    }
+
+    /// Returns a pose sampled from within both tolerances of the target.
+    fn sample_goal<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        self.0.sample_goal(py, &mut rand::rng())
+    }
+}
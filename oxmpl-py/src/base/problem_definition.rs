@@ -27,6 +27,7 @@ use super::{
     se2_state::PySE2State, se2_state_space::PySE2StateSpace, se3_state::PySE3State,
     se3_state_space::PySE3StateSpace, so2_state::PySO2State, so2_state_space::PySO2StateSpace,
     so3_state::PySO3State, so3_state_space::PySO3StateSpace,
+    state_validity_checker::PyStateValidityChecker,
 };
 
 #[derive(Clone)]
@@ -232,4 +233,31 @@ impl PyProblemDefinition {
         // Wrap the result in the correct enum variant
         Self(ProblemDefinitionVariant::SE3(Arc::new(pd)))
     }
+
+    /// Checks the problem for mistakes that would stop any planner from solving it.
+    ///
+    /// Every start state is checked against the space's bounds and `validity_fn`, and the goal is
+    /// sampled to check that it can be reached. Returns a description of each problem found, so an
+    /// empty list means none were found. Errors come first, followed by warnings, which start with
+    /// "Warning: " and may be false alarms, as they are only suspected from a few goal samples.
+    fn validate(&self, validity_fn: PyObject) -> Vec<String> {
+        let checker = PyStateValidityChecker {
+            callback: validity_fn,
+        };
+        let mut rng = rand::rng();
+        let report = match &self.0 {
+            ProblemDefinitionVariant::RealVector(pd) => pd.validate(&checker, &mut rng),
+            ProblemDefinitionVariant::SO2(pd) => pd.validate(&checker, &mut rng),
+            ProblemDefinitionVariant::SO3(pd) => pd.validate(&checker, &mut rng),
+            ProblemDefinitionVariant::Compound(pd) => pd.validate(&checker, &mut rng),
+            ProblemDefinitionVariant::SE2(pd) => pd.validate(&checker, &mut rng),
+            ProblemDefinitionVariant::SE3(pd) => pd.validate(&checker, &mut rng),
+        };
+        report
+            .errors
+            .iter()
+            .map(ToString::to_string)
+            .chain(report.warnings.iter().map(|w| format!("Warning: {w}")))
+            .collect()
+    }
 }
@@ -2,10 +2,14 @@
 //
 // SPDX-License-Identifier: BSD-3-Clause
 
+mod path_metrics;
+mod planner;
 mod prm;
 mod rrt;
 mod rrt_connect;
 mod rrt_star;
+pub use path_metrics::PyPathMetrics;
+pub use planner::PyPlanner;
 pub use prm::PyPrm;
 pub use rrt::PyRrt;
 pub use rrt_connect::PyRrtConnect;
@@ -25,6 +29,8 @@ pub fn create_module(py: Python<'_>) -> PyResult<Bound<'_, PyModule>> {
     geometric_module.add_class::<PyRrtConnect>()?;
     geometric_module.add_class::<PyRrtStar>()?;
     geometric_module.add_class::<PyPrm>()?;
+    geometric_module.add_class::<PyPlanner>()?;
+    geometric_module.add_class::<PyPathMetrics>()?;
 
     sys_modules.set_item("oxmpl_py.geometric", &geometric_module)?;
 
@@ -0,0 +1,149 @@
+// Copyright (c) 2025 Junior Sundar
+//
+// SPDX-License-Identifier: BSD-3-Clause
+
+use pyo3::prelude::*;
+
+use crate::base::{
+    PathVariant, ProblemDefinitionVariant, PyPath, PyProblemDefinition, PyStateConvert,
+    PyStateValidityChecker,
+};
+use oxmpl::{
+    base::{objective::PathLengthObjective, state::State, validity::StateValidityChecker},
+    geometric::PathMetrics,
+};
+
+/// A `StateValidityChecker` that measures clearance by calling a user-provided Python function.
+struct PyClearanceChecker {
+    validity: PyStateValidityChecker,
+    clearance_fn: Option<PyObject>,
+}
+
+impl<S> StateValidityChecker<S> for PyClearanceChecker
+where
+    S: State + PyStateConvert,
+    PyStateValidityChecker: StateValidityChecker<S>,
+{
+    fn is_valid(&self, state: &S) -> bool {
+        self.validity.is_valid(state)
+    }
+
+    fn clearance(&self, state: &S) -> Option<f64> {
+        let clearance_fn = self.clearance_fn.as_ref()?;
+        Python::with_gil(|py| {
+            let result = clearance_fn
+                .call1(py, (state.to_py_wrapper(),))
+                .and_then(|clearance| clearance.extract(py));
+            match result {
+                Ok(clearance) => Some(clearance),
+                Err(e) => {
+                    e.print(py);
+                    None
+                }
+            }
+        })
+    }
+}
+
+/// A summary of the quality of a path.
+#[pyclass(name = "PathMetrics", unsendable)]
+#[derive(Clone)]
+pub struct PyPathMetrics(PathMetrics);
+
+#[pymethods]
+impl PyPathMetrics {
+    /// Computes the metrics of a path found for `problem_definition`.
+    ///
+    /// The cost is the length of the path. If `clearance_fn` is given, it is called with states
+    /// along the path and should return their distance to the nearest obstacle.
+    #[staticmethod]
+    #[pyo3(signature = (path, problem_definition, validity_fn, clearance_fn=None))]
+    fn compute(
+        path: &PyPath,
+        problem_definition: &PyProblemDefinition,
+        validity_fn: PyObject,
+        clearance_fn: Option<PyObject>,
+    ) -> PyResult<Self> {
+        let checker = PyClearanceChecker {
+            validity: PyStateValidityChecker {
+                callback: validity_fn,
+            },
+            clearance_fn,
+        };
+        let metrics = match (&path.0, &problem_definition.0) {
+            (PathVariant::RealVector(path), ProblemDefinitionVariant::RealVector(pd)) => {
+                let objective = PathLengthObjective::new(pd.space.clone());
+                PathMetrics::compute(path, pd.space.clone(), &checker, &objective)
+            }
+            (PathVariant::SO2(path), ProblemDefinitionVariant::SO2(pd)) => {
+                let objective = PathLengthObjective::new(pd.space.clone());
+                PathMetrics::compute(path, pd.space.clone(), &checker, &objective)
+            }
+            (PathVariant::SO3(path), ProblemDefinitionVariant::SO3(pd)) => {
+                let objective = PathLengthObjective::new(pd.space.clone());
+                PathMetrics::compute(path, pd.space.clone(), &checker, &objective)
+            }
+            (PathVariant::Compound(path), ProblemDefinitionVariant::Compound(pd)) => {
+                let objective = PathLengthObjective::new(pd.space.clone());
+                PathMetrics::compute(path, pd.space.clone(), &checker, &objective)
+            }
+            (PathVariant::SE2(path), ProblemDefinitionVariant::SE2(pd)) => {
+                let objective = PathLengthObjective::new(pd.space.clone());
+                PathMetrics::compute(path, pd.space.clone(), &checker, &objective)
+            }
+            (PathVariant::SE3(path), ProblemDefinitionVariant::SE3(pd)) => {
+                let objective = PathLengthObjective::new(pd.space.clone());
+                PathMetrics::compute(path, pd.space.clone(), &checker, &objective)
+            }
+            _ => {
+                return Err(pyo3::exceptions::PyValueError::new_err(
+                    "The path and problem definition are for different state spaces.",
+                ))
+            }
+        };
+        Ok(Self(metrics))
+    }
+
+    /// float: The length of the path.
+    #[getter]
+    fn length(&self) -> f64 {
+        self.0.length
+    }
+
+    /// float: The sum of the angles the path turns through at its vertices, in radians.
+    #[getter]
+    fn smoothness(&self) -> f64 {
+        self.0.smoothness
+    }
+
+    /// float | None: The smallest clearance along the path, if `clearance_fn` was given.
+    #[getter]
+    fn min_clearance(&self) -> Option<f64> {
+        self.0.min_clearance
+    }
+
+    /// float | None: The average clearance along the path, if `clearance_fn` was given.
+    #[getter]
+    fn average_clearance(&self) -> Option<f64> {
+        self.0.average_clearance
+    }
+
+    /// int: The number of states in the path.
+    #[getter]
+    fn vertices(&self) -> usize {
+        self.0.vertices
+    }
+
+    /// float: The cost of the path.
+    #[getter]
+    fn cost(&self) -> f64 {
+        self.0.cost
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "<PathMetrics length={:.3} smoothness={:.3} vertices={} cost={:.3}>",
+            self.0.length, self.0.smoothness, self.0.vertices, self.0.cost
+        )
+    }
+}
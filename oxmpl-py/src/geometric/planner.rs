@@ -0,0 +1,239 @@
+// Copyright (c) 2025 Junior Sundar
+//
+// SPDX-License-Identifier: BSD-3-Clause
+
+use pyo3::{
+    exceptions::{PyException, PyValueError},
+    prelude::*,
+    types::PyDict,
+};
+use std::{sync::Arc, time::Duration};
+
+use crate::base::{
+    check_py_param, param_error_to_py, params_to_dict, stats_to_dict, ProblemDefinitionVariant,
+    PyGoal, PyPath, PyPlannerConfig, PyPlannerObserver, PyProblemDefinition, PyStateConvert,
+    PyStateValidityChecker,
+};
+use oxmpl::{
+    base::{
+        space::{
+            CompoundStateSpace, RealVectorStateSpace, SE2StateSpace, SE3StateSpace, SO2StateSpace,
+            SO3StateSpace, StateSpace,
+        },
+        state::{CompoundState, RealVectorState, SE2State, SE3State, SO2State, SO3State, State},
+    },
+    geometric::{BoxedPlanner, PlannerRegistry},
+};
+
+/// Planners that grow trees on their own threads, which cannot call Python validity checkers
+/// while `solve` holds the GIL.
+const THREADED_PLANNERS: [&str; 1] = ["CForest"];
+
+/// Returns the geometric planners that can be used from Python.
+fn registry<S, SP>() -> PlannerRegistry<S, SP, PyGoal<S>>
+where
+    S: State + Clone + PyStateConvert,
+    SP: StateSpace<StateType = S> + 'static,
+{
+    let mut registry = PlannerRegistry::with_geometric_planners();
+    for name in THREADED_PLANNERS {
+        registry.remove(name);
+    }
+    registry
+}
+
+enum PlannerVariant {
+    RealVector(BoxedPlanner<RealVectorState, RealVectorStateSpace, PyGoal<RealVectorState>>),
+    SO2(BoxedPlanner<SO2State, SO2StateSpace, PyGoal<SO2State>>),
+    SO3(BoxedPlanner<SO3State, SO3StateSpace, PyGoal<SO3State>>),
+    Compound(BoxedPlanner<CompoundState, CompoundStateSpace, PyGoal<CompoundState>>),
+    SE2(BoxedPlanner<SE2State, SE2StateSpace, PyGoal<SE2State>>),
+    SE3(BoxedPlanner<SE3State, SE3StateSpace, PyGoal<SE3State>>),
+}
+
+/// A geometric planner chosen by name, such as "RRTConnect" or "PRM".
+#[pyclass(name = "Planner", unsendable)]
+pub struct PyPlanner {
+    planner: PlannerVariant,
+    pd: ProblemDefinitionVariant,
+}
+
+#[pymethods]
+impl PyPlanner {
+    /// Creates the planner registered under `name`.
+    ///
+    /// The planner's ranges and radii start at 0, so they are derived from the state space when
+    /// the planner is set up. They can be changed with `set_param`. Roadmap planners such as PRM
+    /// build their roadmap on the first call to `solve` after `setup`.
+    ///
+    /// Args:
+    ///     name (str): The name of the planner, as listed by `Planner.names()`.
+    ///     problem_definition (ProblemDefinition): The problem definition.
+    ///     planner_config (PlannerConfig): The planner configuration with planner specific
+    ///         parameters.
+    ///
+    /// Raises:
+    ///     ValueError: If there is no planner with this name.
+    #[new]
+    fn new(
+        name: &str,
+        problem_definition: &PyProblemDefinition,
+        planner_config: &PyPlannerConfig,
+    ) -> PyResult<Self> {
+        let config = &planner_config.0;
+        let planner = match &problem_definition.0 {
+            ProblemDefinitionVariant::RealVector(_) => registry()
+                .create(name, config)
+                .map(PlannerVariant::RealVector),
+            ProblemDefinitionVariant::SO2(_) => {
+                registry().create(name, config).map(PlannerVariant::SO2)
+            }
+            ProblemDefinitionVariant::SO3(_) => {
+                registry().create(name, config).map(PlannerVariant::SO3)
+            }
+            ProblemDefinitionVariant::Compound(_) => registry()
+                .create(name, config)
+                .map(PlannerVariant::Compound),
+            ProblemDefinitionVariant::SE2(_) => {
+                registry().create(name, config).map(PlannerVariant::SE2)
+            }
+            ProblemDefinitionVariant::SE3(_) => {
+                registry().create(name, config).map(PlannerVariant::SE3)
+            }
+        }
+        .map_err(|e| PyValueError::new_err(e.to_string()))?;
+        Ok(Self {
+            planner,
+            pd: problem_definition.0.clone(),
+        })
+    }
+
+    /// Returns the names of the planners that can be created.
+    ///
+    /// Returns:
+    ///     list[str]: The planner names, such as "RRT", "RRTConnect" and "PRM".
+    #[staticmethod]
+    fn names() -> Vec<String> {
+        registry::<RealVectorState, RealVectorStateSpace>()
+            .names()
+            .map(str::to_string)
+            .collect()
+    }
+
+    fn setup(&mut self, validity_callback: PyObject) -> PyResult<()> {
+        let checker = Arc::new(PyStateValidityChecker {
+            callback: validity_callback,
+        });
+        match (&mut self.planner, &self.pd) {
+            (PlannerVariant::RealVector(p), ProblemDefinitionVariant::RealVector(pd)) => {
+                p.setup(pd.clone(), checker)
+            }
+            (PlannerVariant::SO2(p), ProblemDefinitionVariant::SO2(pd)) => {
+                p.setup(pd.clone(), checker)
+            }
+            (PlannerVariant::SO3(p), ProblemDefinitionVariant::SO3(pd)) => {
+                p.setup(pd.clone(), checker)
+            }
+            (PlannerVariant::Compound(p), ProblemDefinitionVariant::Compound(pd)) => {
+                p.setup(pd.clone(), checker)
+            }
+            (PlannerVariant::SE2(p), ProblemDefinitionVariant::SE2(pd)) => {
+                p.setup(pd.clone(), checker)
+            }
+            (PlannerVariant::SE3(p), ProblemDefinitionVariant::SE3(pd)) => {
+                p.setup(pd.clone(), checker)
+            }
+            _ => unreachable!("the planner is created for the problem definition's state space"),
+        }
+        Ok(())
+    }
+
+    fn solve(&mut self, timeout_secs: f32) -> PyResult<PyPath> {
+        let timeout = Duration::from_secs_f32(timeout_secs);
+        match &mut self.planner {
+            PlannerVariant::RealVector(p) => p.solve(timeout).map(PyPath::from),
+            PlannerVariant::SO2(p) => p.solve(timeout).map(PyPath::from),
+            PlannerVariant::SO3(p) => p.solve(timeout).map(PyPath::from),
+            PlannerVariant::Compound(p) => p.solve(timeout).map(PyPath::from),
+            PlannerVariant::SE2(p) => p.solve(timeout).map(PyPath::from),
+            PlannerVariant::SE3(p) => p.solve(timeout).map(PyPath::from),
+        }
+        .map_err(|e| PyException::new_err(e.to_string()))
+    }
+
+    /// Returns the statistics collected since the planner was last set up.
+    ///
+    /// Returns:
+    ///     dict: The counts of iterations, samples drawn and rejected, validity checks, motion
+    ///         checks and nearest-neighbour queries, and the time spent in each phase in seconds
+    ///         under "phase_times".
+    fn stats<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let stats = match &self.planner {
+            PlannerVariant::RealVector(p) => p.stats(),
+            PlannerVariant::SO2(p) => p.stats(),
+            PlannerVariant::SO3(p) => p.stats(),
+            PlannerVariant::Compound(p) => p.stats(),
+            PlannerVariant::SE2(p) => p.stats(),
+            PlannerVariant::SE3(p) => p.stats(),
+        };
+        stats_to_dict(py, &stats)
+    }
+
+    /// Returns the planner's parameters and their current values.
+    ///
+    /// Returns:
+    ///     dict: Maps each parameter's name to a dict of its "value", "description", and the
+    ///         "min" and "max" it may take.
+    fn params<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let params = match &self.planner {
+            PlannerVariant::RealVector(p) => p.params(),
+            PlannerVariant::SO2(p) => p.params(),
+            PlannerVariant::SO3(p) => p.params(),
+            PlannerVariant::Compound(p) => p.params(),
+            PlannerVariant::SE2(p) => p.params(),
+            PlannerVariant::SE3(p) => p.params(),
+        };
+        params_to_dict(py, &params)
+    }
+
+    /// Sets a parameter by name. The value is converted with `str()` and parsed as the
+    /// parameter's type. Takes effect from the next call to `setup`.
+    ///
+    /// Args:
+    ///     name (str): The name of the parameter, as listed by `params()`.
+    ///     value: The new value.
+    ///
+    /// Raises:
+    ///     ValueError: If there is no such parameter, or the value is invalid or out of range.
+    ///         `num_threads` cannot be changed from Python.
+    fn set_param(&mut self, name: &str, value: &Bound<'_, PyAny>) -> PyResult<()> {
+        let value = value.str()?.to_string();
+        check_py_param(name, &value)?;
+        match &mut self.planner {
+            PlannerVariant::RealVector(p) => p.set_param(name, &value),
+            PlannerVariant::SO2(p) => p.set_param(name, &value),
+            PlannerVariant::SO3(p) => p.set_param(name, &value),
+            PlannerVariant::Compound(p) => p.set_param(name, &value),
+            PlannerVariant::SE2(p) => p.set_param(name, &value),
+            PlannerVariant::SE3(p) => p.set_param(name, &value),
+        }
+        .map_err(param_error_to_py)
+    }
+
+    /// Sets an object to be told about the planner's progress while it plans, as for the
+    /// `set_observer` method of the other planners.
+    ///
+    /// Args:
+    ///     observer: The object to notify.
+    fn set_observer(&mut self, observer: PyObject) {
+        let observer = PyPlannerObserver { callback: observer };
+        match &mut self.planner {
+            PlannerVariant::RealVector(p) => p.set_observer(Arc::new(observer)),
+            PlannerVariant::SO2(p) => p.set_observer(Arc::new(observer)),
+            PlannerVariant::SO3(p) => p.set_observer(Arc::new(observer)),
+            PlannerVariant::Compound(p) => p.set_observer(Arc::new(observer)),
+            PlannerVariant::SE2(p) => p.set_observer(Arc::new(observer)),
+            PlannerVariant::SE3(p) => p.set_observer(Arc::new(observer)),
+        }
+    }
+}
@@ -2,11 +2,12 @@
 //
 // SPDX-License-Identifier: BSD-3-Clause
 
-use pyo3::prelude::*;
+use pyo3::{prelude::*, types::PyDict};
 use std::{cell::RefCell, rc::Rc, sync::Arc, time::Duration};
 
 use crate::base::{
-    ProblemDefinitionVariant, PyGoal, PyPath, PyPlannerConfig, PyProblemDefinition,
+    check_py_param, param_error_to_py, params_to_dict, stats_to_dict, ProblemDefinitionVariant,
+    PyGoal, PyPath, PyPlannerConfig, PyPlannerObserver, PyProblemDefinition,
     PyStateValidityChecker,
 };
 use oxmpl::{
@@ -230,6 +231,100 @@ impl PyPrm {
         }
     }
 
+    /// Returns the statistics collected since the planner was last set up.
+    ///
+    /// Returns:
+    ///     dict: The counts of iterations, samples drawn and rejected, validity checks, motion
+    ///         checks and nearest-neighbour queries, and the time spent in each phase in seconds
+    ///         under "phase_times".
+    fn stats<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let stats = match &self.planner {
+            PlannerVariant::RealVector(p) => p.borrow().stats(),
+            PlannerVariant::SO2(p) => p.borrow().stats(),
+            PlannerVariant::SO3(p) => p.borrow().stats(),
+            PlannerVariant::Compound(p) => p.borrow().stats(),
+            PlannerVariant::SE2(p) => p.borrow().stats(),
+            PlannerVariant::SE3(p) => p.borrow().stats(),
+        };
+        stats_to_dict(py, &stats)
+    }
+
+    /// Returns the planner's parameters and their current values.
+    ///
+    /// Returns:
+    ///     dict: Maps each parameter's name to a dict of its "value", "description", and the
+    ///         "min" and "max" it may take.
+    fn params<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let params = match &self.planner {
+            PlannerVariant::RealVector(p) => p.borrow().params(),
+            PlannerVariant::SO2(p) => p.borrow().params(),
+            PlannerVariant::SO3(p) => p.borrow().params(),
+            PlannerVariant::Compound(p) => p.borrow().params(),
+            PlannerVariant::SE2(p) => p.borrow().params(),
+            PlannerVariant::SE3(p) => p.borrow().params(),
+        };
+        params_to_dict(py, &params)
+    }
+
+    /// Sets a parameter by name. The value is converted with `str()` and parsed as the
+    /// parameter's type, so `planner.set_param("goal_bias", 0.1)` and
+    /// `planner.set_param("goal_bias", "0.1")` are equivalent. Takes effect from the next call to
+    /// `setup`.
+    ///
+    /// Args:
+    ///     name (str): The name of the parameter, as listed by `params()`.
+    ///     value: The new value.
+    ///
+    /// Raises:
+    ///     ValueError: If there is no such parameter, or the value is invalid or out of range.
+    ///         `num_threads` cannot be changed from Python.
+    fn set_param(&mut self, name: &str, value: &Bound<'_, PyAny>) -> PyResult<()> {
+        let value = value.str()?.to_string();
+        check_py_param(name, &value)?;
+        match &mut self.planner {
+            PlannerVariant::RealVector(p) => p.borrow_mut().set_param(name, &value),
+            PlannerVariant::SO2(p) => p.borrow_mut().set_param(name, &value),
+            PlannerVariant::SO3(p) => p.borrow_mut().set_param(name, &value),
+            PlannerVariant::Compound(p) => p.borrow_mut().set_param(name, &value),
+            PlannerVariant::SE2(p) => p.borrow_mut().set_param(name, &value),
+            PlannerVariant::SE3(p) => p.borrow_mut().set_param(name, &value),
+        }
+        .map_err(param_error_to_py)
+    }
+
+    /// Sets an object to be told about the planner's progress while it plans.
+    ///
+    /// The object may define any of `on_iteration(iteration)`, `on_node_added(state)`,
+    /// `on_edge_added(from_state, to_state)`, `on_edge_removed(from_state, to_state)`,
+    /// `on_sample_rejected(state)`, where `state` is `None` if sampling failed, and
+    /// `on_solution(path, length)`. Methods it does not define are not called. The planner must not
+    /// be used from inside these methods.
+    ///
+    /// Args:
+    ///     observer: The object to notify.
+    fn set_observer(&mut self, observer: PyObject) {
+        match &mut self.planner {
+            PlannerVariant::RealVector(p) => p
+                .borrow_mut()
+                .set_observer(Arc::new(PyPlannerObserver { callback: observer })),
+            PlannerVariant::SO2(p) => p
+                .borrow_mut()
+                .set_observer(Arc::new(PyPlannerObserver { callback: observer })),
+            PlannerVariant::SO3(p) => p
+                .borrow_mut()
+                .set_observer(Arc::new(PyPlannerObserver { callback: observer })),
+            PlannerVariant::Compound(p) => p
+                .borrow_mut()
+                .set_observer(Arc::new(PyPlannerObserver { callback: observer })),
+            PlannerVariant::SE2(p) => p
+                .borrow_mut()
+                .set_observer(Arc::new(PyPlannerObserver { callback: observer })),
+            PlannerVariant::SE3(p) => p
+                .borrow_mut()
+                .set_observer(Arc::new(PyPlannerObserver { callback: observer })),
+        }
+    }
+
     fn construct_roadmap(&mut self) -> PyResult<()> {
         let result = match &mut self.planner {
             PlannerVariant::RealVector(p) => p.borrow_mut().construct_roadmap(),
@@ -244,4 +339,16 @@ impl PyPrm {
             Err(e) => Err(pyo3::exceptions::PyException::new_err(e.to_string())),
         }
     }
+
+    /// Discards the roadmap, so it is built again by the next call to `construct_roadmap`.
+    fn clear(&mut self) {
+        match &mut self.planner {
+            PlannerVariant::RealVector(p) => p.borrow_mut().clear(),
+            PlannerVariant::SO2(p) => p.borrow_mut().clear(),
+            PlannerVariant::SO3(p) => p.borrow_mut().clear(),
+            PlannerVariant::Compound(p) => p.borrow_mut().clear(),
+            PlannerVariant::SE2(p) => p.borrow_mut().clear(),
+            PlannerVariant::SE3(p) => p.borrow_mut().clear(),
+        }
+    }
 }
@@ -2,12 +2,13 @@
 //
 // SPDX-License-Identifier: BSD-3-Clause
 
-use pyo3::prelude::*;
+use pyo3::{prelude::*, types::PyDict};
 use std::{cell::RefCell, rc::Rc, sync::Arc, time::Duration};
 
 use crate::base::{
-    ProblemDefinitionVariant, PyGoal, PyPath, PyPlannerConfig, PyProblemDefinition,
-    PyStateValidityChecker,
+    check_py_param, param_error_to_py, params_to_dict, stats_to_dict, PathVariant,
+    ProblemDefinitionVariant, PyGoal, PyPath, PyPlannerConfig, PyPlannerObserver,
+    PyProblemDefinition, PyStateValidityChecker,
 };
 use oxmpl::{
     base::{
@@ -239,4 +240,149 @@ impl PyRrtStar {
             }
         }
     }
+
+    /// Returns the statistics collected since the planner was last set up.
+    ///
+    /// Returns:
+    ///     dict: The counts of iterations, samples drawn and rejected, validity checks, motion
+    ///         checks and nearest-neighbour queries, and the time spent in each phase in seconds
+    ///         under "phase_times".
+    fn stats<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let stats = match &self.planner {
+            PlannerVariant::RealVector(p) => p.borrow().stats(),
+            PlannerVariant::SO2(p) => p.borrow().stats(),
+            PlannerVariant::SO3(p) => p.borrow().stats(),
+            PlannerVariant::Compound(p) => p.borrow().stats(),
+            PlannerVariant::SE2(p) => p.borrow().stats(),
+            PlannerVariant::SE3(p) => p.borrow().stats(),
+        };
+        stats_to_dict(py, &stats)
+    }
+
+    /// Returns the planner's parameters and their current values.
+    ///
+    /// Returns:
+    ///     dict: Maps each parameter's name to a dict of its "value", "description", and the
+    ///         "min" and "max" it may take.
+    fn params<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let params = match &self.planner {
+            PlannerVariant::RealVector(p) => p.borrow().params(),
+            PlannerVariant::SO2(p) => p.borrow().params(),
+            PlannerVariant::SO3(p) => p.borrow().params(),
+            PlannerVariant::Compound(p) => p.borrow().params(),
+            PlannerVariant::SE2(p) => p.borrow().params(),
+            PlannerVariant::SE3(p) => p.borrow().params(),
+        };
+        params_to_dict(py, &params)
+    }
+
+    /// Sets a parameter by name. The value is converted with `str()` and parsed as the
+    /// parameter's type, so `planner.set_param("goal_bias", 0.1)` and
+    /// `planner.set_param("goal_bias", "0.1")` are equivalent. Takes effect from the next call to
+    /// `setup`.
+    ///
+    /// Args:
+    ///     name (str): The name of the parameter, as listed by `params()`.
+    ///     value: The new value.
+    ///
+    /// Raises:
+    ///     ValueError: If there is no such parameter, or the value is invalid or out of range.
+    ///         `num_threads` cannot be changed from Python.
+    fn set_param(&mut self, name: &str, value: &Bound<'_, PyAny>) -> PyResult<()> {
+        let value = value.str()?.to_string();
+        check_py_param(name, &value)?;
+        match &mut self.planner {
+            PlannerVariant::RealVector(p) => p.borrow_mut().set_param(name, &value),
+            PlannerVariant::SO2(p) => p.borrow_mut().set_param(name, &value),
+            PlannerVariant::SO3(p) => p.borrow_mut().set_param(name, &value),
+            PlannerVariant::Compound(p) => p.borrow_mut().set_param(name, &value),
+            PlannerVariant::SE2(p) => p.borrow_mut().set_param(name, &value),
+            PlannerVariant::SE3(p) => p.borrow_mut().set_param(name, &value),
+        }
+        .map_err(param_error_to_py)
+    }
+
+    /// Sets an object to be told about the planner's progress while it plans.
+    ///
+    /// The object may define any of `on_iteration(iteration)`, `on_node_added(state)`,
+    /// `on_edge_added(from_state, to_state)`, `on_edge_removed(from_state, to_state)`,
+    /// `on_sample_rejected(state)`, where `state` is `None` if sampling failed, and
+    /// `on_solution(path, length)`. Methods it does not define are not called. The planner must not
+    /// be used from inside these methods.
+    ///
+    /// Args:
+    ///     observer: The object to notify.
+    fn set_observer(&mut self, observer: PyObject) {
+        match &mut self.planner {
+            PlannerVariant::RealVector(p) => p
+                .borrow_mut()
+                .set_observer(Arc::new(PyPlannerObserver { callback: observer })),
+            PlannerVariant::SO2(p) => p
+                .borrow_mut()
+                .set_observer(Arc::new(PyPlannerObserver { callback: observer })),
+            PlannerVariant::SO3(p) => p
+                .borrow_mut()
+                .set_observer(Arc::new(PyPlannerObserver { callback: observer })),
+            PlannerVariant::Compound(p) => p
+                .borrow_mut()
+                .set_observer(Arc::new(PyPlannerObserver { callback: observer })),
+            PlannerVariant::SE2(p) => p
+                .borrow_mut()
+                .set_observer(Arc::new(PyPlannerObserver { callback: observer })),
+            PlannerVariant::SE3(p) => p
+                .borrow_mut()
+                .set_observer(Arc::new(PyPlannerObserver { callback: observer })),
+        }
+    }
+
+    /// Discards the tree, so the next call to `solve` grows a new one from the start state.
+    fn clear(&mut self) {
+        match &mut self.planner {
+            PlannerVariant::RealVector(p) => p.borrow_mut().clear(),
+            PlannerVariant::SO2(p) => p.borrow_mut().clear(),
+            PlannerVariant::SO3(p) => p.borrow_mut().clear(),
+            PlannerVariant::Compound(p) => p.borrow_mut().clear(),
+            PlannerVariant::SE2(p) => p.borrow_mut().clear(),
+            PlannerVariant::SE3(p) => p.borrow_mut().clear(),
+        }
+    }
+
+    /// Adds the states of `path` to the tree, in order, to warm start the planner.
+    ///
+    /// Each state is connected to the nearest node already in the tree if the motion between
+    /// them is valid, and skipped otherwise. `setup` must be called first.
+    ///
+    /// Args:
+    ///     path (Path): A path in the same state space, such as one found in a previous cycle.
+    ///
+    /// Returns:
+    ///     int: The number of states added to the tree.
+    fn warm_start(&mut self, path: &PyPath) -> PyResult<usize> {
+        let result = match (&mut self.planner, &path.0) {
+            (PlannerVariant::RealVector(p), PathVariant::RealVector(path)) => {
+                p.borrow_mut().warm_start(path.0.iter().cloned())
+            }
+            (PlannerVariant::SO2(p), PathVariant::SO2(path)) => {
+                p.borrow_mut().warm_start(path.0.iter().cloned())
+            }
+            (PlannerVariant::SO3(p), PathVariant::SO3(path)) => {
+                p.borrow_mut().warm_start(path.0.iter().cloned())
+            }
+            (PlannerVariant::Compound(p), PathVariant::Compound(path)) => {
+                p.borrow_mut().warm_start(path.0.iter().cloned())
+            }
+            (PlannerVariant::SE2(p), PathVariant::SE2(path)) => {
+                p.borrow_mut().warm_start(path.0.iter().cloned())
+            }
+            (PlannerVariant::SE3(p), PathVariant::SE3(path)) => {
+                p.borrow_mut().warm_start(path.0.iter().cloned())
+            }
+            _ => {
+                return Err(pyo3::exceptions::PyValueError::new_err(
+                    "The path is not in the planner's state space.",
+                ))
+            }
+        };
+        result.map_err(|e| pyo3::exceptions::PyException::new_err(e.to_string()))
+    }
 }
@@ -0,0 +1,210 @@
+import pytest
+import math
+import random
+
+from oxmpl_py.base import (
+    GoalLazySamples,
+    GoalSpace,
+    GoalState,
+    GoalStates,
+    PlannerConfig,
+    ProblemDefinition,
+    RealVectorState,
+    RealVectorStateSpace,
+    SE2PoseGoal,
+    SE2State,
+    SE2StateSpace,
+    SE3PoseGoal,
+    SE3State,
+    SO3State,
+    SO2State,
+    SO2StateSpace,
+)
+from oxmpl_py.geometric import RRT, RRTConnect
+
+
+def is_state_valid(state: RealVectorState) -> bool:
+    x, y = state.values
+
+    wall_x_pos = 5.0
+    wall_y_min = 2.0
+    wall_y_max = 8.0
+    wall_thickness = 0.5
+
+    is_in_wall = (
+        x >= wall_x_pos - wall_thickness / 2.0
+        and x <= wall_x_pos + wall_thickness / 2.0
+        and y >= wall_y_min
+        and y <= wall_y_max
+    )
+
+    return not is_in_wall
+
+
+def solve_rvss(goal, planner_class=RRTConnect):
+    space = RealVectorStateSpace(dimension=2, bounds=[(0.0, 10.0), (0.0, 10.0)])
+    start_state = RealVectorState([1.0, 5.0])
+
+    problem_def = ProblemDefinition.from_real_vector(space, start_state, goal)
+    planner = planner_class(
+        max_distance=0.5,
+        goal_bias=0.05,
+        problem_definition=problem_def,
+        planner_config=PlannerConfig(seed=1),
+    )
+    planner.setup(is_state_valid)
+
+    try:
+        path = planner.solve(timeout_secs=5.0)
+    except Exception as e:
+        pytest.fail(
+            f"Planner failed to find a solution when one should exist. Error: {e}"
+        )
+
+    assert space.distance(path.states[0], start_state) < 1e-9, (
+        "Path must start at the start state."
+    )
+    assert goal.is_satisfied(path.states[-1]), "Path must end inside the goal region."
+    for state in path.states:
+        assert is_state_valid(state), f"Path contains an invalid state: {state.values}"
+
+    return path
+
+
+def test_goal_state():
+    space = RealVectorStateSpace(dimension=2, bounds=[(0.0, 10.0), (0.0, 10.0)])
+    goal = GoalState(space, RealVectorState([9.0, 5.0]), 0.5)
+
+    assert goal.is_satisfied(RealVectorState([9.0, 5.2]))
+    assert not goal.is_satisfied(RealVectorState([8.0, 5.0]))
+    assert goal.distance_goal(RealVectorState([7.0, 5.0])) == pytest.approx(1.5)
+    assert goal.sample_goal().values == [9.0, 5.0]
+
+    solve_rvss(goal)
+
+
+def test_goal_state_wraps_in_so2():
+    space = SO2StateSpace()
+    goal = GoalState(space, SO2State(math.pi - 0.05), 0.2)
+
+    assert goal.is_satisfied(SO2State(-math.pi + 0.05))
+    assert not goal.is_satisfied(SO2State(0.0))
+
+
+def test_goal_state_rejects_mismatched_state():
+    space = RealVectorStateSpace(dimension=2, bounds=[(0.0, 10.0), (0.0, 10.0)])
+
+    with pytest.raises(TypeError):
+        GoalState(space, SO2State(0.0), 0.5)
+
+
+def test_goal_states():
+    space = SE2StateSpace(
+        weight=1.0, bounds=[(0.0, 10.0), (0.0, 10.0), (-math.pi, math.pi)]
+    )
+    goal = GoalStates(space, [], 0.5)
+
+    with pytest.raises(ValueError):
+        goal.sample_goal()
+
+    goal.add_state(SE2State(9.0, 9.0, 0.0))
+    goal.add_state(SE2State(1.0, 9.0, 0.0))
+
+    assert len(goal) == 2
+    assert goal.is_satisfied(SE2State(1.2, 9.0, 0.0))
+    assert not goal.is_satisfied(SE2State(5.0, 5.0, 0.0))
+    assert goal.sample_goal().y == pytest.approx(9.0)
+
+
+def test_goal_space():
+    goal_space = RealVectorStateSpace(dimension=2, bounds=[(8.5, 9.5), (4.5, 5.5)])
+    goal = GoalSpace(goal_space)
+
+    assert goal.is_satisfied(RealVectorState([9.0, 5.0]))
+    assert goal.distance_goal(RealVectorState([7.5, 5.0])) == pytest.approx(1.0)
+
+    solve_rvss(goal)
+
+
+def test_goal_lazy_samples():
+    space = RealVectorStateSpace(dimension=2, bounds=[(0.0, 10.0), (0.0, 10.0)])
+    rng = random.Random(123)
+
+    def sampler():
+        angle = rng.uniform(0, 2 * math.pi)
+        radius = 0.5 * math.sqrt(rng.uniform(0, 1))
+        return RealVectorState(
+            [9.0 + radius * math.cos(angle), 5.0 + radius * math.sin(angle)]
+        )
+
+    goal = GoalLazySamples(space, 0.1, 50, sampler)
+
+    # Blocks until the sampling thread has found its first state.
+    first = goal.sample_goal()
+    assert goal.is_satisfied(first)
+
+    solve_rvss(goal, RRT)
+
+    goal.stop()
+    assert not goal.is_sampling
+    assert 1 <= goal.sample_count <= 50
+
+
+def test_goal_lazy_samples_without_samples():
+    space = RealVectorStateSpace(dimension=2, bounds=[(0.0, 10.0), (0.0, 10.0)])
+    goal = GoalLazySamples(space, 0.1, 50, lambda: None)
+
+    with pytest.raises(ValueError):
+        goal.sample_goal()
+    assert goal.sample_count == 0
+
+
+def test_se2_pose_goal():
+    space = SE2StateSpace(
+        weight=1.0, bounds=[(0.0, 10.0), (0.0, 10.0), (-math.pi, math.pi)]
+    )
+    goal = SE2PoseGoal(SE2State(9.0, 9.0, math.pi / 2.0), 0.2, math.radians(5.0))
+
+    assert goal.is_satisfied(SE2State(9.1, 9.0, math.pi / 2.0 + 0.05))
+    assert not goal.is_satisfied(SE2State(9.1, 9.0, 0.0))
+    assert goal.orientation_error(SE2State(9.0, 9.0, 0.0)) == pytest.approx(math.pi / 2.0)
+    for _ in range(20):
+        assert goal.is_satisfied(goal.sample_goal())
+
+    problem_def = ProblemDefinition.from_se2(space, SE2State(1.0, 1.0, 0.0), goal)
+    planner = RRTConnect(
+        max_distance=0.5,
+        goal_bias=0.1,
+        problem_definition=problem_def,
+        planner_config=PlannerConfig(seed=1),
+    )
+    planner.setup(lambda state: True)
+    path = planner.solve(timeout_secs=5.0)
+
+    assert goal.is_satisfied(path.states[-1]), "Path must end inside the goal region."
+
+    yaw_free = SE2PoseGoal(SE2State(9.0, 9.0, math.pi / 2.0), 0.2, 0.0, yaw_free=True)
+    assert yaw_free.is_satisfied(SE2State(9.1, 9.0, 0.0))
+
+
+def test_se3_pose_goal():
+    identity = SO3State(0.0, 0.0, 0.0, 1.0)
+    half_yaw = math.sin(0.5)
+    yawed = SO3State(0.0, 0.0, half_yaw, math.cos(0.5))
+
+    # Within 5 mm and 2 degrees.
+    goal = SE3PoseGoal(SE3State(0.5, 0.0, 0.3, identity), 0.005, math.radians(2.0))
+    assert goal.is_satisfied(SE3State(0.503, 0.0, 0.3, identity))
+    assert not goal.is_satisfied(SE3State(0.51, 0.0, 0.3, identity))
+    assert not goal.is_satisfied(SE3State(0.5, 0.0, 0.3, yawed))
+    assert goal.orientation_error(SE3State(0.5, 0.0, 0.3, yawed)) == pytest.approx(1.0)
+    for _ in range(20):
+        assert goal.is_satisfied(goal.sample_goal())
+
+    yaw_free = SE3PoseGoal(
+        SE3State(0.5, 0.0, 0.3, identity), 0.005, math.radians(2.0), free_axis="z"
+    )
+    assert yaw_free.is_satisfied(SE3State(0.5, 0.0, 0.3, yawed))
+
+    with pytest.raises(ValueError):
+        SE3PoseGoal(SE3State(0.5, 0.0, 0.3, identity), 0.005, 0.1, free_axis="w")
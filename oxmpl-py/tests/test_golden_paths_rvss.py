@@ -0,0 +1,71 @@
+import json
+from pathlib import Path
+
+from oxmpl_py.base import (
+    GoalSpace,
+    GoalState,
+    PlannerConfig,
+    ProblemDefinition,
+    RealVectorState,
+    RealVectorStateSpace,
+)
+from oxmpl_py.geometric import Planner
+
+# The paths recorded by the Rust golden test, `oxmpl/tests/golden_paths_rvss_tests.rs`, which
+# seeded planners must find exactly from Python too.
+GOLDEN_FILE = (
+    Path(__file__).resolve().parents[2] / "oxmpl" / "tests" / "golden" / "paths_rvss.json"
+)
+
+SEED = 42
+
+CASES = [
+    ("RRT", "GoalState"),
+    ("RRTConnect", "GoalState"),
+    ("RRTStar", "GoalState"),
+    ("RRT", "GoalSpace"),
+    ("RRTConnect", "GoalSpace"),
+]
+
+
+def is_state_valid(state: RealVectorState) -> bool:
+    x, y = state.values
+
+    wall_x_pos = 5.0
+    wall_y_min = 2.0
+    wall_y_max = 8.0
+    wall_thickness = 0.5
+
+    is_in_wall = (
+        x >= wall_x_pos - wall_thickness / 2.0
+        and x <= wall_x_pos + wall_thickness / 2.0
+        and y >= wall_y_min
+        and y <= wall_y_max
+    )
+
+    return not is_in_wall
+
+
+def plan(planner_name: str, goal_name: str) -> list[list[float]]:
+    space = RealVectorStateSpace(dimension=2, bounds=[(0.0, 10.0), (0.0, 10.0)])
+    if goal_name == "GoalState":
+        goal = GoalState(space, RealVectorState([9.0, 5.0]), 0.5)
+    else:
+        goal = GoalSpace(RealVectorStateSpace(dimension=2, bounds=[(8.5, 9.5), (4.5, 5.5)]))
+    problem_def = ProblemDefinition.from_real_vector(space, RealVectorState([1.0, 5.0]), goal)
+
+    planner = Planner(planner_name, problem_def, PlannerConfig(seed=SEED))
+    planner.set_param("max_distance", 0.5)
+    planner.set_param("goal_bias", 0.05)
+    planner.setup(is_state_valid)
+    path = planner.solve(timeout_secs=10.0)
+    return [state.values for state in path.states]
+
+
+def test_seeded_planners_find_the_golden_paths():
+    golden = json.loads(GOLDEN_FILE.read_text())
+    assert list(golden) == [f"{planner}/{goal}" for planner, goal in CASES]
+
+    for planner, goal in CASES:
+        case = f"{planner}/{goal}"
+        assert plan(planner, goal) == golden[case], case
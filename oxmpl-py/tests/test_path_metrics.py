@@ -0,0 +1,62 @@
+import math
+
+import pytest
+
+from oxmpl_py.base import (
+    GoalState,
+    Path,
+    ProblemDefinition,
+    RealVectorState,
+    RealVectorStateSpace,
+    SO2State,
+)
+from oxmpl_py.geometric import PathMetrics
+
+
+def is_state_valid(state: RealVectorState) -> bool:
+    x, y = state.values
+    is_in_wall = 4.75 <= x <= 5.25 and 2.0 <= y <= 8.0
+    return not is_in_wall
+
+
+def distance_to_floor(state: RealVectorState) -> float:
+    return state.values[1]
+
+
+def create_problem() -> ProblemDefinition:
+    space = RealVectorStateSpace(dimension=2, bounds=[(0.0, 10.0), (0.0, 10.0)])
+    goal = GoalState(space, RealVectorState([9.0, 5.0]), 0.5)
+    return ProblemDefinition.from_real_vector(space, RealVectorState([1.0, 5.0]), goal)
+
+
+def test_path_metrics_for_corner_path():
+    path = Path.from_real_vector_states(
+        [
+            RealVectorState([1.0, 1.0]),
+            RealVectorState([4.0, 1.0]),
+            RealVectorState([4.0, 5.0]),
+        ]
+    )
+    metrics = PathMetrics.compute(path, create_problem(), is_state_valid, distance_to_floor)
+
+    assert metrics.length == pytest.approx(7.0)
+    assert metrics.cost == pytest.approx(7.0)
+    assert metrics.smoothness == pytest.approx(math.pi / 2)
+    assert metrics.vertices == 3
+    assert metrics.min_clearance == pytest.approx(1.0)
+    assert 1.0 < metrics.average_clearance < 5.0
+
+
+def test_path_metrics_without_clearance():
+    path = Path.from_real_vector_states([RealVectorState([1.0, 5.0]), RealVectorState([3.0, 5.0])])
+    metrics = PathMetrics.compute(path, create_problem(), is_state_valid)
+
+    assert metrics.smoothness == 0.0
+    assert metrics.min_clearance is None
+    assert metrics.average_clearance is None
+
+
+def test_path_metrics_rejects_mismatched_space():
+    path = Path.from_so2_states([SO2State(0.0), SO2State(1.0)])
+    with pytest.raises(ValueError):
+        PathMetrics.compute(path, create_problem(), is_state_valid)
@@ -0,0 +1,96 @@
+import pytest
+import math
+import random
+
+from oxmpl_py.base import (
+    RealVectorState,
+    RealVectorStateSpace,
+    ProblemDefinition,
+    PlannerConfig,
+)
+from oxmpl_py.geometric import Planner
+
+
+class CircularGoal:
+    def __init__(self, space: RealVectorStateSpace, x: float, y: float, radius: float):
+        self.space = space
+        self.target = RealVectorState([x, y])
+        self.radius = radius
+        self.rng = random.Random(123)
+
+    def is_satisfied(self, state: RealVectorState) -> bool:
+        return self.space.distance(self.target, state) <= self.radius
+
+    def sample_goal(self) -> RealVectorState:
+        angle = self.rng.uniform(0, 2 * math.pi)
+        radius = self.radius * math.sqrt(self.rng.uniform(0, 1))
+
+        x = self.target.values[0] + radius * math.cos(angle)
+        y = self.target.values[1] + radius * math.sin(angle)
+        return RealVectorState([x, y])
+
+
+def is_state_valid(state: RealVectorState) -> bool:
+    x, y = state.values
+
+    wall_x_pos = 5.0
+    wall_y_min = 2.0
+    wall_y_max = 8.0
+    wall_thickness = 0.5
+
+    is_in_wall = (
+        x >= wall_x_pos - wall_thickness / 2.0
+        and x <= wall_x_pos + wall_thickness / 2.0
+        and y >= wall_y_min
+        and y <= wall_y_max
+    )
+
+    return not is_in_wall
+
+
+def test_planners_can_be_created_by_name_in_rvss():
+    names = Planner.names()
+    assert "RRTConnect" in names
+    assert "PRM" in names
+    # CForest grows its trees on other threads, which cannot call Python validity checkers.
+    assert "CForest" not in names
+
+    space = RealVectorStateSpace(dimension=2, bounds=[(0.0, 10.0), (0.0, 10.0)])
+    start_state = RealVectorState([1.0, 5.0])
+
+    for name in ["RRTConnect", "RRTStar", "PRM", "SPARS2"]:
+        goal_region = CircularGoal(space, x=9.0, y=5.0, radius=0.5)
+        problem_def = ProblemDefinition.from_real_vector(space, start_state, goal_region)
+        planner = Planner(name, problem_def, PlannerConfig(seed=3))
+        planner.setup(is_state_valid)
+        path = planner.solve(timeout_secs=2.0)
+
+        assert len(path.states) > 1, name
+        assert path.states[0].values == start_state.values
+        assert goal_region.is_satisfied(path.states[-1]), name
+        assert all(is_state_valid(state) for state in path.states), name
+
+
+def test_planner_created_by_name_can_be_configured_in_rvss():
+    space = RealVectorStateSpace(dimension=2, bounds=[(0.0, 10.0), (0.0, 10.0)])
+    goal_region = CircularGoal(space, x=9.0, y=5.0, radius=0.5)
+    problem_def = ProblemDefinition.from_real_vector(
+        space, RealVectorState([1.0, 5.0]), goal_region
+    )
+
+    with pytest.raises(ValueError):
+        Planner("RRTSharp", problem_def, PlannerConfig(seed=3))
+    with pytest.raises(ValueError):
+        Planner("CForest", problem_def, PlannerConfig(seed=3))
+
+    planner = Planner("PRM", problem_def, PlannerConfig(seed=3))
+    assert planner.params()["connection_radius"]["value"] == 0.0
+    planner.set_param("connection_radius", 2.0)
+    with pytest.raises(ValueError):
+        planner.set_param("num_threads", 2)
+
+    planner.setup(is_state_valid)
+    assert planner.params()["connection_radius"]["value"] == pytest.approx(2.0)
+    path = planner.solve(timeout_secs=2.0)
+    assert len(path.states) > 1
+    assert planner.stats()["iterations"] > 0
@@ -0,0 +1,41 @@
+from oxmpl_py.base import (
+    GoalState,
+    ProblemDefinition,
+    RealVectorState,
+    RealVectorStateSpace,
+)
+
+
+def is_state_valid(state: RealVectorState) -> bool:
+    x, y = state.values
+    is_in_wall = 4.75 <= x <= 5.25 and 2.0 <= y <= 8.0
+    return not is_in_wall
+
+
+def test_validate_reports_no_problems_for_valid_problem():
+    space = RealVectorStateSpace(dimension=2, bounds=[(0.0, 10.0), (0.0, 10.0)])
+    goal = GoalState(space, RealVectorState([9.0, 5.0]), 0.5)
+    problem_def = ProblemDefinition.from_real_vector(space, RealVectorState([1.0, 5.0]), goal)
+
+    assert problem_def.validate(is_state_valid) == []
+
+
+def test_validate_reports_invalid_start_and_goal():
+    space = RealVectorStateSpace(dimension=2, bounds=[(0.0, 10.0), (0.0, 10.0)])
+    goal = GoalState(space, RealVectorState([5.0, 5.0]), 0.0)
+    problem_def = ProblemDefinition.from_real_vector(space, RealVectorState([5.0, 4.0]), goal)
+
+    problems = problem_def.validate(is_state_valid)
+    assert len(problems) == 2
+    assert "Start state 0" in problems[0]
+    assert problems[1].startswith("Warning: ")
+    assert "goal" in problems[1]
+
+
+def test_validate_reports_out_of_bounds_start():
+    space = RealVectorStateSpace(dimension=2, bounds=[(0.0, 10.0), (0.0, 10.0)])
+    goal = GoalState(space, RealVectorState([9.0, 5.0]), 0.5)
+    problem_def = ProblemDefinition.from_real_vector(space, RealVectorState([11.0, 5.0]), goal)
+
+    problems = problem_def.validate(is_state_valid)
+    assert problems == ["Start state 0 is outside the bounds of the space."]
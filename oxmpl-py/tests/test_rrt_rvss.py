@@ -89,3 +89,162 @@ def test_rrt_finds_path_in_rvss():
         assert is_state_valid(state), f"Path contains an invalid state: {state.values}"
 
     print("Path validation successful!")
+
+
+def test_rrt_resumes_and_warm_starts_in_rvss():
+    space = RealVectorStateSpace(dimension=2, bounds=[(0.0, 10.0), (0.0, 10.0)])
+    start_state = RealVectorState([1.0, 5.0])
+    goal_region = CircularGoal(space, x=9.0, y=5.0, radius=0.5)
+    problem_def = ProblemDefinition.from_real_vector(space, start_state, goal_region)
+
+    planner = RRT(
+        max_distance=0.5,
+        goal_bias=0.05,
+        problem_definition=problem_def,
+        planner_config=PlannerConfig(seed=2),
+    )
+    planner.setup(is_state_valid)
+
+    # Plan in small slices of time; each call carries on growing the same tree.
+    path = None
+    for _ in range(10000):
+        try:
+            path = planner.solve(timeout_secs=0.001)
+            break
+        except Exception:
+            continue
+    assert path is not None
+    assert goal_region.is_satisfied(path.states[-1])
+
+    # Once the tree reaches the goal, the path is returned straight away.
+    assert len(planner.solve(timeout_secs=0.0).states) == len(path.states)
+
+    planner.clear()
+    with pytest.raises(Exception):
+        planner.solve(timeout_secs=0.0)
+
+    seeded = RRT(
+        max_distance=0.5,
+        goal_bias=0.05,
+        problem_definition=problem_def,
+        planner_config=PlannerConfig(seed=3),
+    )
+    seeded.setup(is_state_valid)
+    assert seeded.warm_start(path) == len(path.states) - 1
+    seeded_path = seeded.solve(timeout_secs=0.0)
+    assert goal_region.is_satisfied(seeded_path.states[-1])
+
+
+def test_rrt_reports_stats_in_rvss():
+    space = RealVectorStateSpace(dimension=2, bounds=[(0.0, 10.0), (0.0, 10.0)])
+    start_state = RealVectorState([1.0, 5.0])
+    goal_region = CircularGoal(space, x=9.0, y=5.0, radius=0.5)
+    problem_def = ProblemDefinition.from_real_vector(space, start_state, goal_region)
+
+    planner = RRT(
+        max_distance=0.5,
+        goal_bias=0.05,
+        problem_definition=problem_def,
+        planner_config=PlannerConfig(seed=4),
+    )
+    planner.setup(is_state_valid)
+    planner.solve(timeout_secs=5.0)
+
+    stats = planner.stats()
+    assert stats["iterations"] > 0
+    assert stats["samples_drawn"] == stats["iterations"]
+    assert stats["motion_checks"] > 0
+    assert stats["validity_checks"] >= stats["motion_checks"]
+    assert stats["nearest_neighbour_queries"] > 0
+    assert stats["phase_times"]["motion checking"] > 0.0
+
+    planner.setup(is_state_valid)
+    assert planner.stats()["iterations"] == 0
+
+
+class TreeRecorder:
+    """Records the tree as it grows. `on_edge_removed` is left out, so it is never called."""
+
+    def __init__(self):
+        self.iterations = 0
+        self.nodes = []
+        self.edges = []
+        self.solutions = []
+
+    def on_iteration(self, iteration):
+        self.iterations = iteration
+
+    def on_node_added(self, state):
+        self.nodes.append(state.values)
+
+    def on_edge_added(self, from_state, to_state):
+        self.edges.append((from_state.values, to_state.values))
+
+    def on_solution(self, path, length):
+        self.solutions.append((path, length))
+
+
+def test_rrt_streams_tree_to_observer_in_rvss():
+    space = RealVectorStateSpace(dimension=2, bounds=[(0.0, 10.0), (0.0, 10.0)])
+    start_state = RealVectorState([1.0, 5.0])
+    goal_region = CircularGoal(space, x=9.0, y=5.0, radius=0.5)
+    problem_def = ProblemDefinition.from_real_vector(space, start_state, goal_region)
+
+    planner = RRT(
+        max_distance=0.5,
+        goal_bias=0.05,
+        problem_definition=problem_def,
+        planner_config=PlannerConfig(seed=5),
+    )
+    recorder = TreeRecorder()
+    planner.set_observer(recorder)
+    planner.setup(is_state_valid)
+    path = planner.solve(timeout_secs=5.0)
+
+    assert recorder.iterations == planner.stats()["iterations"]
+    assert recorder.nodes[0] == [1.0, 5.0]
+    assert len(recorder.edges) == len(recorder.nodes) - 1
+    assert len(recorder.solutions) == 1
+    solution, length = recorder.solutions[0]
+    assert [s.values for s in solution.states] == [s.values for s in path.states]
+    assert length > space.distance(start_state, RealVectorState([8.5, 5.0]))
+
+
+def test_rrt_params_can_be_listed_and_set_in_rvss():
+    space = RealVectorStateSpace(dimension=2, bounds=[(0.0, 10.0), (0.0, 10.0)])
+    start_state = RealVectorState([1.0, 5.0])
+    goal_region = CircularGoal(space, x=9.0, y=5.0, radius=0.5)
+    problem_def = ProblemDefinition.from_real_vector(space, start_state, goal_region)
+
+    planner = RRT(
+        max_distance=0.5,
+        goal_bias=0.05,
+        problem_definition=problem_def,
+        planner_config=PlannerConfig(seed=7),
+    )
+    params = planner.params()
+    assert list(params) == ["max_distance", "goal_bias", "num_threads"]
+    assert params["goal_bias"]["value"] == pytest.approx(0.05)
+    assert params["goal_bias"]["min"] == 0.0
+    assert params["goal_bias"]["max"] == 1.0
+    assert params["num_threads"]["max"] == math.inf
+    assert params["max_distance"]["description"]
+
+    planner.set_param("goal_bias", 0.2)
+    planner.set_param("num_threads", "1")
+    assert planner.params()["goal_bias"]["value"] == pytest.approx(0.2)
+
+    with pytest.raises(ValueError):
+        planner.set_param("goal_bias", 1.5)
+    with pytest.raises(ValueError):
+        planner.set_param("step_size", 1.0)
+    # Planner threads cannot call Python validity checkers.
+    with pytest.raises(ValueError):
+        planner.set_param("num_threads", 2)
+
+    # A max_distance of 0 means a fifth of the space's maximum extent, and is kept as 0.
+    planner.set_param("max_distance", 0)
+    planner.setup(is_state_valid)
+    assert planner.params()["max_distance"]["value"] == 0.0
+    path = planner.solve(timeout_secs=5.0)
+    assert len(path.states) > 1
@@ -11,7 +11,7 @@ use oxmpl::base::{
 };
 use oxmpl::geometric::RRT;
 
-use rand::Rng;
+use rand::{Rng, RngCore};
 
 /// A StateValidityChecker that checks for collision with a box obstacle.
 /// The robot is considered a point with orientation, but we check if the point
@@ -67,7 +67,7 @@ impl GoalRegion<CompoundState> for CompoundGoalRegion {
 }
 
 impl GoalSampleableRegion<CompoundState> for CompoundGoalRegion {
-    fn sample_goal(&self, rng: &mut impl Rng) -> Result<CompoundState, StateSamplingError> {
+    fn sample_goal(&self, rng: &mut dyn RngCore) -> Result<CompoundState, StateSamplingError> {
         // Get target components
         let target_rv = self.target.components[0]
             .as_any()
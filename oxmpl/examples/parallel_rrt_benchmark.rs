@@ -0,0 +1,106 @@
+use std::{hint::black_box, sync::Arc, time::Duration};
+
+use oxmpl::base::{
+    goal::GoalState, problem_definition::ProblemDefinition, space::RealVectorStateSpace,
+    state::RealVectorState, validity::StateValidityChecker,
+};
+use oxmpl::geometric::RRT;
+use oxmpl::tools::{Benchmark, BenchmarkRequest, RunResult};
+
+/// The thread counts compared by the benchmark.
+const THREAD_COUNTS: [usize; 4] = [1, 2, 4, 8];
+
+/// A StateValidityChecker with a wall that leaves a narrow gap, and a cost per check similar to
+/// that of a collision check against a detailed model.
+struct NarrowGapChecker {
+    wall_x_pos: f64,
+    gap_y_min: f64,
+    gap_y_max: f64,
+    wall_thickness: f64,
+}
+
+impl StateValidityChecker<RealVectorState> for NarrowGapChecker {
+    fn is_valid(&self, state: &RealVectorState) -> bool {
+        let x = state.values[0];
+        let y = state.values[1];
+
+        // Stand in for the work of a real collision check.
+        let mut work = 0.0;
+        for i in 0..20_000 {
+            work += black_box(f64::from(i) * x).sqrt();
+        }
+        black_box(work);
+
+        let is_in_wall = x >= self.wall_x_pos - self.wall_thickness / 2.0
+            && x <= self.wall_x_pos + self.wall_thickness / 2.0
+            && !(self.gap_y_min..=self.gap_y_max).contains(&y);
+
+        !is_in_wall
+    }
+}
+
+/// Compares the time RRT takes to solve a problem with an expensive validity checker on different
+/// numbers of threads.
+///
+/// Run it in release mode:
+///
+/// ```bash
+/// cargo run --release --example parallel_rrt_benchmark
+/// ```
+fn main() {
+    let space = Arc::new(
+        RealVectorStateSpace::new(2, Some(vec![(0.0, 10.0), (0.0, 10.0)]))
+            .expect("Failed to create state space"),
+    );
+    let problem_def = Arc::new(ProblemDefinition {
+        space: space.clone(),
+        start_states: vec![RealVectorState::new(vec![1.0, 5.0])],
+        goal: Arc::new(GoalState::new(
+            space.clone(),
+            RealVectorState::new(vec![9.0, 5.0]),
+            0.5,
+        )),
+    });
+    let checker = Arc::new(NarrowGapChecker {
+        wall_x_pos: 5.0,
+        gap_y_min: 4.75,
+        gap_y_max: 5.25,
+        wall_thickness: 0.5,
+    });
+
+    let mut benchmark = Benchmark::new("parallel RRT", problem_def, checker);
+    for num_threads in THREAD_COUNTS {
+        benchmark.add_planner(format!("RRT ({num_threads} threads)"), move |config| {
+            let mut planner = RRT::new(0.2, 0.05, config);
+            planner.num_threads = num_threads;
+            planner
+        });
+    }
+
+    let results = benchmark.run(&BenchmarkRequest {
+        runs: 20,
+        time_limit: Duration::from_secs(30),
+        simplify: false,
+        // Unseeded, so that RRT grows its tree with concurrent workers rather than in rounds.
+        seed: None,
+    });
+
+    let mean_time = |runs: &[RunResult]| {
+        runs.iter().map(|run| run.time.as_secs_f64()).sum::<f64>() / runs.len() as f64
+    };
+    let sequential_time = mean_time(&results.planners[0].runs);
+    println!(
+        "{:<20} {:>10} {:>10} {:>8}",
+        "planner", "solved", "mean (s)", "speed-up"
+    );
+    for planner in &results.planners {
+        let time = mean_time(&planner.runs);
+        println!(
+            "{:<20} {:>9.0}% {:>10.3} {:>7.2}x",
+            planner.name,
+            planner.success_rate() * 100.0,
+            time,
+            sequential_time / time
+        );
+    }
+}
@@ -105,6 +105,10 @@ pub enum PlanningError {
     InvalidStartState,
     /// State space hasn't been sampled.
     UnsampledStateSpace,
+    /// The problem definition is malformed.
+    InvalidProblem(ProblemDefinitionError),
+    /// No goal was given.
+    NoGoal,
 }
 impl fmt::Display for PlanningError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -130,7 +134,139 @@ impl fmt::Display for PlanningError {
                     "StateSpace is not sampled. Either Tree or Roadmap is empty."
                 )
             }
+            Self::InvalidProblem(e) => {
+                write!(f, "Invalid problem definition: {e}")
+            }
+            Self::NoGoal => {
+                write!(f, "No goal was given.")
+            }
         }
     }
 }
 impl error::Error for PlanningError {}
+
+#[derive(Debug, PartialEq)]
+pub enum ProblemDefinitionError {
+    /// The problem has no start states.
+    NoStartStates,
+    /// A start state lies outside the bounds of the state space.
+    StartStateOutOfBounds { index: usize },
+    /// A start state is rejected by the validity checker.
+    InvalidStartState { index: usize },
+    /// The goal could not be sampled.
+    GoalSamplingFailed(StateSamplingError),
+    /// A state sampled from the goal does not satisfy the goal.
+    GoalSampleNotSatisfied,
+    /// None of the states sampled from the goal are within bounds and valid.
+    NoValidGoalSamples { attempts: usize },
+}
+impl fmt::Display for ProblemDefinitionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoStartStates => write!(f, "The problem has no start states."),
+            Self::StartStateOutOfBounds { index } => {
+                write!(f, "Start state {index} is outside the bounds of the space.")
+            }
+            Self::InvalidStartState { index } => {
+                write!(
+                    f,
+                    "Start state {index} is rejected by the validity checker."
+                )
+            }
+            Self::GoalSamplingFailed(e) => write!(f, "The goal could not be sampled: {e}"),
+            Self::GoalSampleNotSatisfied => {
+                write!(
+                    f,
+                    "A state sampled from the goal does not satisfy the goal."
+                )
+            }
+            Self::NoValidGoalSamples { attempts } => write!(
+                f,
+                "None of {attempts} states sampled from the goal are within bounds and valid."
+            ),
+        }
+    }
+}
+impl error::Error for ProblemDefinitionError {}
+
+#[derive(Debug, PartialEq)]
+pub enum TrajectoryError {
+    /// The path has no states.
+    EmptyPath,
+    /// A state, or a component of a compound state, has no joint positions, e.g. an `SO3State`.
+    UnsupportedState,
+    /// The states of the path, or the limits, have different numbers of joints.
+    DimensionMismatch { expected: usize, found: usize },
+    /// A velocity or acceleration limit is not positive and finite.
+    InvalidLimit { joint: usize },
+}
+impl fmt::Display for TrajectoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptyPath => write!(f, "Cannot time-parameterise an empty path."),
+            Self::UnsupportedState => {
+                write!(
+                    f,
+                    "The states of the path cannot be expressed as joint positions."
+                )
+            }
+            Self::DimensionMismatch { expected, found } => {
+                write!(f, "Expected {expected} joints but found {found}.")
+            }
+            Self::InvalidLimit { joint } => write!(
+                f,
+                "The velocity and acceleration limits of joint {joint} must be positive and finite."
+            ),
+        }
+    }
+}
+impl error::Error for TrajectoryError {}
+
+#[derive(Debug, PartialEq)]
+pub enum ParamError {
+    /// The planner has no parameter with this name.
+    UnknownParam { name: String },
+    /// The value cannot be parsed as, or is not of, the parameter's type.
+    InvalidValue { name: String, value: String },
+    /// The value lies outside the range the parameter may take.
+    OutOfRange {
+        name: String,
+        value: f64,
+        min: f64,
+        max: f64,
+    },
+}
+impl fmt::Display for ParamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownParam { name } => write!(f, "Unknown parameter '{name}'."),
+            Self::InvalidValue { name, value } => {
+                write!(f, "Invalid value '{value}' for parameter '{name}'.")
+            }
+            Self::OutOfRange {
+                name,
+                value,
+                min,
+                max,
+            } => write!(
+                f,
+                "Value {value} for parameter '{name}' is outside the range [{min}, {max}]."
+            ),
+        }
+    }
+}
+impl error::Error for ParamError {}
+
+#[derive(Debug, PartialEq)]
+pub enum PlannerRegistryError {
+    /// No planner is registered under this name.
+    UnknownPlanner { name: String },
+}
+impl fmt::Display for PlannerRegistryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownPlanner { name } => write!(f, "Unknown planner '{name}'."),
+        }
+    }
+}
+impl error::Error for PlannerRegistryError {}
@@ -2,8 +2,25 @@
 //
 // SPDX-License-Identifier: BSD-3-Clause
 
+use std::sync::Arc;
+
 use crate::base::{error::StateSamplingError, state::State};
-use rand::Rng;
+use rand::RngCore;
+
+pub use crate::base::goals::{
+    goal_lazy_samples::GoalLazySamples,
+    goal_space::GoalSpace,
+    goal_state::GoalState,
+    goal_states::GoalStates,
+    pose_goals::{RotationAxis, SE2PoseGoal, SE3PoseGoal},
+    task_space_region::{TSRChain, TaskSpaceRegion},
+};
+
+/// A goal whose type is chosen at runtime.
+///
+/// This is the default goal type of `Planner` and `ProblemDefinition`, so `Box<dyn Planner<S>>`
+/// can hold any planner for any sampleable goal on states `S`.
+pub type DynGoal<S> = Arc<dyn GoalSampleableRegion<S> + Send + Sync>;
 
 /// The base trait for all goal definitions.
 ///
@@ -37,5 +54,41 @@ pub trait GoalSampleableRegion<S: State>: GoalRegion<S> {
     ///
     /// # Errors
     /// Can return an error if sampling is not possible.
-    fn sample_goal(&self, rng: &mut impl Rng) -> Result<S, StateSamplingError>;
+    fn sample_goal(&self, rng: &mut dyn RngCore) -> Result<S, StateSamplingError>;
+}
+
+impl<S: State, G: Goal<S> + ?Sized> Goal<S> for Box<G> {
+    fn is_satisfied(&self, state: &S) -> bool {
+        (**self).is_satisfied(state)
+    }
+}
+
+impl<S: State, G: GoalRegion<S> + ?Sized> GoalRegion<S> for Box<G> {
+    fn distance_goal(&self, state: &S) -> f64 {
+        (**self).distance_goal(state)
+    }
+}
+
+impl<S: State, G: GoalSampleableRegion<S> + ?Sized> GoalSampleableRegion<S> for Box<G> {
+    fn sample_goal(&self, rng: &mut dyn RngCore) -> Result<S, StateSamplingError> {
+        (**self).sample_goal(rng)
+    }
+}
+
+impl<S: State, G: Goal<S> + ?Sized> Goal<S> for Arc<G> {
+    fn is_satisfied(&self, state: &S) -> bool {
+        (**self).is_satisfied(state)
+    }
+}
+
+impl<S: State, G: GoalRegion<S> + ?Sized> GoalRegion<S> for Arc<G> {
+    fn distance_goal(&self, state: &S) -> f64 {
+        (**self).distance_goal(state)
+    }
+}
+
+impl<S: State, G: GoalSampleableRegion<S> + ?Sized> GoalSampleableRegion<S> for Arc<G> {
+    fn sample_goal(&self, rng: &mut dyn RngCore) -> Result<S, StateSamplingError> {
+        (**self).sample_goal(rng)
+    }
 }
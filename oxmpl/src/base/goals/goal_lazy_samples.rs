@@ -0,0 +1,243 @@
+// Copyright (c) 2025 Junior Sundar
+//
+// SPDX-License-Identifier: BSD-3-Clause
+
+use rand::{Rng, RngCore};
+use std::{
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Condvar, Mutex,
+    },
+    thread::{self, JoinHandle},
+};
+
+use crate::base::{
+    error::StateSamplingError,
+    goal::{Goal, GoalRegion, GoalSampleableRegion},
+    space::StateSpace,
+};
+
+/// The goal states found so far, shared between the goal and its sampling thread.
+struct SampleStore<S> {
+    samples: Mutex<Vec<S>>,
+    /// Notified whenever a sample is added or the sampling thread finishes.
+    changed: Condvar,
+    /// Whether the sampling thread is still running.
+    running: AtomicBool,
+    /// Set to ask the sampling thread to finish.
+    stop_requested: AtomicBool,
+}
+
+/// A goal whose states are produced by a user function on a background thread.
+///
+/// This is useful when goal states are expensive to find, for instance when each one needs an
+/// inverse kinematics solve. As soon as the goal is created, a thread starts calling `sampler`
+/// and storing the states it returns, while the planner carries on with the samples found so far.
+/// The thread finishes once `sampler` returns `None` or panics, `max_samples` states have been
+/// stored, or `stop` is called.
+///
+/// A state satisfies the goal if it is within `threshold` of any stored sample.
+///
+/// > [!NOTE]
+/// > `sample_goal` blocks until at least one sample is available, or returns
+/// > `StateSamplingError::GoalRegionUnsatisfiable` if the thread finished without finding any.
+///
+/// > [!WARNING]
+/// > Threads cannot be spawned on `wasm32-unknown-unknown`, so this is not available in the
+/// > JavaScript bindings.
+pub struct GoalLazySamples<SP: StateSpace> {
+    /// The space used to measure distances to the goal samples.
+    pub space: Arc<SP>,
+    /// The distance from a goal sample within which a state satisfies the goal.
+    pub threshold: f64,
+
+    store: Arc<SampleStore<SP::StateType>>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl<SP> GoalLazySamples<SP>
+where
+    SP: StateSpace,
+    SP::StateType: Clone,
+{
+    /// Creates a new `GoalLazySamples` and starts its sampling thread.
+    ///
+    /// # Parameters
+    /// * `space` - The space used to measure distances to the goal samples.
+    /// * `threshold` - The distance from a goal sample within which a state satisfies the goal.
+    /// * `max_samples` - The number of samples after which the thread finishes.
+    /// * `sampler` - Called repeatedly to produce goal states. Returning `None` finishes the
+    ///   thread, as does panicking.
+    pub fn new<F>(space: Arc<SP>, threshold: f64, max_samples: usize, mut sampler: F) -> Self
+    where
+        F: FnMut() -> Option<SP::StateType> + Send + 'static,
+    {
+        let store = Arc::new(SampleStore {
+            samples: Mutex::new(Vec::new()),
+            changed: Condvar::new(),
+            running: AtomicBool::new(true),
+            stop_requested: AtomicBool::new(false),
+        });
+
+        let thread_store = store.clone();
+        let handle = thread::spawn(move || {
+            while !thread_store.stop_requested.load(Ordering::Acquire) {
+                // A panicking sampler finishes the thread, so `sample_goal` is not left waiting.
+                let Ok(Some(state)) = panic::catch_unwind(AssertUnwindSafe(&mut sampler)) else {
+                    break;
+                };
+                let mut samples = thread_store.samples.lock().unwrap();
+                samples.push(state);
+                thread_store.changed.notify_all();
+                if samples.len() >= max_samples {
+                    break;
+                }
+            }
+            // Clear the flag while holding the lock, so a waiting `sample_goal` cannot miss it.
+            let _samples = thread_store.samples.lock().unwrap();
+            thread_store.running.store(false, Ordering::Release);
+            thread_store.changed.notify_all();
+        });
+
+        GoalLazySamples {
+            space,
+            threshold,
+            store,
+            handle: Some(handle),
+        }
+    }
+
+    /// Asks the sampling thread to finish and waits for it. Samples found so far are kept.
+    pub fn stop(&mut self) {
+        self.store.stop_requested.store(true, Ordering::Release);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+
+    /// Returns `true` while the sampling thread is running.
+    pub fn is_sampling(&self) -> bool {
+        self.store.running.load(Ordering::Acquire)
+    }
+
+    /// Returns the number of goal samples found so far.
+    pub fn sample_count(&self) -> usize {
+        self.store.samples.lock().unwrap().len()
+    }
+
+    /// Returns a copy of the goal samples found so far.
+    pub fn samples(&self) -> Vec<SP::StateType> {
+        self.store.samples.lock().unwrap().clone()
+    }
+}
+
+impl<SP: StateSpace> Drop for GoalLazySamples<SP> {
+    fn drop(&mut self) {
+        self.store.stop_requested.store(true, Ordering::Release);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+impl<SP: StateSpace> Goal<SP::StateType> for GoalLazySamples<SP> {
+    fn is_satisfied(&self, state: &SP::StateType) -> bool {
+        self.store
+            .samples
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|sample| self.space.distance(state, sample) <= self.threshold)
+    }
+}
+
+impl<SP: StateSpace> GoalRegion<SP::StateType> for GoalLazySamples<SP> {
+    fn distance_goal(&self, state: &SP::StateType) -> f64 {
+        self.store
+            .samples
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|sample| (self.space.distance(state, sample) - self.threshold).max(0.0))
+            .fold(f64::INFINITY, f64::min)
+    }
+}
+
+impl<SP> GoalSampleableRegion<SP::StateType> for GoalLazySamples<SP>
+where
+    SP: StateSpace,
+    SP::StateType: Clone,
+{
+    /// Picks one of the goal samples found so far at random, waiting for the first one if needed.
+    ///
+    /// # Errors
+    /// Returns `StateSamplingError::GoalRegionUnsatisfiable` if the sampling thread finished
+    /// without finding any samples.
+    fn sample_goal(&self, rng: &mut dyn RngCore) -> Result<SP::StateType, StateSamplingError> {
+        let samples = self
+            .store
+            .changed
+            .wait_while(self.store.samples.lock().unwrap(), |samples| {
+                samples.is_empty() && self.store.running.load(Ordering::Acquire)
+            })
+            .unwrap();
+        if samples.is_empty() {
+            return Err(StateSamplingError::GoalRegionUnsatisfiable);
+        }
+        Ok(samples[rng.random_range(0..samples.len())].clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base::{space::RealVectorStateSpace, state::RealVectorState};
+
+    fn space() -> Arc<RealVectorStateSpace> {
+        Arc::new(RealVectorStateSpace::new(1, Some(vec![(0.0, 10.0)])).unwrap())
+    }
+
+    #[test]
+    fn test_goal_lazy_samples_stops_at_max_samples() {
+        let mut next = 0.0;
+        let goal = GoalLazySamples::new(space(), 0.1, 5, move || {
+            next += 1.0;
+            Some(RealVectorState::new(vec![next]))
+        });
+
+        // Waits for the first sample.
+        let sample = goal.sample_goal(&mut rand::rng()).unwrap();
+        assert!(goal.is_satisfied(&sample));
+
+        while goal.is_sampling() {
+            thread::yield_now();
+        }
+        assert_eq!(goal.sample_count(), 5);
+        assert!(goal.is_satisfied(&RealVectorState::new(vec![5.05])));
+        assert!(!goal.is_satisfied(&RealVectorState::new(vec![6.0])));
+        assert!((goal.distance_goal(&RealVectorState::new(vec![8.0])) - 2.9).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_goal_lazy_samples_without_samples() {
+        let goal = GoalLazySamples::new(space(), 0.1, 5, || None);
+
+        assert!(goal.sample_goal(&mut rand::rng()).is_err());
+        assert!(!goal.is_sampling());
+        assert!(!goal.is_satisfied(&RealVectorState::new(vec![1.0])));
+    }
+
+    #[test]
+    fn test_goal_lazy_samples_stop() {
+        let mut goal = GoalLazySamples::new(space(), 0.1, usize::MAX, || {
+            Some(RealVectorState::new(vec![1.0]))
+        });
+        goal.stop();
+        assert!(!goal.is_sampling());
+
+        let count = goal.sample_count();
+        thread::sleep(std::time::Duration::from_millis(10));
+        assert_eq!(goal.sample_count(), count);
+    }
+}
@@ -0,0 +1,105 @@
+// Copyright (c) 2025 Junior Sundar
+//
+// SPDX-License-Identifier: BSD-3-Clause
+
+use rand::RngCore;
+
+use crate::base::{
+    error::StateSamplingError,
+    goal::{Goal, GoalRegion, GoalSampleableRegion},
+    space::StateSpace,
+};
+
+/// A goal defined by a bounded sub-space of the planning space.
+///
+/// `goal_space` is a state space of the same type as the planning space, but with tighter bounds.
+/// A state satisfies the goal if it lies within the bounds of `goal_space`, and sampling the goal
+/// samples `goal_space` uniformly.
+///
+/// # Examples
+///
+/// ```
+/// use oxmpl::base::goal::{Goal, GoalSpace};
+/// use oxmpl::base::space::RealVectorStateSpace;
+/// use oxmpl::base::state::RealVectorState;
+///
+/// // Any state with x in [8, 10] and y in [4, 6] is a goal state.
+/// let goal_space = RealVectorStateSpace::new(2, Some(vec![(8.0, 10.0), (4.0, 6.0)])).unwrap();
+/// let goal = GoalSpace::new(goal_space);
+///
+/// assert!(goal.is_satisfied(&RealVectorState::new(vec![9.0, 5.5])));
+/// assert!(!goal.is_satisfied(&RealVectorState::new(vec![7.0, 5.0])));
+/// ```
+#[derive(Clone)]
+pub struct GoalSpace<SP: StateSpace> {
+    /// The sub-space that makes up the goal.
+    pub goal_space: SP,
+}
+
+impl<SP: StateSpace> GoalSpace<SP> {
+    /// Creates a new `GoalSpace` from the sub-space that makes up the goal.
+    pub fn new(goal_space: SP) -> Self {
+        GoalSpace { goal_space }
+    }
+}
+
+impl<SP: StateSpace> Goal<SP::StateType> for GoalSpace<SP> {
+    fn is_satisfied(&self, state: &SP::StateType) -> bool {
+        self.goal_space.satisfies_bounds(state)
+    }
+}
+
+impl<SP> GoalRegion<SP::StateType> for GoalSpace<SP>
+where
+    SP: StateSpace,
+    SP::StateType: Clone,
+{
+    /// Returns the distance from `state` to its projection onto the bounds of the goal space.
+    fn distance_goal(&self, state: &SP::StateType) -> f64 {
+        if self.goal_space.satisfies_bounds(state) {
+            return 0.0;
+        }
+        let mut projected = state.clone();
+        self.goal_space.enforce_bounds(&mut projected);
+        self.goal_space.distance(state, &projected)
+    }
+}
+
+impl<SP> GoalSampleableRegion<SP::StateType> for GoalSpace<SP>
+where
+    SP: StateSpace,
+    SP::StateType: Clone,
+{
+    fn sample_goal(&self, rng: &mut dyn RngCore) -> Result<SP::StateType, StateSamplingError> {
+        self.goal_space.sample_uniform(rng)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base::{space::RealVectorStateSpace, state::RealVectorState};
+
+    #[test]
+    fn test_goal_space_bounds() {
+        let goal = GoalSpace::new(
+            RealVectorStateSpace::new(2, Some(vec![(8.0, 10.0), (4.0, 6.0)])).unwrap(),
+        );
+
+        assert_eq!(
+            goal.distance_goal(&RealVectorState::new(vec![9.0, 5.0])),
+            0.0
+        );
+        assert!((goal.distance_goal(&RealVectorState::new(vec![5.0, 5.0])) - 3.0).abs() < 1e-9);
+        assert!(
+            (goal.distance_goal(&RealVectorState::new(vec![5.0, 2.0])) - 13.0_f64.sqrt()).abs()
+                < 1e-9
+        );
+
+        let mut rng = rand::rng();
+        for _ in 0..100 {
+            let sample = goal.sample_goal(&mut rng).unwrap();
+            assert!(goal.is_satisfied(&sample));
+        }
+    }
+}
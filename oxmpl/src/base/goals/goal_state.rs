@@ -0,0 +1,101 @@
+// Copyright (c) 2025 Junior Sundar
+//
+// SPDX-License-Identifier: BSD-3-Clause
+
+use rand::RngCore;
+use std::sync::Arc;
+
+use crate::base::{
+    error::StateSamplingError,
+    goal::{Goal, GoalRegion, GoalSampleableRegion},
+    space::StateSpace,
+};
+
+/// A goal defined by a single state and a tolerance around it.
+///
+/// A state satisfies the goal if its distance to `state`, as measured by `space`, is no more than
+/// `threshold`. Sampling the goal always returns `state` itself.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::Arc;
+/// use oxmpl::base::goal::{Goal, GoalState};
+/// use oxmpl::base::space::RealVectorStateSpace;
+/// use oxmpl::base::state::RealVectorState;
+///
+/// let space = Arc::new(RealVectorStateSpace::new(2, Some(vec![(0.0, 10.0), (0.0, 10.0)])).unwrap());
+/// let goal = GoalState::new(space, RealVectorState::new(vec![9.0, 5.0]), 0.5);
+///
+/// assert!(goal.is_satisfied(&RealVectorState::new(vec![9.0, 5.2])));
+/// assert!(!goal.is_satisfied(&RealVectorState::new(vec![8.0, 5.0])));
+/// ```
+#[derive(Clone)]
+pub struct GoalState<SP: StateSpace> {
+    /// The space used to measure distances to the goal state.
+    pub space: Arc<SP>,
+    /// The goal state.
+    pub state: SP::StateType,
+    /// The distance from `state` within which a state satisfies the goal.
+    pub threshold: f64,
+}
+
+impl<SP: StateSpace> GoalState<SP> {
+    /// Creates a new `GoalState`.
+    ///
+    /// # Parameters
+    /// * `space` - The space used to measure distances to the goal state.
+    /// * `state` - The goal state.
+    /// * `threshold` - The distance from `state` within which a state satisfies the goal.
+    pub fn new(space: Arc<SP>, state: SP::StateType, threshold: f64) -> Self {
+        GoalState {
+            space,
+            state,
+            threshold,
+        }
+    }
+}
+
+impl<SP: StateSpace> Goal<SP::StateType> for GoalState<SP> {
+    fn is_satisfied(&self, state: &SP::StateType) -> bool {
+        self.space.distance(state, &self.state) <= self.threshold
+    }
+}
+
+impl<SP: StateSpace> GoalRegion<SP::StateType> for GoalState<SP> {
+    fn distance_goal(&self, state: &SP::StateType) -> f64 {
+        (self.space.distance(state, &self.state) - self.threshold).max(0.0)
+    }
+}
+
+impl<SP> GoalSampleableRegion<SP::StateType> for GoalState<SP>
+where
+    SP: StateSpace,
+    SP::StateType: Clone,
+{
+    fn sample_goal(&self, _rng: &mut dyn RngCore) -> Result<SP::StateType, StateSamplingError> {
+        Ok(self.state.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base::{space::SO2StateSpace, state::SO2State};
+    use std::f64::consts::PI;
+
+    #[test]
+    fn test_goal_state_uses_space_distance() {
+        // SO2 distances wrap around, so a state just past -PI is close to one just before PI.
+        let space = Arc::new(SO2StateSpace::new(None).unwrap());
+        let goal = GoalState::new(space, SO2State::new(PI - 0.05), 0.2);
+
+        assert!(goal.is_satisfied(&SO2State::new(-PI + 0.05)));
+        assert!(!goal.is_satisfied(&SO2State::new(0.0)));
+        assert_eq!(goal.distance_goal(&SO2State::new(PI - 0.1)), 0.0);
+        assert!((goal.distance_goal(&SO2State::new(PI - 1.05)) - 0.8).abs() < 1e-9);
+
+        let sample = goal.sample_goal(&mut rand::rng()).unwrap();
+        assert_eq!(sample, goal.state);
+    }
+}
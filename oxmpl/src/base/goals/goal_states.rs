@@ -0,0 +1,108 @@
+// Copyright (c) 2025 Junior Sundar
+//
+// SPDX-License-Identifier: BSD-3-Clause
+
+use rand::{Rng, RngCore};
+use std::sync::Arc;
+
+use crate::base::{
+    error::StateSamplingError,
+    goal::{Goal, GoalRegion, GoalSampleableRegion},
+    space::StateSpace,
+};
+
+/// A goal defined by a set of states and a tolerance around each of them.
+///
+/// A state satisfies the goal if it is within `threshold` of any of the goal states. Sampling the
+/// goal picks one of the goal states at random.
+#[derive(Clone)]
+pub struct GoalStates<SP: StateSpace> {
+    /// The space used to measure distances to the goal states.
+    pub space: Arc<SP>,
+    /// The goal states.
+    pub states: Vec<SP::StateType>,
+    /// The distance from a goal state within which a state satisfies the goal.
+    pub threshold: f64,
+}
+
+impl<SP: StateSpace> GoalStates<SP> {
+    /// Creates a new `GoalStates`.
+    ///
+    /// # Parameters
+    /// * `space` - The space used to measure distances to the goal states.
+    /// * `states` - The goal states. More can be added later with `add_state`.
+    /// * `threshold` - The distance from a goal state within which a state satisfies the goal.
+    pub fn new(space: Arc<SP>, states: Vec<SP::StateType>, threshold: f64) -> Self {
+        GoalStates {
+            space,
+            states,
+            threshold,
+        }
+    }
+
+    /// Adds a state to the set of goal states.
+    pub fn add_state(&mut self, state: SP::StateType) {
+        self.states.push(state);
+    }
+}
+
+impl<SP: StateSpace> Goal<SP::StateType> for GoalStates<SP> {
+    fn is_satisfied(&self, state: &SP::StateType) -> bool {
+        self.states
+            .iter()
+            .any(|goal_state| self.space.distance(state, goal_state) <= self.threshold)
+    }
+}
+
+impl<SP: StateSpace> GoalRegion<SP::StateType> for GoalStates<SP> {
+    fn distance_goal(&self, state: &SP::StateType) -> f64 {
+        self.states
+            .iter()
+            .map(|goal_state| (self.space.distance(state, goal_state) - self.threshold).max(0.0))
+            .fold(f64::INFINITY, f64::min)
+    }
+}
+
+impl<SP> GoalSampleableRegion<SP::StateType> for GoalStates<SP>
+where
+    SP: StateSpace,
+    SP::StateType: Clone,
+{
+    /// Picks one of the goal states at random.
+    ///
+    /// # Errors
+    /// Returns `StateSamplingError::GoalRegionUnsatisfiable` if there are no goal states.
+    fn sample_goal(&self, rng: &mut dyn RngCore) -> Result<SP::StateType, StateSamplingError> {
+        if self.states.is_empty() {
+            return Err(StateSamplingError::GoalRegionUnsatisfiable);
+        }
+        Ok(self.states[rng.random_range(0..self.states.len())].clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base::{space::RealVectorStateSpace, state::RealVectorState};
+
+    #[test]
+    fn test_goal_states_checks_every_state() {
+        let space = Arc::new(RealVectorStateSpace::new(1, Some(vec![(0.0, 10.0)])).unwrap());
+        let mut goal = GoalStates::new(space, Vec::new(), 0.5);
+        assert!(goal.sample_goal(&mut rand::rng()).is_err());
+        assert_eq!(
+            goal.distance_goal(&RealVectorState::new(vec![1.0])),
+            f64::INFINITY
+        );
+
+        goal.add_state(RealVectorState::new(vec![2.0]));
+        goal.add_state(RealVectorState::new(vec![8.0]));
+
+        assert!(goal.is_satisfied(&RealVectorState::new(vec![7.6])));
+        assert!(!goal.is_satisfied(&RealVectorState::new(vec![5.0])));
+        assert!((goal.distance_goal(&RealVectorState::new(vec![4.0])) - 1.5).abs() < 1e-9);
+
+        let sample = goal.sample_goal(&mut rand::rng()).unwrap();
+        assert!(goal.states.contains(&sample));
+    }
+}
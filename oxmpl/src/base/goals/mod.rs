@@ -0,0 +1,10 @@
+// Copyright (c) 2025 Junior Sundar
+//
+// SPDX-License-Identifier: BSD-3-Clause
+
+pub mod goal_lazy_samples;
+pub mod goal_space;
+pub mod goal_state;
+pub mod goal_states;
+pub mod pose_goals;
+pub mod task_space_region;
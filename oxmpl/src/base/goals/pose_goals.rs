@@ -0,0 +1,421 @@
+// Copyright (c) 2025 Junior Sundar
+//
+// SPDX-License-Identifier: BSD-3-Clause
+
+use rand::{Rng, RngCore};
+use std::f64::consts::PI;
+
+use crate::base::{
+    error::StateSamplingError,
+    goal::{Goal, GoalRegion, GoalSampleableRegion},
+    state::{SE2State, SE3State, SO2State, SO3State},
+};
+
+/// An axis of the goal pose's own frame, about which rotation can be left free.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RotationAxis {
+    /// The x-axis (roll).
+    X,
+    /// The y-axis (pitch).
+    Y,
+    /// The z-axis (yaw).
+    Z,
+}
+
+impl RotationAxis {
+    /// Returns the axis as a unit vector, followed by two unit vectors that complete an
+    /// orthonormal basis with it.
+    fn basis(self) -> [[f64; 3]; 3] {
+        match self {
+            RotationAxis::X => [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+            RotationAxis::Y => [[0.0, 1.0, 0.0], [0.0, 0.0, 1.0], [1.0, 0.0, 0.0]],
+            RotationAxis::Z => [[0.0, 0.0, 1.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]],
+        }
+    }
+}
+
+/// Samples the distance from the centre of a point drawn uniformly from the `dims`-dimensional
+/// ball of radius `radius`.
+fn ball_radius(rng: &mut dyn RngCore, radius: f64, dims: i32) -> f64 {
+    radius * rng.random::<f64>().powf(1.0 / dims as f64)
+}
+
+/// Samples a unit vector uniformly from the sphere.
+fn random_direction(rng: &mut dyn RngCore) -> [f64; 3] {
+    let z: f64 = rng.random_range(-1.0..=1.0);
+    let phi = rng.random_range(0.0..2.0 * PI);
+    let r = (1.0 - z * z).sqrt();
+    [r * phi.cos(), r * phi.sin(), z]
+}
+
+/// A goal for `SE2State`s with separate position and orientation tolerances.
+///
+/// A state satisfies the goal if its position is within `position_tolerance` of the target's and
+/// its yaw is within `orientation_tolerance` radians of the target's. If `yaw_free` is set, the
+/// yaw is not checked at all.
+///
+/// Unlike a `GoalState` on an `SE2StateSpace`, the two tolerances are independent of the space's
+/// rotation weight.
+///
+/// # Examples
+///
+/// ```
+/// use std::f64::consts::PI;
+/// use oxmpl::base::goal::{Goal, SE2PoseGoal};
+/// use oxmpl::base::state::SE2State;
+///
+/// let goal = SE2PoseGoal::new(SE2State::new(5.0, 5.0, 0.0), 0.1, PI / 18.0);
+///
+/// assert!(goal.is_satisfied(&SE2State::new(5.05, 5.0, 0.1)));
+/// assert!(!goal.is_satisfied(&SE2State::new(5.05, 5.0, 0.5)));
+/// assert!(goal.with_free_yaw().is_satisfied(&SE2State::new(5.05, 5.0, 0.5)));
+/// ```
+#[derive(Clone, Debug)]
+pub struct SE2PoseGoal {
+    /// The target pose.
+    pub target: SE2State,
+    /// The maximum distance between the position of a state and the target's.
+    pub position_tolerance: f64,
+    /// The maximum angle in radians between the yaw of a state and the target's.
+    pub orientation_tolerance: f64,
+    /// How much one radian of orientation error counts against one unit of position error in
+    /// `distance_goal`. Defaults to 1.0.
+    pub orientation_weight: f64,
+    /// Whether the yaw is left free.
+    pub yaw_free: bool,
+}
+
+impl SE2PoseGoal {
+    /// Creates a new `SE2PoseGoal`.
+    ///
+    /// # Parameters
+    /// * `target` - The target pose.
+    /// * `position_tolerance` - The maximum distance from the target position.
+    /// * `orientation_tolerance` - The maximum angle in radians from the target yaw.
+    pub fn new(target: SE2State, position_tolerance: f64, orientation_tolerance: f64) -> Self {
+        SE2PoseGoal {
+            target,
+            position_tolerance,
+            orientation_tolerance,
+            orientation_weight: 1.0,
+            yaw_free: false,
+        }
+    }
+
+    /// Leaves the yaw free, so only the position is checked.
+    pub fn with_free_yaw(mut self) -> Self {
+        self.yaw_free = true;
+        self
+    }
+
+    /// Returns the distance between the position of `state` and the target's.
+    pub fn position_error(&self, state: &SE2State) -> f64 {
+        (state.get_x() - self.target.get_x()).hypot(state.get_y() - self.target.get_y())
+    }
+
+    /// Returns the angle in radians between the yaw of `state` and the target's, or 0.0 if the
+    /// yaw is free.
+    pub fn orientation_error(&self, state: &SE2State) -> f64 {
+        if self.yaw_free {
+            return 0.0;
+        }
+        SO2State::new(state.get_yaw() - self.target.get_yaw())
+            .value
+            .abs()
+    }
+}
+
+impl Goal<SE2State> for SE2PoseGoal {
+    fn is_satisfied(&self, state: &SE2State) -> bool {
+        self.position_error(state) <= self.position_tolerance
+            && self.orientation_error(state) <= self.orientation_tolerance
+    }
+}
+
+impl GoalRegion<SE2State> for SE2PoseGoal {
+    /// Returns how far the position and orientation errors exceed their tolerances, combined
+    /// using `orientation_weight`.
+    fn distance_goal(&self, state: &SE2State) -> f64 {
+        (self.position_error(state) - self.position_tolerance).max(0.0)
+            + self.orientation_weight
+                * (self.orientation_error(state) - self.orientation_tolerance).max(0.0)
+    }
+}
+
+impl GoalSampleableRegion<SE2State> for SE2PoseGoal {
+    /// Samples a position uniformly from the disc around the target position, and a yaw
+    /// uniformly from the allowed range.
+    fn sample_goal(&self, rng: &mut dyn RngCore) -> Result<SE2State, StateSamplingError> {
+        let radius = ball_radius(rng, self.position_tolerance, 2);
+        let angle = rng.random_range(0.0..2.0 * PI);
+        let yaw_range = if self.yaw_free {
+            PI
+        } else {
+            self.orientation_tolerance.min(PI)
+        };
+        let yaw = self.target.get_yaw() + yaw_range * rng.random_range(-1.0..=1.0);
+
+        Ok(SE2State::new(
+            self.target.get_x() + radius * angle.cos(),
+            self.target.get_y() + radius * angle.sin(),
+            yaw,
+        ))
+    }
+}
+
+/// A goal for `SE3State`s with separate position and orientation tolerances.
+///
+/// A state satisfies the goal if its position is within `position_tolerance` of the target's and
+/// the angle of the rotation between its orientation and the target's is within
+/// `orientation_tolerance` radians.
+///
+/// If `free_axis` is set, rotation about that axis of the target's frame is not counted. This
+/// suits symmetric objects, such as a cylinder that can be grasped at any angle about its own
+/// axis. Only the remaining "swing" of the orientation away from the target is then checked.
+///
+/// Only one axis can be left free. Rotations about any two axes combine into every rotation, so
+/// freeing a second axis would leave the orientation free entirely. For that, set
+/// `orientation_tolerance` to `PI` instead.
+///
+/// # Examples
+///
+/// ```
+/// use std::f64::consts::PI;
+/// use oxmpl::base::goal::{Goal, RotationAxis, SE3PoseGoal};
+/// use oxmpl::base::state::{SE3State, SO3State};
+///
+/// // Within 5 mm and 2 degrees.
+/// let goal = SE3PoseGoal::new(SE3State::new(0.5, 0.0, 0.3, SO3State::identity()), 0.005, PI / 90.0);
+///
+/// let yawed = SE3State::new(0.5, 0.0, 0.3, SO3State::from_axis_angle([0.0, 0.0, 1.0], 1.0));
+/// assert!(!goal.is_satisfied(&yawed));
+/// assert!(goal.with_free_axis(RotationAxis::Z).is_satisfied(&yawed));
+/// ```
+#[derive(Clone, Debug)]
+pub struct SE3PoseGoal {
+    /// The target pose.
+    pub target: SE3State,
+    /// The maximum distance between the position of a state and the target's.
+    pub position_tolerance: f64,
+    /// The maximum angle in radians between the orientation of a state and the target's.
+    pub orientation_tolerance: f64,
+    /// How much one radian of orientation error counts against one unit of position error in
+    /// `distance_goal`. Defaults to 1.0.
+    pub orientation_weight: f64,
+    /// The one axis of the target's frame about which rotation is left free, if any.
+    pub free_axis: Option<RotationAxis>,
+}
+
+impl SE3PoseGoal {
+    /// Creates a new `SE3PoseGoal`.
+    ///
+    /// # Parameters
+    /// * `target` - The target pose.
+    /// * `position_tolerance` - The maximum distance from the target position.
+    /// * `orientation_tolerance` - The maximum angle in radians from the target orientation.
+    pub fn new(target: SE3State, position_tolerance: f64, orientation_tolerance: f64) -> Self {
+        SE3PoseGoal {
+            target,
+            position_tolerance,
+            orientation_tolerance,
+            orientation_weight: 1.0,
+            free_axis: None,
+        }
+    }
+
+    /// Leaves rotation about `axis` of the target's frame free.
+    ///
+    /// Only one axis can be free, so this replaces any axis set before. To leave the orientation
+    /// free about every axis, set `orientation_tolerance` to `PI`.
+    pub fn with_free_axis(mut self, axis: RotationAxis) -> Self {
+        self.free_axis = Some(axis);
+        self
+    }
+
+    /// Returns the distance between the position of `state` and the target's.
+    pub fn position_error(&self, state: &SE3State) -> f64 {
+        let target = &self.target.get_translation().values;
+        state
+            .get_translation()
+            .values
+            .iter()
+            .zip(target)
+            .map(|(a, b)| (a - b).powi(2))
+            .sum::<f64>()
+            .sqrt()
+    }
+
+    /// Returns the angle in radians between the orientation of `state` and the target's,
+    /// ignoring any rotation about `free_axis`.
+    pub fn orientation_error(&self, state: &SE3State) -> f64 {
+        let mut relative = self
+            .target
+            .get_rotation()
+            .inverse()
+            .compose(state.get_rotation());
+        relative = relative
+            .normalise()
+            .unwrap_or_else(|_| SO3State::identity());
+
+        let Some(axis) = self.free_axis else {
+            return relative.angle();
+        };
+
+        // Split the relative rotation into a twist about the free axis followed by a swing, and
+        // only measure the swing.
+        let [e, _, _] = axis.basis();
+        let projection = relative.x * e[0] + relative.y * e[1] + relative.z * e[2];
+        let twist = SO3State::new(
+            projection * e[0],
+            projection * e[1],
+            projection * e[2],
+            relative.w,
+        )
+        .normalise()
+        .unwrap_or_else(|_| SO3State::identity());
+        relative.compose(&twist.inverse()).angle()
+    }
+}
+
+impl Goal<SE3State> for SE3PoseGoal {
+    fn is_satisfied(&self, state: &SE3State) -> bool {
+        self.position_error(state) <= self.position_tolerance
+            && self.orientation_error(state) <= self.orientation_tolerance
+    }
+}
+
+impl GoalRegion<SE3State> for SE3PoseGoal {
+    /// Returns how far the position and orientation errors exceed their tolerances, combined
+    /// using `orientation_weight`.
+    fn distance_goal(&self, state: &SE3State) -> f64 {
+        (self.position_error(state) - self.position_tolerance).max(0.0)
+            + self.orientation_weight
+                * (self.orientation_error(state) - self.orientation_tolerance).max(0.0)
+    }
+}
+
+impl GoalSampleableRegion<SE3State> for SE3PoseGoal {
+    /// Samples a position uniformly from the ball around the target position, and an orientation
+    /// within `orientation_tolerance` of the target's. With a `free_axis`, the rotation about
+    /// that axis is sampled uniformly.
+    fn sample_goal(&self, rng: &mut dyn RngCore) -> Result<SE3State, StateSamplingError> {
+        let direction = random_direction(rng);
+        let radius = ball_radius(rng, self.position_tolerance, 3);
+        let target = &self.target.get_translation().values;
+
+        let max_angle = self.orientation_tolerance.min(PI);
+        let rotation = match self.free_axis {
+            None => {
+                let swing = SO3State::from_axis_angle(
+                    random_direction(rng),
+                    ball_radius(rng, max_angle, 3),
+                );
+                self.target.get_rotation().compose(&swing)
+            }
+            Some(axis) => {
+                let [e, u, v] = axis.basis();
+                let phi = rng.random_range(0.0..2.0 * PI);
+                let swing_axis = [0, 1, 2].map(|i| phi.cos() * u[i] + phi.sin() * v[i]);
+                let swing = SO3State::from_axis_angle(swing_axis, ball_radius(rng, max_angle, 2));
+                let twist = SO3State::from_axis_angle(e, rng.random_range(-PI..PI));
+                self.target.get_rotation().compose(&swing).compose(&twist)
+            }
+        };
+
+        Ok(SE3State::new(
+            target[0] + radius * direction[0],
+            target[1] + radius * direction[1],
+            target[2] + radius * direction[2],
+            rotation,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_se2_pose_goal_wraps_yaw() {
+        let goal = SE2PoseGoal::new(SE2State::new(0.0, 0.0, PI - 0.05), 0.1, 0.2);
+
+        assert!(goal.is_satisfied(&SE2State::new(0.05, 0.0, -PI + 0.05)));
+        assert!(!goal.is_satisfied(&SE2State::new(0.0, 0.0, 0.0)));
+        assert!((goal.distance_goal(&SE2State::new(1.1, 0.0, PI - 0.05)) - 1.0).abs() < 1e-9);
+
+        let mut rng = rand::rng();
+        for _ in 0..100 {
+            assert!(goal.is_satisfied(&goal.sample_goal(&mut rng).unwrap()));
+        }
+    }
+
+    #[test]
+    fn test_se3_pose_goal_tolerances_are_independent() {
+        let target = SE3State::new(
+            1.0,
+            2.0,
+            3.0,
+            SO3State::from_axis_angle([1.0, 1.0, 0.0], 0.5),
+        );
+        let goal = SE3PoseGoal::new(target.clone(), 0.005, 2.0_f64.to_radians());
+
+        let rotated = target.get_rotation().compose(&SO3State::from_axis_angle(
+            [0.0, 1.0, 0.0],
+            1.0_f64.to_radians(),
+        ));
+        assert!(goal.is_satisfied(&SE3State::new(1.003, 2.0, 3.0, rotated.clone())));
+        assert!(!goal.is_satisfied(&SE3State::new(1.01, 2.0, 3.0, rotated)));
+
+        let too_rotated = target.get_rotation().compose(&SO3State::from_axis_angle(
+            [0.0, 1.0, 0.0],
+            3.0_f64.to_radians(),
+        ));
+        let state = SE3State::new(1.0, 2.0, 3.0, too_rotated);
+        assert!(!goal.is_satisfied(&state));
+        assert!((goal.distance_goal(&state) - 1.0_f64.to_radians()).abs() < 1e-9);
+
+        let mut rng = rand::rng();
+        for _ in 0..100 {
+            assert!(goal.is_satisfied(&goal.sample_goal(&mut rng).unwrap()));
+        }
+    }
+
+    #[test]
+    fn test_se3_pose_goal_free_axis() {
+        let target = SE3State::new(
+            0.0,
+            0.0,
+            0.0,
+            SO3State::from_axis_angle([1.0, 0.0, 0.0], 0.3),
+        );
+        let goal = SE3PoseGoal::new(target.clone(), 0.01, 0.05).with_free_axis(RotationAxis::Z);
+
+        // Any twist about the target's own z-axis is allowed...
+        let twisted = target
+            .get_rotation()
+            .compose(&SO3State::from_axis_angle([0.0, 0.0, 1.0], 2.5));
+        assert!(goal.orientation_error(&SE3State::new(0.0, 0.0, 0.0, twisted.clone())) < 1e-9);
+
+        // ...but a swing away from it is not, even when combined with a twist.
+        let swung = twisted.compose(&SO3State::from_axis_angle([1.0, 0.0, 0.0], 0.1));
+        let error = goal.orientation_error(&SE3State::new(0.0, 0.0, 0.0, swung));
+        assert!((error - 0.1).abs() < 1e-9);
+
+        let mut rng = rand::rng();
+        let mut twists = Vec::new();
+        for _ in 0..100 {
+            let sample = goal.sample_goal(&mut rng).unwrap();
+            assert!(goal.is_satisfied(&sample));
+            twists.push(sample.get_rotation().z);
+        }
+        // The twist about the free axis should be spread over the whole circle.
+        let spread = twists.iter().cloned().fold(f64::NEG_INFINITY, f64::max)
+            - twists.iter().cloned().fold(f64::INFINITY, f64::min);
+        assert!(spread > 1.0);
+
+        // Only one axis is free, so setting another replaces it.
+        let goal = goal.with_free_axis(RotationAxis::X);
+        assert_eq!(goal.free_axis, Some(RotationAxis::X));
+        assert!(goal.orientation_error(&SE3State::new(0.0, 0.0, 0.0, twisted)) > 2.0);
+    }
+}
@@ -0,0 +1,703 @@
+// Copyright (c) 2025 Junior Sundar
+//
+// SPDX-License-Identifier: BSD-3-Clause
+
+use rand::{Rng, RngCore};
+use std::f64::consts::PI;
+
+use crate::base::{
+    error::{StateSamplingError, StateSpaceError},
+    goal::{Goal, GoalRegion, GoalSampleableRegion},
+    state::{SE3State, SO3State},
+    validity::StateValidityChecker,
+};
+
+/// The default value of `threshold` for `TaskSpaceRegion` and `TSRChain`.
+const DEFAULT_THRESHOLD: f64 = 1e-6;
+
+/// The maximum number of iterations `TSRChain` spends searching for the closest chain pose.
+const CHAIN_SEARCH_ITERATIONS: usize = 100;
+
+/// Returns how far the angle `angle` lies outside `[lower, upper]` going round the circle, along
+/// with the closest angle within the range.
+fn angle_to_range(angle: f64, lower: f64, upper: f64) -> (f64, f64) {
+    if upper - lower >= 2.0 * PI {
+        return (0.0, angle);
+    }
+    let shifted = lower + (angle - lower).rem_euclid(2.0 * PI);
+    if shifted <= upper {
+        return (0.0, shifted);
+    }
+    let above = shifted - upper;
+    let below = lower + 2.0 * PI - shifted;
+    if above <= below {
+        (above, upper)
+    } else {
+        (below, lower)
+    }
+}
+
+/// Returns the transform with translation `(x, y, z)` and rotation `(roll, pitch, yaw)`.
+fn displacement_transform(displacement: &[f64; 6]) -> SE3State {
+    let [x, y, z, roll, pitch, yaw] = *displacement;
+    SE3State::new(x, y, z, SO3State::from_euler(roll, pitch, yaw))
+}
+
+/// Returns the rotation of `state` as a unit quaternion.
+fn unit_rotation(state: &SE3State) -> SO3State {
+    state
+        .get_rotation()
+        .clone()
+        .normalise()
+        .unwrap_or_else(|_| SO3State::identity())
+}
+
+/// A Task Space Region (TSR): a set of end-effector poses described by bounds on the position and
+/// roll, pitch and yaw of the end-effector within a reference frame.
+///
+/// A pose `T` is in the region if it can be written as
+/// `reference * displacement * end_effector_offset`, where `displacement` is a transform whose
+/// translation `(x, y, z)` and rotation `(roll, pitch, yaw)`, as used by `SO3State::from_euler`,
+/// lie within `bounds`. For example, the grasps of a can standing upright at `reference` might
+/// allow any yaw about its axis and a few centimetres of travel along it, with
+/// `end_effector_offset` holding the gripper back from the can's surface.
+///
+/// A `TaskSpaceRegion` can be used as a goal on an `SE3StateSpace`, and also as a
+/// `StateValidityChecker` to constrain every state of a path to the region, such as keeping a
+/// carried cup upright. As a constraint, the region must have volume for a sampling-based planner
+/// to find states in it. `project` can be used to move states onto a thin region.
+///
+/// # Examples
+///
+/// ```
+/// use std::f64::consts::PI;
+/// use oxmpl::base::goal::{Goal, GoalSampleableRegion, TaskSpaceRegion};
+/// use oxmpl::base::state::{SE3State, SO3State};
+///
+/// // A grasp anywhere within 5 cm along the z-axis of the object, at any yaw about it.
+/// let object = SE3State::new(0.5, 0.0, 0.2, SO3State::identity());
+/// let region = TaskSpaceRegion::new(
+///     object,
+///     [(0.0, 0.0), (0.0, 0.0), (-0.05, 0.05), (0.0, 0.0), (0.0, 0.0), (-PI, PI)],
+/// )
+/// .unwrap();
+///
+/// let grasp = SE3State::new(0.5, 0.0, 0.22, SO3State::from_euler(0.0, 0.0, 1.0));
+/// assert!(region.is_satisfied(&grasp));
+/// assert!(!region.is_satisfied(&SE3State::new(0.5, 0.0, 0.3, SO3State::identity())));
+///
+/// let sample = region.sample_goal(&mut rand::rng()).unwrap();
+/// assert!(region.is_satisfied(&sample));
+/// ```
+#[derive(Clone, Debug)]
+pub struct TaskSpaceRegion {
+    /// The pose of the region's frame.
+    pub reference: SE3State,
+    /// The pose of the end-effector relative to the displaced region frame. Defaults to the
+    /// identity.
+    pub end_effector_offset: SE3State,
+    /// The `(lower, upper)` bounds on the displacement's x, y, z, roll, pitch and yaw, in that
+    /// order. Translation bounds may be infinite. Angle bounds at least `2 * PI` apart leave that
+    /// angle free.
+    pub bounds: [(f64, f64); 6],
+    /// The distance from the region within which a pose satisfies it, to allow for numerical
+    /// error. Defaults to `1e-6`.
+    pub threshold: f64,
+}
+
+impl TaskSpaceRegion {
+    /// Creates a new `TaskSpaceRegion` with an identity end-effector offset.
+    ///
+    /// # Parameters
+    /// * `reference` - The pose of the region's frame.
+    /// * `bounds` - The `(lower, upper)` bounds on x, y, z, roll, pitch and yaw.
+    ///
+    /// # Errors
+    /// Returns `StateSpaceError::InvalidBound` if a lower bound is greater than its upper bound.
+    pub fn new(reference: SE3State, bounds: [(f64, f64); 6]) -> Result<Self, StateSpaceError> {
+        if let Some(&(lower, upper)) = bounds.iter().find(|(lower, upper)| lower > upper) {
+            return Err(StateSpaceError::InvalidBound { lower, upper });
+        }
+        Ok(TaskSpaceRegion {
+            reference,
+            end_effector_offset: SE3State::new(0.0, 0.0, 0.0, SO3State::identity()),
+            bounds,
+            threshold: DEFAULT_THRESHOLD,
+        })
+    }
+
+    /// Sets the pose of the end-effector relative to the displaced region frame.
+    pub fn with_end_effector_offset(mut self, offset: SE3State) -> Self {
+        self.end_effector_offset = offset;
+        self
+    }
+
+    /// Returns the end-effector pose for the given displacement
+    /// `[x, y, z, roll, pitch, yaw]` of the region frame.
+    pub fn pose_at(&self, displacement: &[f64; 6]) -> SE3State {
+        self.reference
+            .compose(&displacement_transform(displacement))
+            .compose(&self.end_effector_offset)
+    }
+
+    /// Returns the displacement `[x, y, z, roll, pitch, yaw]` of the region frame that puts the
+    /// end-effector at `state`, whether or not it lies within `bounds`.
+    pub fn displacement(&self, state: &SE3State) -> [f64; 6] {
+        let transform = self
+            .reference
+            .inverse()
+            .compose(state)
+            .compose(&self.end_effector_offset.inverse());
+        let (roll, pitch, yaw) = unit_rotation(&transform).to_euler();
+        [
+            transform.get_x(),
+            transform.get_y(),
+            transform.get_z(),
+            roll,
+            pitch,
+            yaw,
+        ]
+    }
+
+    /// Clamps a displacement into `bounds`, returning the clamped displacement and how far each
+    /// component had to move.
+    fn clamp_displacement(&self, displacement: &[f64; 6]) -> ([f64; 6], [f64; 6]) {
+        let mut clamped = *displacement;
+        let mut violation = [0.0; 6];
+        for i in 0..6 {
+            let (lower, upper) = self.bounds[i];
+            if i < 3 {
+                clamped[i] = displacement[i].clamp(lower, upper);
+                violation[i] = (displacement[i] - clamped[i]).abs();
+            } else {
+                (violation[i], clamped[i]) = angle_to_range(displacement[i], lower, upper);
+            }
+        }
+        (clamped, violation)
+    }
+
+    /// Returns the displacement within `bounds` closest to that of `state`, and the distance
+    /// between the two.
+    fn closest_displacement(&self, state: &SE3State) -> ([f64; 6], f64) {
+        let [x, y, z, roll, pitch, yaw] = self.displacement(state);
+        // Every rotation has a second set of roll, pitch and yaw angles, with pitch outside
+        // [-PI/2, PI/2]. Either may be the one that lies within the bounds.
+        [
+            [x, y, z, roll, pitch, yaw],
+            [x, y, z, roll + PI, PI - pitch, yaw + PI],
+        ]
+        .iter()
+        .map(|displacement| {
+            let (clamped, violation) = self.clamp_displacement(displacement);
+            (clamped, violation.iter().map(|v| v * v).sum::<f64>().sqrt())
+        })
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .unwrap()
+    }
+
+    /// Returns the closest pose to `state` within the region, measured by the distance between
+    /// their displacements.
+    pub fn project(&self, state: &SE3State) -> SE3State {
+        self.pose_at(&self.closest_displacement(state).0)
+    }
+
+    /// Samples a displacement uniformly from `bounds`.
+    ///
+    /// # Errors
+    /// Returns `StateSamplingError::UnboundedDimension` if a translation bound is infinite.
+    pub fn sample_displacement(
+        &self,
+        rng: &mut dyn RngCore,
+    ) -> Result<[f64; 6], StateSamplingError> {
+        let mut displacement = [0.0; 6];
+        for (i, &(lower, upper)) in self.bounds.iter().enumerate() {
+            if !lower.is_finite() || !upper.is_finite() {
+                return Err(StateSamplingError::UnboundedDimension { dimension_index: i });
+            }
+            let upper = if i < 3 {
+                upper
+            } else {
+                upper.min(lower + 2.0 * PI)
+            };
+            displacement[i] = rng.random_range(lower..=upper);
+        }
+        Ok(displacement)
+    }
+}
+
+impl Goal<SE3State> for TaskSpaceRegion {
+    fn is_satisfied(&self, state: &SE3State) -> bool {
+        self.distance_goal(state) <= self.threshold
+    }
+}
+
+impl GoalRegion<SE3State> for TaskSpaceRegion {
+    /// Returns the Euclidean distance between the displacement of `state` and the closest
+    /// displacement within `bounds`, mixing units of length and radians.
+    fn distance_goal(&self, state: &SE3State) -> f64 {
+        self.closest_displacement(state).1
+    }
+}
+
+impl GoalSampleableRegion<SE3State> for TaskSpaceRegion {
+    /// Samples a displacement uniformly from `bounds` and returns the resulting pose.
+    ///
+    /// # Errors
+    /// Returns `StateSamplingError::UnboundedDimension` if a translation bound is infinite.
+    fn sample_goal(&self, rng: &mut dyn RngCore) -> Result<SE3State, StateSamplingError> {
+        Ok(self.pose_at(&self.sample_displacement(rng)?))
+    }
+}
+
+impl StateValidityChecker<SE3State> for TaskSpaceRegion {
+    /// Returns `true` if `state` lies within the region.
+    fn is_valid(&self, state: &SE3State) -> bool {
+        self.is_satisfied(state)
+    }
+}
+
+/// Returns the product of the poses of `regions`, each displaced by the matching entry of
+/// `displacements`.
+fn chain_pose(regions: &[TaskSpaceRegion], displacements: &[[f64; 6]]) -> SE3State {
+    regions.iter().zip(displacements).fold(
+        SE3State::new(0.0, 0.0, 0.0, SO3State::identity()),
+        |pose, (region, displacement)| pose.compose(&region.pose_at(displacement)),
+    )
+}
+
+/// A chain of `TaskSpaceRegion`s, each of which is displaced relative to the previous one.
+///
+/// The pose of the chain is the product of the poses of its regions, with the `reference` of
+/// each region after the first given relative to the end-effector of the one before. This can
+/// describe poses with more than one degree of freedom, such as a gripper holding a door handle,
+/// which may rotate about its own axis, while the door rotates about its hinge.
+///
+/// As with a single region, a chain can be used as a goal or as a `StateValidityChecker`.
+///
+/// > [!NOTE]
+/// > Unlike a single region, the distance from a pose to a chain has no closed form. It is found
+/// > by a bounded local search over the displacements of all the regions, so it can overestimate
+/// > the distance for poses far from the chain, and it is much more expensive to compute.
+///
+/// # Examples
+///
+/// ```
+/// use std::f64::consts::PI;
+/// use oxmpl::base::goal::{Goal, GoalSampleableRegion, TaskSpaceRegion, TSRChain};
+/// use oxmpl::base::state::{SE3State, SO3State};
+///
+/// // A door hinged about the z-axis at the origin, opened by up to 90 degrees...
+/// let hinge = TaskSpaceRegion::new(
+///     SE3State::new(0.0, 0.0, 0.0, SO3State::identity()),
+///     [(0.0, 0.0), (0.0, 0.0), (0.0, 0.0), (0.0, 0.0), (0.0, 0.0), (0.0, PI / 2.0)],
+/// )
+/// .unwrap();
+/// // ...with a handle 0.8 m from the hinge, which the gripper may grasp at any point along 5 cm.
+/// let handle = TaskSpaceRegion::new(
+///     SE3State::new(0.8, 0.0, 1.0, SO3State::identity()),
+///     [(0.0, 0.0), (0.0, 0.0), (-0.025, 0.025), (0.0, 0.0), (0.0, 0.0), (0.0, 0.0)],
+/// )
+/// .unwrap();
+/// let chain = TSRChain::new(vec![hinge, handle]);
+///
+/// let open = SE3State::new(0.0, 0.8, 1.01, SO3State::from_euler(0.0, 0.0, PI / 2.0));
+/// assert!(chain.is_satisfied(&open));
+///
+/// let sample = chain.sample_goal(&mut rand::rng()).unwrap();
+/// assert!(chain.is_satisfied(&sample));
+/// ```
+#[derive(Clone, Debug)]
+pub struct TSRChain {
+    /// The regions of the chain, from the first to the last.
+    pub regions: Vec<TaskSpaceRegion>,
+    /// The distance from the chain within which a pose satisfies it. Defaults to `1e-6`.
+    pub threshold: f64,
+}
+
+impl TSRChain {
+    /// Creates a new `TSRChain` from its regions, in order.
+    pub fn new(regions: Vec<TaskSpaceRegion>) -> Self {
+        TSRChain {
+            regions,
+            threshold: DEFAULT_THRESHOLD,
+        }
+    }
+
+    /// Returns the end-effector pose of the chain when each region is displaced by the
+    /// corresponding entry of `displacements`.
+    ///
+    /// # Panics
+    /// Panics if `displacements` does not have one entry per region.
+    pub fn pose_at(&self, displacements: &[[f64; 6]]) -> SE3State {
+        assert_eq!(displacements.len(), self.regions.len());
+        chain_pose(&self.regions, displacements)
+    }
+
+    /// Returns the difference between `target` and `pose`, as a translation followed by a
+    /// rotation vector, both in the world frame.
+    fn pose_error(target: &SE3State, pose: &SE3State) -> [f64; 6] {
+        let rotation = unit_rotation(target).compose(&unit_rotation(pose).inverse());
+        // Take the shorter way round, so the rotation vector is at most PI long.
+        let sign = if rotation.w < 0.0 { -1.0 } else { 1.0 };
+        let vector = [rotation.x * sign, rotation.y * sign, rotation.z * sign];
+        let norm = vector.iter().map(|v| v * v).sum::<f64>().sqrt();
+        let scale = if norm < 1e-12 {
+            2.0
+        } else {
+            rotation.angle() / norm
+        };
+        [
+            target.get_x() - pose.get_x(),
+            target.get_y() - pose.get_y(),
+            target.get_z() - pose.get_z(),
+            vector[0] * scale,
+            vector[1] * scale,
+            vector[2] * scale,
+        ]
+    }
+
+    /// Combines a pose error into a distance, as the translation distance plus the rotation
+    /// angle.
+    fn error_distance(error: &[f64; 6]) -> f64 {
+        let length = |v: &[f64]| v.iter().map(|e| e * e).sum::<f64>().sqrt();
+        length(&error[..3]) + length(&error[3..])
+    }
+
+    /// Clamps every displacement into the bounds of its region.
+    fn clamp(&self, displacements: &mut [[f64; 6]]) {
+        for (region, displacement) in self.regions.iter().zip(displacements.iter_mut()) {
+            for (value, &(lower, upper)) in displacement.iter_mut().zip(&region.bounds) {
+                *value = value.clamp(lower, upper);
+            }
+        }
+    }
+
+    /// Searches for the displacements whose chain pose is closest to `state`, using damped least
+    /// squares within the bounds of each region. Returns the distance to the closest pose found.
+    fn closest_distance(&self, state: &SE3State) -> f64 {
+        let Some(last) = self.regions.last() else {
+            return f64::INFINITY;
+        };
+
+        // Start from the middle of every region but the last, and solve for the last exactly.
+        let mut displacements: Vec<[f64; 6]> = self
+            .regions
+            .iter()
+            .map(|region| {
+                region.bounds.map(
+                    |(lower, upper)| match (lower.is_finite(), upper.is_finite()) {
+                        (true, true) => (lower + upper) / 2.0,
+                        _ => 0.0_f64.clamp(lower, upper),
+                    },
+                )
+            })
+            .collect();
+        let n = displacements.len();
+        let prefix = chain_pose(&self.regions[..n - 1], &displacements[..n - 1]);
+        displacements[n - 1] = last
+            .closest_displacement(&prefix.inverse().compose(state))
+            .0;
+        self.clamp(&mut displacements);
+
+        let mut error = Self::pose_error(state, &self.pose_at(&displacements));
+        let mut distance = Self::error_distance(&error);
+        let step = 1e-6;
+        for _ in 0..CHAIN_SEARCH_ITERATIONS {
+            if distance <= self.threshold * 1e-3 {
+                break;
+            }
+
+            // Numerical Jacobian of the pose with respect to every displacement component that
+            // is free to move.
+            let mut jacobian = vec![[0.0; 6]; 6 * n];
+            for (k, column) in jacobian.iter_mut().enumerate() {
+                let (lower, upper) = self.regions[k / 6].bounds[k % 6];
+                if lower == upper {
+                    continue;
+                }
+                let mut forward = displacements.clone();
+                forward[k / 6][k % 6] += step;
+                let mut backward = displacements.clone();
+                backward[k / 6][k % 6] -= step;
+                let forward_error = Self::pose_error(state, &self.pose_at(&forward));
+                let backward_error = Self::pose_error(state, &self.pose_at(&backward));
+                for row in 0..6 {
+                    column[row] = (backward_error[row] - forward_error[row]) / (2.0 * step);
+                }
+            }
+
+            // delta = J^T (J J^T + lambda^2 I)^-1 error, dropping components that are pushed
+            // against their bounds until none are.
+            let mut delta = vec![0.0; 6 * n];
+            loop {
+                let mut system = [[0.0; 7]; 6];
+                for (row, equation) in system.iter_mut().enumerate() {
+                    for col in 0..6 {
+                        equation[col] = jacobian.iter().map(|c| c[row] * c[col]).sum();
+                    }
+                    equation[row] += 1e-9;
+                    equation[6] = error[row];
+                }
+                let Some(weights) = solve_linear_system(system) else {
+                    break;
+                };
+                for (d, column) in delta.iter_mut().zip(&jacobian) {
+                    *d = column.iter().zip(&weights).map(|(j, w)| j * w).sum();
+                }
+
+                let blocked: Vec<usize> = (0..6 * n)
+                    .filter(|&k| {
+                        let (lower, upper) = self.regions[k / 6].bounds[k % 6];
+                        let value = displacements[k / 6][k % 6];
+                        jacobian[k] != [0.0; 6]
+                            && ((value >= upper && delta[k] > 0.0)
+                                || (value <= lower && delta[k] < 0.0))
+                    })
+                    .collect();
+                if blocked.is_empty() {
+                    break;
+                }
+                for k in blocked {
+                    jacobian[k] = [0.0; 6];
+                    delta[k] = 0.0;
+                }
+            }
+
+            // Halve the step until it brings the pose closer.
+            let mut scale = 1.0;
+            let mut improved = false;
+            while scale > 1e-4 {
+                let mut candidate = displacements.clone();
+                for (k, d) in delta.iter().enumerate() {
+                    candidate[k / 6][k % 6] += scale * d;
+                }
+                self.clamp(&mut candidate);
+                let candidate_error = Self::pose_error(state, &self.pose_at(&candidate));
+                let candidate_distance = Self::error_distance(&candidate_error);
+                if candidate_distance < distance {
+                    displacements = candidate;
+                    error = candidate_error;
+                    distance = candidate_distance;
+                    improved = true;
+                    break;
+                }
+                scale /= 2.0;
+            }
+            if !improved {
+                break;
+            }
+        }
+        distance
+    }
+}
+
+/// Solves a 6x6 linear system, given as its augmented matrix, by Gaussian elimination with
+/// partial pivoting. Returns `None` if the system is singular.
+fn solve_linear_system(mut system: [[f64; 7]; 6]) -> Option<[f64; 6]> {
+    for col in 0..6 {
+        let pivot =
+            (col..6).max_by(|&a, &b| system[a][col].abs().total_cmp(&system[b][col].abs()))?;
+        if system[pivot][col].abs() < 1e-12 {
+            return None;
+        }
+        system.swap(col, pivot);
+        let pivot_row = system[col];
+        for equation in system.iter_mut().skip(col + 1) {
+            let factor = equation[col] / pivot_row[col];
+            for (value, pivot_value) in equation.iter_mut().zip(&pivot_row).skip(col) {
+                *value -= factor * pivot_value;
+            }
+        }
+    }
+    let mut solution = [0.0; 6];
+    for row in (0..6).rev() {
+        let rest: f64 = (row + 1..6).map(|k| system[row][k] * solution[k]).sum();
+        solution[row] = (system[row][6] - rest) / system[row][row];
+    }
+    Some(solution)
+}
+
+impl Goal<SE3State> for TSRChain {
+    fn is_satisfied(&self, state: &SE3State) -> bool {
+        self.distance_goal(state) <= self.threshold
+    }
+}
+
+impl GoalRegion<SE3State> for TSRChain {
+    /// Returns the translation distance plus the rotation angle between `state` and the closest
+    /// chain pose found. A chain with a single region uses that region's distance instead.
+    fn distance_goal(&self, state: &SE3State) -> f64 {
+        match self.regions.as_slice() {
+            [region] => region.distance_goal(state),
+            _ => self.closest_distance(state),
+        }
+    }
+}
+
+impl GoalSampleableRegion<SE3State> for TSRChain {
+    /// Samples a displacement uniformly from the bounds of every region and returns the
+    /// resulting chain pose.
+    ///
+    /// # Errors
+    /// Returns `StateSamplingError::GoalRegionUnsatisfiable` if the chain has no regions, or
+    /// `StateSamplingError::UnboundedDimension` if a translation bound is infinite.
+    fn sample_goal(&self, rng: &mut dyn RngCore) -> Result<SE3State, StateSamplingError> {
+        if self.regions.is_empty() {
+            return Err(StateSamplingError::GoalRegionUnsatisfiable);
+        }
+        let displacements = self
+            .regions
+            .iter()
+            .map(|region| region.sample_displacement(rng))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(self.pose_at(&displacements))
+    }
+}
+
+impl StateValidityChecker<SE3State> for TSRChain {
+    /// Returns `true` if `state` lies within the chain.
+    fn is_valid(&self, state: &SE3State) -> bool {
+        self.is_satisfied(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn upright_region() -> TaskSpaceRegion {
+        TaskSpaceRegion::new(
+            SE3State::new(1.0, 0.0, 0.5, SO3State::from_euler(0.0, 0.0, PI / 2.0)),
+            [
+                (-0.1, 0.1),
+                (0.0, 0.0),
+                (0.0, 0.2),
+                (-0.1, 0.1),
+                (-0.1, 0.1),
+                (-PI, PI),
+            ],
+        )
+        .unwrap()
+        .with_end_effector_offset(SE3State::new(0.0, 0.0, -0.05, SO3State::identity()))
+    }
+
+    #[test]
+    fn test_task_space_region_invalid_bounds() {
+        let mut bounds = [(0.0, 0.0); 6];
+        bounds[4] = (0.2, -0.2);
+        let result =
+            TaskSpaceRegion::new(SE3State::new(0.0, 0.0, 0.0, SO3State::identity()), bounds);
+        assert_eq!(
+            result.err(),
+            Some(StateSpaceError::InvalidBound {
+                lower: 0.2,
+                upper: -0.2
+            })
+        );
+    }
+
+    #[test]
+    fn test_task_space_region_distance_and_projection() {
+        let region = upright_region();
+
+        let inside = region.pose_at(&[0.05, 0.0, 0.1, 0.0, 0.05, 3.0]);
+        assert!(region.distance_goal(&inside) < 1e-9);
+
+        // 0.3 beyond the upper z bound and 0.2 rad beyond the roll bound.
+        let outside = region.pose_at(&[0.0, 0.0, 0.5, 0.3, 0.0, -2.0]);
+        assert!((region.distance_goal(&outside) - 0.3_f64.hypot(0.2)).abs() < 1e-9);
+        assert!(!region.is_valid(&outside));
+
+        let projected = region.project(&outside);
+        assert!(region.is_satisfied(&projected));
+        let expected = region.pose_at(&[0.0, 0.0, 0.2, 0.1, 0.0, -2.0]);
+        assert!((projected.get_z() - expected.get_z()).abs() < 1e-9);
+        assert!(
+            projected
+                .get_rotation()
+                .inverse()
+                .compose(expected.get_rotation())
+                .angle()
+                < 1e-9
+        );
+    }
+
+    #[test]
+    fn test_task_space_region_yaw_wraps() {
+        let mut bounds = [(0.0, 0.0); 6];
+        bounds[5] = (PI - 0.1, PI + 0.1);
+        let region =
+            TaskSpaceRegion::new(SE3State::new(0.0, 0.0, 0.0, SO3State::identity()), bounds)
+                .unwrap();
+
+        let state = SE3State::new(0.0, 0.0, 0.0, SO3State::from_euler(0.0, 0.0, -PI + 0.05));
+        assert!(region.is_satisfied(&state));
+        let state = SE3State::new(0.0, 0.0, 0.0, SO3State::from_euler(0.0, 0.0, -PI + 0.3));
+        assert!((region.distance_goal(&state) - 0.2).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_task_space_region_samples_are_satisfied() {
+        let region = upright_region();
+        let mut rng = rand::rng();
+        for _ in 0..100 {
+            let sample = region.sample_goal(&mut rng).unwrap();
+            assert!(region.is_satisfied(&sample));
+        }
+
+        let mut unbounded = region.clone();
+        unbounded.bounds[1] = (f64::NEG_INFINITY, f64::INFINITY);
+        assert_eq!(
+            unbounded.sample_goal(&mut rng).err(),
+            Some(StateSamplingError::UnboundedDimension { dimension_index: 1 })
+        );
+    }
+
+    #[test]
+    fn test_tsr_chain_door() {
+        let mut hinge_bounds = [(0.0, 0.0); 6];
+        hinge_bounds[5] = (0.0, PI / 2.0);
+        let hinge = TaskSpaceRegion::new(
+            SE3State::new(0.0, 0.0, 0.0, SO3State::identity()),
+            hinge_bounds,
+        )
+        .unwrap();
+        let mut handle_bounds = [(0.0, 0.0); 6];
+        handle_bounds[2] = (-0.05, 0.05);
+        handle_bounds[5] = (-0.2, 0.2);
+        let handle = TaskSpaceRegion::new(
+            SE3State::new(0.8, 0.0, 1.0, SO3State::identity()),
+            handle_bounds,
+        )
+        .unwrap();
+        let chain = TSRChain::new(vec![hinge, handle]);
+
+        let pose = chain.pose_at(&[
+            [0.0, 0.0, 0.0, 0.0, 0.0, 1.2],
+            [0.0, 0.0, 0.03, 0.0, 0.0, -0.1],
+        ]);
+        assert!(chain.is_satisfied(&pose));
+        assert!(chain.is_valid(&pose));
+
+        // Out past the end of the handle, with the door fully open.
+        let pose = chain.pose_at(&[
+            [0.0, 0.0, 0.0, 0.0, 0.0, PI / 2.0],
+            [0.0, 0.0, 0.15, 0.0, 0.0, 0.0],
+        ]);
+        assert!((chain.distance_goal(&pose) - 0.1).abs() < 1e-4);
+
+        // The door cannot swing the other way.
+        let pose = chain.pose_at(&[
+            [0.0, 0.0, 0.0, 0.0, 0.0, -0.5],
+            [0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+        ]);
+        assert!(!chain.is_satisfied(&pose));
+
+        let mut rng = rand::rng();
+        for _ in 0..50 {
+            assert!(chain.is_satisfied(&chain.sample_goal(&mut rng).unwrap()));
+        }
+
+        assert!(TSRChain::new(Vec::new()).sample_goal(&mut rng).is_err());
+    }
+}
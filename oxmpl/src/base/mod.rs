@@ -4,13 +4,18 @@
 
 //! Base utilities and routines shared by both geometric and differential/control based planners.
 
+mod goals;
 mod spaces;
 mod states;
 
 pub mod error;
 pub mod goal;
+pub mod objective;
+pub mod observer;
+pub mod param;
 pub mod planner;
 pub mod problem_definition;
+pub mod rng;
 pub mod space;
 pub mod state;
 pub mod validity;
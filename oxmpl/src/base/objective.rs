@@ -0,0 +1,65 @@
+// Copyright (c) 2025 Junior Sundar
+//
+// SPDX-License-Identifier: BSD-3-Clause
+
+use std::sync::Arc;
+
+use crate::base::{planner::Path, space::StateSpace, state::State};
+
+/// A measure of the cost of a path, where lower is better.
+///
+/// Objectives let planners and tools, such as `PathMetrics`, compare paths by something other than
+/// their length, e.g. the energy or time they take.
+///
+/// # Example
+///
+/// ```
+/// use oxmpl::base::{objective::OptimizationObjective, planner::Path, state::RealVectorState};
+///
+/// // Every motion costs the same, so the best path has the fewest vertices.
+/// struct VertexCountObjective;
+///
+/// impl OptimizationObjective<RealVectorState> for VertexCountObjective {
+///     fn motion_cost(&self, _from: &RealVectorState, _to: &RealVectorState) -> f64 {
+///         1.0
+///     }
+/// }
+///
+/// let path = Path(vec![
+///     RealVectorState::new(vec![0.0]),
+///     RealVectorState::new(vec![1.0]),
+///     RealVectorState::new(vec![2.0]),
+/// ]);
+/// assert_eq!(VertexCountObjective.path_cost(&path), 2.0);
+/// ```
+pub trait OptimizationObjective<S: State>: Send + Sync {
+    /// Returns the cost of moving directly from `from` to `to`.
+    fn motion_cost(&self, from: &S, to: &S) -> f64;
+
+    /// Returns the cost of `path`. By default, this is the sum of the costs of its motions.
+    fn path_cost(&self, path: &Path<S>) -> f64 {
+        path.0
+            .windows(2)
+            .map(|pair| self.motion_cost(&pair[0], &pair[1]))
+            .sum()
+    }
+}
+
+/// An objective that minimises the length of a path, as measured by the distance of its space.
+pub struct PathLengthObjective<SP: StateSpace> {
+    /// The space used to measure the motions.
+    pub space: Arc<SP>,
+}
+
+impl<SP: StateSpace> PathLengthObjective<SP> {
+    /// Creates a new `PathLengthObjective`.
+    pub fn new(space: Arc<SP>) -> Self {
+        PathLengthObjective { space }
+    }
+}
+
+impl<SP: StateSpace> OptimizationObjective<SP::StateType> for PathLengthObjective<SP> {
+    fn motion_cost(&self, from: &SP::StateType, to: &SP::StateType) -> f64 {
+        self.space.distance(from, to)
+    }
+}
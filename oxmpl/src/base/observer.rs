@@ -0,0 +1,80 @@
+// Copyright (c) 2025 Junior Sundar
+//
+// SPDX-License-Identifier: BSD-3-Clause
+
+use crate::base::{planner::Path, state::State};
+
+/// Receives progress events from a planner while it plans.
+///
+/// Every method does nothing by default, so an observer only needs to implement the events it is
+/// interested in. Multi-threaded planners may call an observer from several threads at once, and
+/// all calls are made in the middle of planning, so they should return quickly.
+///
+/// Set an observer with `Planner::set_observer`. Planners use `LogObserver` until one is set.
+///
+/// # Example
+///
+/// ```
+/// use std::sync::Mutex;
+///
+/// use oxmpl::base::{observer::PlannerObserver, state::RealVectorState};
+///
+/// /// Collects the edges of the tree so that it can be drawn.
+/// #[derive(Default)]
+/// struct TreeRecorder {
+///     edges: Mutex<Vec<(Vec<f64>, Vec<f64>)>>,
+/// }
+///
+/// impl PlannerObserver<RealVectorState> for TreeRecorder {
+///     fn on_edge_added(&self, from: &RealVectorState, to: &RealVectorState) {
+///         let edge = (from.values.clone(), to.values.clone());
+///         self.edges.lock().unwrap().push(edge);
+///     }
+/// }
+/// ```
+pub trait PlannerObserver<S: State>: Send + Sync {
+    /// Called at the start of every iteration of the planner's main loop, with the number of
+    /// iterations since the planner was set up.
+    fn on_iteration(&self, _iteration: u64) {}
+
+    /// Called when a state is added to the planner's tree or roadmap.
+    fn on_node_added(&self, _state: &S) {}
+
+    /// Called when an edge between two states is added to the planner's tree or roadmap.
+    fn on_edge_added(&self, _from: &S, _to: &S) {}
+
+    /// Called when an edge is removed from the planner's tree, for instance when `RRTStar` rewires
+    /// a node to a cheaper parent.
+    fn on_edge_removed(&self, _from: &S, _to: &S) {}
+
+    /// Called when a sample is discarded. `sample` is `None` if no state could be sampled at all.
+    fn on_sample_rejected(&self, _sample: Option<&S>) {}
+
+    /// Called when the planner finds a solution, or one that improves on its previous solution,
+    /// with the length of the path.
+    fn on_solution(&self, _path: &Path<S>, _length: f64) {}
+}
+
+/// An observer that forwards events to the [`log`](https://docs.rs/log) facade.
+///
+/// Solutions are logged at the `info` level, and node and edge additions at the `trace` level.
+/// Nothing is printed unless the application installs a logger, such as `env_logger`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LogObserver;
+
+impl<S: State> PlannerObserver<S> for LogObserver {
+    fn on_node_added(&self, state: &S) {
+        log::trace!("Node added: {state:?}");
+    }
+
+    fn on_edge_added(&self, from: &S, to: &S) {
+        log::trace!("Edge added: {from:?} -> {to:?}");
+    }
+
+    fn on_solution(&self, path: &Path<S>, length: f64) {
+        log::info!(
+            "Solution found with length {length:.4} and {} states.",
+            path.0.len()
+        );
+    }
+}
@@ -0,0 +1,454 @@
+// Copyright (c) 2025 Junior Sundar
+//
+// SPDX-License-Identifier: BSD-3-Clause
+
+//! Named, typed and range-checked planner parameters.
+//!
+//! Every planner describes its options as a `ParamSet`, which can be listed at runtime and set
+//! from strings. This lets configuration files, benchmarking sweeps and the language bindings
+//! configure any planner without knowing its constructor.
+//!
+//! # Examples
+//!
+//! ```
+//! use oxmpl::base::param::{ParamSet, ParamValue};
+//!
+//! let mut params = ParamSet::default()
+//!     .with_real("max_distance", "The maximum length of a motion.", 0.5, 0.0..=f64::INFINITY)
+//!     .with_integer("num_threads", "The number of threads.", 1, 1.0..=f64::INFINITY)
+//!     .with_bool("verbose", "Whether to log progress.", false);
+//!
+//! params.set("max_distance", "0.25").unwrap();
+//! params.set("verbose", "true").unwrap();
+//! assert_eq!(params.real("max_distance"), Some(0.25));
+//! assert_eq!(params.get("verbose").unwrap().value(), ParamValue::Bool(true));
+//!
+//! // Values are checked against the parameter's type and range.
+//! assert!(params.set("num_threads", "0").is_err());
+//! assert!(params.set("num_threads", "two").is_err());
+//! assert!(params.set("step_size", "1.0").is_err());
+//! ```
+
+use std::{fmt, ops::RangeInclusive};
+
+use crate::base::error::ParamError;
+
+/// The value of a planner parameter.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ParamValue {
+    /// A real number, such as a distance or a probability.
+    Real(f64),
+    /// A non-negative integer, such as a number of threads.
+    Integer(usize),
+    /// A flag.
+    Bool(bool),
+}
+
+impl ParamValue {
+    /// Returns the value as a real number, if it is numeric.
+    fn as_f64(&self) -> Option<f64> {
+        match self {
+            Self::Real(value) => Some(*value),
+            Self::Integer(value) => Some(*value as f64),
+            Self::Bool(_) => None,
+        }
+    }
+
+    /// Returns the value as JSON.
+    fn to_json(self) -> String {
+        match self {
+            Self::Real(value) => json_number(value),
+            Self::Integer(value) => value.to_string(),
+            Self::Bool(value) => value.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ParamValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Real(value) => write!(f, "{value}"),
+            Self::Integer(value) => write!(f, "{value}"),
+            Self::Bool(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+/// A single named planner parameter, with its current value and the range it may take.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Param {
+    name: &'static str,
+    description: &'static str,
+    value: ParamValue,
+    range: RangeInclusive<f64>,
+}
+
+impl Param {
+    /// The name the parameter is set by, e.g. `"max_distance"`.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// A one-line description of what the parameter does.
+    pub fn description(&self) -> &'static str {
+        self.description
+    }
+
+    /// The current value of the parameter.
+    pub fn value(&self) -> ParamValue {
+        self.value
+    }
+
+    /// The range a numeric parameter may take. Boolean parameters have the range `0.0..=1.0`.
+    pub fn range(&self) -> &RangeInclusive<f64> {
+        &self.range
+    }
+
+    /// Parses `value` as this parameter's type.
+    fn parse(&self, value: &str) -> Result<ParamValue, ParamError> {
+        let invalid = || ParamError::InvalidValue {
+            name: self.name.to_string(),
+            value: value.to_string(),
+        };
+        let value = value.trim();
+        match self.value {
+            ParamValue::Real(_) => match value.parse::<f64>() {
+                Ok(value) if !value.is_nan() => Ok(ParamValue::Real(value)),
+                _ => Err(invalid()),
+            },
+            ParamValue::Integer(_) => value
+                .parse()
+                .map(ParamValue::Integer)
+                .map_err(|_| invalid()),
+            ParamValue::Bool(_) => match value.to_ascii_lowercase().as_str() {
+                "true" | "1" => Ok(ParamValue::Bool(true)),
+                "false" | "0" => Ok(ParamValue::Bool(false)),
+                _ => Err(invalid()),
+            },
+        }
+    }
+
+    /// Sets the value, if it has the parameter's type and lies in its range.
+    fn set_value(&mut self, value: ParamValue) -> Result<(), ParamError> {
+        if std::mem::discriminant(&value) != std::mem::discriminant(&self.value) {
+            return Err(ParamError::InvalidValue {
+                name: self.name.to_string(),
+                value: value.to_string(),
+            });
+        }
+        if let Some(number) = value.as_f64() {
+            if !self.range.contains(&number) {
+                return Err(ParamError::OutOfRange {
+                    name: self.name.to_string(),
+                    value: number,
+                    min: *self.range.start(),
+                    max: *self.range.end(),
+                });
+            }
+        }
+        self.value = value;
+        Ok(())
+    }
+}
+
+/// The parameters of a planner.
+///
+/// A planner returns its parameters, holding their current values, from `Planner::params`, and
+/// reads them back in `Planner::set_params`. The parameters keep the order they were added in.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ParamSet {
+    params: Vec<Param>,
+}
+
+impl ParamSet {
+    /// Adds a real-valued parameter.
+    pub fn with_real(
+        self,
+        name: &'static str,
+        description: &'static str,
+        value: f64,
+        range: RangeInclusive<f64>,
+    ) -> Self {
+        self.with(name, description, ParamValue::Real(value), range)
+    }
+
+    /// Adds an integer parameter.
+    pub fn with_integer(
+        self,
+        name: &'static str,
+        description: &'static str,
+        value: usize,
+        range: RangeInclusive<f64>,
+    ) -> Self {
+        self.with(name, description, ParamValue::Integer(value), range)
+    }
+
+    /// Adds a boolean parameter.
+    pub fn with_bool(self, name: &'static str, description: &'static str, value: bool) -> Self {
+        self.with(name, description, ParamValue::Bool(value), 0.0..=1.0)
+    }
+
+    fn with(
+        mut self,
+        name: &'static str,
+        description: &'static str,
+        value: ParamValue,
+        range: RangeInclusive<f64>,
+    ) -> Self {
+        self.params.retain(|param| param.name != name);
+        self.params.push(Param {
+            name,
+            description,
+            value,
+            range,
+        });
+        self
+    }
+
+    /// Returns the parameter called `name`, if there is one.
+    pub fn get(&self, name: &str) -> Option<&Param> {
+        self.params.iter().find(|param| param.name == name)
+    }
+
+    /// Returns the value of the real-valued parameter called `name`.
+    pub fn real(&self, name: &str) -> Option<f64> {
+        match self.get(name)?.value {
+            ParamValue::Real(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of the integer parameter called `name`.
+    pub fn integer(&self, name: &str) -> Option<usize> {
+        match self.get(name)?.value {
+            ParamValue::Integer(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of the boolean parameter called `name`.
+    pub fn boolean(&self, name: &str) -> Option<bool> {
+        match self.get(name)?.value {
+            ParamValue::Bool(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Parses `value` and sets the parameter called `name` to it.
+    ///
+    /// Booleans may be given as `true`/`false` or `1`/`0`, in any case.
+    ///
+    /// # Errors
+    /// Returns `ParamError::UnknownParam` if there is no such parameter,
+    /// `ParamError::InvalidValue` if `value` cannot be parsed as the parameter's type, and
+    /// `ParamError::OutOfRange` if it lies outside the parameter's range. The parameter is left
+    /// unchanged on error.
+    pub fn set(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+        let param = self.get_mut(name)?;
+        let value = param.parse(value)?;
+        param.set_value(value)
+    }
+
+    /// Sets the parameter called `name` to `value`, which must be of the parameter's type.
+    ///
+    /// # Errors
+    /// The same as `set`.
+    pub fn set_value(&mut self, name: &str, value: ParamValue) -> Result<(), ParamError> {
+        self.get_mut(name)?.set_value(value)
+    }
+
+    fn get_mut(&mut self, name: &str) -> Result<&mut Param, ParamError> {
+        self.params
+            .iter_mut()
+            .find(|param| param.name == name)
+            .ok_or_else(|| ParamError::UnknownParam {
+                name: name.to_string(),
+            })
+    }
+
+    /// Returns an iterator over the parameters, in the order they were added.
+    pub fn iter(&self) -> impl Iterator<Item = &Param> {
+        self.params.iter()
+    }
+
+    /// Returns the number of parameters.
+    pub fn len(&self) -> usize {
+        self.params.len()
+    }
+
+    /// Returns `true` if there are no parameters.
+    pub fn is_empty(&self) -> bool {
+        self.params.is_empty()
+    }
+
+    /// Returns the parameters as a JSON object, keyed by name. Infinite bounds are written as
+    /// `null`.
+    pub fn to_json(&self) -> String {
+        let params = self
+            .params
+            .iter()
+            .map(|param| {
+                format!(
+                    "{}:{{\"value\":{},\"description\":{},\"min\":{},\"max\":{}}}",
+                    json_string(param.name),
+                    param.value.to_json(),
+                    json_string(param.description),
+                    json_number(*param.range.start()),
+                    json_number(*param.range.end()),
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+        format!("{{{params}}}")
+    }
+}
+
+impl fmt::Display for ParamSet {
+    /// Writes one `name = value` line per parameter, the format accepted by configuration files.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, param) in self.params.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{} = {}", param.name, param.value)?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes `value` as a quoted JSON string, escaping quotes, backslashes and control characters.
+pub(crate) fn json_string(value: &str) -> String {
+    let mut json = String::with_capacity(value.len() + 2);
+    json.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+/// Writes `value` as a JSON number, or `null` if it is not finite.
+fn json_number(value: f64) -> String {
+    if value.is_finite() {
+        format!("{value:?}")
+    } else {
+        "null".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params() -> ParamSet {
+        ParamSet::default()
+            .with_real("goal_bias", "Goal sampling probability.", 0.05, 0.0..=1.0)
+            .with_integer("num_threads", "Worker threads.", 1, 1.0..=f64::INFINITY)
+            .with_bool("lazy", "Defer collision checks.", false)
+    }
+
+    #[test]
+    fn test_set_parses_each_type() {
+        let mut params = params();
+        params.set("goal_bias", " 0.2 ").unwrap();
+        params.set("num_threads", "4").unwrap();
+        params.set("lazy", "TRUE").unwrap();
+        assert_eq!(params.real("goal_bias"), Some(0.2));
+        assert_eq!(params.integer("num_threads"), Some(4));
+        assert_eq!(params.boolean("lazy"), Some(true));
+        params.set("lazy", "0").unwrap();
+        assert_eq!(params.boolean("lazy"), Some(false));
+    }
+
+    #[test]
+    fn test_set_rejects_bad_values_and_keeps_old_value() {
+        let mut params = params();
+        assert_eq!(
+            params.set("goal_bias", "1.5"),
+            Err(ParamError::OutOfRange {
+                name: "goal_bias".to_string(),
+                value: 1.5,
+                min: 0.0,
+                max: 1.0,
+            })
+        );
+        assert_eq!(
+            params.set("goal_bias", "NaN"),
+            Err(ParamError::InvalidValue {
+                name: "goal_bias".to_string(),
+                value: "NaN".to_string(),
+            })
+        );
+        assert!(params.set("num_threads", "1.5").is_err());
+        assert!(params.set("num_threads", "-1").is_err());
+        assert!(params.set("lazy", "yes").is_err());
+        assert_eq!(
+            params.set("range", "1.0"),
+            Err(ParamError::UnknownParam {
+                name: "range".to_string(),
+            })
+        );
+        assert_eq!(params, self::params());
+    }
+
+    #[test]
+    fn test_set_value_checks_type() {
+        let mut params = params();
+        assert!(params
+            .set_value("num_threads", ParamValue::Real(2.0))
+            .is_err());
+        params
+            .set_value("num_threads", ParamValue::Integer(2))
+            .unwrap();
+        assert_eq!(params.integer("num_threads"), Some(2));
+        assert_eq!(params.real("num_threads"), None);
+    }
+
+    #[test]
+    fn test_adding_a_param_twice_replaces_it() {
+        let params = params().with_real("goal_bias", "Changed.", 0.1, 0.0..=1.0);
+        assert_eq!(params.len(), 3);
+        assert_eq!(params.real("goal_bias"), Some(0.1));
+        assert_eq!(params.iter().last().unwrap().description(), "Changed.");
+    }
+
+    #[test]
+    fn test_display_and_json() {
+        let params = params();
+        assert_eq!(
+            params.to_string(),
+            "goal_bias = 0.05\nnum_threads = 1\nlazy = false"
+        );
+        assert_eq!(
+            params.to_json(),
+            "{\"goal_bias\":{\"value\":0.05,\"description\":\"Goal sampling probability.\",\
+             \"min\":0.0,\"max\":1.0},\
+             \"num_threads\":{\"value\":1,\"description\":\"Worker threads.\",\
+             \"min\":1.0,\"max\":null},\
+             \"lazy\":{\"value\":false,\"description\":\"Defer collision checks.\",\
+             \"min\":0.0,\"max\":1.0}}"
+        );
+    }
+
+    #[test]
+    fn test_json_escapes_strings() {
+        assert_eq!(json_string("plain"), "\"plain\"");
+        assert_eq!(
+            json_string("a \"b\"\\c\nd\u{7f}"),
+            "\"a \\\"b\\\"\\\\c\\nd\\u007f\""
+        );
+
+        let params = ParamSet::default().with_bool("lazy", "Skip \"early\"\tchecks.", false);
+        assert!(params
+            .to_json()
+            .contains("\"description\":\"Skip \\\"early\\\"\\tchecks.\""));
+    }
+}
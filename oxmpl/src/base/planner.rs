@@ -3,10 +3,16 @@
 // SPDX-License-Identifier: BSD-3-Clause
 
 use crate::base::{
-    error::PlanningError, goal::Goal, problem_definition::ProblemDefinition, space::StateSpace,
-    state::State, validity::StateValidityChecker,
+    error::{ParamError, PlanningError},
+    goal::{DynGoal, Goal},
+    observer::PlannerObserver,
+    param::{json_string, ParamSet},
+    problem_definition::ProblemDefinition,
+    space::{DynStateSpace, StateSpace},
+    state::State,
+    validity::StateValidityChecker,
 };
-use std::{sync::Arc, time::Duration};
+use std::{fmt, sync::Arc, time::Duration};
 
 /// Represents a solution path found by a planner.
 ///
@@ -23,7 +29,16 @@ pub struct Path<S: State>(pub Vec<S>);
 ///
 /// This trait is highly generic, allowing specific planner implementations (like RRT) to be
 /// written once and then used to solve problems in many different state spaces.
-pub trait Planner<S: State, SP: StateSpace<StateType = S>, G: Goal<S>> {
+///
+/// The trait is dyn-compatible. The space and goal types default to `DynStateSpace` and
+/// `DynGoal`, so a `Box<dyn Planner<S>>` can hold any planner, for any space and goal of states
+/// `S` chosen at runtime.
+pub trait Planner<
+    S: State,
+    SP: StateSpace<StateType = S> = DynStateSpace<S>,
+    G: Goal<S> = DynGoal<S>,
+>
+{
     /// Configures the planner for a specific motion planning problem.
     ///
     /// This method **MUST** be called before `solve()`. It provides the planner with all the
@@ -57,11 +72,187 @@ pub trait Planner<S: State, SP: StateSpace<StateType = S>, G: Goal<S>> {
     /// * `Err(PlanningError)` if no solution is found within the timeout, or if another error
     ///   occurs.
     fn solve(&mut self, timeout: Duration) -> Result<Path<S>, PlanningError>;
+
+    /// Returns the statistics collected since the planner was last set up.
+    ///
+    /// The default implementation returns empty statistics, for planners that do not collect any.
+    fn stats(&self) -> PlannerStats {
+        PlannerStats::default()
+    }
+
+    /// Sets the observer that is told about the planner's progress, in place of `LogObserver`.
+    ///
+    /// The default implementation ignores the observer, for planners that do not report progress.
+    fn set_observer(&mut self, _observer: Arc<dyn PlannerObserver<S>>) {}
+
+    /// Returns the planner's parameters, holding their current values.
+    ///
+    /// The default implementation returns no parameters, for planners that have none.
+    fn params(&self) -> ParamSet {
+        ParamSet::default()
+    }
+
+    /// Updates the planner from the values in `params`. Parameters the planner does not have are
+    /// ignored.
+    ///
+    /// `params` is expected to come from `params`, so its values have already been checked.
+    /// Changes take effect from the next call to `setup`.
+    fn set_params(&mut self, _params: &ParamSet) {}
+
+    /// Parses `value` and sets the parameter called `name` to it.
+    ///
+    /// # Errors
+    /// Returns a `ParamError` if the planner has no such parameter, or if `value` cannot be parsed
+    /// as its type or is out of range. The planner is left unchanged on error.
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+        let mut params = self.params();
+        params.set(name, value)?;
+        self.set_params(&params);
+        Ok(())
+    }
+}
+
+/// Lets boxed planners, such as those built by a `PlannerRegistry`, be used wherever a planner is
+/// expected.
+impl<S, SP, G, P> Planner<S, SP, G> for Box<P>
+where
+    S: State,
+    SP: StateSpace<StateType = S>,
+    G: Goal<S>,
+    P: Planner<S, SP, G> + ?Sized,
+{
+    fn setup(
+        &mut self,
+        problem_def: Arc<ProblemDefinition<S, SP, G>>,
+        validity_checker: Arc<dyn StateValidityChecker<S>>,
+    ) {
+        (**self).setup(problem_def, validity_checker);
+    }
+
+    fn solve(&mut self, timeout: Duration) -> Result<Path<S>, PlanningError> {
+        (**self).solve(timeout)
+    }
+
+    fn stats(&self) -> PlannerStats {
+        (**self).stats()
+    }
+
+    fn set_observer(&mut self, observer: Arc<dyn PlannerObserver<S>>) {
+        (**self).set_observer(observer);
+    }
+
+    fn params(&self) -> ParamSet {
+        (**self).params()
+    }
+
+    fn set_params(&mut self, params: &ParamSet) {
+        (**self).set_params(params);
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+        (**self).set_param(name, value)
+    }
+}
+
+/// Counts of the work a planner has done, and the time it has spent in each phase.
+///
+/// Planners collect these from `setup` onwards, across any number of calls to `solve`. Work done
+/// on several threads is added up, so the phase times of a multi-threaded planner may add up to
+/// more than the time it ran for.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PlannerStats {
+    /// The number of iterations of the planner's main loop.
+    pub iterations: u64,
+    /// The number of states sampled from the space or the goal.
+    pub samples_drawn: u64,
+    /// The number of sampled states that were discarded, e.g. because they were invalid.
+    pub samples_rejected: u64,
+    /// The number of states checked by the validity checker, including those along motions.
+    pub validity_checks: u64,
+    /// The number of motions checked.
+    pub motion_checks: u64,
+    /// The number of nearest-neighbour and radius queries.
+    pub nearest_neighbour_queries: u64,
+    /// The time spent in each phase, such as sampling or checking motions, in the order the
+    /// phases were first entered.
+    pub phase_times: Vec<(String, Duration)>,
+}
+
+impl PlannerStats {
+    /// Returns the time spent in `phase`, or zero if it was never entered.
+    pub fn phase_time(&self, phase: &str) -> Duration {
+        self.phase_times
+            .iter()
+            .filter(|(name, _)| name == phase)
+            .map(|(_, time)| *time)
+            .sum()
+    }
+
+    /// Adds the counts and phase times of `other` to these statistics.
+    pub fn merge(&mut self, other: &PlannerStats) {
+        self.iterations += other.iterations;
+        self.samples_drawn += other.samples_drawn;
+        self.samples_rejected += other.samples_rejected;
+        self.validity_checks += other.validity_checks;
+        self.motion_checks += other.motion_checks;
+        self.nearest_neighbour_queries += other.nearest_neighbour_queries;
+        for (phase, time) in &other.phase_times {
+            match self.phase_times.iter_mut().find(|(name, _)| name == phase) {
+                Some((_, total)) => *total += *time,
+                None => self.phase_times.push((phase.clone(), *time)),
+            }
+        }
+    }
+
+    /// Returns the statistics as a JSON object, with phase times in seconds.
+    pub fn to_json(&self) -> String {
+        let phases = self
+            .phase_times
+            .iter()
+            .map(|(phase, time)| format!("{}:{}", json_string(phase), time.as_secs_f64()))
+            .collect::<Vec<_>>()
+            .join(",");
+        format!(
+            "{{\"iterations\":{},\"samples_drawn\":{},\"samples_rejected\":{},\
+             \"validity_checks\":{},\"motion_checks\":{},\"nearest_neighbour_queries\":{},\
+             \"phase_times\":{{{phases}}}}}",
+            self.iterations,
+            self.samples_drawn,
+            self.samples_rejected,
+            self.validity_checks,
+            self.motion_checks,
+            self.nearest_neighbour_queries,
+        )
+    }
+}
+
+impl fmt::Display for PlannerStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Iterations: {}", self.iterations)?;
+        writeln!(
+            f,
+            "Samples: {} drawn, {} rejected",
+            self.samples_drawn, self.samples_rejected
+        )?;
+        writeln!(f, "Validity checks: {}", self.validity_checks)?;
+        writeln!(f, "Motion checks: {}", self.motion_checks)?;
+        write!(
+            f,
+            "Nearest-neighbour queries: {}",
+            self.nearest_neighbour_queries
+        )?;
+        for (phase, time) in &self.phase_times {
+            write!(f, "\n{phase}: {:.6} s", time.as_secs_f64())?;
+        }
+        Ok(())
+    }
 }
 
 /// Configuration for a planner.
 #[derive(Default)]
 pub struct PlannerConfig {
-    /// The seed for the random number generator.
+    /// The seed for the planner's `PlannerRng`. With a seed, the planner finds the same path for
+    /// the same problem on every platform and from every binding. Without one, the generator is
+    /// seeded from the operating system.
     pub seed: Option<u64>,
 }
@@ -2,8 +2,18 @@
 //
 // SPDX-License-Identifier: BSD-3-Clause
 
-use crate::base::{goal::Goal, space::StateSpace, state::State};
-use std::sync::Arc;
+use crate::base::{
+    error::ProblemDefinitionError,
+    goal::{DynGoal, Goal, GoalSampleableRegion},
+    space::{DynStateSpace, StateSpace},
+    state::State,
+    validity::StateValidityChecker,
+};
+use rand::Rng;
+use std::{fmt, sync::Arc};
+
+/// The number of goal states sampled by `ProblemDefinition::validate`.
+const GOAL_VALIDATION_SAMPLES: usize = 10;
 
 /// Encapsulates the definition of a complete motion planning problem.
 ///
@@ -12,9 +22,297 @@ use std::sync::Arc;
 /// `Planner`'s `setup` method to configure it for a specific task.
 ///
 /// The fields use `Arc` to allow for safe, shared ownership of the space and goal definitions,
-/// which might be referenced by multiple parts of the planning process.
-pub struct ProblemDefinition<S: State, SP: StateSpace<StateType = S>, G: Goal<S>> {
+/// which might be referenced by multiple parts of the planning process. The space and goal types
+/// default to `DynStateSpace` and `DynGoal`, for problems whose space and goal are chosen at
+/// runtime.
+pub struct ProblemDefinition<
+    S: State,
+    SP: StateSpace<StateType = S> = DynStateSpace<S>,
+    G: Goal<S> = DynGoal<S>,
+> {
     pub space: Arc<SP>,
     pub start_states: Vec<S>,
     pub goal: Arc<G>,
 }
+
+/// The result of `ProblemDefinition::validate`: every problem found, along with how the goal fared
+/// when sampled.
+///
+/// Problems are split into errors, which stop any planner from solving the problem, and warnings,
+/// which are only suspected from a handful of goal samples and may be false alarms.
+#[derive(Debug, Default, PartialEq)]
+pub struct ValidationReport {
+    /// Every problem that stops the problem from being solved, in the order it was found.
+    pub errors: Vec<ProblemDefinitionError>,
+    /// Every problem that may stop the problem from being solved, in the order it was found. For
+    /// example, none of the goal samples may have been valid, although a planner might still find
+    /// valid goal states.
+    pub warnings: Vec<ProblemDefinitionError>,
+    /// The number of states sampled from the goal.
+    pub goal_samples: usize,
+    /// The number of goal samples that were within bounds and valid.
+    pub valid_goal_samples: usize,
+}
+
+impl ValidationReport {
+    /// Returns `true` if no errors were found. There may still be warnings.
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+impl fmt::Display for ValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.errors.is_empty() && self.warnings.is_empty() {
+            write!(f, "No problems found.")?;
+        }
+        if !self.errors.is_empty() {
+            write!(f, "{} error(s) found:", self.errors.len())?;
+            for error in &self.errors {
+                write!(f, "\n  - {error}")?;
+            }
+        }
+        if !self.warnings.is_empty() {
+            if !self.errors.is_empty() {
+                writeln!(f)?;
+            }
+            write!(f, "{} warning(s) found:", self.warnings.len())?;
+            for warning in &self.warnings {
+                write!(f, "\n  - {warning}")?;
+            }
+        }
+        write!(
+            f,
+            "\n{} of {} goal samples were within bounds and valid.",
+            self.valid_goal_samples, self.goal_samples
+        )
+    }
+}
+
+impl<S, SP, G> ProblemDefinition<S, SP, G>
+where
+    S: State,
+    SP: StateSpace<StateType = S>,
+    G: GoalSampleableRegion<S>,
+{
+    /// Checks the problem for mistakes that would stop any planner from solving it.
+    ///
+    /// * There must be at least one start state.
+    /// * Every start state must be within the bounds of the space and accepted by
+    ///   `validity_checker`.
+    /// * The goal is sampled a few times. Each sample must satisfy the goal, and at least one should
+    ///   be within bounds and accepted by `validity_checker`. As only a few samples are drawn, the
+    ///   latter is reported as a warning rather than an error.
+    ///
+    /// Every problem found is collected in the returned `ValidationReport`, rather than stopping at
+    /// the first.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use oxmpl::base::{
+    ///     error::ProblemDefinitionError,
+    ///     goal::GoalState,
+    ///     problem_definition::ProblemDefinition,
+    ///     space::RealVectorStateSpace,
+    ///     state::RealVectorState,
+    ///     validity::StateValidityChecker,
+    /// };
+    ///
+    /// struct NegativeXIsInvalid;
+    /// impl StateValidityChecker<RealVectorState> for NegativeXIsInvalid {
+    ///     fn is_valid(&self, state: &RealVectorState) -> bool {
+    ///         state.values[0] >= 0.0
+    ///     }
+    /// }
+    ///
+    /// let space = Arc::new(RealVectorStateSpace::new(2, Some(vec![(-5.0, 5.0), (-5.0, 5.0)])).unwrap());
+    /// let problem = ProblemDefinition {
+    ///     space: space.clone(),
+    ///     start_states: vec![RealVectorState::new(vec![-1.0, 0.0])],
+    ///     goal: Arc::new(GoalState::new(space, RealVectorState::new(vec![4.0, 0.0]), 0.1)),
+    /// };
+    ///
+    /// let report = problem.validate(&NegativeXIsInvalid, &mut rand::rng());
+    /// assert_eq!(report.errors, vec![ProblemDefinitionError::InvalidStartState { index: 0 }]);
+    /// ```
+    pub fn validate(
+        &self,
+        validity_checker: &dyn StateValidityChecker<S>,
+        rng: &mut impl Rng,
+    ) -> ValidationReport {
+        let mut report = ValidationReport::default();
+
+        if self.start_states.is_empty() {
+            report.errors.push(ProblemDefinitionError::NoStartStates);
+        }
+        for (index, start) in self.start_states.iter().enumerate() {
+            if !self.space.satisfies_bounds(start) {
+                report
+                    .errors
+                    .push(ProblemDefinitionError::StartStateOutOfBounds { index });
+            } else if !validity_checker.is_valid(start) {
+                report
+                    .errors
+                    .push(ProblemDefinitionError::InvalidStartState { index });
+            }
+        }
+
+        let mut unsatisfied = false;
+        for _ in 0..GOAL_VALIDATION_SAMPLES {
+            let sample = match self.goal.sample_goal(rng) {
+                Ok(sample) => sample,
+                Err(e) => {
+                    report
+                        .errors
+                        .push(ProblemDefinitionError::GoalSamplingFailed(e));
+                    return report;
+                }
+            };
+            report.goal_samples += 1;
+            unsatisfied |= !self.goal.is_satisfied(&sample);
+            if self.space.satisfies_bounds(&sample) && validity_checker.is_valid(&sample) {
+                report.valid_goal_samples += 1;
+            }
+        }
+        if unsatisfied {
+            report
+                .errors
+                .push(ProblemDefinitionError::GoalSampleNotSatisfied);
+        }
+        if report.valid_goal_samples == 0 {
+            report
+                .warnings
+                .push(ProblemDefinitionError::NoValidGoalSamples {
+                    attempts: report.goal_samples,
+                });
+        }
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base::{
+        error::StateSamplingError,
+        goal::{GoalRegion, GoalState, GoalStates},
+        space::RealVectorStateSpace,
+        state::RealVectorState,
+    };
+    use rand::RngCore;
+
+    /// Rejects states with a negative x-coordinate.
+    struct NegativeXIsInvalid;
+    impl StateValidityChecker<RealVectorState> for NegativeXIsInvalid {
+        fn is_valid(&self, state: &RealVectorState) -> bool {
+            state.values[0] >= 0.0
+        }
+    }
+
+    /// A goal whose samples never satisfy it.
+    struct InconsistentGoal;
+    impl Goal<RealVectorState> for InconsistentGoal {
+        fn is_satisfied(&self, _state: &RealVectorState) -> bool {
+            false
+        }
+    }
+    impl GoalRegion<RealVectorState> for InconsistentGoal {
+        fn distance_goal(&self, _state: &RealVectorState) -> f64 {
+            1.0
+        }
+    }
+    impl GoalSampleableRegion<RealVectorState> for InconsistentGoal {
+        fn sample_goal(
+            &self,
+            _rng: &mut dyn RngCore,
+        ) -> Result<RealVectorState, StateSamplingError> {
+            Ok(RealVectorState::new(vec![1.0, 1.0]))
+        }
+    }
+
+    fn space() -> Arc<RealVectorStateSpace> {
+        Arc::new(RealVectorStateSpace::new(2, Some(vec![(-5.0, 5.0), (-5.0, 5.0)])).unwrap())
+    }
+
+    #[test]
+    fn test_validate_valid_problem() {
+        let problem = ProblemDefinition {
+            space: space(),
+            start_states: vec![RealVectorState::new(vec![0.0, 0.0])],
+            goal: Arc::new(GoalState::new(
+                space(),
+                RealVectorState::new(vec![4.0, 4.0]),
+                0.1,
+            )),
+        };
+        let report = problem.validate(&NegativeXIsInvalid, &mut rand::rng());
+        assert!(report.is_valid());
+        assert!(report.warnings.is_empty());
+        assert_eq!(report.goal_samples, GOAL_VALIDATION_SAMPLES);
+        assert_eq!(report.valid_goal_samples, GOAL_VALIDATION_SAMPLES);
+    }
+
+    #[test]
+    fn test_validate_collects_every_problem() {
+        let problem = ProblemDefinition {
+            space: space(),
+            start_states: vec![
+                RealVectorState::new(vec![0.0, 0.0]),
+                RealVectorState::new(vec![6.0, 0.0]),
+                RealVectorState::new(vec![-1.0, 0.0]),
+            ],
+            goal: Arc::new(GoalState::new(
+                space(),
+                RealVectorState::new(vec![-4.0, 4.0]),
+                0.1,
+            )),
+        };
+        let report = problem.validate(&NegativeXIsInvalid, &mut rand::rng());
+        assert_eq!(
+            report.errors,
+            vec![
+                ProblemDefinitionError::StartStateOutOfBounds { index: 1 },
+                ProblemDefinitionError::InvalidStartState { index: 2 },
+            ]
+        );
+        assert_eq!(
+            report.warnings,
+            vec![ProblemDefinitionError::NoValidGoalSamples {
+                attempts: GOAL_VALIDATION_SAMPLES
+            }]
+        );
+    }
+
+    #[test]
+    fn test_validate_goal_problems() {
+        let problem = ProblemDefinition {
+            space: space(),
+            start_states: Vec::new(),
+            goal: Arc::new(GoalStates::new(space(), Vec::new(), 0.1)),
+        };
+        let report = problem.validate(&NegativeXIsInvalid, &mut rand::rng());
+        assert_eq!(
+            report.errors,
+            vec![
+                ProblemDefinitionError::NoStartStates,
+                ProblemDefinitionError::GoalSamplingFailed(
+                    StateSamplingError::GoalRegionUnsatisfiable
+                ),
+            ]
+        );
+        assert_eq!(report.goal_samples, 0);
+
+        let problem = ProblemDefinition {
+            space: space(),
+            start_states: vec![RealVectorState::new(vec![0.0, 0.0])],
+            goal: Arc::new(InconsistentGoal),
+        };
+        let report = problem.validate(&NegativeXIsInvalid, &mut rand::rng());
+        assert_eq!(
+            report.errors,
+            vec![ProblemDefinitionError::GoalSampleNotSatisfied]
+        );
+    }
+}
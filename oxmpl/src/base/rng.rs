@@ -0,0 +1,27 @@
+// Copyright (c) 2025 Junior Sundar
+//
+// SPDX-License-Identifier: BSD-3-Clause
+
+//! The random number generator used by the planners.
+//!
+//! Planners draw every random number from a `PlannerRng`. Its algorithm, ChaCha with 8 rounds,
+//! and the way it expands a `u64` seed are fixed by the `rand_chacha` crate and do not depend on
+//! the platform, so a planner given the same seed and the same problem finds the same path in
+//! native Rust, in Python and in WebAssembly. This is not the case for `rand::rngs::StdRng`,
+//! whose algorithm may change between releases of `rand`.
+//!
+//! The guarantee holds as long as the planner's inputs are the same, including the answers of the
+//! validity checker, and no planner is run on more than one thread. A change to a planner's
+//! algorithm, or to the version of `rand_chacha` that `oxmpl` depends on, may change the paths it
+//! finds and is noted in the changelog.
+
+use rand::SeedableRng;
+
+/// The portable random number generator used by the planners.
+pub type PlannerRng = rand_chacha::ChaCha8Rng;
+
+/// Creates the random number generator for a planner, from `seed` if there is one and otherwise
+/// from the operating system's source of randomness.
+pub fn planner_rng(seed: Option<u64>) -> PlannerRng {
+    seed.map_or_else(PlannerRng::from_os_rng, PlannerRng::seed_from_u64)
+}
@@ -2,7 +2,9 @@
 //
 // SPDX-License-Identifier: BSD-3-Clause
 
-use rand::Rng;
+use std::sync::Arc;
+
+use rand::RngCore;
 
 pub use crate::base::spaces::{
     any_state_space::AnyStateSpace, compound_state_space::CompoundStateSpace,
@@ -12,6 +14,12 @@ pub use crate::base::spaces::{
 
 use crate::base::{error::StateSamplingError, state::State};
 
+/// A state space whose type is chosen at runtime.
+///
+/// This is the default state space type of `Planner` and `ProblemDefinition`, so
+/// `Box<dyn Planner<S>>` can hold any planner for any space of states `S`.
+pub type DynStateSpace<S> = Arc<dyn StateSpace<StateType = S>>;
+
 /// Defines a space in which planning can be performed.
 ///
 /// A `StateSpace` represents the manifold where states exist. It defines the properties and
@@ -23,6 +31,11 @@ use crate::base::{error::StateSamplingError, state::State};
 /// written to be generic over this trait, allowing them to solve problems in any space that
 /// implements these fundamental operations.
 ///
+/// # Thread Safety
+///
+/// State spaces must be `Send + Sync`, as the `ProblemDefinition` holding them may be shared
+/// between planners running on different threads (see `ParallelPlan`).
+///
 /// # Examples
 ///
 /// ```
@@ -30,7 +43,7 @@ use crate::base::{error::StateSamplingError, state::State};
 /// use oxmpl::base::state::State;
 /// use oxmpl::base::space::StateSpace;
 /// use oxmpl::base::error::StateSamplingError;
-/// use rand::Rng;
+/// use rand::{Rng, RngCore};
 ///
 /// #[derive(Debug, Clone, PartialEq)]
 /// struct Point1D {
@@ -65,7 +78,7 @@ use crate::base::{error::StateSamplingError, state::State};
 ///         state.x >= self.bounds.0 && state.x <= self.bounds.1
 ///     }
 ///
-///     fn sample_uniform(&self, rng: &mut impl Rng) -> Result<Self::StateType, StateSamplingError> {
+///     fn sample_uniform(&self, rng: &mut dyn RngCore) -> Result<Self::StateType, StateSamplingError> {
 ///         Ok(Point1D { x: rng.random_range(self.bounds.0..self.bounds.1) })
 ///     }
 ///
@@ -81,7 +94,7 @@ use crate::base::{error::StateSamplingError, state::State};
 /// assert!(space.satisfies_bounds(&random_state));
 /// assert_eq!(space.get_longest_valid_segment_length(), 0.5);
 /// ```
-pub trait StateSpace {
+pub trait StateSpace: Send + Sync {
     /// StateType defines what is acceptable in current StateSpace
     type StateType: State;
 
@@ -141,7 +154,16 @@ pub trait StateSpace {
     /// # Errors
     /// Returns a `StateSamplingError::UnboundedDimension` if the space is unbounded
     /// in any dimension, as uniform sampling from an infinite domain is not possible.
-    fn sample_uniform(&self, rng: &mut impl Rng) -> Result<Self::StateType, StateSamplingError>;
+    fn sample_uniform(&self, rng: &mut dyn RngCore) -> Result<Self::StateType, StateSamplingError>;
+
+    /// Gets the largest distance that can separate two states in this space.
+    ///
+    /// Planners use this to pick sensible defaults for distance-based parameters, such as the
+    /// maximum extension length of RRT, when the user does not set them. Spaces that cannot bound
+    /// their extent return `f64::INFINITY`, which is also the default.
+    fn get_maximum_extent(&self) -> f64 {
+        f64::INFINITY
+    }
 
     /// Gets the length of the longest segment that can be assumed valid.
     ///
@@ -149,3 +171,43 @@ pub trait StateSpace {
     /// means motions are checked more frequently.
     fn get_longest_valid_segment_length(&self) -> f64;
 }
+
+/// Lets a shared state space, including a `DynStateSpace`, be used wherever a state space is
+/// expected.
+impl<SP: StateSpace + ?Sized> StateSpace for Arc<SP> {
+    type StateType = SP::StateType;
+
+    fn distance(&self, state1: &Self::StateType, state2: &Self::StateType) -> f64 {
+        (**self).distance(state1, state2)
+    }
+
+    fn interpolate(
+        &self,
+        from: &Self::StateType,
+        to: &Self::StateType,
+        t: f64,
+        state: &mut Self::StateType,
+    ) {
+        (**self).interpolate(from, to, t, state)
+    }
+
+    fn enforce_bounds(&self, state: &mut Self::StateType) {
+        (**self).enforce_bounds(state)
+    }
+
+    fn satisfies_bounds(&self, state: &Self::StateType) -> bool {
+        (**self).satisfies_bounds(state)
+    }
+
+    fn sample_uniform(&self, rng: &mut dyn RngCore) -> Result<Self::StateType, StateSamplingError> {
+        (**self).sample_uniform(rng)
+    }
+
+    fn get_maximum_extent(&self) -> f64 {
+        (**self).get_maximum_extent()
+    }
+
+    fn get_longest_valid_segment_length(&self) -> f64 {
+        (**self).get_longest_valid_segment_length()
+    }
+}
@@ -38,7 +38,7 @@ impl Clone for Box<dyn AnyStateSpace> {
 /// State`.
 ///
 /// This is an internal implementation detail and is not typically used directly by end-users.
-pub trait AnyStateSpace: DynCloneAnyStateSpace {
+pub trait AnyStateSpace: DynCloneAnyStateSpace + Send + Sync {
     /// A dynamically-dispatchable version of `StateSpace::distance`.
     ///
     /// # Panics
@@ -75,6 +75,9 @@ pub trait AnyStateSpace: DynCloneAnyStateSpace {
         rng: &mut dyn RngCore,
     ) -> Result<Box<dyn State>, StateSamplingError>;
 
+    /// A dynamically-dispatchable version of `StateSpace::get_maximum_extent`.
+    fn get_maximum_extent_dyn(&self) -> f64;
+
     /// A dynamically-dispatchable version of `StateSpace::get_longest_valid_segment_length`.
     fn get_longest_valid_segment_length_dyn(&self) -> f64;
 }
@@ -105,23 +108,7 @@ where
         &self,
         rng: &mut dyn RngCore,
     ) -> Result<Box<dyn State>, StateSamplingError> {
-        // Adaptor because of Sized trait issue causing problems in downcasting. It's a mess!
-        // TODO: Get better in Rust and find a different way to go about it.
-        struct RngWrapper<'a>(&'a mut dyn RngCore);
-        impl<'a> RngCore for RngWrapper<'a> {
-            fn next_u32(&mut self) -> u32 {
-                self.0.next_u32()
-            }
-            fn next_u64(&mut self) -> u64 {
-                self.0.next_u64()
-            }
-            fn fill_bytes(&mut self, dest: &mut [u8]) {
-                self.0.fill_bytes(dest)
-            }
-        }
-
-        let mut wrapper = RngWrapper(rng);
-        let concrete_state = self.sample_uniform(&mut wrapper)?;
+        let concrete_state = self.sample_uniform(rng)?;
         Ok(Box::new(concrete_state))
     }
 
@@ -137,6 +124,10 @@ where
         self.satisfies_bounds(state_s)
     }
 
+    fn get_maximum_extent_dyn(&self) -> f64 {
+        self.get_maximum_extent()
+    }
+
     fn get_longest_valid_segment_length_dyn(&self) -> f64 {
         self.get_longest_valid_segment_length()
     }
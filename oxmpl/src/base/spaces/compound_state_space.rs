@@ -2,7 +2,7 @@
 //
 // SPDX-License-Identifier: BSD-3-Clause
 
-use rand::Rng;
+use rand::RngCore;
 
 use crate::base::{
     error::StateSamplingError,
@@ -106,7 +106,7 @@ impl StateSpace for CompoundStateSpace {
     }
 
     /// Generates a random state by sampling from each subspace and combining the results.
-    fn sample_uniform(&self, rng: &mut impl Rng) -> Result<Self::StateType, StateSamplingError> {
+    fn sample_uniform(&self, rng: &mut dyn RngCore) -> Result<Self::StateType, StateSamplingError> {
         let mut components = Vec::with_capacity(self.subspaces.len());
         for subspace in &self.subspaces {
             let component_state = subspace.sample_uniform_dyn(rng)?;
@@ -133,6 +133,17 @@ impl StateSpace for CompoundStateSpace {
         true
     }
 
+    /// Calculates the weighted root sum square of the subspaces' maximum extents, matching the
+    /// metric used by `distance`.
+    fn get_maximum_extent(&self) -> f64 {
+        let mut total_extent_sq = 0.0;
+        for i in 0..self.subspaces.len() {
+            let component_extent = self.subspaces[i].get_maximum_extent_dyn();
+            total_extent_sq += (component_extent * self.weights[i]).powi(2);
+        }
+        total_extent_sq.sqrt()
+    }
+
     /// Calculates the weighted root mean square of the subspaces' longest valid segment lengths.
     fn get_longest_valid_segment_length(&self) -> f64 {
         let mut total_longest_valid_segment_length_sq = 0.0;
@@ -209,6 +220,16 @@ mod tests {
         assert_eq!(sample.unwrap().components.len(), 3);
     }
 
+    #[test]
+    fn test_maximum_extent_is_weighted() {
+        let rvs = RealVectorStateSpace::new(2, Some(vec![(0.0, 3.0), (0.0, 4.0)])).unwrap();
+        let so2 = SO2StateSpace::new(None).unwrap();
+        let space = CompoundStateSpace::new(vec![Box::new(rvs), Box::new(so2)], vec![1.0, 0.5]);
+
+        let expected = (25.0 + (0.5 * PI).powi(2)).sqrt();
+        assert!((space.get_maximum_extent() - expected).abs() < 1e-9);
+    }
+
     #[test]
     #[should_panic(expected = "Number of subspaces must match number of weights.")]
     fn test_mismatched_subspaces_and_weights() {
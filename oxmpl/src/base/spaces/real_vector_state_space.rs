@@ -2,7 +2,7 @@
 //
 // SPDX-License-Identifier: BSD-3-Clause
 
-use rand::Rng;
+use rand::{Rng, RngCore};
 
 use crate::base::{
     error::{StateSamplingError, StateSpaceError},
@@ -99,24 +99,6 @@ impl RealVectorStateSpace {
         })
     }
 
-    /// A helper to calculate the diagonal of the space's bounding box.
-    pub fn get_maximum_extent(&self) -> f64 {
-        if self
-            .bounds
-            .iter()
-            .any(|(low, high)| !low.is_finite() || !high.is_finite())
-        {
-            1.0
-        } else {
-            let sum_sq_diff: f64 = self
-                .bounds
-                .iter()
-                .map(|(low, high)| (high - low).powi(2))
-                .sum();
-            sum_sq_diff.sqrt()
-        }
-    }
-
     /// Allows a user to configure the motion checking resolution.
     pub fn set_longest_valid_segment_fraction(&mut self, fraction: f64) {
         if fraction > 0.0 && fraction <= 1.0 {
@@ -230,7 +212,7 @@ impl StateSpace for RealVectorStateSpace {
     /// * `StateSamplingError::UnboundedDimension` if any dimension of the space is infinite.
     /// * `StateSamplingError::ZeroVolume` if any dimension has a lower bound greater than
     ///   or equal to its upper bound.
-    fn sample_uniform(&self, rng: &mut impl Rng) -> Result<Self::StateType, StateSamplingError> {
+    fn sample_uniform(&self, rng: &mut dyn RngCore) -> Result<Self::StateType, StateSamplingError> {
         let mut values = Vec::with_capacity(self.dimension);
 
         for i in 0..self.dimension {
@@ -248,6 +230,24 @@ impl StateSpace for RealVectorStateSpace {
         Ok(RealVectorState { values })
     }
 
+    /// A helper to calculate the diagonal of the space's bounding box.
+    fn get_maximum_extent(&self) -> f64 {
+        if self
+            .bounds
+            .iter()
+            .any(|(low, high)| !low.is_finite() || !high.is_finite())
+        {
+            1.0
+        } else {
+            let sum_sq_diff: f64 = self
+                .bounds
+                .iter()
+                .map(|(low, high)| (high - low).powi(2))
+                .sum();
+            sum_sq_diff.sqrt()
+        }
+    }
+
     fn get_longest_valid_segment_length(&self) -> f64 {
         self.get_maximum_extent() * self.longest_valid_segment_fraction
     }
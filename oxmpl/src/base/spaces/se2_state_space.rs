@@ -7,6 +7,7 @@ use crate::base::{
     space::{AnyStateSpace, CompoundStateSpace, RealVectorStateSpace, SO2StateSpace, StateSpace},
     state::SE2State,
 };
+use rand::RngCore;
 
 /// A state space for 2D rigid body transformations (SE(2)).
 ///
@@ -97,12 +98,16 @@ impl StateSpace for SE2StateSpace {
 
     fn sample_uniform(
         &self,
-        rng: &mut impl rand::Rng,
+        rng: &mut dyn RngCore,
     ) -> Result<Self::StateType, crate::base::error::StateSamplingError> {
         let compound_state = self.0.sample_uniform(rng)?;
         Ok(SE2State(compound_state))
     }
 
+    fn get_maximum_extent(&self) -> f64 {
+        self.0.get_maximum_extent_dyn()
+    }
+
     fn get_longest_valid_segment_length(&self) -> f64 {
         self.0.get_longest_valid_segment_length_dyn()
     }
@@ -7,6 +7,7 @@ use crate::base::{
     space::{AnyStateSpace, CompoundStateSpace, RealVectorStateSpace, SO3StateSpace, StateSpace},
     state::SE3State,
 };
+use rand::RngCore;
 
 /// A state space for 3D rigid body transformations (SE(3)).
 ///
@@ -101,12 +102,16 @@ impl StateSpace for SE3StateSpace {
 
     fn sample_uniform(
         &self,
-        rng: &mut impl rand::Rng,
+        rng: &mut dyn RngCore,
     ) -> Result<Self::StateType, crate::base::error::StateSamplingError> {
         let compound_state = self.0.sample_uniform(rng)?;
         Ok(SE3State(compound_state))
     }
 
+    fn get_maximum_extent(&self) -> f64 {
+        self.0.get_maximum_extent_dyn()
+    }
+
     fn get_longest_valid_segment_length(&self) -> f64 {
         self.0.get_longest_valid_segment_length_dyn()
     }
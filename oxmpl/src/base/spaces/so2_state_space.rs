@@ -2,7 +2,7 @@
 //
 // SPDX-License-Identifier: BSD-3-Clause
 
-use rand::Rng;
+use rand::{Rng, RngCore};
 use std::f64::consts::PI;
 
 use crate::base::{
@@ -73,11 +73,6 @@ impl SO2StateSpace {
         })
     }
 
-    /// Returns the maximum possible distance in this space, which is always PI.
-    pub fn get_maximum_extent(&self) -> f64 {
-        PI
-    }
-
     /// Sets the fraction used to determine motion checking resolution.
     pub fn set_longest_valid_segment_fraction(&mut self, fraction: f64) {
         if fraction > 0.0 && fraction <= 1.0 {
@@ -158,13 +153,18 @@ impl StateSpace for SO2StateSpace {
     /// This implementation will not return an error, as the constructor ensures
     /// the bounds are always valid for sampling. The `Result` is returned to
     /// satisfy the `StateSpace` trait.
-    fn sample_uniform(&self, rng: &mut impl Rng) -> Result<SO2State, StateSamplingError> {
+    fn sample_uniform(&self, rng: &mut dyn RngCore) -> Result<SO2State, StateSamplingError> {
         let (lower, upper) = self.bounds;
         Ok(SO2State {
             value: rng.random_range(lower..upper),
         })
     }
 
+    /// Returns the maximum possible distance in this space, which is always PI.
+    fn get_maximum_extent(&self) -> f64 {
+        PI
+    }
+
     fn get_longest_valid_segment_length(&self) -> f64 {
         self.get_maximum_extent() * self.longest_valid_segment_fraction
     }
@@ -2,7 +2,7 @@
 //
 // SPDX-License-Identifier: BSD-3-Clause
 
-use rand::Rng;
+use rand::{Rng, RngCore};
 use std::f64::consts::PI;
 
 use crate::base::{
@@ -78,11 +78,6 @@ impl SO3StateSpace {
         })
     }
 
-    /// Returns the maximum possible distance in this space, which is always 0.5*PI.
-    pub fn get_maximum_extent(&self) -> f64 {
-        0.5 * PI
-    }
-
     /// Sets the fraction used to determine motion checking resolution.
     pub fn set_longest_valid_segment_fraction(&mut self, fraction: f64) {
         if fraction > 0.0 && fraction <= 1.0 {
@@ -195,7 +190,7 @@ impl StateSpace for SO3StateSpace {
     ///
     /// This function will not return an error, as the constructor ensures the bounds are always
     /// valid. The `Result` is returned to satisfy the `StateSpace` trait.
-    fn sample_uniform(&self, rng: &mut impl Rng) -> Result<SO3State, StateSamplingError> {
+    fn sample_uniform(&self, rng: &mut dyn RngCore) -> Result<SO3State, StateSamplingError> {
         let (center_rotation, max_angle) = &self.bounds;
 
         if *max_angle < 1e-9 {
@@ -228,6 +223,11 @@ impl StateSpace for SO3StateSpace {
         }
     }
 
+    /// Returns the maximum possible distance in this space, which is always 0.5*PI.
+    fn get_maximum_extent(&self) -> f64 {
+        0.5 * PI
+    }
+
     fn get_longest_valid_segment_length(&self) -> f64 {
         self.get_maximum_extent() * self.longest_valid_segment_fraction
     }
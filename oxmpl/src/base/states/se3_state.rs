@@ -59,6 +59,42 @@ impl SE3State {
             .expect("Issue found in retreiving the rotation.")
     }
 
+    /// Returns the transform that applies `other` first and then `self`.
+    ///
+    /// Treating both states as rigid body transforms, if `other` is a pose expressed in the frame
+    /// of `self`, the result is that pose expressed in the frame `self` is expressed in.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::f64::consts::PI;
+    /// use oxmpl::base::state::{SE3State, SO3State};
+    ///
+    /// let frame = SE3State::new(1.0, 0.0, 0.0, SO3State::from_euler(0.0, 0.0, PI / 2.0));
+    /// let offset = SE3State::new(1.0, 0.0, 0.0, SO3State::identity());
+    ///
+    /// let pose = frame.compose(&offset);
+    /// assert!((pose.get_x() - 1.0).abs() < 1e-9);
+    /// assert!((pose.get_y() - 1.0).abs() < 1e-9);
+    /// ```
+    pub fn compose(&self, other: &SE3State) -> Self {
+        let rotation = self.get_rotation();
+        let offset = rotation.rotate([other.get_x(), other.get_y(), other.get_z()]);
+        SE3State::new(
+            self.get_x() + offset[0],
+            self.get_y() + offset[1],
+            self.get_z() + offset[2],
+            rotation.compose(other.get_rotation()),
+        )
+    }
+
+    /// Returns the inverse transform, such that `state.compose(&state.inverse())` is the identity.
+    pub fn inverse(&self) -> Self {
+        let rotation = self.get_rotation().inverse();
+        let translation = rotation.rotate([self.get_x(), self.get_y(), self.get_z()]);
+        SE3State::new(-translation[0], -translation[1], -translation[2], rotation)
+    }
+
     /// Returns the x-coordinate of the state.
     pub fn get_x(&self) -> f64 {
         (self.0.components[0].deref() as &dyn Any)
@@ -118,4 +154,21 @@ mod tests {
         assert_eq!(state1.get_translation(), state2.get_translation());
         assert_eq!(state1.get_rotation(), state2.get_rotation());
     }
+
+    #[test]
+    fn test_se3_state_compose_and_inverse() {
+        let state = SE3State::new(1.0, -2.0, 0.5, SO3State::from_euler(0.3, -0.4, 1.1));
+        let identity = state.compose(&state.inverse());
+
+        assert!(identity.get_x().abs() < 1e-9);
+        assert!(identity.get_y().abs() < 1e-9);
+        assert!(identity.get_z().abs() < 1e-9);
+        assert!(identity.get_rotation().angle() < 1e-9);
+
+        let other = SE3State::new(0.0, 1.0, 0.0, SO3State::identity());
+        let back = state.inverse().compose(&state.compose(&other));
+        assert!(back.get_x().abs() < 1e-9);
+        assert!((back.get_y() - 1.0).abs() < 1e-9);
+        assert!(back.get_z().abs() < 1e-9);
+    }
 }
@@ -81,6 +81,115 @@ impl SO3State {
             w: 1.,
         }
     }
+
+    /// Creates the rotation of `angle` radians about `axis`.
+    ///
+    /// The axis does not need to be normalised. A zero axis gives the identity rotation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::f64::consts::PI;
+    /// use oxmpl::base::state::SO3State;
+    ///
+    /// let quarter_turn = SO3State::from_axis_angle([0.0, 0.0, 2.0], PI / 2.0);
+    /// assert!((quarter_turn.z - (PI / 4.0).sin()).abs() < 1e-9);
+    /// assert!((quarter_turn.angle() - PI / 2.0).abs() < 1e-9);
+    /// ```
+    pub fn from_axis_angle(axis: [f64; 3], angle: f64) -> Self {
+        let norm = (axis[0].powi(2) + axis[1].powi(2) + axis[2].powi(2)).sqrt();
+        if norm < 1e-12 {
+            return Self::identity();
+        }
+        let s = (angle / 2.0).sin() / norm;
+        SO3State {
+            x: axis[0] * s,
+            y: axis[1] * s,
+            z: axis[2] * s,
+            w: (angle / 2.0).cos(),
+        }
+    }
+
+    /// Returns the rotation that applies `other` first and then `self` (the Hamilton product
+    /// `self * other`).
+    pub fn compose(&self, other: &SO3State) -> Self {
+        SO3State {
+            x: self.w * other.x + self.x * other.w + self.y * other.z - self.z * other.y,
+            y: self.w * other.y - self.x * other.z + self.y * other.w + self.z * other.x,
+            z: self.w * other.z + self.x * other.y - self.y * other.x + self.z * other.w,
+            w: self.w * other.w - self.x * other.x - self.y * other.y - self.z * other.z,
+        }
+    }
+
+    /// Returns the inverse rotation. For a unit quaternion this is its conjugate.
+    pub fn inverse(&self) -> Self {
+        SO3State {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+            w: self.w,
+        }
+    }
+
+    /// Creates a rotation from roll, pitch and yaw angles in radians.
+    ///
+    /// The angles are applied about the fixed x-, y- and z-axes in that order, so the rotation is
+    /// `Rz(yaw) * Ry(pitch) * Rx(roll)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use oxmpl::base::state::SO3State;
+    ///
+    /// let rotation = SO3State::from_euler(0.1, -0.2, 0.3);
+    /// let (roll, pitch, yaw) = rotation.to_euler();
+    /// assert!((roll - 0.1).abs() < 1e-9);
+    /// assert!((pitch + 0.2).abs() < 1e-9);
+    /// assert!((yaw - 0.3).abs() < 1e-9);
+    /// ```
+    pub fn from_euler(roll: f64, pitch: f64, yaw: f64) -> Self {
+        Self::from_axis_angle([0.0, 0.0, 1.0], yaw)
+            .compose(&Self::from_axis_angle([0.0, 1.0, 0.0], pitch))
+            .compose(&Self::from_axis_angle([1.0, 0.0, 0.0], roll))
+    }
+
+    /// Returns the roll, pitch and yaw angles of the rotation, as used by `from_euler`.
+    ///
+    /// Roll and yaw are in `[-PI, PI]` and pitch is in `[-PI/2, PI/2]`.
+    pub fn to_euler(&self) -> (f64, f64, f64) {
+        let (x, y, z, w) = (self.x, self.y, self.z, self.w);
+        let roll = (2.0 * (w * x + y * z)).atan2(1.0 - 2.0 * (x * x + y * y));
+        let pitch = (2.0 * (w * y - z * x)).clamp(-1.0, 1.0).asin();
+        let yaw = (2.0 * (w * z + x * y)).atan2(1.0 - 2.0 * (y * y + z * z));
+        (roll, pitch, yaw)
+    }
+
+    /// Applies the rotation to a 3D vector.
+    pub fn rotate(&self, v: [f64; 3]) -> [f64; 3] {
+        // v' = v + 2w(u x v) + 2u x (u x v), where u is the vector part of the quaternion.
+        let u = [self.x, self.y, self.z];
+        let cross = |a: [f64; 3], b: [f64; 3]| {
+            [
+                a[1] * b[2] - a[2] * b[1],
+                a[2] * b[0] - a[0] * b[2],
+                a[0] * b[1] - a[1] * b[0],
+            ]
+        };
+        let uv = cross(u, v);
+        let uuv = cross(u, uv);
+        [
+            v[0] + 2.0 * (self.w * uv[0] + uuv[0]),
+            v[1] + 2.0 * (self.w * uv[1] + uuv[1]),
+            v[2] + 2.0 * (self.w * uv[2] + uuv[2]),
+        ]
+    }
+
+    /// Returns the angle of the rotation in radians, in the range `[0, PI]`.
+    pub fn angle(&self) -> f64 {
+        // atan2 stays accurate near the identity, where acos(w) does not.
+        let vector_norm = (self.x.powi(2) + self.y.powi(2) + self.z.powi(2)).sqrt();
+        2.0 * vector_norm.atan2(self.w.abs())
+    }
 }
 impl State for SO3State {
     fn as_any(&self) -> &dyn std::any::Any {
@@ -151,4 +260,49 @@ mod tests {
             StateError::ZeroMagnitude => (),
         }
     }
+
+    #[test]
+    fn test_so3_state_compose_and_inverse() {
+        use std::f64::consts::PI;
+
+        let about_z = SO3State::from_axis_angle([0.0, 0.0, 1.0], PI / 2.0);
+        let about_x = SO3State::from_axis_angle([1.0, 0.0, 0.0], PI / 3.0);
+
+        // Two quarter turns about the same axis make a half turn.
+        let half_turn = about_z.compose(&about_z);
+        assert!((half_turn.angle() - PI).abs() < 1e-9);
+        assert!((half_turn.z.abs() - 1.0).abs() < 1e-9);
+
+        // Composing with the inverse gives the identity.
+        let composed = about_x.compose(&about_z);
+        let undone = composed
+            .compose(&about_z.inverse())
+            .compose(&about_x.inverse());
+        assert!(undone.angle() < 1e-9);
+
+        // Rotations about different axes do not commute.
+        let reversed = about_z.compose(&about_x);
+        assert!(composed.inverse().compose(&reversed).angle() > 1e-3);
+    }
+
+    #[test]
+    fn test_so3_state_euler_and_rotate() {
+        use std::f64::consts::PI;
+
+        let yaw_only = SO3State::from_euler(0.0, 0.0, PI / 2.0);
+        let rotated = yaw_only.rotate([1.0, 0.0, 0.0]);
+        assert!(rotated[0].abs() < 1e-9);
+        assert!((rotated[1] - 1.0).abs() < 1e-9);
+
+        // Roll is applied before yaw.
+        let rotation = SO3State::from_euler(PI / 2.0, 0.0, PI / 2.0);
+        let rotated = rotation.rotate([0.0, 1.0, 0.0]);
+        assert!((rotated[2] - 1.0).abs() < 1e-9);
+
+        let rotation = SO3State::from_euler(-2.5, 1.2, 3.0);
+        let (roll, pitch, yaw) = rotation.to_euler();
+        assert!((roll + 2.5).abs() < 1e-9);
+        assert!((pitch - 1.2).abs() < 1e-9);
+        assert!((yaw - 3.0).abs() < 1e-9);
+    }
 }
@@ -14,6 +14,13 @@ use crate::base::state;
 /// logic for state validity (e.g., checking against complex geometry, satisfying
 /// physical constraints) without changing the core planners.
 ///
+/// # Thread Safety
+///
+/// Checkers must be `Send + Sync`, as a single checker may be shared between planners running on
+/// different threads (see `ParallelPlan`). `is_valid` takes `&self`, so any internal state, such as
+/// a cache or call counter, needs interior mutability that is safe to share, like a `Mutex` or an
+/// atomic.
+///
 /// # Example
 ///
 /// ```
@@ -36,7 +43,7 @@ use crate::base::state;
 /// assert!(checker.is_valid(&valid_state));
 /// assert!(!checker.is_valid(&invalid_state));
 /// ```
-pub trait StateValidityChecker<S: state::State> {
+pub trait StateValidityChecker<S: state::State>: Send + Sync {
     /// Checks if the given state is valid.
     ///
     /// # Parameters
@@ -45,4 +52,12 @@ pub trait StateValidityChecker<S: state::State> {
     /// # Returns
     /// Returns `true` if the state is valid, and `false` otherwise.
     fn is_valid(&self, state: &S) -> bool;
+
+    /// Returns the distance from the state to the nearest obstacle, if the checker can measure it.
+    ///
+    /// This is used to report how much clearance a path has, e.g. by `PathMetrics`. The default
+    /// implementation returns `None`.
+    fn clearance(&self, _state: &S) -> Option<f64> {
+        None
+    }
 }
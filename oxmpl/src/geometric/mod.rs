@@ -4,9 +4,26 @@
 
 //! Planners under geometric contraints.
 
-mod planners;
+mod path_geometric;
+mod path_metrics;
+mod path_simplifier;
+mod planner_registry;
+pub(crate) mod planners;
+mod simple_setup;
 
+pub use self::path_geometric::{PathGeometric, PathValidityReport};
+pub use self::path_metrics::PathMetrics;
+pub use self::path_simplifier::PathSimplifier;
+pub use self::planner_registry::{BoxedPlanner, PlannerRegistry};
+pub use self::planners::bi_rrt_star::BiRRTStar;
+pub use self::planners::cforest::CForest;
+pub use self::planners::lazy_rrt::LazyRRT;
+pub use self::planners::lazy_rrt_connect::LazyRRTConnect;
 pub use self::planners::prm::PRM;
 pub use self::planners::rrt::RRT;
 pub use self::planners::rrt_connect::RRTConnect;
 pub use self::planners::rrt_star::RRTStar;
+pub use self::planners::rrtx::RRTX;
+pub use self::planners::spars::SPARS;
+pub use self::planners::spars2::SPARS2;
+pub use self::simple_setup::SimpleSetup;
@@ -0,0 +1,560 @@
+// Copyright (c) 2025 Junior Sundar
+//
+// SPDX-License-Identifier: BSD-3-Clause
+
+use std::{f64::consts::PI, ops::RangeBounds, sync::Arc};
+
+use rand::Rng;
+
+use crate::{
+    base::{planner::Path, space::StateSpace, state::State, validity::StateValidityChecker},
+    geometric::planners::common::check_motion,
+};
+
+/// The result of `PathGeometric::check`: which vertices and segments of a path are invalid.
+///
+/// Segment `i` joins vertex `i` to vertex `i + 1`. A segment is invalid if any state along it,
+/// including its end points, is rejected by the validity checker.
+#[derive(Debug, Default, PartialEq)]
+pub struct PathValidityReport {
+    /// The indices of the vertices rejected by the validity checker, in order.
+    pub invalid_vertices: Vec<usize>,
+    /// The indices of the invalid segments, in order.
+    pub invalid_segments: Vec<usize>,
+}
+
+impl PathValidityReport {
+    /// Returns `true` if every vertex and segment of the path is valid.
+    pub fn is_valid(&self) -> bool {
+        self.invalid_vertices.is_empty() && self.invalid_segments.is_empty()
+    }
+
+    /// Returns the index of the first invalid segment, if any.
+    pub fn first_invalid_segment(&self) -> Option<usize> {
+        self.invalid_segments.first().copied()
+    }
+}
+
+/// A path of states together with the space it lies in.
+///
+/// Planners return a bare `Path`, which is only a list of states. `PathGeometric` pairs the
+/// states with their `StateSpace`, so the path can be measured, interpolated and checked without
+/// passing the space around.
+///
+/// # Example
+///
+/// ```
+/// use std::sync::Arc;
+/// use oxmpl::base::{space::RealVectorStateSpace, state::RealVectorState};
+/// use oxmpl::geometric::PathGeometric;
+///
+/// let space = Arc::new(RealVectorStateSpace::new(2, Some(vec![(0.0, 10.0), (0.0, 10.0)])).unwrap());
+/// let mut path = PathGeometric::new(
+///     space,
+///     vec![
+///         RealVectorState::new(vec![0.0, 0.0]),
+///         RealVectorState::new(vec![3.0, 4.0]),
+///     ],
+/// );
+/// assert_eq!(path.length(), 5.0);
+///
+/// path.densify(1.0);
+/// assert_eq!(path.len(), 6);
+/// ```
+pub struct PathGeometric<S: State, SP: StateSpace<StateType = S>> {
+    space: Arc<SP>,
+    states: Vec<S>,
+}
+
+impl<S, SP> Clone for PathGeometric<S, SP>
+where
+    S: State + Clone,
+    SP: StateSpace<StateType = S>,
+{
+    fn clone(&self) -> Self {
+        PathGeometric {
+            space: self.space.clone(),
+            states: self.states.clone(),
+        }
+    }
+}
+
+impl<S, SP> PathGeometric<S, SP>
+where
+    S: State + Clone,
+    SP: StateSpace<StateType = S>,
+{
+    /// Creates a path through `states` in `space`.
+    pub fn new(space: Arc<SP>, states: Vec<S>) -> Self {
+        PathGeometric { space, states }
+    }
+
+    /// Creates a path from a `Path` returned by a planner.
+    pub fn from_path(space: Arc<SP>, path: Path<S>) -> Self {
+        Self::new(space, path.0)
+    }
+
+    /// Converts the path back into a bare `Path`.
+    pub fn into_path(self) -> Path<S> {
+        Path(self.states)
+    }
+
+    /// Returns the space the path lies in.
+    pub fn space(&self) -> &Arc<SP> {
+        &self.space
+    }
+
+    /// Returns the states of the path.
+    pub fn states(&self) -> &[S] {
+        &self.states
+    }
+
+    /// Returns the states of the path, to change them in place.
+    pub fn states_mut(&mut self) -> &mut Vec<S> {
+        &mut self.states
+    }
+
+    /// Returns the number of states in the path.
+    pub fn len(&self) -> usize {
+        self.states.len()
+    }
+
+    /// Returns `true` if the path has no states.
+    pub fn is_empty(&self) -> bool {
+        self.states.is_empty()
+    }
+
+    /// Returns the total length of the path, measured by the space's distance.
+    pub fn length(&self) -> f64 {
+        self.segment_lengths().iter().sum()
+    }
+
+    /// Returns the sum of the angles the path turns through at its vertices, in radians.
+    ///
+    /// The angle at each vertex is found from the distances between it and its neighbours, so it
+    /// is defined in any space. A straight path has a smoothness of 0.
+    pub fn smoothness(&self) -> f64 {
+        self.states
+            .windows(3)
+            .map(|triple| {
+                let a = self.space.distance(&triple[0], &triple[1]);
+                let b = self.space.distance(&triple[1], &triple[2]);
+                if a == 0.0 || b == 0.0 {
+                    return 0.0;
+                }
+                let c = self.space.distance(&triple[0], &triple[2]);
+                let cos_interior = ((a * a + b * b - c * c) / (2.0 * a * b)).clamp(-1.0, 1.0);
+                PI - cos_interior.acos()
+            })
+            .sum()
+    }
+
+    /// Returns the smallest and the average clearance along the path, as measured by
+    /// `StateValidityChecker::clearance`.
+    ///
+    /// The clearance is measured at states spaced no more than the space's longest valid segment
+    /// length apart. Returns `None` if the path is empty or the checker cannot measure clearance.
+    pub fn clearance(&self, validity_checker: &dyn StateValidityChecker<S>) -> Option<(f64, f64)> {
+        let mut dense = self.clone();
+        dense.densify(self.space.get_longest_valid_segment_length());
+        let clearances = dense
+            .states
+            .iter()
+            .map(|state| validity_checker.clearance(state))
+            .collect::<Option<Vec<f64>>>()?;
+        if clearances.is_empty() {
+            return None;
+        }
+        let min = clearances.iter().copied().fold(f64::INFINITY, f64::min);
+        let average = clearances.iter().sum::<f64>() / clearances.len() as f64;
+        Some((min, average))
+    }
+
+    /// Inserts states along the path so that no segment is longer than `resolution`.
+    ///
+    /// The vertices of the path are kept; new states are spaced evenly along each segment.
+    pub fn densify(&mut self, resolution: f64) {
+        if resolution <= 0.0 {
+            return;
+        }
+        let counts = self
+            .segment_lengths()
+            .iter()
+            .map(|length| (length / resolution).ceil().max(1.0) as usize - 1)
+            .collect::<Vec<_>>();
+        self.insert_states(&counts);
+    }
+
+    /// Inserts states along the path until it has `count` states.
+    ///
+    /// The new states are shared out between the segments in proportion to their lengths, and
+    /// spaced evenly along each. Nothing is done if the path already has `count` states or more,
+    /// or fewer than two.
+    pub fn densify_to_count(&mut self, count: usize) {
+        if self.states.len() < 2 || count <= self.states.len() {
+            return;
+        }
+        let extra = count - self.states.len();
+        let lengths = self.segment_lengths();
+        let total: f64 = lengths.iter().sum();
+
+        // Share the new states out by largest remainder, so exactly `extra` are inserted.
+        let shares: Vec<f64> = if total > 0.0 {
+            lengths
+                .iter()
+                .map(|length| extra as f64 * length / total)
+                .collect()
+        } else {
+            vec![extra as f64 / lengths.len() as f64; lengths.len()]
+        };
+        let mut counts: Vec<usize> = shares.iter().map(|share| share.floor() as usize).collect();
+        let mut by_remainder: Vec<usize> = (0..shares.len()).collect();
+        by_remainder.sort_by(|&a, &b| {
+            (shares[b] - shares[b].floor()).total_cmp(&(shares[a] - shares[a].floor()))
+        });
+        let remaining = extra - counts.iter().sum::<usize>();
+        for &segment in by_remainder.iter().take(remaining) {
+            counts[segment] += 1;
+        }
+        self.insert_states(&counts);
+    }
+
+    /// Reverses the order of the states.
+    pub fn reverse(&mut self) {
+        self.states.reverse();
+    }
+
+    /// Returns the part of the path between the vertices in `range`.
+    ///
+    /// # Panics
+    /// Panics if `range` is out of bounds, as slicing does.
+    pub fn subpath(&self, range: impl RangeBounds<usize>) -> Self {
+        let range = (range.start_bound().cloned(), range.end_bound().cloned());
+        Self::new(self.space.clone(), self.states[range].to_vec())
+    }
+
+    /// Appends the states of `other` to the end of the path.
+    ///
+    /// If `other` starts where this path ends, its first state is skipped, so the joining state
+    /// is not repeated.
+    pub fn append(&mut self, other: &Self) {
+        let skip = match (self.states.last(), other.states.first()) {
+            (Some(last), Some(first)) => usize::from(self.space.distance(last, first) == 0.0),
+            _ => 0,
+        };
+        self.states.extend(other.states.iter().skip(skip).cloned());
+    }
+
+    /// Appends a single state to the end of the path.
+    pub fn append_state(&mut self, state: S) {
+        self.states.push(state);
+    }
+
+    /// Returns the index of the vertex closest to `state`, or `None` if the path is empty.
+    pub fn closest_index(&self, state: &S) -> Option<usize> {
+        self.states
+            .iter()
+            .map(|vertex| self.space.distance(vertex, state))
+            .enumerate()
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(index, _)| index)
+    }
+
+    /// Checks every vertex and segment of the path with `validity_checker`.
+    pub fn check(&self, validity_checker: &dyn StateValidityChecker<S>) -> PathValidityReport {
+        let vertex_valid: Vec<bool> = self
+            .states
+            .iter()
+            .map(|state| validity_checker.is_valid(state))
+            .collect();
+
+        let mut report = PathValidityReport {
+            invalid_vertices: (0..self.states.len())
+                .filter(|&i| !vertex_valid[i])
+                .collect(),
+            invalid_segments: Vec::new(),
+        };
+        for (i, pair) in self.states.windows(2).enumerate() {
+            if !vertex_valid[i] || !check_motion(&*self.space, validity_checker, &pair[0], &pair[1])
+            {
+                report.invalid_segments.push(i);
+            }
+        }
+        report
+    }
+
+    /// Tries to make an invalid path valid by resampling around its invalid parts.
+    ///
+    /// The path is walked from the start. Where the segment into a vertex is invalid, the vertex
+    /// is replaced by a valid state near it that joins its neighbours with valid motions. If there
+    /// is none, or the vertex is the last, a new vertex is inserted near the middle of the segment
+    /// instead. Each search draws up to `attempts` states, at distances from the original that
+    /// grow with every attempt.
+    ///
+    /// The first and last states are never moved, so a path whose first or last state is invalid
+    /// cannot be repaired.
+    ///
+    /// Returns `true` if the path is valid afterwards. If not, it may have been partly repaired.
+    pub fn repair(
+        &mut self,
+        validity_checker: &dyn StateValidityChecker<S>,
+        rng: &mut impl Rng,
+        attempts: usize,
+    ) -> bool {
+        let Some(first) = self.states.first() else {
+            return true;
+        };
+        if !validity_checker.is_valid(first) {
+            return false;
+        }
+
+        let space = &*self.space;
+        let motion_valid = |from: &S, to: &S| check_motion(space, validity_checker, from, to);
+        let mut i = 1;
+        while i < self.states.len() {
+            if motion_valid(&self.states[i - 1], &self.states[i]) {
+                i += 1;
+                continue;
+            }
+
+            // Replace the vertex with one that joins both of its neighbours.
+            if i + 1 < self.states.len() {
+                let (previous, next) = (&self.states[i - 1], &self.states[i + 1]);
+                let replacement = self.sample_near(&self.states[i], rng, attempts, |candidate| {
+                    motion_valid(previous, candidate) && motion_valid(candidate, next)
+                });
+                if let Some(replacement) = replacement {
+                    self.states[i] = replacement;
+                    i += 2;
+                    continue;
+                }
+            }
+
+            // Otherwise, go around the obstacle with a new vertex near the middle of the segment.
+            if !validity_checker.is_valid(&self.states[i]) {
+                return false;
+            }
+            let (previous, next) = (&self.states[i - 1], &self.states[i]);
+            let mut midpoint = previous.clone();
+            space.interpolate(previous, next, 0.5, &mut midpoint);
+            let detour = self.sample_near(&midpoint, rng, attempts, |candidate| {
+                motion_valid(previous, candidate) && motion_valid(candidate, next)
+            });
+            match detour {
+                Some(detour) => {
+                    self.states.insert(i, detour);
+                    i += 2;
+                }
+                None => return false,
+            }
+        }
+        true
+    }
+
+    /// Returns the lengths of the segments of the path, in order.
+    fn segment_lengths(&self) -> Vec<f64> {
+        self.states
+            .windows(2)
+            .map(|pair| self.space.distance(&pair[0], &pair[1]))
+            .collect()
+    }
+
+    /// Inserts `counts[i]` evenly spaced states into segment `i`, for every segment.
+    fn insert_states(&mut self, counts: &[usize]) {
+        let mut states = Vec::with_capacity(self.states.len() + counts.iter().sum::<usize>());
+        for (pair, &count) in self.states.windows(2).zip(counts) {
+            states.push(pair[0].clone());
+            for j in 1..=count {
+                let mut state = pair[0].clone();
+                let t = j as f64 / (count + 1) as f64;
+                self.space.interpolate(&pair[0], &pair[1], t, &mut state);
+                states.push(state);
+            }
+        }
+        if let Some(last) = self.states.last() {
+            states.push(last.clone());
+        }
+        self.states = states;
+    }
+
+    /// Draws up to `attempts` states around `centre`, each further away than the last, and returns
+    /// the first that is within bounds and accepted by `accept`.
+    ///
+    /// Each state is found by moving from `centre` towards a uniform sample of the space, by a
+    /// multiple of the space's longest valid segment length.
+    fn sample_near(
+        &self,
+        centre: &S,
+        rng: &mut impl Rng,
+        attempts: usize,
+        accept: impl Fn(&S) -> bool,
+    ) -> Option<S> {
+        let step = self.space.get_longest_valid_segment_length();
+        for attempt in 1..=attempts {
+            let Ok(target) = self.space.sample_uniform(rng) else {
+                continue;
+            };
+            let distance = self.space.distance(centre, &target);
+            if distance <= 0.0 {
+                continue;
+            }
+            let radius = step * attempt as f64 * rng.random::<f64>();
+            let mut candidate = centre.clone();
+            self.space.interpolate(
+                centre,
+                &target,
+                (radius / distance).min(1.0),
+                &mut candidate,
+            );
+            if self.space.satisfies_bounds(&candidate) && accept(&candidate) {
+                return Some(candidate);
+            }
+        }
+        None
+    }
+}
+
+impl<S, SP> From<PathGeometric<S, SP>> for Path<S>
+where
+    S: State + Clone,
+    SP: StateSpace<StateType = S>,
+{
+    fn from(path: PathGeometric<S, SP>) -> Self {
+        path.into_path()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base::{space::RealVectorStateSpace, state::RealVectorState};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    /// A wall at x = 5 with a gap above y = 8.
+    struct WallChecker;
+    impl StateValidityChecker<RealVectorState> for WallChecker {
+        fn is_valid(&self, state: &RealVectorState) -> bool {
+            let (x, y) = (state.values[0], state.values[1]);
+            !((4.5..=5.5).contains(&x) && y < 8.0)
+        }
+    }
+
+    fn path(points: &[[f64; 2]]) -> PathGeometric<RealVectorState, RealVectorStateSpace> {
+        let space =
+            Arc::new(RealVectorStateSpace::new(2, Some(vec![(0.0, 10.0), (0.0, 10.0)])).unwrap());
+        PathGeometric::new(
+            space,
+            points
+                .iter()
+                .map(|p| RealVectorState::new(p.to_vec()))
+                .collect(),
+        )
+    }
+
+    fn values(path: &PathGeometric<RealVectorState, RealVectorStateSpace>) -> Vec<Vec<f64>> {
+        path.states().iter().map(|s| s.values.clone()).collect()
+    }
+
+    #[test]
+    fn test_path_geometric_editing() {
+        let mut p = path(&[[0.0, 0.0], [3.0, 0.0], [3.0, 1.0]]);
+        assert_eq!(p.length(), 4.0);
+
+        let mut dense = p.clone();
+        dense.densify(1.0);
+        assert_eq!(dense.len(), 5);
+        assert!((dense.length() - 4.0).abs() < 1e-12);
+
+        let mut counted = p.clone();
+        counted.densify_to_count(7);
+        assert_eq!(counted.len(), 7);
+        assert_eq!(
+            values(&counted)[..4],
+            [[0.0, 0.0], [0.75, 0.0], [1.5, 0.0], [2.25, 0.0]]
+        );
+
+        let mut reversed = p.clone();
+        reversed.reverse();
+        assert_eq!(values(&reversed)[0], vec![3.0, 1.0]);
+
+        let sub = p.subpath(1..);
+        assert_eq!(values(&sub), vec![vec![3.0, 0.0], vec![3.0, 1.0]]);
+
+        p.append(&path(&[[3.0, 1.0], [0.0, 1.0]]));
+        assert_eq!(p.len(), 4);
+        p.append_state(RealVectorState::new(vec![0.0, 2.0]));
+        assert_eq!(p.len(), 5);
+
+        assert_eq!(
+            p.closest_index(&RealVectorState::new(vec![0.5, 1.2])),
+            Some(3)
+        );
+        assert_eq!(
+            path(&[]).closest_index(&RealVectorState::new(vec![0.0, 0.0])),
+            None
+        );
+        assert_eq!(p.into_path().0.len(), 5);
+    }
+
+    #[test]
+    fn test_path_geometric_smoothness_and_clearance() {
+        assert_eq!(
+            path(&[[0.0, 0.0], [1.0, 0.0], [2.0, 0.0]]).smoothness(),
+            0.0
+        );
+        let turns = path(&[[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]]);
+        assert!((turns.smoothness() - PI).abs() < 1e-12);
+
+        /// Measures the distance to the line y = 0.
+        struct FloorChecker;
+        impl StateValidityChecker<RealVectorState> for FloorChecker {
+            fn is_valid(&self, state: &RealVectorState) -> bool {
+                state.values[1] > 0.0
+            }
+            fn clearance(&self, state: &RealVectorState) -> Option<f64> {
+                Some(state.values[1])
+            }
+        }
+        let (min, average) = path(&[[0.0, 1.0], [0.0, 3.0]])
+            .clearance(&FloorChecker)
+            .unwrap();
+        assert!((min - 1.0).abs() < 1e-12);
+        assert!((average - 2.0).abs() < 1e-12);
+        assert_eq!(turns.clearance(&WallChecker), None);
+    }
+
+    #[test]
+    fn test_path_geometric_check() {
+        let p = path(&[[1.0, 1.0], [5.0, 5.0], [9.0, 1.0], [9.0, 9.0]]);
+        let report = p.check(&WallChecker);
+        assert_eq!(report.invalid_vertices, vec![1]);
+        assert_eq!(report.invalid_segments, vec![0, 1]);
+        assert_eq!(report.first_invalid_segment(), Some(0));
+        assert!(!report.is_valid());
+
+        assert!(path(&[[1.0, 9.0], [9.0, 9.0]])
+            .check(&WallChecker)
+            .is_valid());
+    }
+
+    #[test]
+    fn test_path_geometric_repair() {
+        let mut rng = StdRng::seed_from_u64(3);
+
+        // An invalid vertex inside the wall.
+        let mut p = path(&[[1.0, 7.0], [5.0, 7.0], [9.0, 7.0]]);
+        assert!(p.repair(&WallChecker, &mut rng, 1000));
+        assert!(p.check(&WallChecker).is_valid());
+        assert_eq!(values(&p)[0], vec![1.0, 7.0]);
+        assert_eq!(values(&p).last().unwrap(), &vec![9.0, 7.0]);
+
+        // A segment through the wall between two valid vertices.
+        let mut p = path(&[[4.0, 7.5], [6.0, 7.5]]);
+        assert!(p.repair(&WallChecker, &mut rng, 1000));
+        assert!(p.check(&WallChecker).is_valid());
+
+        // The goal lies inside the wall.
+        let mut p = path(&[[1.0, 1.0], [5.0, 1.0]]);
+        assert!(!p.repair(&WallChecker, &mut rng, 100));
+    }
+}
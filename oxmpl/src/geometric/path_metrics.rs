@@ -0,0 +1,116 @@
+// Copyright (c) 2025 Junior Sundar
+//
+// SPDX-License-Identifier: BSD-3-Clause
+
+use std::{fmt, sync::Arc};
+
+use crate::{
+    base::{
+        objective::OptimizationObjective, planner::Path, space::StateSpace, state::State,
+        validity::StateValidityChecker,
+    },
+    geometric::path_geometric::PathGeometric,
+};
+
+/// A summary of the quality of a path.
+///
+/// # Example
+///
+/// ```
+/// use std::sync::Arc;
+/// use oxmpl::base::{
+///     objective::PathLengthObjective,
+///     planner::Path,
+///     space::RealVectorStateSpace,
+///     state::RealVectorState,
+///     validity::StateValidityChecker,
+/// };
+/// use oxmpl::geometric::PathMetrics;
+///
+/// struct NoObstacles;
+/// impl StateValidityChecker<RealVectorState> for NoObstacles {
+///     fn is_valid(&self, _state: &RealVectorState) -> bool {
+///         true
+///     }
+/// }
+///
+/// let space = Arc::new(RealVectorStateSpace::new(2, Some(vec![(0.0, 10.0), (0.0, 10.0)])).unwrap());
+/// let path = Path(vec![
+///     RealVectorState::new(vec![0.0, 0.0]),
+///     RealVectorState::new(vec![3.0, 0.0]),
+///     RealVectorState::new(vec![3.0, 4.0]),
+/// ]);
+/// let metrics = PathMetrics::compute(
+///     &path,
+///     space.clone(),
+///     &NoObstacles,
+///     &PathLengthObjective::new(space),
+/// );
+///
+/// assert_eq!(metrics.length, 7.0);
+/// assert_eq!(metrics.vertices, 3);
+/// assert!((metrics.smoothness - std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+/// assert_eq!(metrics.min_clearance, None);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct PathMetrics {
+    /// The length of the path, as measured by the distance of its space.
+    pub length: f64,
+    /// The sum of the angles the path turns through at its vertices, in radians. See
+    /// `PathGeometric::smoothness`.
+    pub smoothness: f64,
+    /// The smallest clearance along the path, if the validity checker can measure clearance.
+    pub min_clearance: Option<f64>,
+    /// The average clearance along the path, if the validity checker can measure clearance.
+    pub average_clearance: Option<f64>,
+    /// The number of states in the path.
+    pub vertices: usize,
+    /// The cost of the path under the objective.
+    pub cost: f64,
+}
+
+impl PathMetrics {
+    /// Computes the metrics of `path`, which lies in `space`.
+    ///
+    /// # Parameters
+    /// * `path` - The path to measure.
+    /// * `space` - The space the path lies in.
+    /// * `validity_checker` - Used to measure the clearance along the path.
+    /// * `objective` - Used to measure the cost of the path.
+    pub fn compute<S, SP>(
+        path: &Path<S>,
+        space: Arc<SP>,
+        validity_checker: &dyn StateValidityChecker<S>,
+        objective: &dyn OptimizationObjective<S>,
+    ) -> Self
+    where
+        S: State + Clone,
+        SP: StateSpace<StateType = S>,
+    {
+        let geometric = PathGeometric::new(space, path.0.clone());
+        let clearance = geometric.clearance(validity_checker);
+        PathMetrics {
+            length: geometric.length(),
+            smoothness: geometric.smoothness(),
+            min_clearance: clearance.map(|(min, _)| min),
+            average_clearance: clearance.map(|(_, average)| average),
+            vertices: geometric.len(),
+            cost: objective.path_cost(path),
+        }
+    }
+}
+
+impl fmt::Display for PathMetrics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Length: {:.6}", self.length)?;
+        writeln!(f, "Smoothness: {:.6} rad", self.smoothness)?;
+        match (self.min_clearance, self.average_clearance) {
+            (Some(min), Some(average)) => {
+                writeln!(f, "Clearance: {min:.6} minimum, {average:.6} average")?
+            }
+            _ => writeln!(f, "Clearance: not measured")?,
+        }
+        writeln!(f, "Vertices: {}", self.vertices)?;
+        write!(f, "Cost: {:.6}", self.cost)
+    }
+}
@@ -0,0 +1,283 @@
+// Copyright (c) 2025 Junior Sundar
+//
+// SPDX-License-Identifier: BSD-3-Clause
+
+use std::sync::Arc;
+
+use rand::Rng;
+
+use crate::{
+    base::{
+        planner::{Path, PlannerConfig},
+        rng::{planner_rng, PlannerRng},
+        space::StateSpace,
+        state::State,
+        validity::StateValidityChecker,
+    },
+    geometric::planners::common::check_motion,
+    time::{Duration, Instant},
+};
+
+/// Shortens and smooths solution paths after planning.
+///
+/// Sampling-based planners return paths with many unnecessary detours. `PathSimplifier` removes
+/// them by replacing sections of the path with direct motions wherever those motions are valid.
+/// Every change it makes keeps the path valid, provided the path it is given is valid.
+///
+/// # Example
+///
+/// ```
+/// use std::{sync::Arc, time::Duration};
+/// use oxmpl::base::{
+///     planner::{Path, PlannerConfig},
+///     space::RealVectorStateSpace,
+///     state::RealVectorState,
+///     validity::StateValidityChecker,
+/// };
+/// use oxmpl::geometric::PathSimplifier;
+///
+/// struct NoObstacles;
+/// impl StateValidityChecker<RealVectorState> for NoObstacles {
+///     fn is_valid(&self, _state: &RealVectorState) -> bool {
+///         true
+///     }
+/// }
+///
+/// let space = Arc::new(RealVectorStateSpace::new(2, Some(vec![(0.0, 10.0), (0.0, 10.0)])).unwrap());
+/// let mut simplifier = PathSimplifier::new(space, Arc::new(NoObstacles), &PlannerConfig::default());
+///
+/// let mut path = Path(vec![
+///     RealVectorState::new(vec![0.0, 0.0]),
+///     RealVectorState::new(vec![5.0, 3.0]),
+///     RealVectorState::new(vec![10.0, 0.0]),
+/// ]);
+/// simplifier.simplify(&mut path, Duration::from_millis(100));
+/// assert_eq!(path.0.len(), 2);
+/// ```
+pub struct PathSimplifier<S: State, SP: StateSpace<StateType = S>> {
+    space: Arc<SP>,
+    validity_checker: Arc<dyn StateValidityChecker<S>>,
+    rng: PlannerRng,
+}
+
+impl<S, SP> PathSimplifier<S, SP>
+where
+    S: State + Clone,
+    SP: StateSpace<StateType = S>,
+{
+    /// Creates a new `PathSimplifier`.
+    ///
+    /// # Parameters
+    /// * `space` - The space the paths are in, used to measure and interpolate them.
+    /// * `validity_checker` - Used to check the motions that replace sections of a path.
+    /// * `config` - The configuration, for the seed of the random number generator.
+    pub fn new(
+        space: Arc<SP>,
+        validity_checker: Arc<dyn StateValidityChecker<S>>,
+        config: &PlannerConfig,
+    ) -> Self {
+        let rng = planner_rng(config.seed);
+        PathSimplifier {
+            space,
+            validity_checker,
+            rng,
+        }
+    }
+
+    /// Returns the total length of a path, measured by the space's distance.
+    pub fn path_length(&self, path: &Path<S>) -> f64 {
+        path.0
+            .windows(2)
+            .map(|pair| self.space.distance(&pair[0], &pair[1]))
+            .sum()
+    }
+
+    /// Removes vertices of the path by connecting pairs of non-consecutive vertices directly.
+    ///
+    /// Pairs of vertices are picked at random `max_steps` times. Whenever the motion between a pair
+    /// is valid, every vertex between them is removed.
+    ///
+    /// Returns `true` if any vertex was removed.
+    pub fn reduce_vertices(&mut self, path: &mut Path<S>, max_steps: usize) -> bool {
+        let mut changed = false;
+        for _ in 0..max_steps {
+            let len = path.0.len();
+            if len < 3 {
+                break;
+            }
+            let from = self.rng.random_range(0..len - 2);
+            let to = self.rng.random_range(from + 2..len);
+            if check_motion(
+                &*self.space,
+                &*self.validity_checker,
+                &path.0[from],
+                &path.0[to],
+            ) {
+                path.0.drain(from + 1..to);
+                changed = true;
+            }
+        }
+        changed
+    }
+
+    /// Shortens the path by connecting pairs of points anywhere along it directly.
+    ///
+    /// Two points are picked at random along the path `max_steps` times, not only at its vertices.
+    /// Whenever the direct motion between them is valid and shorter than the path between them,
+    /// that section of the path is replaced by the direct motion.
+    ///
+    /// Returns `true` if the path was shortened.
+    pub fn shortcut_path(&mut self, path: &mut Path<S>, max_steps: usize) -> bool {
+        let mut changed = false;
+        for _ in 0..max_steps {
+            if path.0.len() < 3 {
+                break;
+            }
+            let segment_lengths: Vec<f64> = path
+                .0
+                .windows(2)
+                .map(|pair| self.space.distance(&pair[0], &pair[1]))
+                .collect();
+            let length: f64 = segment_lengths.iter().sum();
+            if length <= 0.0 {
+                break;
+            }
+
+            let mut a = self.rng.random_range(0.0..length);
+            let mut b = self.rng.random_range(0.0..length);
+            if a > b {
+                std::mem::swap(&mut a, &mut b);
+            }
+            let (segment_a, t_a) = Self::locate(&segment_lengths, a);
+            let (segment_b, t_b) = Self::locate(&segment_lengths, b);
+            if segment_a == segment_b {
+                continue;
+            }
+
+            let mut state_a = path.0[segment_a].clone();
+            self.space.interpolate(
+                &path.0[segment_a],
+                &path.0[segment_a + 1],
+                t_a,
+                &mut state_a,
+            );
+            let mut state_b = path.0[segment_b].clone();
+            self.space.interpolate(
+                &path.0[segment_b],
+                &path.0[segment_b + 1],
+                t_b,
+                &mut state_b,
+            );
+
+            // Only accept a clear improvement, so the path does not fill up with near-duplicate
+            // states.
+            let direct = self.space.distance(&state_a, &state_b);
+            if direct >= (b - a) - 1e-9 * length {
+                continue;
+            }
+            if !check_motion(&*self.space, &*self.validity_checker, &state_a, &state_b) {
+                continue;
+            }
+
+            let mut states = Vec::with_capacity(path.0.len());
+            states.extend_from_slice(&path.0[..=segment_a]);
+            states.push(state_a);
+            states.push(state_b);
+            states.extend_from_slice(&path.0[segment_b + 1..]);
+            path.0 = states;
+            changed = true;
+        }
+        changed
+    }
+
+    /// Simplifies the path as much as possible within `timeout`.
+    ///
+    /// Alternates `reduce_vertices` and `shortcut_path` until neither makes the path any shorter or
+    /// the time runs out.
+    ///
+    /// Returns `true` if the path was changed.
+    pub fn simplify(&mut self, path: &mut Path<S>, timeout: Duration) -> bool {
+        let start_time = Instant::now();
+        let mut changed = false;
+        let mut length = self.path_length(path);
+        while start_time.elapsed() < timeout {
+            let steps = path.0.len().max(10);
+            let reduced = self.reduce_vertices(path, steps);
+            let shortcut = self.shortcut_path(path, steps);
+            let new_length = self.path_length(path);
+            if !(reduced || shortcut) || new_length >= length - 1e-9 * length.max(1.0) {
+                changed |= reduced || shortcut;
+                break;
+            }
+            changed = true;
+            length = new_length;
+        }
+        changed
+    }
+
+    /// Returns the segment of the path containing the point `distance` along it, and how far along
+    /// that segment the point lies, as a fraction.
+    fn locate(segment_lengths: &[f64], distance: f64) -> (usize, f64) {
+        let mut remaining = distance;
+        for (i, &segment_length) in segment_lengths.iter().enumerate() {
+            if remaining < segment_length {
+                return (i, remaining / segment_length);
+            }
+            remaining -= segment_length;
+        }
+        (segment_lengths.len() - 1, 1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base::{space::RealVectorStateSpace, state::RealVectorState};
+
+    /// A wall at x = 5 with a gap above y = 8.
+    struct WallChecker;
+    impl StateValidityChecker<RealVectorState> for WallChecker {
+        fn is_valid(&self, state: &RealVectorState) -> bool {
+            let (x, y) = (state.values[0], state.values[1]);
+            !((4.5..=5.5).contains(&x) && y < 8.0)
+        }
+    }
+
+    #[test]
+    fn test_path_simplifier_keeps_path_valid() {
+        let space =
+            Arc::new(RealVectorStateSpace::new(2, Some(vec![(0.0, 10.0), (0.0, 10.0)])).unwrap());
+        let checker: Arc<dyn StateValidityChecker<RealVectorState>> = Arc::new(WallChecker);
+        let mut simplifier = PathSimplifier::new(
+            space.clone(),
+            checker.clone(),
+            &PlannerConfig { seed: Some(7) },
+        );
+
+        let mut path = Path(
+            [
+                [1.0, 1.0],
+                [2.0, 4.0],
+                [1.0, 7.0],
+                [3.0, 9.0],
+                [5.0, 9.5],
+                [7.0, 9.0],
+                [9.0, 6.0],
+                [8.0, 3.0],
+                [9.0, 1.0],
+            ]
+            .iter()
+            .map(|v| RealVectorState::new(v.to_vec()))
+            .collect(),
+        );
+        let original_length = simplifier.path_length(&path);
+
+        assert!(simplifier.simplify(&mut path, Duration::from_millis(200)));
+        assert!(simplifier.path_length(&path) < original_length);
+        assert_eq!(path.0.first().unwrap().values, vec![1.0, 1.0]);
+        assert_eq!(path.0.last().unwrap().values, vec![9.0, 1.0]);
+        for pair in path.0.windows(2) {
+            assert!(check_motion(&*space, &*checker, &pair[0], &pair[1]));
+        }
+    }
+}
@@ -0,0 +1,246 @@
+// Copyright (c) 2025 Junior Sundar
+//
+// SPDX-License-Identifier: BSD-3-Clause
+
+use std::thread;
+
+use crate::{
+    base::{
+        error::PlannerRegistryError,
+        goal::{DynGoal, Goal, GoalSampleableRegion},
+        param::ParamSet,
+        planner::{Planner, PlannerConfig},
+        space::{DynStateSpace, StateSpace},
+        state::State,
+    },
+    geometric::{
+        BiRRTStar, CForest, LazyRRT, LazyRRTConnect, RRTConnect, RRTStar, PRM, RRT, RRTX, SPARS,
+        SPARS2,
+    },
+    tools::PreparedPlanner,
+};
+
+/// A planner built by a `PlannerRegistry`.
+pub type BoxedPlanner<S, SP = DynStateSpace<S>, G = DynGoal<S>> = Box<dyn Planner<S, SP, G> + Send>;
+
+/// Builds a planner from its configuration.
+type PlannerFactory<S, SP, G> = Box<dyn Fn(&PlannerConfig) -> BoxedPlanner<S, SP, G> + Send + Sync>;
+
+/// The goal bias of the built-in planners.
+const DEFAULT_GOAL_BIAS: f64 = 0.05;
+
+/// The time, in seconds, the built-in roadmap planners spend building their roadmap.
+const DEFAULT_ROADMAP_TIMEOUT: f64 = 1.0;
+
+/// The stretch factor of the built-in sparse roadmap planners.
+const DEFAULT_STRETCH_FACTOR: f64 = 3.0;
+
+/// The number of consecutive failures after which the built-in sparse roadmap planners stop
+/// adding nodes.
+const DEFAULT_MAX_FAILURES: usize = 1000;
+
+/// Maps planner names to functions that construct them, so that planners can be chosen at
+/// runtime, for example from a configuration file.
+///
+/// Every planner is built as a `BoxedPlanner`, which is itself a `Planner`, so it can be set up,
+/// solved and configured through `Planner::set_param` like any other. Planners that need a
+/// roadmap before they can solve, such as `PRM`, are built so that the roadmap is constructed on
+/// their first `solve` after each `setup`.
+///
+/// `with_geometric_planners` returns a registry of every planner in `oxmpl::geometric`, built
+/// with ranges and radii of 0 so that they are derived from the state space when the planner is
+/// set up. Further planners can be added with `register`.
+///
+/// # Example
+///
+/// ```
+/// use std::{sync::Arc, time::Duration};
+/// use oxmpl::base::{
+///     goal::GoalState,
+///     planner::{Planner, PlannerConfig},
+///     problem_definition::ProblemDefinition,
+///     space::RealVectorStateSpace,
+///     state::RealVectorState,
+///     validity::StateValidityChecker,
+/// };
+/// use oxmpl::geometric::PlannerRegistry;
+///
+/// struct NoObstacles;
+/// impl StateValidityChecker<RealVectorState> for NoObstacles {
+///     fn is_valid(&self, _state: &RealVectorState) -> bool {
+///         true
+///     }
+/// }
+///
+/// let space = Arc::new(RealVectorStateSpace::new(2, Some(vec![(0.0, 10.0), (0.0, 10.0)])).unwrap());
+/// let goal = GoalState::new(space.clone(), RealVectorState::new(vec![9.0, 9.0]), 0.5);
+/// let problem_def = Arc::new(ProblemDefinition {
+///     space,
+///     start_states: vec![RealVectorState::new(vec![1.0, 1.0])],
+///     goal: Arc::new(goal),
+/// });
+///
+/// let registry = PlannerRegistry::with_geometric_planners();
+/// let mut planner = registry.create("RRTConnect", &PlannerConfig::default()).unwrap();
+/// planner.set_param("max_distance", "0.5").unwrap();
+/// planner.setup(problem_def, Arc::new(NoObstacles));
+/// assert!(planner.solve(Duration::from_secs(1)).is_ok());
+/// ```
+pub struct PlannerRegistry<
+    S: State,
+    SP: StateSpace<StateType = S> = DynStateSpace<S>,
+    G: Goal<S> = DynGoal<S>,
+> {
+    factories: Vec<(String, PlannerFactory<S, SP, G>)>,
+}
+
+impl<S, SP, G> Default for PlannerRegistry<S, SP, G>
+where
+    S: State,
+    SP: StateSpace<StateType = S>,
+    G: Goal<S>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S, SP, G> PlannerRegistry<S, SP, G>
+where
+    S: State,
+    SP: StateSpace<StateType = S>,
+    G: Goal<S>,
+{
+    /// Creates a registry with no planners.
+    pub fn new() -> Self {
+        Self {
+            factories: Vec::new(),
+        }
+    }
+
+    /// Registers `factory` under `name`, replacing any planner already registered under it.
+    pub fn register<P, F>(&mut self, name: impl Into<String>, factory: F)
+    where
+        P: Planner<S, SP, G> + Send + 'static,
+        F: Fn(&PlannerConfig) -> P + Send + Sync + 'static,
+    {
+        let name = name.into();
+        let factory: PlannerFactory<S, SP, G> = Box::new(move |config| Box::new(factory(config)));
+        match self.factories.iter_mut().find(|(n, _)| *n == name) {
+            Some(entry) => entry.1 = factory,
+            None => self.factories.push((name, factory)),
+        }
+    }
+
+    /// Removes the planner registered under `name`, returning whether there was one.
+    pub fn remove(&mut self, name: &str) -> bool {
+        let len = self.factories.len();
+        self.factories.retain(|(n, _)| n != name);
+        self.factories.len() != len
+    }
+
+    /// Returns whether a planner is registered under `name`.
+    pub fn contains(&self, name: &str) -> bool {
+        self.factories.iter().any(|(n, _)| n == name)
+    }
+
+    /// Returns the names of the registered planners, in the order they were registered.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.factories.iter().map(|(name, _)| name.as_str())
+    }
+
+    /// Constructs the planner registered under `name`.
+    ///
+    /// # Errors
+    /// Returns `PlannerRegistryError::UnknownPlanner` if no planner is registered under `name`.
+    pub fn create(
+        &self,
+        name: &str,
+        config: &PlannerConfig,
+    ) -> Result<BoxedPlanner<S, SP, G>, PlannerRegistryError> {
+        self.factories
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, factory)| factory(config))
+            .ok_or_else(|| PlannerRegistryError::UnknownPlanner {
+                name: name.to_string(),
+            })
+    }
+
+    /// Returns the parameters, with their default values, of the planner registered under `name`.
+    ///
+    /// # Errors
+    /// Returns `PlannerRegistryError::UnknownPlanner` if no planner is registered under `name`.
+    pub fn params(&self, name: &str) -> Result<ParamSet, PlannerRegistryError> {
+        Ok(self.create(name, &PlannerConfig::default())?.params())
+    }
+}
+
+impl<S, SP, G> PlannerRegistry<S, SP, G>
+where
+    S: State + Clone,
+    SP: StateSpace<StateType = S> + 'static,
+    G: GoalSampleableRegion<S> + Send + Sync + 'static,
+{
+    /// Creates a registry of every planner in `oxmpl::geometric`, registered under the name of
+    /// its type, such as `"RRTConnect"` or `"PRM"`.
+    pub fn with_geometric_planners() -> Self {
+        let mut registry = Self::new();
+        registry.register("RRT", |config| RRT::new(0.0, DEFAULT_GOAL_BIAS, config));
+        registry.register("RRTConnect", |config| {
+            RRTConnect::new(0.0, DEFAULT_GOAL_BIAS, config)
+        });
+        registry.register("RRTStar", |config| {
+            RRTStar::new(0.0, DEFAULT_GOAL_BIAS, 0.0, config)
+        });
+        registry.register("BiRRTStar", |config| {
+            BiRRTStar::new(0.0, DEFAULT_GOAL_BIAS, 0.0, config)
+        });
+        registry.register("LazyRRT", |config| {
+            LazyRRT::new(0.0, DEFAULT_GOAL_BIAS, config)
+        });
+        registry.register("LazyRRTConnect", |config| {
+            LazyRRTConnect::new(0.0, DEFAULT_GOAL_BIAS, config)
+        });
+        registry.register("RRTX", |config| {
+            RRTX::new(0.0, DEFAULT_GOAL_BIAS, 0.0, config)
+        });
+        registry.register("CForest", |config| {
+            let num_threads = thread::available_parallelism().map_or(1, |n| n.get());
+            CForest::new(0.0, DEFAULT_GOAL_BIAS, 0.0, num_threads, config)
+        });
+        registry.register("PRM", |config| {
+            PreparedPlanner::new(
+                PRM::new(DEFAULT_ROADMAP_TIMEOUT, 0.0, config),
+                |prm: &mut PRM<S, SP, G>| prm.construct_roadmap(),
+            )
+        });
+        registry.register("SPARS", |config| {
+            PreparedPlanner::new(
+                SPARS::new(
+                    DEFAULT_ROADMAP_TIMEOUT,
+                    0.0,
+                    0.0,
+                    DEFAULT_STRETCH_FACTOR,
+                    DEFAULT_MAX_FAILURES,
+                    config,
+                ),
+                |spars: &mut SPARS<S, SP, G>| spars.construct_roadmap(),
+            )
+        });
+        registry.register("SPARS2", |config| {
+            PreparedPlanner::new(
+                SPARS2::new(
+                    DEFAULT_ROADMAP_TIMEOUT,
+                    0.0,
+                    0.0,
+                    DEFAULT_STRETCH_FACTOR,
+                    DEFAULT_MAX_FAILURES,
+                    config,
+                ),
+                |spars: &mut SPARS2<S, SP, G>| spars.construct_roadmap(),
+            )
+        });
+        registry
+    }
+}
@@ -0,0 +1,414 @@
+// Copyright (c) 2025 Junior Sundar
+//
+// SPDX-License-Identifier: BSD-3-Clause
+
+use std::sync::Arc;
+
+use rand::{Rng, SeedableRng};
+
+use crate::{
+    base::{
+        error::PlanningError,
+        goal::{Goal, GoalSampleableRegion},
+        observer::PlannerObserver,
+        param::ParamSet,
+        planner::{Path, Planner, PlannerConfig, PlannerStats},
+        problem_definition::ProblemDefinition,
+        rng::PlannerRng,
+        space::StateSpace,
+        state::State,
+        validity::StateValidityChecker,
+    },
+    time::{Duration, Instant},
+};
+
+use super::{
+    common::reconstruct_path,
+    rrt::resolve_max_distance,
+    rrt_connect::steer,
+    rrt_star::{choose_parent, find_neighbours, resolve_search_radius, rewire, Node},
+    stats::{self, StatsRecorder},
+};
+
+/// The number of attempts made to sample a valid goal state for the root of the goal tree.
+const GOAL_ROOT_SAMPLE_ATTEMPTS: usize = 100;
+
+/// The best connection found so far between the start tree and the goal tree.
+///
+/// `goal_idx` is `None` when a start tree node lies in the goal region by itself.
+#[derive(Clone, Copy)]
+struct Connection {
+    start_idx: usize,
+    goal_idx: Option<usize>,
+}
+
+/// An implementation of a bidirectional RRT* algorithm.
+///
+/// This planner grows a tree from the start state and a tree from a goal state, in the same
+/// balanced fashion as `RRTConnect`. Every new node is inserted with RRT*'s "Choose Parent" and
+/// "Rewire" steps, so each tree is an RRT* tree whose costs are measured from its own root.
+///
+/// After each insertion, the new node is connected to its neighbours in the other tree. The
+/// cheapest valid connection found so far is tracked, so the solution improves as the trees grow.
+///
+/// # Algorithm Overview
+/// 1. Initialize `start_tree` with the start state and `goal_tree` with a state from the goal
+///    region.
+/// 2. Loop until the timeout:
+///    a. Sample a random state `q_rand` and steer the smaller tree towards it to get `q_new`.
+///    b. Choose the cheapest valid parent for `q_new` and rewire its neighbours through it.
+///    c. For every node of the other tree within `search_radius` of `q_new`, check whether
+///    connecting the trees through it beats the best solution so far.
+/// 3. Return the best solution.
+///
+/// > [!NOTE]
+/// > Unlike `RRTConnect`, `solve` uses the whole of its `timeout` to improve the solution, and only
+/// > returns `PlanningError::Timeout` if no solution was found at all.
+pub struct BiRRTStar<S: State, SP: StateSpace<StateType = S>, G: Goal<S>> {
+    /// The maximum distance between nodes in the tree. This is the "step size". If it is 0,
+    /// each call to `setup` uses a fifth of the maximum extent of the space.
+    pub max_distance: f64,
+    /// The probability of sampling the goal region instead of the whole space (e.g., 0.05 for 5%).
+    pub goal_bias: f64,
+    /// The radius to search for neighbors during the "Choose Parent", "Rewire" and connection
+    /// steps. If it is 0, each call to `setup` uses twice `max_distance`.
+    pub search_radius: f64,
+
+    // The parameters above, with any that are 0 resolved by `setup`.
+    resolved_max_distance: f64,
+    resolved_search_radius: f64,
+    problem_def: Option<Arc<ProblemDefinition<S, SP, G>>>,
+    validity_checker: Option<Arc<dyn StateValidityChecker<S>>>,
+    start_tree: Vec<Node<S>>,
+    goal_tree: Vec<Node<S>>,
+    best_connection: Option<Connection>,
+    rng: Option<Box<PlannerRng>>,
+    stats: Arc<StatsRecorder<S>>,
+}
+
+impl<S, SP, G> BiRRTStar<S, SP, G>
+where
+    S: State + Clone,
+    SP: StateSpace<StateType = S>,
+    G: Goal<S> + GoalSampleableRegion<S>,
+{
+    /// Creates a new `BiRRTStar` planner with the specified parameters.
+    ///
+    /// # Parameters
+    /// * `max_distance` - The maximum length of a single branch in the tree.
+    /// * `goal_bias` - The probability (0.0 to 1.0) of sampling the goal.
+    /// * `search_radius` - The radius for finding neighbors to optimize connections.
+    /// * `config` - The planner configuration, for planner-specific parameters.
+    pub fn new(
+        max_distance: f64,
+        goal_bias: f64,
+        search_radius: f64,
+        config: &PlannerConfig,
+    ) -> Self {
+        let rng = config.seed.map(|s| Box::new(PlannerRng::seed_from_u64(s)));
+        BiRRTStar {
+            max_distance,
+            goal_bias,
+            search_radius,
+            resolved_max_distance: max_distance,
+            resolved_search_radius: search_radius,
+            problem_def: None,
+            validity_checker: None,
+            start_tree: Vec::new(),
+            goal_tree: Vec::new(),
+            best_connection: None,
+            rng,
+            stats: Arc::default(),
+        }
+    }
+
+    /// Returns the length of the best solution found so far, if any.
+    pub fn best_cost(&self) -> Option<f64> {
+        let pd = self.problem_def.as_ref()?;
+        let path = self.reconstruct_solution(self.best_connection?);
+        Some(
+            path.0
+                .windows(2)
+                .map(|pair| pd.space.distance(&pair[0], &pair[1]))
+                .sum(),
+        )
+    }
+
+    /// Calculates the current cost of a connection from the node costs. Costs are recomputed
+    /// rather than cached, as rewiring can lower them after the connection was made. Since rewiring
+    /// does not update descendants, this can overestimate the true path length.
+    fn connection_cost(&self, connection: Connection, space: &SP) -> f64 {
+        let start_node = &self.start_tree[connection.start_idx];
+        match connection.goal_idx {
+            Some(goal_idx) => {
+                let goal_node = &self.goal_tree[goal_idx];
+                start_node.cost
+                    + space.distance(&start_node.state, &goal_node.state)
+                    + goal_node.cost
+            }
+            None => start_node.cost,
+        }
+    }
+
+    fn reconstruct_solution(&self, connection: Connection) -> Path<S> {
+        let mut start_path = reconstruct_path(&self.start_tree, connection.start_idx).0;
+        if let Some(goal_idx) = connection.goal_idx {
+            // The goal path is from goal to connection, so it needs to be reversed.
+            let mut goal_path = reconstruct_path(&self.goal_tree, goal_idx).0;
+            goal_path.reverse();
+            start_path.extend(goal_path);
+        }
+        Path(start_path)
+    }
+}
+
+impl<S, SP, G> Planner<S, SP, G> for BiRRTStar<S, SP, G>
+where
+    S: State + Clone,
+    SP: StateSpace<StateType = S>,
+    G: Goal<S> + GoalSampleableRegion<S>,
+{
+    fn setup(
+        &mut self,
+        problem_def: Arc<ProblemDefinition<S, SP, G>>,
+        validity_checker: Arc<dyn StateValidityChecker<S>>,
+    ) {
+        self.resolved_max_distance = resolve_max_distance(self.max_distance, &*problem_def.space);
+        self.resolved_search_radius =
+            resolve_search_radius(self.search_radius, self.resolved_max_distance);
+        self.start_tree.clear();
+        self.goal_tree.clear();
+        self.best_connection = None;
+        self.stats.reset();
+
+        // Initialise the trees beginning from start and goal states.
+        self.stats.node_added(&problem_def.start_states[0], None);
+        self.start_tree.push(Node {
+            state: problem_def.start_states[0].clone(),
+            parent_index: None,
+            cost: 0.0,
+        });
+
+        let mut rng = self
+            .rng
+            .take()
+            .unwrap_or_else(|| Box::new(PlannerRng::from_os_rng()));
+        let stats = &*self.stats;
+        let vc = stats.checker(&*validity_checker);
+        let goal_state = (0..GOAL_ROOT_SAMPLE_ATTEMPTS)
+            .filter_map(|_| stats.sample(|| problem_def.goal.sample_goal(&mut rng)).ok())
+            .find(|state| {
+                let valid = vc.is_valid(state);
+                if !valid {
+                    stats.reject_sample(Some(state));
+                }
+                valid
+            });
+        if let Some(goal_state) = goal_state {
+            stats.node_added(&goal_state, None);
+            self.goal_tree.push(Node {
+                state: goal_state,
+                parent_index: None,
+                cost: 0.0,
+            });
+        }
+        self.rng = Some(rng);
+
+        self.problem_def = Some(problem_def);
+        self.validity_checker = Some(validity_checker);
+    }
+
+    fn solve(&mut self, timeout: Duration) -> Result<Path<S>, PlanningError> {
+        let pd = self
+            .problem_def
+            .clone()
+            .ok_or(PlanningError::PlannerUninitialised)?;
+        let vc = self
+            .validity_checker
+            .clone()
+            .ok_or(PlanningError::PlannerUninitialised)?;
+        let goal = &pd.goal;
+        let space = &*pd.space;
+        let stats = self.stats.clone();
+        let vc = stats.checker(&*vc);
+        let motion_valid = |from: &S, to: &S| stats.check_motion(space, &vc, from, to);
+
+        if self.goal_tree.is_empty() {
+            return Err(PlanningError::NoSolutionFound);
+        }
+
+        let mut rng = self
+            .rng
+            .take()
+            .unwrap_or_else(|| Box::new(PlannerRng::from_os_rng()));
+        let start_time = Instant::now();
+
+        // Main loop
+        while start_time.elapsed() <= timeout {
+            stats.iteration();
+
+            // 1. Determine which tree to grow (tree_a) and which to connect to (tree_b).
+            let is_growing_start_tree = self.start_tree.len() <= self.goal_tree.len();
+            let (tree_a, tree_b) = if is_growing_start_tree {
+                (&mut self.start_tree, &self.goal_tree)
+            } else {
+                (&mut self.goal_tree, &self.start_tree)
+            };
+
+            // 2. Sample a random target state `q_rand`, with goal biasing.
+            let sample = stats.sample(|| {
+                if rng.random_bool(self.goal_bias) {
+                    goal.sample_goal(&mut rng)
+                } else {
+                    space.sample_uniform(&mut rng)
+                }
+            });
+            let Ok(q_rand) = sample else {
+                stats.reject_sample(None);
+                continue;
+            };
+
+            // 3. Extend tree_a towards q_rand.
+            let (nearest_node_index, q_new, _) = stats.nearest(|| {
+                steer(
+                    tree_a.iter().map(|n| &n.state),
+                    &q_rand,
+                    space,
+                    self.resolved_max_distance,
+                )
+            });
+            if !motion_valid(&tree_a[nearest_node_index].state, &q_new) {
+                continue;
+            }
+
+            // 4. Choose parent, add q_new and rewire tree_a.
+            let neighbours = stats
+                .nearest(|| find_neighbours(tree_a, &q_new, space, self.resolved_search_radius));
+            let (best_parent_index, min_cost) = choose_parent(
+                tree_a,
+                &q_new,
+                nearest_node_index,
+                &neighbours,
+                space,
+                motion_valid,
+            );
+            stats.node_added(&q_new, Some(&tree_a[best_parent_index].state));
+            tree_a.push(Node {
+                state: q_new.clone(),
+                parent_index: Some(best_parent_index),
+                cost: min_cost,
+            });
+            let new_node_index = tree_a.len() - 1;
+            rewire(
+                tree_a,
+                new_node_index,
+                &neighbours,
+                space,
+                &stats,
+                motion_valid,
+            );
+
+            // 5. Collect candidate connections to tree_b, cheapest first.
+            let mut candidates: Vec<(f64, Connection)> = stats
+                .nearest(|| find_neighbours(tree_b, &q_new, space, self.resolved_search_radius))
+                .into_iter()
+                .map(|other_idx| {
+                    let cost = min_cost
+                        + space.distance(&q_new, &tree_b[other_idx].state)
+                        + tree_b[other_idx].cost;
+                    let connection = if is_growing_start_tree {
+                        Connection {
+                            start_idx: new_node_index,
+                            goal_idx: Some(other_idx),
+                        }
+                    } else {
+                        Connection {
+                            start_idx: other_idx,
+                            goal_idx: Some(new_node_index),
+                        }
+                    };
+                    (cost, connection)
+                })
+                .collect();
+            if is_growing_start_tree && goal.is_satisfied(&q_new) {
+                candidates.push((
+                    min_cost,
+                    Connection {
+                        start_idx: new_node_index,
+                        goal_idx: None,
+                    },
+                ));
+            }
+            candidates.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+            // 6. Keep the cheapest valid connection if it improves on the best solution.
+            let best_cost = self
+                .best_connection
+                .map_or(f64::INFINITY, |c| self.connection_cost(c, space));
+            for (cost, connection) in candidates {
+                if cost >= best_cost {
+                    break;
+                }
+                let valid = match connection.goal_idx {
+                    Some(goal_idx) => motion_valid(
+                        &self.start_tree[connection.start_idx].state,
+                        &self.goal_tree[goal_idx].state,
+                    ),
+                    None => true,
+                };
+                if valid {
+                    self.best_connection = Some(connection);
+                    stats.solution(space, &self.reconstruct_solution(connection));
+                    break;
+                }
+            }
+        }
+        self.rng = Some(rng);
+
+        self.best_connection
+            .map(|connection| self.reconstruct_solution(connection))
+            .ok_or(PlanningError::Timeout)
+    }
+
+    fn stats(&self) -> PlannerStats {
+        self.stats.snapshot()
+    }
+
+    fn set_observer(&mut self, observer: Arc<dyn PlannerObserver<S>>) {
+        stats::set_observer(&mut self.stats, observer);
+    }
+
+    fn params(&self) -> ParamSet {
+        ParamSet::default()
+            .with_real(
+                "max_distance",
+                "The maximum length of a motion. 0 uses a fifth of the space's maximum extent.",
+                self.max_distance,
+                0.0..=f64::INFINITY,
+            )
+            .with_real(
+                "goal_bias",
+                "The probability of sampling the goal region instead of the whole space.",
+                self.goal_bias,
+                0.0..=1.0,
+            )
+            .with_real(
+                "search_radius",
+                "The radius for rewiring and joining the trees. 0 uses twice max_distance.",
+                self.search_radius,
+                0.0..=f64::INFINITY,
+            )
+    }
+
+    fn set_params(&mut self, params: &ParamSet) {
+        if let Some(max_distance) = params.real("max_distance") {
+            self.max_distance = max_distance;
+        }
+        if let Some(goal_bias) = params.real("goal_bias") {
+            self.goal_bias = goal_bias;
+        }
+        if let Some(search_radius) = params.real("search_radius") {
+            self.search_radius = search_radius;
+        }
+    }
+}
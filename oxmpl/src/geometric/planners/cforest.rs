@@ -0,0 +1,549 @@
+// Copyright (c) 2025 Junior Sundar
+//
+// SPDX-License-Identifier: BSD-3-Clause
+
+use std::{
+    sync::{
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    thread,
+};
+
+use rand::{Rng, SeedableRng};
+
+use crate::{
+    base::{
+        error::PlanningError,
+        goal::{Goal, GoalSampleableRegion},
+        observer::PlannerObserver,
+        param::ParamSet,
+        planner::{Path, Planner, PlannerConfig, PlannerStats},
+        problem_definition::ProblemDefinition,
+        rng::PlannerRng,
+        space::StateSpace,
+        state::State,
+        validity::StateValidityChecker,
+    },
+    time::{Duration, Instant},
+};
+
+use super::{
+    common::reconstruct_path,
+    rrt::resolve_max_distance,
+    rrt_connect::steer,
+    rrt_star::{choose_parent, find_neighbours, resolve_search_radius, rewire, Node},
+    stats::{self, StatsRecorder},
+};
+
+/// The best solution found by any tree, shared between the threads of a `CForest`.
+///
+/// The cost and version are kept in atomics so that trees can check for a new solution, and prune
+/// against its cost, without taking the lock on every iteration.
+struct SharedSolution<S: State> {
+    cost_bits: AtomicU64,
+    version: AtomicUsize,
+    path: Mutex<Option<Path<S>>>,
+}
+
+impl<S: State + Clone> SharedSolution<S> {
+    fn new() -> Self {
+        SharedSolution {
+            cost_bits: AtomicU64::new(f64::INFINITY.to_bits()),
+            version: AtomicUsize::new(0),
+            path: Mutex::new(None),
+        }
+    }
+
+    /// The cost of the best solution so far, or infinity if there is none.
+    fn cost(&self) -> f64 {
+        f64::from_bits(self.cost_bits.load(Ordering::Acquire))
+    }
+
+    /// Incremented every time the best solution improves.
+    fn version(&self) -> usize {
+        self.version.load(Ordering::Acquire)
+    }
+
+    fn path(&self) -> Option<Path<S>> {
+        self.path.lock().unwrap().clone()
+    }
+
+    /// Replaces the best solution if `cost` improves on it. Returns whether it did.
+    fn offer(&self, cost: f64, path: Path<S>) -> bool {
+        let mut best = self.path.lock().unwrap();
+        if cost >= self.cost() {
+            return false;
+        }
+        *best = Some(path);
+        self.cost_bits.store(cost.to_bits(), Ordering::Release);
+        self.version.fetch_add(1, Ordering::AcqRel);
+        true
+    }
+}
+
+/// One of the RRT* trees grown by a `CForest`, along with the state it needs on its thread.
+struct CForestTree<'a, S: State, SP: StateSpace<StateType = S>, G: Goal<S>> {
+    max_distance: f64,
+    goal_bias: f64,
+    search_radius: f64,
+    pd: &'a ProblemDefinition<S, SP, G>,
+    vc: &'a dyn StateValidityChecker<S>,
+    shared: &'a SharedSolution<S>,
+    stats: &'a StatsRecorder<S>,
+    tree: Vec<Node<S>>,
+    /// The indices of the nodes in `tree` that satisfy the goal.
+    goal_nodes: Vec<usize>,
+    rng: PlannerRng,
+}
+
+impl<S, SP, G> CForestTree<'_, S, SP, G>
+where
+    S: State + Clone,
+    SP: StateSpace<StateType = S>,
+    G: Goal<S> + GoalSampleableRegion<S>,
+{
+    /// A lower bound on the cost of any solution passing through `state`.
+    fn heuristic_cost(&self, state: &S) -> f64 {
+        self.pd.space.distance(&self.pd.start_states[0], state) + self.pd.goal.distance_goal(state)
+    }
+
+    /// Grows the tree until `timeout`, sharing solutions through `shared`.
+    fn grow(&mut self, start_time: Instant, timeout: Duration) -> usize {
+        let mut seen_version = 0;
+        self.stats.node_added(&self.tree[0].state, None);
+
+        while start_time.elapsed() <= timeout {
+            self.stats.iteration();
+
+            // 1. Add the states of a newly shared solution to this tree as samples.
+            let version = self.shared.version();
+            if version != seen_version {
+                seen_version = version;
+                if let Some(path) = self.shared.path() {
+                    for state in path.0.iter().skip(1) {
+                        self.extend(state);
+                    }
+                }
+            }
+
+            // 2. Sample a state, rejecting it if it cannot improve on the shared solution.
+            let sample = self.stats.sample(|| {
+                if self.rng.random_bool(self.goal_bias) {
+                    self.pd.goal.sample_goal(&mut self.rng)
+                } else {
+                    self.pd.space.sample_uniform(&mut self.rng)
+                }
+            });
+            let Ok(q_rand) = sample else {
+                self.stats.reject_sample(None);
+                continue;
+            };
+            if self.heuristic_cost(&q_rand) >= self.shared.cost() {
+                self.stats.reject_sample(Some(&q_rand));
+                continue;
+            }
+
+            // 3. Extend the tree towards it as RRT* would.
+            self.extend(&q_rand);
+        }
+
+        self.tree.len()
+    }
+
+    /// Extends the tree towards `q_target` with RRT*'s "Choose Parent" and "Rewire" steps, offering
+    /// any new solution to the other trees.
+    fn extend(&mut self, q_target: &S) {
+        let space = &*self.pd.space;
+        let (vc, stats) = (self.vc, self.stats);
+        let motion_valid = |from: &S, to: &S| stats.check_motion(space, vc, from, to);
+
+        let (nearest_node_index, q_new, _) = stats.nearest(|| {
+            steer(
+                self.tree.iter().map(|n| &n.state),
+                q_target,
+                space,
+                self.max_distance,
+            )
+        });
+        if !motion_valid(&self.tree[nearest_node_index].state, &q_new) {
+            return;
+        }
+
+        let neighbours =
+            stats.nearest(|| find_neighbours(&self.tree, &q_new, space, self.search_radius));
+        let (best_parent_index, min_cost) = choose_parent(
+            &self.tree,
+            &q_new,
+            nearest_node_index,
+            &neighbours,
+            space,
+            motion_valid,
+        );
+
+        // Nodes that cannot lead to a better solution than the shared one are not worth keeping.
+        if min_cost + self.pd.goal.distance_goal(&q_new) >= self.shared.cost() {
+            return;
+        }
+
+        stats.node_added(&q_new, Some(&self.tree[best_parent_index].state));
+        self.tree.push(Node {
+            state: q_new.clone(),
+            parent_index: Some(best_parent_index),
+            cost: min_cost,
+        });
+        let new_node_index = self.tree.len() - 1;
+        let rewired = rewire(
+            &mut self.tree,
+            new_node_index,
+            &neighbours,
+            space,
+            stats,
+            motion_valid,
+        );
+
+        let reached_goal = self.pd.goal.is_satisfied(&q_new);
+        if reached_goal {
+            self.goal_nodes.push(new_node_index);
+        }
+        // Rewiring can lower the cost of goal nodes already in the tree, so they are checked too.
+        if reached_goal || !rewired.is_empty() {
+            self.share_best_goal_node();
+        }
+    }
+
+    /// Offers the path to the goal node with the lowest cost to the other trees.
+    ///
+    /// The cost of each goal node is measured along its branch, as rewiring a node does not update
+    /// the costs stored in its descendants.
+    fn share_best_goal_node(&self) {
+        let space = &*self.pd.space;
+        let branch_cost = |mut index: usize| {
+            let mut cost = 0.0;
+            while let Some(parent_index) = self.tree[index].parent_index {
+                cost += space.distance(&self.tree[parent_index].state, &self.tree[index].state);
+                index = parent_index;
+            }
+            cost
+        };
+        let Some((cost, index)) = self
+            .goal_nodes
+            .iter()
+            .map(|&index| (branch_cost(index), index))
+            .min_by(|a, b| a.0.total_cmp(&b.0))
+        else {
+            return;
+        };
+        if cost < self.shared.cost() {
+            let path = reconstruct_path(&self.tree, index);
+            if self.shared.offer(cost, path.clone()) {
+                self.stats.solution(space, &path);
+            }
+        }
+    }
+}
+
+/// An implementation of the Coupled Forest of Random Engrafting Search Trees (CForest)
+/// algorithm.
+///
+/// CForest runs several `RRTStar` trees concurrently, one per thread. Whenever a tree improves on
+/// the best solution found so far, that solution is shared with all the other trees, which add its
+/// states to themselves as samples. The shared cost is then used to prune: samples and new nodes
+/// that cannot possibly lead to a cheaper solution are discarded, so every tree focuses on the
+/// part of the space that can still improve the result.
+///
+/// # Algorithm Overview
+/// 1. Start `num_threads` RRT* trees from the start state, each with its own random number
+///    generator.
+/// 2. In every tree, until the timeout:
+///    a. If the shared solution has improved, add its states to the tree as samples.
+///    b. Sample a random state `q_rand`. Discard it if the straight-line distance from the start
+///    plus its distance to the goal is no less than the shared cost.
+///    c. Extend the tree towards `q_rand` with RRT*'s "Choose Parent" and "Rewire" steps.
+///    d. If the new node, or a goal node whose branch was shortened by rewiring, reaches the goal
+///    with a lower cost, share the solution.
+/// 3. Return the best shared solution.
+///
+/// > [!NOTE]
+/// > Like `BiRRTStar`, `solve` uses the whole of its `timeout` to improve the solution, and only
+/// > returns `PlanningError::Timeout` if no solution was found at all.
+///
+/// # Trait Bounds
+///
+/// To use this planner, the following trait bounds must be met:
+/// - The `State` type (`S`) must be `Clone`.
+/// - The `Goal` type (`G`) must implement `GoalSampleableRegion`, for goal-biasing and for the
+///   distance to the goal used when pruning. It must also be `Send + Sync` to be shared between
+///   threads.
+pub struct CForest<S: State, SP: StateSpace<StateType = S>, G: Goal<S>> {
+    /// The maximum distance between nodes in the tree. This is the "step size". If it is 0,
+    /// each call to `setup` uses a fifth of the maximum extent of the space.
+    pub max_distance: f64,
+    /// The probability of sampling the goal region instead of the whole space (e.g., 0.05 for 5%).
+    pub goal_bias: f64,
+    /// The radius to search for neighbors during the "Choose Parent" and "Rewire" steps. If it is
+    /// 0, each call to `setup` uses twice `max_distance`.
+    pub search_radius: f64,
+    /// The number of trees to grow, each on its own thread. `std::thread::available_parallelism`
+    /// is a good default.
+    pub num_threads: usize,
+
+    // The parameters above, with any that are 0 resolved by `setup`.
+    resolved_max_distance: f64,
+    resolved_search_radius: f64,
+    problem_def: Option<Arc<ProblemDefinition<S, SP, G>>>,
+    validity_checker: Option<Arc<dyn StateValidityChecker<S>>>,
+    best_path: Option<Path<S>>,
+    best_cost: Option<f64>,
+    tree_sizes: Vec<usize>,
+    rng: Option<Box<PlannerRng>>,
+    stats: Arc<StatsRecorder<S>>,
+}
+
+impl<S, SP, G> CForest<S, SP, G>
+where
+    S: State + Clone,
+    SP: StateSpace<StateType = S>,
+    G: Goal<S> + GoalSampleableRegion<S> + Send + Sync,
+{
+    /// Creates a new `CForest` planner with the specified parameters.
+    ///
+    /// # Parameters
+    /// * `max_distance` - The maximum length of a single branch in the tree.
+    /// * `goal_bias` - The probability (0.0 to 1.0) of sampling the goal.
+    /// * `search_radius` - The radius for finding neighbors to optimize connections.
+    /// * `num_threads` - The number of trees to grow in parallel.
+    /// * `config` - The planner configuration, for planner-specific parameters. The seed is used
+    ///   to derive a seed for each tree.
+    pub fn new(
+        max_distance: f64,
+        goal_bias: f64,
+        search_radius: f64,
+        num_threads: usize,
+        config: &PlannerConfig,
+    ) -> Self {
+        let rng = config.seed.map(|s| Box::new(PlannerRng::seed_from_u64(s)));
+        CForest {
+            max_distance,
+            goal_bias,
+            search_radius,
+            num_threads,
+            resolved_max_distance: max_distance,
+            resolved_search_radius: search_radius,
+            problem_def: None,
+            validity_checker: None,
+            best_path: None,
+            best_cost: None,
+            tree_sizes: Vec::new(),
+            rng,
+            stats: Arc::default(),
+        }
+    }
+
+    /// Returns the length of the best solution found so far, if any.
+    pub fn best_cost(&self) -> Option<f64> {
+        self.best_cost
+    }
+
+    /// Returns the number of nodes in each tree at the end of the last call to `solve`.
+    pub fn tree_sizes(&self) -> &[usize] {
+        &self.tree_sizes
+    }
+}
+
+impl<S, SP, G> Planner<S, SP, G> for CForest<S, SP, G>
+where
+    S: State + Clone,
+    SP: StateSpace<StateType = S>,
+    G: Goal<S> + GoalSampleableRegion<S> + Send + Sync,
+{
+    fn setup(
+        &mut self,
+        problem_def: Arc<ProblemDefinition<S, SP, G>>,
+        validity_checker: Arc<dyn StateValidityChecker<S>>,
+    ) {
+        self.resolved_max_distance = resolve_max_distance(self.max_distance, &*problem_def.space);
+        self.resolved_search_radius =
+            resolve_search_radius(self.search_radius, self.resolved_max_distance);
+        self.problem_def = Some(problem_def);
+        self.validity_checker = Some(validity_checker);
+        self.best_path = None;
+        self.best_cost = None;
+        self.tree_sizes.clear();
+        self.stats.reset();
+    }
+
+    fn solve(&mut self, timeout: Duration) -> Result<Path<S>, PlanningError> {
+        let pd = self
+            .problem_def
+            .clone()
+            .ok_or(PlanningError::PlannerUninitialised)?;
+        let vc = self
+            .validity_checker
+            .clone()
+            .ok_or(PlanningError::PlannerUninitialised)?;
+
+        let mut rng = self
+            .rng
+            .take()
+            .unwrap_or_else(|| Box::new(PlannerRng::from_os_rng()));
+        let tree_seeds: Vec<u64> = (0..self.num_threads.max(1)).map(|_| rng.random()).collect();
+        self.rng = Some(rng);
+
+        // Seed the shared solution with the result of a previous call, so that work is not lost.
+        let shared = SharedSolution::new();
+        if let (Some(cost), Some(path)) = (self.best_cost, self.best_path.clone()) {
+            shared.offer(cost, path);
+        }
+
+        let stats = &*self.stats;
+        let vc = stats.checker(&*vc);
+        let start_time = Instant::now();
+        let tree_sizes = thread::scope(|scope| {
+            let handles: Vec<_> = tree_seeds
+                .into_iter()
+                .map(|seed| {
+                    let mut tree = CForestTree {
+                        max_distance: self.resolved_max_distance,
+                        goal_bias: self.goal_bias,
+                        search_radius: self.resolved_search_radius,
+                        pd: &*pd,
+                        vc: &vc,
+                        shared: &shared,
+                        stats,
+                        tree: vec![Node {
+                            state: pd.start_states[0].clone(),
+                            parent_index: None,
+                            cost: 0.0,
+                        }],
+                        goal_nodes: Vec::new(),
+                        rng: PlannerRng::seed_from_u64(seed),
+                    };
+                    scope.spawn(move || tree.grow(start_time, timeout))
+                })
+                .collect();
+            handles
+                .into_iter()
+                .map(|handle| handle.join().unwrap())
+                .collect()
+        });
+        self.tree_sizes = tree_sizes;
+
+        let cost = shared.cost();
+        self.best_path = shared.path();
+        self.best_cost = self.best_path.as_ref().map(|_| cost);
+        self.best_path.clone().ok_or(PlanningError::Timeout)
+    }
+
+    fn stats(&self) -> PlannerStats {
+        self.stats.snapshot()
+    }
+
+    fn set_observer(&mut self, observer: Arc<dyn PlannerObserver<S>>) {
+        stats::set_observer(&mut self.stats, observer);
+    }
+
+    fn params(&self) -> ParamSet {
+        ParamSet::default()
+            .with_real(
+                "max_distance",
+                "The maximum length of a motion. 0 uses a fifth of the space's maximum extent.",
+                self.max_distance,
+                0.0..=f64::INFINITY,
+            )
+            .with_real(
+                "goal_bias",
+                "The probability of sampling the goal region instead of the whole space.",
+                self.goal_bias,
+                0.0..=1.0,
+            )
+            .with_real(
+                "search_radius",
+                "The radius for choosing parents and rewiring. 0 uses twice max_distance.",
+                self.search_radius,
+                0.0..=f64::INFINITY,
+            )
+            .with_integer(
+                "num_threads",
+                "The number of trees to grow, each on its own thread.",
+                self.num_threads,
+                1.0..=f64::INFINITY,
+            )
+    }
+
+    fn set_params(&mut self, params: &ParamSet) {
+        if let Some(max_distance) = params.real("max_distance") {
+            self.max_distance = max_distance;
+        }
+        if let Some(goal_bias) = params.real("goal_bias") {
+            self.goal_bias = goal_bias;
+        }
+        if let Some(search_radius) = params.real("search_radius") {
+            self.search_radius = search_radius;
+        }
+        if let Some(num_threads) = params.integer("num_threads") {
+            self.num_threads = num_threads;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base::{goal::GoalState, space::RealVectorStateSpace, state::RealVectorState};
+
+    struct NoObstacles;
+    impl StateValidityChecker<RealVectorState> for NoObstacles {
+        fn is_valid(&self, _state: &RealVectorState) -> bool {
+            true
+        }
+    }
+
+    #[test]
+    fn test_rewiring_a_goal_node_shares_its_lower_cost() {
+        let space =
+            Arc::new(RealVectorStateSpace::new(2, Some(vec![(0.0, 10.0), (0.0, 10.0)])).unwrap());
+        let pd = ProblemDefinition {
+            space: space.clone(),
+            start_states: vec![RealVectorState::new(vec![0.0, 0.0])],
+            goal: Arc::new(GoalState::new(
+                space,
+                RealVectorState::new(vec![2.0, 4.0]),
+                0.1,
+            )),
+        };
+        let shared = SharedSolution::new();
+        let stats = StatsRecorder::default();
+        let node = |values: Vec<f64>, parent_index, cost| Node {
+            state: RealVectorState::new(values),
+            parent_index,
+            cost,
+        };
+
+        // The goal node is reached by a detour, at a cost of 6.
+        let mut tree = CForestTree {
+            max_distance: 10.0,
+            goal_bias: 0.0,
+            search_radius: 10.0,
+            pd: &pd,
+            vc: &NoObstacles,
+            shared: &shared,
+            stats: &stats,
+            tree: vec![
+                node(vec![0.0, 0.0], None, 0.0),
+                node(vec![0.0, 4.0], Some(0), 4.0),
+                node(vec![2.0, 4.0], Some(1), 6.0),
+            ],
+            goal_nodes: vec![2],
+            rng: PlannerRng::seed_from_u64(0),
+        };
+        tree.share_best_goal_node();
+        assert_eq!(shared.cost(), 6.0);
+
+        // A node at (2, 2) is not in the goal, but the goal node is rewired through it.
+        tree.extend(&RealVectorState::new(vec![2.0, 2.0]));
+        assert_eq!(tree.tree[2].parent_index, Some(3));
+        let expected = 8.0_f64.sqrt() + 2.0;
+        assert!((shared.cost() - expected).abs() < 1e-9);
+    }
+}
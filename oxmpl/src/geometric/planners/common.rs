@@ -0,0 +1,171 @@
+// Copyright (c) 2025 Junior Sundar
+//
+// SPDX-License-Identifier: BSD-3-Clause
+
+//! Motion and graph helpers shared by the planners.
+
+use std::{cmp::Ordering, collections::BinaryHeap};
+
+use crate::base::{planner::Path, space::StateSpace, state::State, validity::StateValidityChecker};
+
+use super::prm::Node;
+
+/// A node of a tree whose nodes are stored in a vector and point to their parent by index.
+pub(crate) trait TreeNode<S> {
+    /// Returns the state of the node.
+    fn state(&self) -> &S;
+
+    /// Returns the index of the node's parent, or `None` for the root.
+    fn parent_index(&self) -> Option<usize>;
+}
+
+/// A node of a search tree over borrowed states, such as a breadth-first search of a roadmap.
+impl<S> TreeNode<S> for (&S, Option<usize>) {
+    fn state(&self) -> &S {
+        self.0
+    }
+
+    fn parent_index(&self) -> Option<usize> {
+        self.1
+    }
+}
+
+/// Follows parent indices from `last_node_idx` back to the root of `tree`, returning the states in
+/// root-to-node order.
+pub(crate) fn reconstruct_path<S: State + Clone, N: TreeNode<S>>(
+    tree: &[N],
+    last_node_idx: usize,
+) -> Path<S> {
+    let mut path_states = Vec::new();
+    let mut current_index = Some(last_node_idx);
+    while let Some(index) = current_index {
+        path_states.push(tree[index].state().clone());
+        current_index = tree[index].parent_index();
+    }
+    path_states.reverse();
+
+    Path(path_states)
+}
+
+/// A min-heap entry for Dijkstra's algorithm.
+pub(crate) struct QueueEntry {
+    pub(crate) cost: f64,
+    pub(crate) index: usize,
+}
+
+impl PartialEq for QueueEntry {
+    fn eq(&self, other: &Self) -> bool {
+        self.cost == other.cost
+    }
+}
+
+impl Eq for QueueEntry {}
+
+impl PartialOrd for QueueEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for QueueEntry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reversed so that `BinaryHeap` pops the cheapest entry first.
+        other.cost.total_cmp(&self.cost)
+    }
+}
+
+/// Runs Dijkstra's algorithm over a roadmap.
+///
+/// # Parameters
+/// * `graph` - The roadmap to search.
+/// * `sources` - The nodes to start from, each with an initial cost.
+/// * `is_target` - Returns `true` for nodes that terminate the search.
+/// * `edge_cost` - Returns the cost of traversing the edge between two node indices.
+///
+/// Returns the cost of the cheapest path and its node indices, from source to target.
+pub(crate) fn shortest_path<S: State>(
+    graph: &[Node<S>],
+    sources: &[(usize, f64)],
+    is_target: impl Fn(usize) -> bool,
+    edge_cost: impl Fn(usize, usize) -> f64,
+) -> Option<(f64, Vec<usize>)> {
+    let mut costs = vec![f64::INFINITY; graph.len()];
+    let mut parents: Vec<Option<usize>> = vec![None; graph.len()];
+    let mut queue = BinaryHeap::new();
+
+    for &(index, cost) in sources {
+        if cost < costs[index] {
+            costs[index] = cost;
+            queue.push(QueueEntry { cost, index });
+        }
+    }
+
+    while let Some(QueueEntry { cost, index }) = queue.pop() {
+        if cost > costs[index] {
+            continue;
+        }
+
+        if is_target(index) {
+            let mut path = vec![index];
+            let mut current = index;
+            while let Some(parent) = parents[current] {
+                path.push(parent);
+                current = parent;
+            }
+            path.reverse();
+            return Some((cost, path));
+        }
+
+        for &neighbour in &graph[index].edges {
+            let new_cost = cost + edge_cost(index, neighbour);
+            if new_cost < costs[neighbour] {
+                costs[neighbour] = new_cost;
+                parents[neighbour] = Some(index);
+                queue.push(QueueEntry {
+                    cost: new_cost,
+                    index: neighbour,
+                });
+            }
+        }
+    }
+
+    None
+}
+
+/// Checks if the straight-line motion between two states is valid.
+///
+/// It works by discretizing the motion between `from` and `to` into small steps and calling the
+/// `StateValidityChecker` on each intermediate state.
+pub(crate) fn check_motion<S: State + Clone, SP: StateSpace<StateType = S>>(
+    space: &SP,
+    vc: &dyn StateValidityChecker<S>,
+    from: &S,
+    to: &S,
+) -> bool {
+    let dist = space.distance(from, to);
+    let num_steps = (dist / (space.get_longest_valid_segment_length() * 0.1)).ceil() as usize;
+
+    if num_steps <= 1 {
+        return vc.is_valid(to);
+    }
+
+    let mut interpolated_state = from.clone();
+    for i in 1..=num_steps {
+        let t = i as f64 / num_steps as f64;
+        space.interpolate(from, to, t, &mut interpolated_state);
+        if !vc.is_valid(&interpolated_state) {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Returns the two indices in increasing order, to key undirected edges.
+pub(crate) fn ordered_pair(a: usize, b: usize) -> (usize, usize) {
+    if a < b {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
@@ -0,0 +1,342 @@
+// Copyright (c) 2025 Junior Sundar
+//
+// SPDX-License-Identifier: BSD-3-Clause
+
+use std::sync::Arc;
+
+use rand::{Rng, SeedableRng};
+
+use crate::{
+    base::{
+        error::PlanningError,
+        goal::{Goal, GoalSampleableRegion},
+        observer::PlannerObserver,
+        param::ParamSet,
+        planner::{Path, Planner, PlannerConfig, PlannerStats},
+        problem_definition::ProblemDefinition,
+        rng::PlannerRng,
+        space::StateSpace,
+        state::State,
+        validity::StateValidityChecker,
+    },
+    time::{Duration, Instant},
+};
+
+use super::{
+    common::reconstruct_path,
+    rrt::{resolve_max_distance, Node},
+    rrt_connect::steer,
+    stats::{self, StatsRecorder},
+};
+
+/// A tree whose edges are only checked for validity once they are part of a candidate solution.
+///
+/// `validated[i]` records whether the edge from node `i` to its parent has been checked. Nodes are
+/// only ever appended after their parent, so a child always has a larger index than its parent.
+#[derive(Clone)]
+pub(crate) struct LazyTree<S: State> {
+    pub(crate) nodes: Vec<Node<S>>,
+    validated: Vec<bool>,
+}
+
+impl<S: State> Default for LazyTree<S> {
+    fn default() -> Self {
+        LazyTree {
+            nodes: Vec::new(),
+            validated: Vec::new(),
+        }
+    }
+}
+
+impl<S: State + Clone> LazyTree<S> {
+    /// Adds a node without checking the motion from its parent, reporting it to the observer of
+    /// `stats`.
+    pub(crate) fn push(
+        &mut self,
+        state: S,
+        parent_index: Option<usize>,
+        stats: &StatsRecorder<S>,
+    ) -> usize {
+        stats.node_added(&state, parent_index.map(|p| &self.nodes[p].state));
+        self.nodes.push(Node {
+            state,
+            parent_index,
+        });
+        // The root has no incoming edge to check. Its state is checked by the planner's `solve`.
+        self.validated.push(parent_index.is_none());
+        self.nodes.len() - 1
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.nodes.clear();
+        self.validated.clear();
+    }
+
+    /// Checks every unvalidated edge between the root and `node_idx`, starting from the root.
+    ///
+    /// If an invalid edge is found, the subtree below it is removed from the tree and `false` is
+    /// returned. Indices of nodes after the removed subtree will have changed.
+    pub(crate) fn validate_branch<SP: StateSpace<StateType = S>>(
+        &mut self,
+        node_idx: usize,
+        space: &SP,
+        vc: &dyn StateValidityChecker<S>,
+        stats: &StatsRecorder<S>,
+    ) -> bool {
+        let mut branch = Vec::new();
+        let mut current_index = Some(node_idx);
+        while let Some(index) = current_index {
+            branch.push(index);
+            current_index = self.nodes[index].parent_index;
+        }
+        branch.reverse();
+
+        for &index in &branch {
+            if self.validated[index] {
+                continue;
+            }
+            let Some(parent) = self.nodes[index].parent_index else {
+                continue;
+            };
+
+            if stats.check_motion(
+                space,
+                vc,
+                &self.nodes[parent].state,
+                &self.nodes[index].state,
+            ) {
+                self.validated[index] = true;
+            } else {
+                self.prune_subtree(index, stats);
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Removes `root_idx` and all of its descendants, compacting the remaining nodes. The edges of
+    /// the removed nodes are reported to the observer of `stats`.
+    fn prune_subtree(&mut self, root_idx: usize, stats: &StatsRecorder<S>) {
+        let mut removed = vec![false; self.nodes.len()];
+        removed[root_idx] = true;
+        for i in root_idx + 1..self.nodes.len() {
+            if let Some(parent) = self.nodes[i].parent_index {
+                removed[i] = removed[parent];
+            }
+        }
+        for (i, node) in self.nodes.iter().enumerate() {
+            if let (true, Some(parent)) = (removed[i], node.parent_index) {
+                stats.edge_removed(&self.nodes[parent].state, &node.state);
+            }
+        }
+
+        let mut new_indices = vec![None; self.nodes.len()];
+        let mut next_index = 0;
+        for (i, is_removed) in removed.iter().enumerate() {
+            if !is_removed {
+                new_indices[i] = Some(next_index);
+                next_index += 1;
+            }
+        }
+
+        let nodes = std::mem::take(&mut self.nodes);
+        let validated = std::mem::take(&mut self.validated);
+        for (i, (node, is_validated)) in nodes.into_iter().zip(validated).enumerate() {
+            if removed[i] {
+                continue;
+            }
+            self.nodes.push(Node {
+                state: node.state,
+                parent_index: node.parent_index.and_then(|p| new_indices[p]),
+            });
+            self.validated.push(is_validated);
+        }
+    }
+}
+
+/// An implementation of the Lazy RRT algorithm.
+///
+/// Lazy RRT grows a tree in the same way as `RRT`, but does not check any motions while doing so.
+/// Only when a node reaches the goal region are the edges leading to it checked. If one of them is
+/// invalid, the subtree hanging from that edge is removed and the search continues.
+///
+/// This trades extra (cheap) sampling for far fewer (expensive) validity checks, which pays off
+/// when the `StateValidityChecker` is costly to call, such as one implemented in Python.
+///
+/// # Trait Bounds
+///
+/// To use this planner, the following trait bounds must be met:
+/// - The `State` type (`S`) must be `Clone`.
+/// - The `Goal` type (`G`) must implement `GoalSampleableRegion` to support goal-biasing.
+pub struct LazyRRT<S: State, SP: StateSpace<StateType = S>, G: Goal<S>> {
+    /// The maximum distance between nodes in the tree. This is the "step size". If it is 0,
+    /// each call to `setup` uses a fifth of the maximum extent of the space.
+    pub max_distance: f64,
+    /// The probability of sampling the goal region instead of the whole space (e.g., 0.05 for 5%).
+    pub goal_bias: f64,
+
+    // The parameters above, with any that are 0 resolved by `setup`.
+    resolved_max_distance: f64,
+    problem_def: Option<Arc<ProblemDefinition<S, SP, G>>>,
+    validity_checker: Option<Arc<dyn StateValidityChecker<S>>>,
+    tree: LazyTree<S>,
+    rng: Option<Box<PlannerRng>>,
+    stats: Arc<StatsRecorder<S>>,
+}
+
+impl<S, SP, G> LazyRRT<S, SP, G>
+where
+    S: State + Clone,
+    SP: StateSpace<StateType = S>,
+    G: Goal<S>,
+{
+    /// Creates a new `LazyRRT` planner with the specified parameters.
+    ///
+    /// # Parameters
+    /// * `max_distance` - The maximum length of a single branch in the tree.
+    /// * `goal_bias` - The probability (0.0 to 1.0) of sampling the goal.
+    /// * `config` - The planner configuration, for planner-specific parameters.
+    pub fn new(max_distance: f64, goal_bias: f64, config: &PlannerConfig) -> Self {
+        let rng = config.seed.map(|s| Box::new(PlannerRng::seed_from_u64(s)));
+        LazyRRT {
+            max_distance,
+            goal_bias,
+            resolved_max_distance: max_distance,
+            problem_def: None,
+            validity_checker: None,
+            tree: LazyTree::default(),
+            rng,
+            stats: Arc::default(),
+        }
+    }
+}
+
+impl<S, SP, G> Planner<S, SP, G> for LazyRRT<S, SP, G>
+where
+    S: State + Clone,
+    SP: StateSpace<StateType = S>,
+    G: Goal<S> + GoalSampleableRegion<S>,
+{
+    fn setup(
+        &mut self,
+        problem_def: Arc<ProblemDefinition<S, SP, G>>,
+        validity_checker: Arc<dyn StateValidityChecker<S>>,
+    ) {
+        self.resolved_max_distance = resolve_max_distance(self.max_distance, &*problem_def.space);
+        self.tree.clear();
+        self.stats.reset();
+        self.tree
+            .push(problem_def.start_states[0].clone(), None, &self.stats);
+
+        self.problem_def = Some(problem_def);
+        self.validity_checker = Some(validity_checker);
+    }
+
+    fn solve(&mut self, timeout: Duration) -> Result<Path<S>, PlanningError> {
+        let pd = self
+            .problem_def
+            .as_ref()
+            .ok_or(PlanningError::PlannerUninitialised)?;
+        let vc = self
+            .validity_checker
+            .as_ref()
+            .ok_or(PlanningError::PlannerUninitialised)?;
+        let goal = &pd.goal;
+        let stats = &self.stats;
+        let vc = stats.checker(&**vc);
+
+        // The root is never reached by edge validation, so the start state is checked here.
+        if !vc.is_valid(&pd.start_states[0]) {
+            return Err(PlanningError::InvalidStartState);
+        }
+
+        let mut rng = self
+            .rng
+            .take()
+            .unwrap_or_else(|| Box::new(PlannerRng::from_os_rng()));
+        let start_time = Instant::now();
+
+        // Main Loop
+        let result = loop {
+            // 1. Check for timeout
+            if start_time.elapsed() > timeout {
+                break Err(PlanningError::Timeout);
+            }
+            stats.iteration();
+
+            // 2. Sample a state (q_rand)
+            let sample = stats.sample(|| {
+                if rng.random_bool(self.goal_bias) {
+                    goal.sample_goal(&mut rng)
+                } else {
+                    pd.space.sample_uniform(&mut rng)
+                }
+            });
+            let Ok(q_rand) = sample else {
+                stats.reject_sample(None);
+                continue;
+            };
+
+            // 3. Steer from the nearest node towards q_rand and add q_new without checking it.
+            let (nearest_node_index, q_new, _) = stats.nearest(|| {
+                steer(
+                    self.tree.nodes.iter().map(|n| &n.state),
+                    &q_rand,
+                    &*pd.space,
+                    self.resolved_max_distance,
+                )
+            });
+            let reached_goal = goal.is_satisfied(&q_new);
+            let new_node_index = self.tree.push(q_new, Some(nearest_node_index), stats);
+
+            // 4. If the goal was reached, validate the branch leading to it. Invalid edges are
+            //    pruned along with their subtrees.
+            if reached_goal
+                && self
+                    .tree
+                    .validate_branch(new_node_index, &*pd.space, &vc, stats)
+            {
+                let path = reconstruct_path(&self.tree.nodes, new_node_index);
+                stats.solution(&*pd.space, &path);
+                break Ok(path);
+            }
+        };
+        // Keep the random number generator, so a seeded planner stays reproducible across calls.
+        self.rng = Some(rng);
+        result
+    }
+
+    fn stats(&self) -> PlannerStats {
+        self.stats.snapshot()
+    }
+
+    fn set_observer(&mut self, observer: Arc<dyn PlannerObserver<S>>) {
+        stats::set_observer(&mut self.stats, observer);
+    }
+
+    fn params(&self) -> ParamSet {
+        ParamSet::default()
+            .with_real(
+                "max_distance",
+                "The maximum length of a motion. 0 uses a fifth of the space's maximum extent.",
+                self.max_distance,
+                0.0..=f64::INFINITY,
+            )
+            .with_real(
+                "goal_bias",
+                "The probability of sampling the goal region instead of the whole space.",
+                self.goal_bias,
+                0.0..=1.0,
+            )
+    }
+
+    fn set_params(&mut self, params: &ParamSet) {
+        if let Some(max_distance) = params.real("max_distance") {
+            self.max_distance = max_distance;
+        }
+        if let Some(goal_bias) = params.real("goal_bias") {
+            self.goal_bias = goal_bias;
+        }
+    }
+}
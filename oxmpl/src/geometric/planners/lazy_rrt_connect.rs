@@ -0,0 +1,294 @@
+// Copyright (c) 2025 Junior Sundar
+//
+// SPDX-License-Identifier: BSD-3-Clause
+
+use std::sync::Arc;
+
+use rand::{Rng, SeedableRng};
+
+use crate::{
+    base::{
+        error::PlanningError,
+        goal::{Goal, GoalSampleableRegion},
+        observer::PlannerObserver,
+        param::ParamSet,
+        planner::{Path, Planner, PlannerConfig, PlannerStats},
+        problem_definition::ProblemDefinition,
+        rng::PlannerRng,
+        space::StateSpace,
+        state::State,
+        validity::StateValidityChecker,
+    },
+    time::{Duration, Instant},
+};
+
+use super::{
+    common::reconstruct_path,
+    lazy_rrt::LazyTree,
+    rrt::resolve_max_distance,
+    rrt_connect::{steer, ExtendResult},
+    stats::{self, StatsRecorder},
+};
+
+/// The number of attempts made to sample a valid goal state for the root of the goal tree.
+const GOAL_ROOT_SAMPLE_ATTEMPTS: usize = 100;
+
+/// A lazy variant of the RRT-Connect algorithm.
+///
+/// Like `RRTConnect`, this grows one tree from the start state and one from a goal state and tries
+/// to connect them. Like `LazyRRT`, no motions are checked while the trees grow. Once the trees
+/// connect (or the start tree reaches the goal region directly), the edges along the candidate
+/// path are checked, starting from each root. Invalid edges are removed together with their
+/// subtrees and the search continues.
+///
+/// # Trait Bounds
+///
+/// To use this planner, the following trait bounds must be met:
+/// - The `State` type (`S`) must be `Clone`.
+/// - The `Goal` type (`G`) must implement `GoalSampleableRegion` to initialize the goal tree.
+pub struct LazyRRTConnect<S: State, SP: StateSpace<StateType = S>, G: Goal<S>> {
+    /// The maximum distance between nodes in the tree. This is the "step size". If it is 0,
+    /// each call to `setup` uses a fifth of the maximum extent of the space.
+    pub max_distance: f64,
+    /// The probability of sampling the goal region instead of the whole space (e.g., 0.05 for 5%).
+    pub goal_bias: f64,
+
+    // The parameters above, with any that are 0 resolved by `setup`.
+    resolved_max_distance: f64,
+    problem_def: Option<Arc<ProblemDefinition<S, SP, G>>>,
+    validity_checker: Option<Arc<dyn StateValidityChecker<S>>>,
+    start_tree: LazyTree<S>,
+    goal_tree: LazyTree<S>,
+    rng: Option<Box<PlannerRng>>,
+    stats: Arc<StatsRecorder<S>>,
+}
+
+impl<S, SP, G> LazyRRTConnect<S, SP, G>
+where
+    S: State + Clone,
+    SP: StateSpace<StateType = S>,
+    G: Goal<S> + GoalSampleableRegion<S>,
+{
+    /// Creates a new `LazyRRTConnect` planner with the specified parameters.
+    ///
+    /// # Parameters
+    /// * `max_distance` - The maximum length of a single branch in the tree.
+    /// * `goal_bias` - The probability (0.0 to 1.0) of sampling the goal.
+    /// * `config` - The planner configuration, for planner-specific parameters.
+    pub fn new(max_distance: f64, goal_bias: f64, config: &PlannerConfig) -> Self {
+        let rng = config.seed.map(|s| Box::new(PlannerRng::seed_from_u64(s)));
+        LazyRRTConnect {
+            max_distance,
+            goal_bias,
+            resolved_max_distance: max_distance,
+            problem_def: None,
+            validity_checker: None,
+            start_tree: LazyTree::default(),
+            goal_tree: LazyTree::default(),
+            rng,
+            stats: Arc::default(),
+        }
+    }
+}
+
+/// The main implementation of the Planner trait for LazyRRTConnect.
+impl<S, SP, G> Planner<S, SP, G> for LazyRRTConnect<S, SP, G>
+where
+    S: State + Clone,
+    SP: StateSpace<StateType = S>,
+    G: Goal<S> + GoalSampleableRegion<S>,
+{
+    fn setup(
+        &mut self,
+        problem_def: Arc<ProblemDefinition<S, SP, G>>,
+        validity_checker: Arc<dyn StateValidityChecker<S>>,
+    ) {
+        self.resolved_max_distance = resolve_max_distance(self.max_distance, &*problem_def.space);
+        self.start_tree.clear();
+        self.goal_tree.clear();
+        self.stats.reset();
+
+        // Initialise the trees beginning from start and goal states. The goal root is the one
+        // state that is checked eagerly, as no edge validation would ever cover it.
+        self.start_tree
+            .push(problem_def.start_states[0].clone(), None, &self.stats);
+
+        let mut rng = self
+            .rng
+            .take()
+            .unwrap_or_else(|| Box::new(PlannerRng::from_os_rng()));
+        let stats = &*self.stats;
+        let vc = stats.checker(&*validity_checker);
+        let goal_state = (0..GOAL_ROOT_SAMPLE_ATTEMPTS)
+            .filter_map(|_| stats.sample(|| problem_def.goal.sample_goal(&mut rng)).ok())
+            .find(|state| {
+                let valid = vc.is_valid(state);
+                if !valid {
+                    stats.reject_sample(Some(state));
+                }
+                valid
+            });
+        if let Some(goal_state) = goal_state {
+            self.goal_tree.push(goal_state, None, stats);
+        }
+        self.rng = Some(rng);
+
+        self.problem_def = Some(problem_def);
+        self.validity_checker = Some(validity_checker);
+    }
+
+    fn solve(&mut self, timeout: Duration) -> Result<Path<S>, PlanningError> {
+        let pd = self
+            .problem_def
+            .as_ref()
+            .ok_or(PlanningError::PlannerUninitialised)?;
+        let vc = self
+            .validity_checker
+            .as_ref()
+            .ok_or(PlanningError::PlannerUninitialised)?;
+        let goal = &pd.goal;
+        let space = &*pd.space;
+        let stats = &self.stats;
+        let vc = stats.checker(&**vc);
+
+        // The roots are never reached by edge validation. The start state is checked here, and
+        // the goal root was checked when `setup` sampled it.
+        if !vc.is_valid(&pd.start_states[0]) {
+            return Err(PlanningError::InvalidStartState);
+        }
+        if self.goal_tree.nodes.is_empty() {
+            return Err(PlanningError::NoSolutionFound);
+        }
+
+        let mut rng = self
+            .rng
+            .take()
+            .unwrap_or_else(|| Box::new(PlannerRng::from_os_rng()));
+        let start_time = Instant::now();
+
+        // Main loop
+        let result = loop {
+            // 1. Check for timeout
+            if start_time.elapsed() > timeout {
+                break Err(PlanningError::Timeout);
+            }
+            stats.iteration();
+
+            // 2. Determine which tree to grow (tree_a) and which to connect to (tree_b).
+            let is_growing_start_tree = self.start_tree.nodes.len() <= self.goal_tree.nodes.len();
+            let (tree_a, tree_b) = if is_growing_start_tree {
+                (&mut self.start_tree, &mut self.goal_tree)
+            } else {
+                (&mut self.goal_tree, &mut self.start_tree)
+            };
+
+            // 3. Sample a random target state `q_rand`, with goal biasing.
+            let sample = stats.sample(|| {
+                if rng.random_bool(self.goal_bias) {
+                    goal.sample_goal(&mut rng)
+                } else {
+                    space.sample_uniform(&mut rng)
+                }
+            });
+            let Ok(q_rand) = sample else {
+                stats.reject_sample(None);
+                continue;
+            };
+
+            // 4. Extend tree_a towards q_rand without checking the motion.
+            let (nearest_a, q_new, _) = stats.nearest(|| {
+                steer(
+                    tree_a.nodes.iter().map(|n| &n.state),
+                    &q_rand,
+                    space,
+                    self.resolved_max_distance,
+                )
+            });
+            let new_node_idx_a = tree_a.push(q_new.clone(), Some(nearest_a), stats);
+
+            // If growing the start tree, check if the new node is already in the goal.
+            if is_growing_start_tree && goal.is_satisfied(&q_new) {
+                if tree_a.validate_branch(new_node_idx_a, space, &vc, stats) {
+                    let path = reconstruct_path(&tree_a.nodes, new_node_idx_a);
+                    stats.solution(space, &path);
+                    break Ok(path);
+                }
+                continue;
+            }
+
+            // 5. Try to connect tree_b to the new state `q_new`, again without checking.
+            let (nearest_b, q_connect, connect_result) = stats.nearest(|| {
+                steer(
+                    tree_b.nodes.iter().map(|n| &n.state),
+                    &q_new,
+                    space,
+                    self.resolved_max_distance,
+                )
+            });
+            let new_node_idx_b = tree_b.push(q_connect, Some(nearest_b), stats);
+            if connect_result != ExtendResult::Reached {
+                continue;
+            }
+
+            // 6. The trees have met. Validate the candidate path from both roots, pruning any
+            //    invalid subtrees found along the way.
+            if !tree_a.validate_branch(new_node_idx_a, space, &vc, stats)
+                || !tree_b.validate_branch(new_node_idx_b, space, &vc, stats)
+            {
+                continue;
+            }
+
+            let (start_idx, goal_idx) = if is_growing_start_tree {
+                (new_node_idx_a, new_node_idx_b)
+            } else {
+                (new_node_idx_b, new_node_idx_a)
+            };
+
+            // 7. Reconstruct the path from both trees and merge them.
+            let mut start_path = reconstruct_path(&self.start_tree.nodes, start_idx).0;
+            let mut goal_path = reconstruct_path(&self.goal_tree.nodes, goal_idx).0;
+            goal_path.reverse();
+            start_path.extend(goal_path.into_iter().skip(1));
+
+            let path = Path(start_path);
+            stats.solution(space, &path);
+            break Ok(path);
+        };
+        // Keep the random number generator, so a seeded planner stays reproducible across calls.
+        self.rng = Some(rng);
+        result
+    }
+
+    fn stats(&self) -> PlannerStats {
+        self.stats.snapshot()
+    }
+
+    fn set_observer(&mut self, observer: Arc<dyn PlannerObserver<S>>) {
+        stats::set_observer(&mut self.stats, observer);
+    }
+
+    fn params(&self) -> ParamSet {
+        ParamSet::default()
+            .with_real(
+                "max_distance",
+                "The maximum length of a motion. 0 uses a fifth of the space's maximum extent.",
+                self.max_distance,
+                0.0..=f64::INFINITY,
+            )
+            .with_real(
+                "goal_bias",
+                "The probability of sampling the goal region instead of the whole space.",
+                self.goal_bias,
+                0.0..=1.0,
+            )
+    }
+
+    fn set_params(&mut self, params: &ParamSet) {
+        if let Some(max_distance) = params.real("max_distance") {
+            self.max_distance = max_distance;
+        }
+        if let Some(goal_bias) = params.real("goal_bias") {
+            self.goal_bias = goal_bias;
+        }
+    }
+}
@@ -2,7 +2,18 @@
 //
 // SPDX-License-Identifier: BSD-3-Clause
 
+pub mod bi_rrt_star;
+pub mod cforest;
+pub mod lazy_rrt;
+pub mod lazy_rrt_connect;
 pub mod prm;
 pub mod rrt;
 pub mod rrt_connect;
 pub mod rrt_star;
+pub mod rrtx;
+pub mod spars;
+pub mod spars2;
+
+pub(crate) mod common;
+mod stats;
+mod union_find;
@@ -3,31 +3,77 @@
 // SPDX-License-Identifier: BSD-3-Clause
 
 use std::{
-    collections::{HashMap, VecDeque},
+    collections::{HashMap, HashSet, VecDeque},
     sync::Arc,
+    thread,
 };
 
-use rand::{rngs::StdRng, SeedableRng};
+use rand::{Rng, SeedableRng};
 
 use crate::time::{Duration, Instant};
 
 use crate::base::{
     error::PlanningError,
     goal::{Goal, GoalSampleableRegion},
-    planner::{Path, Planner, PlannerConfig},
+    observer::PlannerObserver,
+    param::ParamSet,
+    planner::{Path, Planner, PlannerConfig, PlannerStats},
     problem_definition::ProblemDefinition,
+    rng::PlannerRng,
     space::StateSpace,
     state::State,
     validity::StateValidityChecker,
 };
 
+use super::{
+    common::reconstruct_path,
+    rrt::resolve_distance,
+    stats::{self, StatsRecorder},
+    union_find::UnionFind,
+};
+
+/// The phase in which the roadmap is constructed, which includes its sampling, nearest-neighbour
+/// queries and motion checks.
+const ROADMAP_CONSTRUCTION: &str = "roadmap construction";
+
+/// The number of samples each thread processes per round of parallel roadmap construction.
+const SAMPLES_PER_THREAD: usize = 8;
+
+/// The fraction of the space's maximum extent used as the default `connection_radius`.
+const DEFAULT_CONNECTION_RADIUS_FRACTION: f64 = 0.1;
+
+/// The parent of every roadmap node visited by a graph search, or `None` for the nodes it started
+/// from.
+type ParentMap = HashMap<usize, Option<usize>>;
+
+/// A roadmap node that a new sample can connect to during parallel construction.
+#[derive(Clone, Copy)]
+enum Neighbour {
+    /// A node already in the roadmap, by index.
+    Roadmap(usize),
+    /// An earlier sample from the same round, by its index in the round.
+    Batch(usize),
+}
+
 /// Represents a node (or "milestone") in the probabilistic roadmap.
 #[derive(Clone)]
 pub struct Node<S: State> {
     /// The state associated with this node.
-    state: S,
+    pub(crate) state: S,
     /// A list of indices pointing to other connected nodes in the roadmap.
-    edges: Vec<usize>,
+    pub(crate) edges: Vec<usize>,
+}
+
+impl<S: State> Node<S> {
+    /// Returns the state associated with this node.
+    pub fn state(&self) -> &S {
+        &self.state
+    }
+
+    /// Returns the indices of the nodes this node is connected to.
+    pub fn edges(&self) -> &[usize] {
+        &self.edges
+    }
 }
 
 /// An implementation of the Probabilistic Roadmap (PRM) algorithm.
@@ -44,19 +90,62 @@ pub struct Node<S: State> {
 ///     c. If a valid, collision-free motion exists between the new sample and a neighbor, add an
 ///     edge connecting them in the roadmap.
 /// 2.  **Query Phase**:
-///     a. Connect the start and goal states to the roadmap.
+///     a. Connect the start state to the roadmap.
 ///     b. Use a graph search algorithm (in this case, Breadth-First Search) to find a path on the
-///     roadmap from the start to the goal.
+///     roadmap from the start to a node in the goal region.
+///     c. If no such node is reachable, keep sampling states from the goal region and adding
+///     them to the roadmap until one connects to the start or the timeout is reached.
+///
+/// Construction also draws a fraction (`goal_bias`) of its samples from the goal region, so that
+/// small goal regions are represented in the roadmap.
+///
+/// # Parallel Construction
+///
+/// If `num_threads` is greater than 1, the roadmap is built in rounds. Each round draws a batch of
+/// samples (including goal samples) from the planner's random number generator, and the samples
+/// are checked for validity and connected to their neighbours on `num_threads` threads. Each
+/// sample is connected to the nodes within `connection_radius` that it has a valid motion to,
+/// including the earlier samples of its round, but only to one node of each connected component:
+/// motions to roadmap nodes in a component the sample already reaches are not checked. The new
+/// nodes are then merged into the roadmap in the order they were sampled, and an edge is only
+/// added if it joins two components, so the roadmap is a forest.
+///
+/// As no result depends on how the threads are scheduled, a seeded planner always builds the same
+/// roadmap for a given number of threads; a roadmap built in less time is a prefix of one built in
+/// more time.
+///
+/// # Connected Components
+///
+/// A union-find of the connected components of the roadmap is kept as it is built. `solve` uses it
+/// to connect the start state to only one node of each component, and to go straight to sampling
+/// goal states when no goal node shares a component with the start state.
+///
+/// # Reusing the Roadmap
+///
+/// Calling `setup` again keeps the roadmap, as long as the space and validity checker are the same
+/// `Arc`s as before, so it can answer queries with new start states and goals. Otherwise the
+/// roadmap is discarded. Call `clear` to discard it explicitly.
 pub struct PRM<S: State, SP: StateSpace<StateType = S>, G: Goal<S>> {
     /// The time allocated for roadmap construction, in seconds.
     pub timeout: f64,
-    /// The radius within which to search for neighbors to connect to a new sample.
+    /// The radius within which to search for neighbors to connect to a new sample. If it is 0,
+    /// each call to `setup` uses a tenth of the maximum extent of the space.
     pub connection_radius: f64,
-
+    /// The probability of drawing a construction sample from the goal region instead of the whole
+    /// space (e.g., 0.05 for 5%). Defaults to 0.05.
+    pub goal_bias: f64,
+    /// The number of threads used to construct the roadmap. With 1 (the default), the roadmap is
+    /// built sequentially.
+    pub num_threads: usize,
+
+    // The parameters above, with any that are 0 resolved by `setup`.
+    resolved_connection_radius: f64,
     problem_def: Option<Arc<ProblemDefinition<S, SP, G>>>,
     validity_checker: Option<Arc<dyn StateValidityChecker<S>>>,
     roadmap: Vec<Node<S>>,
-    rng: Option<Box<StdRng>>,
+    components: UnionFind,
+    rng: Option<Box<PlannerRng>>,
+    stats: Arc<StatsRecorder<S>>,
 }
 
 impl<S, SP, G> PRM<S, SP, G>
@@ -72,17 +161,30 @@ where
     /// * `connection_radius` - The radius for connecting new nodes to the roadmap.
     /// * `config` - The planner configuration, for planner-specific parameters.
     pub fn new(timeout: f64, connection_radius: f64, config: &PlannerConfig) -> Self {
-        let rng = config.seed.map(|s| Box::new(StdRng::seed_from_u64(s)));
+        let rng = config.seed.map(|s| Box::new(PlannerRng::seed_from_u64(s)));
         PRM {
             timeout,
             connection_radius,
+            goal_bias: 0.05,
+            num_threads: 1,
+            resolved_connection_radius: connection_radius,
             problem_def: None,
             validity_checker: None,
             roadmap: Vec::new(),
+            components: UnionFind::default(),
             rng,
+            stats: Arc::default(),
         }
     }
 
+    /// Discards the roadmap, so it is built again by the next call to `construct_roadmap`.
+    ///
+    /// The problem definition and validity checker are kept.
+    pub fn clear(&mut self) {
+        self.roadmap.clear();
+        self.components.clear();
+    }
+
     /// Get private variable `roadmap` as a clone.
     /// TODO: Determine if this needs to be obtainable.
     pub fn get_roadmap(&self) -> Vec<Node<S>> {
@@ -95,6 +197,107 @@ where
         self.problem_def = Some(pd);
     }
 
+    /// Returns the indices of the roadmap nodes within `connection_radius` of `state`.
+    fn near_nodes(&self, state: &S, space: &SP) -> Vec<usize> {
+        self.stats.nearest(|| {
+            (0..self.roadmap.len())
+                .filter(|&i| {
+                    space.distance(state, &self.roadmap[i].state) < self.resolved_connection_radius
+                })
+                .collect()
+        })
+    }
+
+    /// Adds a valid state to the roadmap, connecting it to every node within `connection_radius`
+    /// that it has a valid motion to. Returns the index of the new node.
+    fn add_milestone(&mut self, state: S, space: &SP, vc: &dyn StateValidityChecker<S>) -> usize {
+        let edges: Vec<usize> = self
+            .near_nodes(&state, space)
+            .into_iter()
+            .filter(|&i| {
+                self.stats
+                    .check_motion(space, vc, &state, &self.roadmap[i].state)
+            })
+            .collect();
+
+        let new_node_idx = self.components.make_set();
+        self.stats.node_added(&state, None);
+        for &i in &edges {
+            self.stats.edge_added(&state, &self.roadmap[i].state);
+            self.roadmap[i].edges.push(new_node_idx);
+            self.components.union(new_node_idx, i);
+        }
+        self.roadmap.push(Node { state, edges });
+        new_node_idx
+    }
+
+    /// Searches the roadmap breadth-first from the `start_connections` nodes for a node that
+    /// satisfies `is_target`. Returns that node and the parent of every node visited, or `None` if
+    /// no such node is reachable.
+    ///
+    /// # Errors
+    /// Returns `PlanningError::Timeout` if the search runs past `timeout`, measured from
+    /// `start_time`.
+    fn search_roadmap(
+        &self,
+        start_connections: &[usize],
+        is_target: impl Fn(usize) -> bool,
+        start_time: Instant,
+        timeout: Duration,
+    ) -> Result<Option<(usize, ParentMap)>, PlanningError> {
+        let mut queue: VecDeque<usize> = VecDeque::new();
+        let mut parent_map = ParentMap::new();
+        let mut visited = vec![false; self.roadmap.len()];
+
+        for &idx in start_connections {
+            queue.push_back(idx);
+            parent_map.insert(idx, None);
+            visited[idx] = true;
+        }
+
+        while let Some(current_idx) = queue.pop_front() {
+            if start_time.elapsed() > timeout {
+                return Err(PlanningError::Timeout);
+            }
+
+            if is_target(current_idx) {
+                return Ok(Some((current_idx, parent_map)));
+            }
+
+            for &neighbor_idx in &self.roadmap[current_idx].edges {
+                if !visited[neighbor_idx] {
+                    visited[neighbor_idx] = true;
+                    parent_map.insert(neighbor_idx, Some(current_idx));
+                    queue.push_back(neighbor_idx);
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Returns the path from `start_state` to the roadmap node `goal_idx`, following the parents
+    /// recorded by the graph search.
+    fn search_path(&self, start_state: &S, parent_map: &ParentMap, goal_idx: usize) -> Path<S> {
+        let search_tree: Vec<_> = self
+            .roadmap
+            .iter()
+            .enumerate()
+            .map(|(i, node)| (&node.state, parent_map.get(&i).copied().flatten()))
+            .collect();
+
+        let mut path = vec![start_state.clone()];
+        path.extend(reconstruct_path(&search_tree, goal_idx).0);
+        Path(path)
+    }
+}
+
+impl<S, SP, G> PRM<S, SP, G>
+where
+    S: State + Clone,
+    SP: StateSpace<StateType = S>,
+    G: Goal<S> + GoalSampleableRegion<S>,
+{
     /// Constructs the probabilistic roadmap.
     ///
     /// This method populates the roadmap by sampling states and connecting them until the
@@ -102,15 +305,15 @@ where
     pub fn construct_roadmap(&mut self) -> Result<(), PlanningError> {
         let pd = self
             .problem_def
-            .as_ref()
+            .clone()
             .ok_or(PlanningError::PlannerUninitialised)?;
         let vc = self
             .validity_checker
-            .as_ref()
+            .clone()
             .ok_or(PlanningError::PlannerUninitialised)?;
 
         if !self.roadmap.is_empty() {
-            println!(
+            log::info!(
                 "PRM: Roadmap already constructed with {} milestones.",
                 self.roadmap.len()
             );
@@ -121,40 +324,35 @@ where
         let mut rng = self
             .rng
             .take()
-            .unwrap_or_else(|| Box::new(StdRng::from_os_rng()));
+            .unwrap_or_else(|| Box::new(PlannerRng::from_os_rng()));
+        let stats = self.stats.clone();
+        let vc = stats.checker(&*vc);
         let start_time = Instant::now();
-        loop {
-            if start_time.elapsed().as_secs_f64() > self.timeout {
-                break;
-            }
-
-            let q_rand = pd.space.sample_uniform(&mut *rng).unwrap();
-            if vc.is_valid(&q_rand) {
-                let mut new_node = Node {
-                    state: q_rand.clone(),
-                    edges: Vec::new(),
-                };
-
-                let mut to_update: Vec<usize> = Vec::new();
-
-                for i in 0..self.roadmap.len() {
-                    let other_state = self.roadmap[i].state.clone();
-                    let dist = pd.space.distance(&q_rand, &other_state);
-                    if dist < self.connection_radius && self.check_motion(&q_rand, &other_state) {
-                        new_node.edges.push(i);
-                        to_update.push(i);
+        stats.time(ROADMAP_CONSTRUCTION, || {
+            if self.num_threads > 1 {
+                self.grow_roadmap_in_parallel(&pd, &vc, &mut rng, start_time);
+            } else {
+                while start_time.elapsed().as_secs_f64() <= self.timeout {
+                    stats.iteration();
+                    let sample = stats.sample(|| {
+                        if rng.random_bool(self.goal_bias) {
+                            pd.goal.sample_goal(&mut rng)
+                        } else {
+                            pd.space.sample_uniform(&mut rng)
+                        }
+                    });
+                    match sample {
+                        Ok(q_rand) if vc.is_valid(&q_rand) => {
+                            self.add_milestone(q_rand, &pd.space, &vc);
+                        }
+                        Ok(q_rand) => stats.reject_sample(Some(&q_rand)),
+                        Err(_) => stats.reject_sample(None),
                     }
                 }
-
-                let new_node_idx = self.roadmap.len();
-                self.roadmap.push(new_node);
-
-                for i in to_update {
-                    self.roadmap[i].edges.push(new_node_idx);
-                }
             }
-        }
-        println!(
+        });
+        self.rng = Some(rng);
+        log::info!(
             "PRM: Roadmap constructed with {} milestones.",
             self.roadmap.len()
         );
@@ -162,58 +360,106 @@ where
         Ok(())
     }
 
-    /// An internal helper function to check if the motion between two states is valid.
-    ///
-    /// It works by discretizing the straight-line path between `from` and `to` into small steps
-    /// and calling the `StateValidityChecker` on each intermediate state. If any intermediate
-    /// state is invalid, the entire motion is considered invalid.
-    fn check_motion(&self, from: &S, to: &S) -> bool {
-        // We need access to the space and checker from our stored setup info.
-        if let (Some(pd), Some(vc)) = (&self.problem_def, &self.validity_checker) {
-            let space = &pd.space;
-
-            let dist = space.distance(from, to);
-            let num_steps =
-                (dist / (space.get_longest_valid_segment_length() * 0.1)).ceil() as usize;
-
-            if num_steps <= 1 {
-                return vc.is_valid(to);
-            }
-
-            let mut interpolated_state = from.clone();
-            for i in 1..=num_steps {
-                let t = i as f64 / num_steps as f64;
-                space.interpolate(from, to, t, &mut interpolated_state);
-                if !vc.is_valid(&interpolated_state) {
-                    return false;
+    /// Builds the roadmap in rounds until the timeout, fanning out validity and motion checks
+    /// across `num_threads` threads and merging the results in sample order.
+    fn grow_roadmap_in_parallel(
+        &mut self,
+        pd: &ProblemDefinition<S, SP, G>,
+        vc: &dyn StateValidityChecker<S>,
+        rng: &mut PlannerRng,
+        start_time: Instant,
+    ) {
+        let space = &*pd.space;
+        let stats = &*self.stats;
+        let batch_size = self.num_threads * SAMPLES_PER_THREAD;
+
+        while start_time.elapsed().as_secs_f64() <= self.timeout {
+            // 1. Draw the samples for this round sequentially, so they only depend on the seed.
+            let samples: Vec<S> = (0..batch_size)
+                .filter_map(|_| {
+                    stats.iteration();
+                    let sample = stats.sample(|| {
+                        if rng.random_bool(self.goal_bias) {
+                            pd.goal.sample_goal(rng).ok()
+                        } else {
+                            space.sample_uniform(rng).ok()
+                        }
+                    });
+                    if sample.is_none() {
+                        stats.reject_sample(None);
+                    }
+                    sample
+                })
+                .collect();
+
+            // 2. Check the samples and find their connections in parallel, against the roadmap as
+            //    it was at the start of the round.
+            let (roadmap, samples_ref) = (&self.roadmap, &samples);
+            let components: Vec<usize> = (0..roadmap.len())
+                .map(|i| self.components.find(i))
+                .collect();
+            let components = &components;
+            let radius = self.resolved_connection_radius;
+            let chunk_size = samples.len().div_ceil(self.num_threads).max(1);
+            let connections: Vec<Option<Vec<Neighbour>>> = thread::scope(|scope| {
+                let handles: Vec<_> = (0..samples.len())
+                    .step_by(chunk_size)
+                    .map(|first| {
+                        scope.spawn(move || {
+                            (first..(first + chunk_size).min(samples_ref.len()))
+                                .map(|j| {
+                                    find_connections(
+                                        &samples_ref[..=j],
+                                        roadmap,
+                                        components,
+                                        space,
+                                        vc,
+                                        stats,
+                                        radius,
+                                    )
+                                })
+                                .collect::<Vec<_>>()
+                        })
+                    })
+                    .collect();
+                handles
+                    .into_iter()
+                    .flat_map(|handle| handle.join().unwrap())
+                    .collect()
+            });
+
+            // 3. Merge the valid samples into the roadmap in order, with the edges that join two
+            //    components.
+            let mut batch_indices = vec![None; samples.len()];
+            for (j, (sample, connections)) in samples.into_iter().zip(connections).enumerate() {
+                let Some(connections) = connections else {
+                    continue;
+                };
+                let new_node_idx = self.components.make_set();
+                stats.node_added(&sample, None);
+                self.roadmap.push(Node {
+                    state: sample,
+                    edges: Vec::new(),
+                });
+                batch_indices[j] = Some(new_node_idx);
+
+                for neighbour in connections {
+                    let other = match neighbour {
+                        Neighbour::Roadmap(i) => Some(i),
+                        Neighbour::Batch(k) => batch_indices[k],
+                    };
+                    if let Some(other) = other {
+                        if !self.components.union(new_node_idx, other) {
+                            continue;
+                        }
+                        let roadmap = &self.roadmap;
+                        stats.edge_added(&roadmap[new_node_idx].state, &roadmap[other].state);
+                        self.roadmap[new_node_idx].edges.push(other);
+                        self.roadmap[other].edges.push(new_node_idx);
+                    }
                 }
             }
-
-            true
-        } else {
-            false
-        }
-    }
-
-    fn reconstruct_path(
-        &self,
-        start_state: &S,
-        parent_map: HashMap<usize, Option<usize>>,
-        goal_idx: usize,
-    ) -> Path<S> {
-        let mut path = vec![start_state.clone()];
-        let mut current = goal_idx;
-        let mut states = Vec::new();
-
-        while let Some(parent) = parent_map[&current] {
-            states.push(self.roadmap[current].state.clone());
-            current = parent;
         }
-        states.push(self.roadmap[current].state.clone());
-        states.reverse();
-        path.extend(states);
-
-        Path(path)
     }
 }
 
@@ -228,22 +474,41 @@ where
         problem_def: Arc<ProblemDefinition<S, SP, G>>,
         validity_checker: Arc<dyn StateValidityChecker<S>>,
     ) {
+        self.resolved_connection_radius = resolve_distance(
+            self.connection_radius,
+            DEFAULT_CONNECTION_RADIUS_FRACTION,
+            &*problem_def.space,
+        );
+        // The roadmap only depends on the space and the validity checker.
+        let same_space = self
+            .problem_def
+            .as_ref()
+            .is_some_and(|pd| Arc::ptr_eq(&pd.space, &problem_def.space));
+        let same_checker = self
+            .validity_checker
+            .as_ref()
+            .is_some_and(|vc| Arc::ptr_eq(vc, &validity_checker));
+        if !(same_space && same_checker) {
+            self.clear();
+        }
         self.problem_def = Some(problem_def);
         self.validity_checker = Some(validity_checker);
-        self.roadmap.clear();
+        self.stats.reset();
     }
 
     fn solve(&mut self, timeout: Duration) -> Result<Path<S>, PlanningError> {
         // Ensure setup has been called.
         let pd = self
             .problem_def
-            .as_ref()
+            .clone()
             .ok_or(PlanningError::PlannerUninitialised)?;
         let vc = self
             .validity_checker
-            .as_ref()
+            .clone()
             .ok_or(PlanningError::PlannerUninitialised)?;
         let goal = &pd.goal;
+        let stats = self.stats.clone();
+        let vc = stats.checker(&*vc);
 
         if self.roadmap.is_empty() {
             return Err(PlanningError::UnsampledStateSpace);
@@ -254,12 +519,23 @@ where
             return Err(PlanningError::InvalidStartState);
         }
 
-        // Connect start state to the roadmap
-        let mut start_connections = Vec::new();
-        for i in 0..self.roadmap.len() {
-            if pd.space.distance(start_state, &self.roadmap[i].state) < self.connection_radius
-                && self.check_motion(start_state, &self.roadmap[i].state)
+        // Connect the start state to the roadmap, trying the nearest nodes first and skipping
+        // components it is already connected to.
+        let mut near = self.near_nodes(start_state, &pd.space);
+        near.sort_by(|&i, &j| {
+            let dist_i = pd.space.distance(start_state, &self.roadmap[i].state);
+            let dist_j = pd.space.distance(start_state, &self.roadmap[j].state);
+            dist_i.total_cmp(&dist_j)
+        });
+        let mut start_connections: Vec<usize> = Vec::new();
+        for i in near {
+            if start_connections
+                .iter()
+                .any(|&j| self.components.same_set(i, j))
             {
+                continue;
+            }
+            if stats.check_motion(&*pd.space, &vc, start_state, &self.roadmap[i].state) {
                 start_connections.push(i);
             }
         }
@@ -272,46 +548,189 @@ where
             }
         }
 
-        if start_connections.is_empty() || goal_indices.is_empty() {
-            return Err(PlanningError::NoSolutionFound);
+        // Only search the roadmap if a goal node is in a component the start state connects to.
+        let start_time = Instant::now();
+        let goal_reachable = goal_indices.iter().any(|&g| {
+            start_connections
+                .iter()
+                .any(|&i| self.components.same_set(g, i))
+        });
+        if goal_reachable {
+            if let Some((goal_node_idx, parent_map)) = self.search_roadmap(
+                &start_connections,
+                |i| goal_indices.contains(&i),
+                start_time,
+                timeout,
+            )? {
+                let path = self.search_path(start_state, &parent_map, goal_node_idx);
+                stats.solution(&*pd.space, &path);
+                return Ok(path);
+            }
         }
 
-        // Graph Search (Breadth-First Search)
-        let mut queue: VecDeque<usize> = start_connections.clone().into_iter().collect();
-        let mut parent_map: HashMap<usize, Option<usize>> = HashMap::new();
-        let mut visited = vec![false; self.roadmap.len()];
-
-        for idx in &start_connections {
-            queue.push_back(*idx);
-            parent_map.insert(*idx, None);
-            visited[*idx] = true;
+        // No goal node is reachable from the start. Keep adding goal samples to the roadmap until
+        // one joins a component the start state connects to, or connects directly to the start.
+        let mut rng = self
+            .rng
+            .take()
+            .unwrap_or_else(|| Box::new(PlannerRng::from_os_rng()));
+        let mut result = Err(PlanningError::NoSolutionFound);
+        while start_time.elapsed() <= timeout {
+            stats.iteration();
+            let goal_state = match stats.sample(|| goal.sample_goal(&mut rng)) {
+                Ok(goal_state) if vc.is_valid(&goal_state) => goal_state,
+                Ok(goal_state) => {
+                    stats.reject_sample(Some(&goal_state));
+                    continue;
+                }
+                Err(_) => {
+                    stats.reject_sample(None);
+                    continue;
+                }
+            };
+
+            let new_node_idx = self.add_milestone(goal_state, &pd.space, &vc);
+            let joins_start = start_connections
+                .iter()
+                .any(|&i| self.components.same_set(i, new_node_idx));
+            let goal_state = &self.roadmap[new_node_idx].state;
+            if joins_start {
+                result = self
+                    .search_roadmap(
+                        &start_connections,
+                        |i| i == new_node_idx,
+                        start_time,
+                        timeout,
+                    )
+                    .and_then(|found| found.ok_or(PlanningError::NoSolutionFound))
+                    .map(|(_, parent_map)| {
+                        self.search_path(start_state, &parent_map, new_node_idx)
+                    });
+            } else if pd.space.distance(start_state, goal_state) < self.resolved_connection_radius
+                && stats.check_motion(&*pd.space, &vc, start_state, goal_state)
+            {
+                result = Ok(Path(vec![start_state.clone(), goal_state.clone()]));
+            } else {
+                continue;
+            }
+            if let Ok(path) = &result {
+                stats.solution(&*pd.space, path);
+            }
+            break;
         }
+        self.rng = Some(rng);
 
-        let mut goal_reached = None;
+        result
+    }
 
-        let start_time = Instant::now();
-        while let Some(current_idx) = queue.pop_front() {
-            if start_time.elapsed() > timeout {
-                return Err(PlanningError::Timeout);
-            }
+    fn stats(&self) -> PlannerStats {
+        self.stats.snapshot()
+    }
 
-            if goal_indices.contains(&current_idx) {
-                goal_reached = Some(current_idx);
-                break;
-            }
+    fn set_observer(&mut self, observer: Arc<dyn PlannerObserver<S>>) {
+        stats::set_observer(&mut self.stats, observer);
+    }
 
-            for &neighbor_idx in &self.roadmap[current_idx].edges {
-                if !visited[neighbor_idx] {
-                    visited[neighbor_idx] = true;
-                    parent_map.insert(neighbor_idx, Some(current_idx));
-                    queue.push_back(neighbor_idx);
-                }
-            }
-        }
+    fn params(&self) -> ParamSet {
+        ParamSet::default()
+            .with_real(
+                "timeout",
+                "The time allocated for roadmap construction, in seconds.",
+                self.timeout,
+                0.0..=f64::INFINITY,
+            )
+            .with_real(
+                "connection_radius",
+                "The radius for connecting samples. 0 uses a tenth of the space's maximum extent.",
+                self.connection_radius,
+                0.0..=f64::INFINITY,
+            )
+            .with_real(
+                "goal_bias",
+                "The probability of drawing a construction sample from the goal region.",
+                self.goal_bias,
+                0.0..=1.0,
+            )
+            .with_integer(
+                "num_threads",
+                "The number of threads used to construct the roadmap.",
+                self.num_threads,
+                1.0..=f64::INFINITY,
+            )
+    }
 
-        // If no goal was reached, no path exists
-        let goal_node_idx = goal_reached.ok_or(PlanningError::NoSolutionFound)?;
+    fn set_params(&mut self, params: &ParamSet) {
+        if let Some(timeout) = params.real("timeout") {
+            self.timeout = timeout;
+        }
+        if let Some(connection_radius) = params.real("connection_radius") {
+            self.connection_radius = connection_radius;
+        }
+        if let Some(goal_bias) = params.real("goal_bias") {
+            self.goal_bias = goal_bias;
+        }
+        if let Some(num_threads) = params.integer("num_threads") {
+            self.num_threads = num_threads;
+        }
+    }
+}
 
-        Ok(self.reconstruct_path(start_state, parent_map, goal_node_idx))
+/// Checks the last of `samples`, the samples of a round drawn so far, and finds the nodes it should
+/// connect to.
+///
+/// Returns `None` if the sample is invalid. Otherwise returns, nearest first, the roadmap nodes
+/// and earlier samples of the round within `connection_radius` that can be reached with a valid
+/// motion. `components` holds the component of each roadmap node, and only the nearest reachable
+/// node of each component is returned.
+fn find_connections<S, SP>(
+    samples: &[S],
+    roadmap: &[Node<S>],
+    components: &[usize],
+    space: &SP,
+    vc: &dyn StateValidityChecker<S>,
+    stats: &StatsRecorder<S>,
+    connection_radius: f64,
+) -> Option<Vec<Neighbour>>
+where
+    S: State + Clone,
+    SP: StateSpace<StateType = S>,
+{
+    let (q, earlier) = samples.split_last()?;
+    if !vc.is_valid(q) {
+        stats.reject_sample(Some(q));
+        return None;
     }
+
+    let candidates = stats.nearest(|| {
+        let roadmap_candidates = roadmap
+            .iter()
+            .enumerate()
+            .map(|(i, node)| (space.distance(q, &node.state), Neighbour::Roadmap(i)));
+        let batch_candidates = earlier
+            .iter()
+            .enumerate()
+            .map(|(k, other)| (space.distance(q, other), Neighbour::Batch(k)));
+        let mut candidates: Vec<(f64, Neighbour)> = roadmap_candidates
+            .chain(batch_candidates)
+            .filter(|(dist, _)| *dist < connection_radius)
+            .collect();
+        candidates.sort_by(|a, b| a.0.total_cmp(&b.0));
+        candidates
+    });
+
+    let mut reached = HashSet::new();
+    let connections = candidates
+        .into_iter()
+        .map(|(_, neighbour)| neighbour)
+        .filter(|neighbour| match *neighbour {
+            Neighbour::Roadmap(i) => {
+                !reached.contains(&components[i])
+                    && stats.check_motion(space, vc, q, &roadmap[i].state)
+                    && reached.insert(components[i])
+            }
+            Neighbour::Batch(k) => stats.check_motion(space, vc, q, &samples[k]),
+        })
+        .collect();
+
+    Some(connections)
 }
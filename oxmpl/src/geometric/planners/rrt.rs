@@ -2,16 +2,27 @@
 //
 // SPDX-License-Identifier: BSD-3-Clause
 
-use std::sync::Arc;
+use std::{
+    mem,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc::{self, TrySendError},
+        Arc, Mutex, RwLock,
+    },
+    thread,
+};
 
-use rand::{rngs::StdRng, Rng, SeedableRng};
+use rand::{Rng, SeedableRng};
 
 use crate::{
     base::{
         error::PlanningError,
         goal::{Goal, GoalSampleableRegion},
-        planner::{Path, Planner, PlannerConfig},
+        observer::PlannerObserver,
+        param::ParamSet,
+        planner::{Path, Planner, PlannerConfig, PlannerStats},
         problem_definition::ProblemDefinition,
+        rng::PlannerRng,
         space::StateSpace,
         state::State,
         validity::StateValidityChecker,
@@ -19,12 +30,83 @@ use crate::{
     time::{Duration, Instant},
 };
 
+use super::{
+    common::{reconstruct_path, TreeNode},
+    rrt_connect::steer,
+    stats::{self, StatsRecorder},
+};
+
 // A helper struct to build the tree. Each node stores its state and the index of its parent in the
 // tree vector.
 #[derive(Clone)]
-struct Node<S: State> {
-    state: S,
-    parent_index: Option<usize>,
+pub(crate) struct Node<S: State> {
+    pub(crate) state: S,
+    pub(crate) parent_index: Option<usize>,
+}
+
+impl<S: State> TreeNode<S> for Node<S> {
+    fn state(&self) -> &S {
+        &self.state
+    }
+
+    fn parent_index(&self) -> Option<usize> {
+        self.parent_index
+    }
+}
+
+/// Returns `true` if a tree grown by a planner that was set up with `old_problem_def` and
+/// `old_validity_checker`, and rooted at `root`, can be kept when it is set up with
+/// `problem_def` and `validity_checker` instead.
+///
+/// Every motion in such a tree was checked against the same space and validity checker, and it
+/// is rooted at the new start state, so only the goal may differ.
+pub(crate) fn can_keep_tree<S, SP, G>(
+    old_problem_def: Option<&Arc<ProblemDefinition<S, SP, G>>>,
+    old_validity_checker: Option<&Arc<dyn StateValidityChecker<S>>>,
+    root: Option<&S>,
+    problem_def: &ProblemDefinition<S, SP, G>,
+    validity_checker: &Arc<dyn StateValidityChecker<S>>,
+) -> bool
+where
+    S: State,
+    SP: StateSpace<StateType = S>,
+    G: Goal<S>,
+{
+    let (Some(old_pd), Some(old_vc), Some(root), Some(start)) = (
+        old_problem_def,
+        old_validity_checker,
+        root,
+        problem_def.start_states.first(),
+    ) else {
+        return false;
+    };
+    Arc::ptr_eq(&old_pd.space, &problem_def.space)
+        && Arc::ptr_eq(old_vc, validity_checker)
+        && problem_def.space.distance(root, start) == 0.0
+}
+
+/// The number of samples each thread processes per round when a seeded RRT grows its tree in
+/// parallel.
+const SAMPLES_PER_THREAD: usize = 8;
+
+/// The fraction of the space's maximum extent used as the default `max_distance`.
+const DEFAULT_MAX_DISTANCE_FRACTION: f64 = 0.2;
+
+/// Returns `max_distance` if it is positive, or otherwise a fifth of the maximum extent of `space`.
+///
+/// Tree planners call this in `setup`, so that a `max_distance` of 0 picks a step size suited to
+/// the space they are planning in.
+pub(crate) fn resolve_max_distance<SP: StateSpace>(max_distance: f64, space: &SP) -> f64 {
+    resolve_distance(max_distance, DEFAULT_MAX_DISTANCE_FRACTION, space)
+}
+
+/// Returns `distance` if it is positive, or otherwise `fraction` of the maximum extent of `space`.
+pub(crate) fn resolve_distance<SP: StateSpace>(distance: f64, fraction: f64, space: &SP) -> f64 {
+    if distance > 0.0 {
+        distance
+    } else {
+        fraction * space.get_maximum_extent()
+    }
 }
 
 /// An implementation of the Rapidly-exploring Random Tree (RRT) algorithm.
@@ -46,21 +128,60 @@ struct Node<S: State> {
 /// This implementation includes goal-biasing, a common optimization where the planner occasionally
 /// samples from the goal region directly to speed up convergence.
 ///
+/// # Parallel Growth
+///
+/// If `num_threads` is greater than 1, `solve` spawns that many worker threads, which sample the
+/// space, find the nearest node, steer and check the motion concurrently, adding their new nodes
+/// to the shared tree as they go. Each worker draws from its own random number generator, seeded
+/// from the planner's. The goal is only used on the calling thread: it checks each new node
+/// against the goal and hands goal samples to the workers, which sample the space instead when
+/// none is ready. The order in which the workers add nodes depends on how they are scheduled.
+///
+/// A seeded planner instead grows the tree in rounds, so that it always finds the same path for a
+/// given number of threads. Each round draws a batch of samples from the planner's random number
+/// generator, then finds their nearest nodes, steers and checks the motions on `num_threads`
+/// threads, all reading the same unchanging tree. The valid new nodes are then added to the tree
+/// in the order they were sampled.
+///
+/// Whether the tree is grown sequentially or in parallel, a sample that cannot be drawn, because
+/// the space or goal fails to sample, is counted as rejected and skipped.
+///
+/// # Resuming and Warm Starting
+///
+/// The tree is kept between calls to `solve`, so a call that times out can be followed by another
+/// that carries on growing the same tree. This allows planning within a small time budget per
+/// cycle. If the tree already reaches the goal, `solve` returns that path straight away.
+///
+/// Calling `setup` again keeps the tree too, as long as the space and validity checker are the
+/// same `Arc`s as before and the start state is unchanged; only the goal may differ. Otherwise
+/// the tree is discarded. Call `clear` to discard it explicitly.
+///
+/// A new tree can be seeded with `warm_start`, for example with the states of a path found in a
+/// previous cycle or of another planner's tree.
+///
 /// # Trait Bounds
 ///
 /// To use this planner, the following trait bounds must be met:
 /// - The `State` type (`S`) must be `Clone`.
 /// - The `Goal` type (`G`) must implement `GoalSampleableRegion` to support goal-biasing.
 pub struct RRT<S: State, SP: StateSpace<StateType = S>, G: Goal<S>> {
-    /// The maximum distance between nodes in the tree. This is the "step size".
+    /// The maximum distance between nodes in the tree. This is the "step size". If it is 0,
+    /// each call to `setup` uses a fifth of the maximum extent of the space.
     pub max_distance: f64,
     /// The probability of sampling the goal region instead of the whole space (e.g., 0.05 for 5%).
     pub goal_bias: f64,
+    /// The number of threads used to grow the tree. With 1 (the default), the tree is grown
+    /// sequentially.
+    pub num_threads: usize,
 
+    // The parameters above, with any that are 0 resolved by `setup`.
+    resolved_max_distance: f64,
     problem_def: Option<Arc<ProblemDefinition<S, SP, G>>>,
     validity_checker: Option<Arc<dyn StateValidityChecker<S>>>,
     tree: Vec<Node<S>>,
-    rng: Option<Box<StdRng>>,
+    rng: Option<Box<PlannerRng>>,
+    seeded: bool,
+    stats: Arc<StatsRecorder<S>>,
 }
 
 impl<S, SP, G> RRT<S, SP, G>
@@ -76,103 +197,119 @@ where
     /// * `goal_bias` - The probability (0.0 to 1.0) of sampling the goal.
     /// * `config` - The planner configuration, for planner-specific parameters.
     pub fn new(max_distance: f64, goal_bias: f64, config: &PlannerConfig) -> Self {
-        let rng = config.seed.map(|s| Box::new(StdRng::seed_from_u64(s)));
+        let rng = config.seed.map(|s| Box::new(PlannerRng::seed_from_u64(s)));
         RRT {
             max_distance,
             goal_bias,
+            num_threads: 1,
+            resolved_max_distance: max_distance,
             problem_def: None,
             validity_checker: None,
             tree: Vec::new(),
+            seeded: rng.is_some(),
             rng,
+            stats: Arc::default(),
         }
     }
 
-    /// An internal helper function to check if the motion between two states is valid.
+    /// Discards the tree, so the next call to `solve` grows a new one from the start state.
     ///
-    /// It works by discretizing the straight-line path between `from` and `to` into small steps
-    /// and calling the `StateValidityChecker` on each intermediate state. If any intermediate
-    /// state is invalid, the entire motion is considered invalid.
-    fn check_motion(&self, from: &S, to: &S) -> bool {
-        // We need access to the space and checker from our stored setup info.
-        if let (Some(pd), Some(vc)) = (&self.problem_def, &self.validity_checker) {
-            let space = &pd.space;
-
-            let dist = space.distance(from, to);
-            let num_steps =
-                (dist / (space.get_longest_valid_segment_length() * 0.1)).ceil() as usize;
-
-            if num_steps <= 1 {
-                return vc.is_valid(to);
-            }
+    /// The problem definition and validity checker are kept.
+    pub fn clear(&mut self) {
+        self.tree.clear();
+    }
 
-            let mut interpolated_state = from.clone();
-            for i in 1..=num_steps {
-                let t = i as f64 / num_steps as f64;
-                space.interpolate(from, to, t, &mut interpolated_state);
-                if !vc.is_valid(&interpolated_state) {
-                    return false;
-                }
-            }
+    /// Returns the states in the tree. Every state comes after its parent.
+    pub fn tree_states(&self) -> impl Iterator<Item = &S> {
+        self.tree.iter().map(|node| &node.state)
+    }
 
-            true
-        } else {
-            false
+    /// Adds `states` to the tree, in order, to warm start the planner.
+    ///
+    /// Each state is connected to the nearest node already in the tree, if the motion between
+    /// them is valid. Otherwise, or if the state is outside the bounds of the space or already in
+    /// the tree, it is skipped. To seed the tree with a previous path, pass its states in order
+    /// from the start; to copy another tree, pass its `tree_states`.
+    ///
+    /// Returns the number of states added.
+    ///
+    /// # Errors
+    /// Returns `PlanningError::PlannerUninitialised` if `setup` has not been called.
+    pub fn warm_start(
+        &mut self,
+        states: impl IntoIterator<Item = S>,
+    ) -> Result<usize, PlanningError> {
+        let pd = self
+            .problem_def
+            .clone()
+            .ok_or(PlanningError::PlannerUninitialised)?;
+        let vc = self
+            .validity_checker
+            .clone()
+            .ok_or(PlanningError::PlannerUninitialised)?;
+        let space = &*pd.space;
+        self.initialise_tree(&pd);
+        let vc = self.stats.checker(&*vc);
+
+        let mut added = 0;
+        for state in states {
+            if !space.satisfies_bounds(&state) {
+                continue;
+            }
+            let (nearest_node_index, _, _) = self.stats.nearest(|| {
+                steer(
+                    self.tree.iter().map(|node| &node.state),
+                    &state,
+                    space,
+                    f64::INFINITY,
+                )
+            });
+            let q_near = &self.tree[nearest_node_index].state;
+            if space.distance(q_near, &state) == 0.0
+                || !self.stats.check_motion(space, &vc, q_near, &state)
+            {
+                continue;
+            }
+            self.stats.node_added(&state, Some(q_near));
+            self.tree.push(Node {
+                state,
+                parent_index: Some(nearest_node_index),
+            });
+            added += 1;
         }
+        Ok(added)
     }
 
-    fn reconstruct_path(&self, start_node_idx: usize) -> Path<S> {
-        let mut path_states = Vec::new();
-        let mut current_index = Some(start_node_idx);
-        while let Some(index) = current_index {
-            path_states.push(self.tree[index].state.clone());
-            current_index = self.tree[index].parent_index;
+    /// Roots the tree at the first start state, if it is empty.
+    fn initialise_tree(&mut self, pd: &ProblemDefinition<S, SP, G>) {
+        if self.tree.is_empty() {
+            self.stats.node_added(&pd.start_states[0], None);
+            self.tree.push(Node {
+                state: pd.start_states[0].clone(),
+                parent_index: None,
+            });
         }
-        path_states.reverse();
-
-        Path(path_states)
     }
 }
 
-// The main implementation of the Planner trait for RRT.
-impl<S, SP, G> Planner<S, SP, G> for RRT<S, SP, G>
+impl<S, SP, G> RRT<S, SP, G>
 where
-    // RRT needs to clone states to store them in its tree.
     S: State + Clone,
     SP: StateSpace<StateType = S>,
-    // For goal biasing, the Goal type must be sampleable.
     G: Goal<S> + GoalSampleableRegion<S>,
 {
-    fn setup(
+    /// Grows the tree one sample at a time until a new node reaches the goal.
+    fn solve_sequentially(
         &mut self,
-        problem_def: Arc<ProblemDefinition<S, SP, G>>,
-        validity_checker: Arc<dyn StateValidityChecker<S>>,
-    ) {
-        self.problem_def = Some(problem_def);
-        self.validity_checker = Some(validity_checker);
-        self.tree.clear();
-
-        // Initialise the tree with the start state.
-        let start_state = self.problem_def.as_ref().unwrap().start_states[0].clone();
-        let start_node = Node {
-            state: start_state,
-            parent_index: None,
-        };
-        self.tree.push(start_node);
-    }
-
-    fn solve(&mut self, timeout: Duration) -> Result<Path<S>, PlanningError> {
-        // Ensure setup has been called.
-        let pd = self
-            .problem_def
-            .as_ref()
-            .ok_or(PlanningError::PlannerUninitialised)?;
+        pd: &ProblemDefinition<S, SP, G>,
+        vc: &dyn StateValidityChecker<S>,
+        rng: &mut PlannerRng,
+        start_time: Instant,
+        timeout: Duration,
+    ) -> Result<Path<S>, PlanningError> {
         let goal = &pd.goal;
-
-        let mut rng = self
-            .rng
-            .take()
-            .unwrap_or_else(|| Box::new(StdRng::from_os_rng()));
-        let start_time = Instant::now();
+        let stats = &self.stats;
+        let vc = stats.checker(vc);
 
         // Main Loop
         loop {
@@ -180,35 +317,42 @@ where
             if start_time.elapsed() > timeout {
                 return Err(PlanningError::Timeout);
             }
+            stats.iteration();
 
-            // 2. Sample a state (q_rand)
-            let q_rand = if rng.random_bool(self.goal_bias) {
-                // TODO: assume sample_goal can't fail here for simplicity, but a real
-                // implementation would handle the Result.
-                goal.sample_goal(&mut rng).unwrap()
-            } else {
-                // TODO: assume uniform sampling can't fail if bounds are set correctly.
-                pd.space.sample_uniform(&mut rng).unwrap()
+            // 2. Sample a state (q_rand), skipping the iteration if sampling fails
+            let sample = stats.sample(|| {
+                if rng.random_bool(self.goal_bias) {
+                    goal.sample_goal(rng)
+                } else {
+                    pd.space.sample_uniform(rng)
+                }
+            });
+            let Ok(q_rand) = sample else {
+                stats.reject_sample(None);
+                continue;
             };
 
             // 3. Find the nearest node in the tree (q_near)
-            let mut nearest_node_index = 0;
-            let mut min_dist = pd.space.distance(&self.tree[0].state, &q_rand);
-
-            for i in 1..self.tree.len() {
-                let dist = pd.space.distance(&self.tree[i].state, &q_rand);
-                if dist < min_dist {
-                    min_dist = dist;
-                    nearest_node_index = i;
+            let (nearest_node_index, min_dist) = stats.nearest(|| {
+                let mut nearest_node_index = 0;
+                let mut min_dist = pd.space.distance(&self.tree[0].state, &q_rand);
+
+                for i in 1..self.tree.len() {
+                    let dist = pd.space.distance(&self.tree[i].state, &q_rand);
+                    if dist < min_dist {
+                        min_dist = dist;
+                        nearest_node_index = i;
+                    }
                 }
-            }
+                (nearest_node_index, min_dist)
+            });
             let q_near = &self.tree[nearest_node_index].state;
 
             // 4. Steer from q_near towards q_rand to get q_new
             let mut q_new = q_near.clone();
-            if min_dist > self.max_distance {
+            if min_dist > self.resolved_max_distance {
                 // If q_rand is too far, interpolate to a point at max_distance
-                let t = self.max_distance / min_dist;
+                let t = self.resolved_max_distance / min_dist;
                 pd.space.interpolate(q_near, &q_rand, t, &mut q_new);
             } else {
                 // If q_rand is close enough, just use it as q_new
@@ -216,8 +360,9 @@ where
             }
 
             // 5. Check if the motion to q_new is valid
-            if self.check_motion(q_near, &q_new) {
+            if stats.check_motion(&*pd.space, &vc, q_near, &q_new) {
                 // 6. Add q_new to the tree
+                stats.node_added(&q_new, Some(q_near));
                 let new_node = Node {
                     state: q_new.clone(),
                     parent_index: Some(nearest_node_index),
@@ -226,11 +371,340 @@ where
 
                 // 7. Check if the new node satisfies the goal
                 if goal.is_satisfied(&q_new) {
-                    println!("Solution found after {} nodes.", self.tree.len());
-                    return Ok(self.reconstruct_path(self.tree.len() - 1));
+                    let path = reconstruct_path(&self.tree, self.tree.len() - 1);
+                    stats.solution(&*pd.space, &path);
+                    return Ok(path);
                 }
             }
         }
         // TODO: Limit iteration counts and add Err(PlanningError::NoSolutionFound)
     }
+
+    /// Grows the tree in rounds of `num_threads * SAMPLES_PER_THREAD` samples, which are extended
+    /// in parallel and then added to the tree in the order they were sampled.
+    fn solve_in_batches(
+        &mut self,
+        pd: &ProblemDefinition<S, SP, G>,
+        vc: &dyn StateValidityChecker<S>,
+        rng: &mut PlannerRng,
+        start_time: Instant,
+        timeout: Duration,
+    ) -> Result<Path<S>, PlanningError> {
+        let space = &*pd.space;
+        let goal = &pd.goal;
+        let stats = &self.stats;
+        let vc = &stats.checker(vc);
+        let max_distance = self.resolved_max_distance;
+        let batch_size = self.num_threads * SAMPLES_PER_THREAD;
+
+        while start_time.elapsed() <= timeout {
+            // 1. Draw the samples for this round sequentially, so they only depend on the seed.
+            let samples: Vec<S> = (0..batch_size)
+                .filter_map(|_| {
+                    stats.iteration();
+                    let sample = stats.sample(|| {
+                        if rng.random_bool(self.goal_bias) {
+                            goal.sample_goal(rng)
+                        } else {
+                            space.sample_uniform(rng)
+                        }
+                    });
+                    if sample.is_err() {
+                        stats.reject_sample(None);
+                    }
+                    sample.ok()
+                })
+                .collect();
+
+            // 2. Find the nearest node, steer and check the motion for every sample in parallel.
+            //    The tree is only read during this step.
+            let tree = &self.tree;
+            let chunk_size = samples.len().div_ceil(self.num_threads).max(1);
+            let extensions: Vec<Option<(usize, S)>> = thread::scope(|scope| {
+                let handles: Vec<_> = samples
+                    .chunks(chunk_size)
+                    .map(|chunk| {
+                        scope.spawn(move || {
+                            chunk
+                                .iter()
+                                .map(|q_rand| {
+                                    let (nearest_node_index, q_new, _) = stats.nearest(|| {
+                                        steer(
+                                            tree.iter().map(|node| &node.state),
+                                            q_rand,
+                                            space,
+                                            max_distance,
+                                        )
+                                    });
+                                    let q_near = &tree[nearest_node_index].state;
+                                    stats
+                                        .check_motion(space, vc, q_near, &q_new)
+                                        .then_some((nearest_node_index, q_new))
+                                })
+                                .collect::<Vec<_>>()
+                        })
+                    })
+                    .collect();
+                handles
+                    .into_iter()
+                    .flat_map(|handle| handle.join().unwrap())
+                    .collect()
+            });
+
+            // 3. Add the valid new nodes in sample order, stopping at the first to reach the goal.
+            for (nearest_node_index, q_new) in extensions.into_iter().flatten() {
+                stats.node_added(&q_new, Some(&self.tree[nearest_node_index].state));
+                let reached_goal = goal.is_satisfied(&q_new);
+                self.tree.push(Node {
+                    state: q_new,
+                    parent_index: Some(nearest_node_index),
+                });
+                if reached_goal {
+                    let path = reconstruct_path(&self.tree, self.tree.len() - 1);
+                    stats.solution(space, &path);
+                    return Ok(path);
+                }
+            }
+        }
+
+        Err(PlanningError::Timeout)
+    }
+
+    /// Grows the tree on `num_threads` worker threads, which are spawned once and run until a new
+    /// node reaches the goal or the time runs out.
+    ///
+    /// The goal is only used on the calling thread, which checks every new node against it and
+    /// keeps the workers supplied with goal samples for goal biasing.
+    fn solve_in_parallel(
+        &mut self,
+        pd: &ProblemDefinition<S, SP, G>,
+        vc: &dyn StateValidityChecker<S>,
+        rng: &mut PlannerRng,
+        start_time: Instant,
+        timeout: Duration,
+    ) -> Result<Path<S>, PlanningError> {
+        let space = &*pd.space;
+        let goal = &pd.goal;
+        let stats = &self.stats;
+        let vc = &stats.checker(vc);
+        let max_distance = self.resolved_max_distance;
+        let goal_bias = self.goal_bias;
+
+        let tree = RwLock::new(mem::take(&mut self.tree));
+        let done = AtomicBool::new(false);
+        let (new_nodes, new_node_indices) = mpsc::channel();
+        let (goal_samples, goal_sample_queue) = mpsc::sync_channel(self.num_threads);
+        let goal_sample_queue = Mutex::new(goal_sample_queue);
+        // Each worker has its own generator, seeded from the planner's.
+        let seeds: Vec<u64> = (0..self.num_threads).map(|_| rng.random()).collect();
+
+        let result = thread::scope(|scope| {
+            for seed in seeds {
+                let new_nodes = new_nodes.clone();
+                let (tree, done, goal_sample_queue) = (&tree, &done, &goal_sample_queue);
+                scope.spawn(move || {
+                    let mut rng = PlannerRng::seed_from_u64(seed);
+                    while !done.load(Ordering::Relaxed) && start_time.elapsed() <= timeout {
+                        stats.iteration();
+
+                        // 1. Take a goal sample if one is ready, and otherwise sample the space.
+                        let goal_sample = if rng.random_bool(goal_bias) {
+                            goal_sample_queue.lock().unwrap().try_recv().ok()
+                        } else {
+                            None
+                        };
+                        let q_rand = match goal_sample {
+                            Some(q_rand) => q_rand,
+                            None => match stats.sample(|| space.sample_uniform(&mut rng)) {
+                                Ok(q_rand) => q_rand,
+                                Err(_) => {
+                                    stats.reject_sample(None);
+                                    continue;
+                                }
+                            },
+                        };
+
+                        // 2. Find the nearest node and steer towards the sample.
+                        let (nearest_node_index, q_near, q_new) = stats.nearest(|| {
+                            let tree = tree.read().unwrap();
+                            let (nearest_node_index, q_new, _) = steer(
+                                tree.iter().map(|node| &node.state),
+                                &q_rand,
+                                space,
+                                max_distance,
+                            );
+                            (
+                                nearest_node_index,
+                                tree[nearest_node_index].state.clone(),
+                                q_new,
+                            )
+                        });
+
+                        // 3. Check the motion without holding the lock, then add the new node.
+                        if stats.check_motion(space, vc, &q_near, &q_new) {
+                            stats.node_added(&q_new, Some(&q_near));
+                            let mut tree = tree.write().unwrap();
+                            tree.push(Node {
+                                state: q_new,
+                                parent_index: Some(nearest_node_index),
+                            });
+                            let _ = new_nodes.send(tree.len() - 1);
+                        }
+                    }
+                });
+            }
+            drop(new_nodes);
+
+            // Check the new nodes against the goal, topping up the goal samples as they are used.
+            let mut goal_sample = None;
+            let result = loop {
+                if goal_bias > 0.0 {
+                    if goal_sample.is_none() {
+                        match stats.sample(|| goal.sample_goal(rng)) {
+                            Ok(sample) => goal_sample = Some(sample),
+                            Err(_) => stats.reject_sample(None),
+                        }
+                    }
+                    if let Some(sample) = goal_sample.take() {
+                        if let Err(TrySendError::Full(sample)) = goal_samples.try_send(sample) {
+                            goal_sample = Some(sample);
+                        }
+                    }
+                }
+
+                let remaining = timeout.saturating_sub(start_time.elapsed());
+                match new_node_indices.recv_timeout(remaining) {
+                    Ok(index) => {
+                        if goal.is_satisfied(&tree.read().unwrap()[index].state) {
+                            break Ok(index);
+                        }
+                    }
+                    Err(_) => break Err(PlanningError::Timeout),
+                }
+            };
+            done.store(true, Ordering::Relaxed);
+            result
+        });
+
+        self.tree = tree.into_inner().unwrap();
+        let goal_node_index = result?;
+        let path = reconstruct_path(&self.tree, goal_node_index);
+        stats.solution(space, &path);
+        Ok(path)
+    }
+}
+
+// The main implementation of the Planner trait for RRT.
+impl<S, SP, G> Planner<S, SP, G> for RRT<S, SP, G>
+where
+    // RRT needs to clone states to store them in its tree.
+    S: State + Clone,
+    SP: StateSpace<StateType = S>,
+    // For goal biasing, the Goal type must be sampleable.
+    G: Goal<S> + GoalSampleableRegion<S>,
+{
+    fn setup(
+        &mut self,
+        problem_def: Arc<ProblemDefinition<S, SP, G>>,
+        validity_checker: Arc<dyn StateValidityChecker<S>>,
+    ) {
+        self.resolved_max_distance = resolve_max_distance(self.max_distance, &*problem_def.space);
+        if !can_keep_tree(
+            self.problem_def.as_ref(),
+            self.validity_checker.as_ref(),
+            self.tree.first().map(|node| &node.state),
+            &problem_def,
+            &validity_checker,
+        ) {
+            self.tree.clear();
+        }
+        self.initialise_tree(&problem_def);
+        self.problem_def = Some(problem_def);
+        self.validity_checker = Some(validity_checker);
+        self.stats.reset();
+    }
+
+    fn solve(&mut self, timeout: Duration) -> Result<Path<S>, PlanningError> {
+        // Ensure setup has been called.
+        let pd = self
+            .problem_def
+            .clone()
+            .ok_or(PlanningError::PlannerUninitialised)?;
+        let vc = self
+            .validity_checker
+            .clone()
+            .ok_or(PlanningError::PlannerUninitialised)?;
+        let goal = &pd.goal;
+        self.initialise_tree(&pd);
+
+        // The tree may already reach the goal, from an earlier call or a warm start.
+        if let Some(goal_node_index) = self
+            .tree
+            .iter()
+            .position(|node| goal.is_satisfied(&node.state))
+        {
+            let path = reconstruct_path(&self.tree, goal_node_index);
+            self.stats.solution(&*pd.space, &path);
+            return Ok(path);
+        }
+
+        let mut rng = self
+            .rng
+            .take()
+            .unwrap_or_else(|| Box::new(PlannerRng::from_os_rng()));
+        let start_time = Instant::now();
+
+        let result = if self.num_threads > 1 && self.seeded {
+            self.solve_in_batches(&pd, &*vc, &mut rng, start_time, timeout)
+        } else if self.num_threads > 1 {
+            self.solve_in_parallel(&pd, &*vc, &mut rng, start_time, timeout)
+        } else {
+            self.solve_sequentially(&pd, &*vc, &mut rng, start_time, timeout)
+        };
+        // Keep the random number generator, so a seeded planner stays reproducible across calls.
+        self.rng = Some(rng);
+        result
+    }
+
+    fn stats(&self) -> PlannerStats {
+        self.stats.snapshot()
+    }
+
+    fn set_observer(&mut self, observer: Arc<dyn PlannerObserver<S>>) {
+        stats::set_observer(&mut self.stats, observer);
+    }
+
+    fn params(&self) -> ParamSet {
+        ParamSet::default()
+            .with_real(
+                "max_distance",
+                "The maximum length of a motion. 0 uses a fifth of the space's maximum extent.",
+                self.max_distance,
+                0.0..=f64::INFINITY,
+            )
+            .with_real(
+                "goal_bias",
+                "The probability of sampling the goal region instead of the whole space.",
+                self.goal_bias,
+                0.0..=1.0,
+            )
+            .with_integer(
+                "num_threads",
+                "The number of threads used to grow the tree.",
+                self.num_threads,
+                1.0..=f64::INFINITY,
+            )
+    }
+
+    fn set_params(&mut self, params: &ParamSet) {
+        if let Some(max_distance) = params.real("max_distance") {
+            self.max_distance = max_distance;
+        }
+        if let Some(goal_bias) = params.real("goal_bias") {
+            self.goal_bias = goal_bias;
+        }
+        if let Some(num_threads) = params.integer("num_threads") {
+            self.num_threads = num_threads;
+        }
+    }
 }
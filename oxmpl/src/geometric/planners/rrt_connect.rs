@@ -4,14 +4,17 @@
 
 use std::sync::Arc;
 
-use rand::{rngs::StdRng, Rng, SeedableRng};
+use rand::{Rng, SeedableRng};
 
 use crate::{
     base::{
         error::PlanningError,
         goal::{Goal, GoalSampleableRegion},
-        planner::{Path, Planner, PlannerConfig},
+        observer::PlannerObserver,
+        param::ParamSet,
+        planner::{Path, Planner, PlannerConfig, PlannerStats},
         problem_definition::ProblemDefinition,
+        rng::PlannerRng,
         space::StateSpace,
         state::State,
         validity::StateValidityChecker,
@@ -19,16 +22,15 @@ use crate::{
     time::{Duration, Instant},
 };
 
-// A helper struct to build the tree. Each node stores its state and the index of its parent in the
-#[derive(Clone)]
-struct Node<S: State> {
-    state: S,
-    parent_index: Option<usize>,
-}
+use super::{
+    common::reconstruct_path,
+    rrt::{resolve_max_distance, Node},
+    stats::{self, StatsRecorder},
+};
 
 /// The result of an `extend` operation on a tree.
 #[derive(PartialEq, Debug)]
-enum ExtendResult {
+pub(crate) enum ExtendResult {
     /// The tree was extended, but did not reach the target state.
     Advanced,
     /// The tree was extended and reached the target state exactly.
@@ -62,16 +64,20 @@ enum ExtendResult {
 /// - The `State` type (`S`) must be `Clone`.
 /// - The `Goal` type (`G`) must implement `GoalSampleableRegion` to initialize the goal tree.
 pub struct RRTConnect<S: State, SP: StateSpace<StateType = S>, G: Goal<S>> {
-    /// The maximum distance between nodes in the tree. This is the "step size".
+    /// The maximum distance between nodes in the tree. This is the "step size". If it is 0,
+    /// each call to `setup` uses a fifth of the maximum extent of the space.
     pub max_distance: f64,
     /// The probability of sampling the goal region instead of the whole space (e.g., 0.05 for 5%).
     pub goal_bias: f64,
 
+    // The parameters above, with any that are 0 resolved by `setup`.
+    resolved_max_distance: f64,
     problem_def: Option<Arc<ProblemDefinition<S, SP, G>>>,
     validity_checker: Option<Arc<dyn StateValidityChecker<S>>>,
     start_tree: Vec<Node<S>>,
     goal_tree: Vec<Node<S>>,
-    rng: Option<Box<StdRng>>,
+    rng: Option<Box<PlannerRng>>,
+    stats: Arc<StatsRecorder<S>>,
 }
 
 impl<S, SP, G> RRTConnect<S, SP, G>
@@ -87,29 +93,20 @@ where
     /// * `goal_bias` - The probability (0.0 to 1.0) of sampling the goal.
     /// * `config` - The planner configuration, for planner-specific parameters.
     pub fn new(max_distance: f64, goal_bias: f64, config: &PlannerConfig) -> Self {
-        let rng = config.seed.map(|s| Box::new(StdRng::seed_from_u64(s)));
+        let rng = config.seed.map(|s| Box::new(PlannerRng::seed_from_u64(s)));
         RRTConnect {
             max_distance,
             goal_bias,
+            resolved_max_distance: max_distance,
             problem_def: None,
             validity_checker: None,
             start_tree: Vec::new(),
             goal_tree: Vec::new(),
             rng,
+            stats: Arc::default(),
         }
     }
 
-    fn reconstruct_path(&self, tree: &[Node<S>], last_node_idx: usize) -> Path<S> {
-        let mut path_states = Vec::new();
-        let mut current_index = Some(last_node_idx);
-        while let Some(index) = current_index {
-            path_states.push(tree[index].state.clone());
-            current_index = tree[index].parent_index;
-        }
-        path_states.reverse();
-        Path(path_states)
-    }
-
     /// Helper function to extend a tree towards a target state.
     ///
     /// This function finds the node in the `tree` nearest to `q_target`. It then creates a new state
@@ -127,32 +124,23 @@ where
         tree: &mut Vec<Node<S>>,
         q_target: &S,
         pd: &ProblemDefinition<S, SP, G>,
-        vc: &Arc<dyn StateValidityChecker<S>>,
+        vc: &dyn StateValidityChecker<S>,
+        stats: &StatsRecorder<S>,
         max_distance: f64,
     ) -> Option<(ExtendResult, usize)> {
-        let mut nearest_node_index = 0;
-        let mut min_dist = pd.space.distance(&tree[0].state, q_target);
-        for (i, node) in tree.iter().enumerate().skip(1) {
-            let dist = pd.space.distance(&node.state, q_target);
-            if dist < min_dist {
-                min_dist = dist;
-                nearest_node_index = i;
-            }
-        }
+        let (nearest_node_index, q_new, result) = stats.nearest(|| {
+            steer(
+                tree.iter().map(|n| &n.state),
+                q_target,
+                &*pd.space,
+                max_distance,
+            )
+        });
+        let q_near = &tree[nearest_node_index].state;
 
-        let q_near = tree[nearest_node_index].state.clone();
-        let mut q_new = q_near.clone();
-        let result = if min_dist > max_distance {
-            let t = max_distance / min_dist;
-            pd.space.interpolate(&q_near, q_target, t, &mut q_new);
-            ExtendResult::Advanced
-        } else {
-            q_new = q_target.clone();
-            ExtendResult::Reached
-        };
-
-        if Self::check_motion(&q_near, &q_new, pd, vc) {
+        if stats.check_motion(&*pd.space, vc, q_near, &q_new) {
             let new_node_idx = tree.len();
+            stats.node_added(&q_new, Some(q_near));
             tree.push(Node {
                 state: q_new,
                 parent_index: Some(nearest_node_index),
@@ -162,36 +150,6 @@ where
             None
         }
     }
-
-    /// An internal helper function to check if the motion between two states is valid.
-    ///
-    /// It works by discretizing the straight-line path between `from` and `to` into small steps and
-    /// calling the `StateValidityChecker` on each intermediate state. If any intermediate state is
-    /// invalid, the entire motion is considered invalid.
-    fn check_motion(
-        from: &S,
-        to: &S,
-        pd: &ProblemDefinition<S, SP, G>,
-        vc: &Arc<dyn StateValidityChecker<S>>,
-    ) -> bool {
-        let space = &pd.space;
-        let dist = space.distance(from, to);
-        let num_steps = (dist / (space.get_longest_valid_segment_length() * 0.1)).ceil() as usize;
-
-        if num_steps <= 1 {
-            return vc.is_valid(to);
-        }
-
-        let mut interpolated_state = from.clone();
-        for i in 1..=num_steps {
-            let t = i as f64 / num_steps as f64;
-            space.interpolate(from, to, t, &mut interpolated_state);
-            if !vc.is_valid(&interpolated_state) {
-                return false;
-            }
-        }
-        true
-    }
 }
 
 /// The main implementation of the Planner trait for RRTConnect.
@@ -206,22 +164,31 @@ where
         problem_def: Arc<ProblemDefinition<S, SP, G>>,
         validity_checker: Arc<dyn StateValidityChecker<S>>,
     ) {
+        self.resolved_max_distance = resolve_max_distance(self.max_distance, &*problem_def.space);
         self.problem_def = Some(problem_def);
         self.validity_checker = Some(validity_checker);
         self.start_tree.clear();
         self.goal_tree.clear();
+        self.stats.reset();
         let pd = self.problem_def.as_ref().unwrap();
 
         // Initialise the trees beginning from start and goal states.
         let start_state = pd.start_states[0].clone();
+        self.stats.node_added(&start_state, None);
         let start_node = Node {
             state: start_state,
             parent_index: None,
         };
         self.start_tree.push(start_node);
 
-        let mut rng = rand::rng();
-        let goal_state = pd.goal.sample_goal(&mut rng).unwrap();
+        let rng = self
+            .rng
+            .get_or_insert_with(|| Box::new(PlannerRng::from_os_rng()));
+        let goal_state = self
+            .stats
+            .sample(|| pd.goal.sample_goal(&mut **rng))
+            .unwrap();
+        self.stats.node_added(&goal_state, None);
         let goal_node = Node {
             state: goal_state,
             parent_index: None,
@@ -233,7 +200,7 @@ where
         let mut rng = self
             .rng
             .take()
-            .unwrap_or_else(|| Box::new(StdRng::from_os_rng()));
+            .unwrap_or_else(|| Box::new(PlannerRng::from_os_rng()));
         let start_time = Instant::now();
         let pd = self
             .problem_def
@@ -244,13 +211,16 @@ where
             .as_ref()
             .ok_or(PlanningError::PlannerUninitialised)?;
         let goal = &pd.goal;
+        let stats = &self.stats;
+        let vc = &stats.checker(&**vc);
 
         // Main loop
-        loop {
+        let result = loop {
             // 1. Check for timeout
             if start_time.elapsed() > timeout {
-                return Err(PlanningError::Timeout);
+                break Err(PlanningError::Timeout);
             }
+            stats.iteration();
 
             // 2. Determine which tree to grow (tree_a) and which to connect to (tree_b). This
             //    balances the trees, which is more efficient.
@@ -263,35 +233,33 @@ where
 
             // 3. Sample a random target state `q_rand`, with goal biasing.
             // TODO: Handle sampling failures.
-            let q_rand = if rng.random_bool(self.goal_bias) {
-                goal.sample_goal(&mut rng).unwrap()
-            } else {
-                pd.space.sample_uniform(&mut rng).unwrap()
-            };
+            let q_rand = stats.sample(|| {
+                if rng.random_bool(self.goal_bias) {
+                    goal.sample_goal(&mut rng).unwrap()
+                } else {
+                    pd.space.sample_uniform(&mut rng).unwrap()
+                }
+            });
 
             // 4. Try to extend tree_a towards q_rand.
             if let Some((_extend_result, new_node_idx_a)) =
-                Self::extend(tree_a, &q_rand, pd, vc, self.max_distance)
+                Self::extend(tree_a, &q_rand, pd, vc, stats, self.resolved_max_distance)
             {
                 let q_new = &tree_a[new_node_idx_a].state;
 
                 // If growing the start tree, check if the new node is already in the goal.
                 if is_growing_start_tree && goal.is_satisfied(q_new) {
-                    println!("Solution found by start tree reaching goal directly.");
-                    return Ok(self.reconstruct_path(&self.start_tree, new_node_idx_a));
+                    let path = reconstruct_path(&self.start_tree, new_node_idx_a);
+                    stats.solution(&*pd.space, &path);
+                    break Ok(path);
                 }
 
                 // 5. Try to connect tree_b to the new state `q_new`.
                 if let Some((connect_result, new_node_idx_b)) =
-                    Self::extend(tree_b, q_new, pd, vc, self.max_distance)
+                    Self::extend(tree_b, q_new, pd, vc, stats, self.resolved_max_distance)
                 {
                     // 6. If the connection reached q_new, a solution is found.
                     if connect_result == ExtendResult::Reached {
-                        println!(
-                            "Solution found after {} total nodes.",
-                            self.start_tree.len() + self.goal_tree.len()
-                        );
-
                         // Identify which final node belongs to which tree.
                         let (start_idx, goal_idx) = if is_growing_start_tree {
                             (new_node_idx_a, new_node_idx_b)
@@ -300,8 +268,8 @@ where
                         };
 
                         // 7. Reconstruct the path from both trees and merge them.
-                        let mut start_path = self.reconstruct_path(&self.start_tree, start_idx).0;
-                        let mut goal_path = self.reconstruct_path(&self.goal_tree, goal_idx).0;
+                        let mut start_path = reconstruct_path(&self.start_tree, start_idx).0;
+                        let mut goal_path = reconstruct_path(&self.goal_tree, goal_idx).0;
 
                         // The goal path is from goal to connection, so it needs to be reversed.
                         goal_path.reverse();
@@ -309,10 +277,85 @@ where
                         // connection point) to the start path.
                         start_path.extend(goal_path.into_iter().skip(1));
 
-                        return Ok(Path(start_path));
+                        let path = Path(start_path);
+                        stats.solution(&*pd.space, &path);
+                        break Ok(path);
                     }
                 }
             }
+        };
+        // Keep the random number generator, so a seeded planner stays reproducible across calls.
+        self.rng = Some(rng);
+        result
+    }
+
+    fn stats(&self) -> PlannerStats {
+        self.stats.snapshot()
+    }
+
+    fn set_observer(&mut self, observer: Arc<dyn PlannerObserver<S>>) {
+        stats::set_observer(&mut self.stats, observer);
+    }
+
+    fn params(&self) -> ParamSet {
+        ParamSet::default()
+            .with_real(
+                "max_distance",
+                "The maximum length of a motion. 0 uses a fifth of the space's maximum extent.",
+                self.max_distance,
+                0.0..=f64::INFINITY,
+            )
+            .with_real(
+                "goal_bias",
+                "The probability of sampling the goal region instead of the whole space.",
+                self.goal_bias,
+                0.0..=1.0,
+            )
+    }
+
+    fn set_params(&mut self, params: &ParamSet) {
+        if let Some(max_distance) = params.real("max_distance") {
+            self.max_distance = max_distance;
+        }
+        if let Some(goal_bias) = params.real("goal_bias") {
+            self.goal_bias = goal_bias;
         }
     }
 }
+
+/// Finds the state in `states` nearest to `q_target` and moves from it towards `q_target` by at
+/// most `max_distance`.
+///
+/// Returns the index of the nearest state, the new state, and whether `q_target` was reached. No
+/// validity checking is done here. `states` must not be empty.
+pub(crate) fn steer<'a, S, SP>(
+    states: impl IntoIterator<Item = &'a S>,
+    q_target: &S,
+    space: &SP,
+    max_distance: f64,
+) -> (usize, S, ExtendResult)
+where
+    S: State + Clone + 'a,
+    SP: StateSpace<StateType = S>,
+{
+    let mut nearest: Option<(usize, &S, f64)> = None;
+    for (i, state) in states.into_iter().enumerate() {
+        let dist = space.distance(state, q_target);
+        if nearest.is_none_or(|(_, _, min_dist)| dist < min_dist) {
+            nearest = Some((i, state, dist));
+        }
+    }
+    let (nearest_node_index, q_near, min_dist) = nearest.expect("steer requires a non-empty tree");
+
+    let mut q_new = q_near.clone();
+    let result = if min_dist > max_distance {
+        let t = max_distance / min_dist;
+        space.interpolate(q_near, q_target, t, &mut q_new);
+        ExtendResult::Advanced
+    } else {
+        q_new = q_target.clone();
+        ExtendResult::Reached
+    };
+
+    (nearest_node_index, q_new, result)
+}
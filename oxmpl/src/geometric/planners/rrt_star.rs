@@ -9,25 +9,45 @@ use crate::{
     time::{Duration, Instant},
 };
 
-use rand::{rngs::StdRng, Rng, SeedableRng};
+use rand::{Rng, SeedableRng};
 
 use crate::base::{
     error::PlanningError,
     goal::{Goal, GoalSampleableRegion},
-    planner::{Path, Planner},
+    observer::PlannerObserver,
+    param::ParamSet,
+    planner::{Path, Planner, PlannerStats},
     problem_definition::ProblemDefinition,
+    rng::PlannerRng,
     space::StateSpace,
     state::State,
     validity::StateValidityChecker,
 };
 
+use super::{
+    common::{reconstruct_path, TreeNode},
+    rrt::{can_keep_tree, resolve_max_distance},
+    rrt_connect::steer,
+    stats::{self, StatsRecorder},
+};
+
 // A helper struct to build the tree. Each node stores its state and the index of its parent in
 // the. For RRT* you also need to know the cost to get to the node.
 #[derive(Clone)]
-struct Node<S: State> {
-    state: S,
-    parent_index: Option<usize>,
-    cost: f64,
+pub(crate) struct Node<S: State> {
+    pub(crate) state: S,
+    pub(crate) parent_index: Option<usize>,
+    pub(crate) cost: f64,
+}
+
+impl<S: State> TreeNode<S> for Node<S> {
+    fn state(&self) -> &S {
+        &self.state
+    }
+
+    fn parent_index(&self) -> Option<usize> {
+        self.parent_index
+    }
 }
 
 /// An implementation of the RRT* (RRT-star) algorithm.
@@ -40,18 +60,36 @@ struct Node<S: State> {
 ///     neighbors that will give it the lowest-cost path from the start.
 /// 2.  **"Rewire"**: After a new node is added, it checks if it can provide a shorter path for any
 ///     of its neighbors, and if so, it updates their parent connections.
+///
+/// # Resuming and Warm Starting
+///
+/// The tree is kept between calls to `solve`. Each call grows it until a new node reaches the
+/// goal or the time runs out, and then returns the lowest-cost path to the goal found so far. A
+/// planner that is called repeatedly within a small time budget therefore returns paths that
+/// never get worse.
+///
+/// Calling `setup` again keeps the tree too, as long as the space and validity checker are the
+/// same `Arc`s as before and the start state is unchanged; only the goal may differ. Otherwise
+/// the tree is discarded. Call `clear` to discard it explicitly, and `warm_start` to seed it with
+/// the states of a previous path or tree.
 pub struct RRTStar<S: State, SP: StateSpace<StateType = S>, G: Goal<S>> {
-    /// The maximum distance between nodes in the tree. This is the "step size".
+    /// The maximum distance between nodes in the tree. This is the "step size". If it is 0,
+    /// each call to `setup` uses a fifth of the maximum extent of the space.
     pub max_distance: f64,
     /// The probability of sampling the goal region instead of the whole space (e.g., 0.05 for 5%).
     pub goal_bias: f64,
-    /// The radius to search for neighbors during the "Choose Parent" and "Rewire" steps.
+    /// The radius to search for neighbors during the "Choose Parent" and "Rewire" steps. If it is
+    /// 0, each call to `setup` uses twice `max_distance`.
     pub search_radius: f64,
 
+    // The parameters above, with any that are 0 resolved by `setup`.
+    resolved_max_distance: f64,
+    resolved_search_radius: f64,
     problem_def: Option<Arc<ProblemDefinition<S, SP, G>>>,
     validity_checker: Option<Arc<dyn StateValidityChecker<S>>>,
     tree: Vec<Node<S>>,
-    rng: Option<Box<StdRng>>,
+    rng: Option<Box<PlannerRng>>,
+    stats: Arc<StatsRecorder<S>>,
 }
 
 impl<S, SP, G> RRTStar<S, SP, G>
@@ -73,85 +111,165 @@ where
         search_radius: f64,
         config: &PlannerConfig,
     ) -> Self {
-        let rng = config.seed.map(|s| Box::new(StdRng::seed_from_u64(s)));
+        let rng = config.seed.map(|s| Box::new(PlannerRng::seed_from_u64(s)));
         RRTStar {
             max_distance,
             goal_bias,
             search_radius,
+            resolved_max_distance: max_distance,
+            resolved_search_radius: search_radius,
             problem_def: None,
             validity_checker: None,
             tree: Vec::new(),
             rng,
+            stats: Arc::default(),
         }
     }
 
-    fn check_motion(&self, from: &S, to: &S) -> bool {
-        // We need access to the space and checker from our stored setup info.
-        if let (Some(pd), Some(vc)) = (&self.problem_def, &self.validity_checker) {
-            let space = &pd.space;
-
-            // Determine the number of steps to check based on distance and resolution.
-            // A simple approach: one check per unit of distance (or a fraction thereof).
-            let dist = space.distance(from, to);
-            let num_steps =
-                (dist / (space.get_longest_valid_segment_length() * 0.1)).ceil() as usize;
-
-            if num_steps <= 1 {
-                return vc.is_valid(to);
-            }
+    /// Discards the tree, so the next call to `solve` grows a new one from the start state.
+    ///
+    /// The problem definition and validity checker are kept.
+    pub fn clear(&mut self) {
+        self.tree.clear();
+    }
 
-            let mut interpolated_state = from.clone();
-            for i in 1..=num_steps {
-                let t = i as f64 / num_steps as f64;
-                space.interpolate(from, to, t, &mut interpolated_state);
-                if !vc.is_valid(&interpolated_state) {
-                    return false;
-                }
+    /// Returns the states in the tree, breadth first from the start state. Every state comes after
+    /// its parent.
+    pub fn tree_states(&self) -> impl Iterator<Item = &S> {
+        // Rewiring can give a node a parent that was added after it, so the tree is not stored in
+        // this order.
+        let mut children = vec![Vec::new(); self.tree.len()];
+        let mut order = Vec::with_capacity(self.tree.len());
+        for (index, node) in self.tree.iter().enumerate() {
+            match node.parent_index {
+                Some(parent_index) => children[parent_index].push(index),
+                None => order.push(index),
             }
-
-            true
-        } else {
-            false
         }
-    }
-
-    /// Calculates the cost to reach `current_node` if it were parented by `neighbour_node`.
-    fn cost(&self, current_node: &Node<S>, neighbour_node: &Node<S>) -> f64 {
-        if let Some(pd) = &self.problem_def {
-            neighbour_node.cost
-                + pd.space
-                    .distance(&current_node.state, &neighbour_node.state)
-        } else {
-            f64::INFINITY
+        let mut next = 0;
+        while next < order.len() {
+            order.extend_from_slice(&children[order[next]]);
+            next += 1;
         }
+        order.into_iter().map(|index| &self.tree[index].state)
     }
 
-    /// Finds all nodes in the tree that are within the `search_radius` of a given node.
+    /// Adds `states` to the tree, in order, to warm start the planner.
     ///
-    /// This is a simple linear scan;
-    /// TODO: Consider using kd-trees.
-    fn find_neighbours(&self, node: &Node<S>) -> Vec<usize> {
-        let mut neighbours: Vec<usize> = Vec::new();
-        if let Some(pd) = &self.problem_def {
-            for i in 0..self.tree.len() {
-                if pd.space.distance(&node.state, &self.tree[i].state) < self.search_radius {
-                    neighbours.push(i);
-                }
+    /// Each state is added as if it had been sampled: it is connected to the neighbour that gives
+    /// it the lowest cost, and its neighbours are rewired through it. A state is skipped if it is
+    /// outside the bounds of the space, already in the tree, or cannot be reached with a valid
+    /// motion from the nearest node in the tree.
+    ///
+    /// Returns the number of states added.
+    ///
+    /// # Errors
+    /// Returns `PlanningError::PlannerUninitialised` if `setup` has not been called.
+    pub fn warm_start(
+        &mut self,
+        states: impl IntoIterator<Item = S>,
+    ) -> Result<usize, PlanningError> {
+        let pd = self
+            .problem_def
+            .clone()
+            .ok_or(PlanningError::PlannerUninitialised)?;
+        let vc = self
+            .validity_checker
+            .clone()
+            .ok_or(PlanningError::PlannerUninitialised)?;
+        let space = &*pd.space;
+        let stats = self.stats.clone();
+        let vc = stats.checker(&*vc);
+        let motion_valid = |from: &S, to: &S| stats.check_motion(space, &vc, from, to);
+        self.initialise_tree(&pd);
+
+        let mut added = 0;
+        for state in states {
+            if !space.satisfies_bounds(&state) {
+                continue;
+            }
+            let (nearest_node_index, _, _) = stats.nearest(|| {
+                steer(
+                    self.tree.iter().map(|node| &node.state),
+                    &state,
+                    space,
+                    f64::INFINITY,
+                )
+            });
+            let q_near = &self.tree[nearest_node_index].state;
+            if space.distance(q_near, &state) == 0.0 || !motion_valid(q_near, &state) {
+                continue;
             }
+            self.insert(state, nearest_node_index, space, motion_valid);
+            added += 1;
         }
-        neighbours
+        Ok(added)
     }
 
-    fn reconstruct_path(&self, start_node_idx: usize) -> Path<S> {
-        let mut path_states = Vec::new();
-        let mut current_index = Some(start_node_idx);
-        while let Some(index) = current_index {
-            path_states.push(self.tree[index].state.clone());
-            current_index = self.tree[index].parent_index;
+    /// Roots the tree at the first start state, if it is empty.
+    fn initialise_tree(&mut self, pd: &ProblemDefinition<S, SP, G>) {
+        if self.tree.is_empty() {
+            self.stats.node_added(&pd.start_states[0], None);
+            self.tree.push(Node {
+                state: pd.start_states[0].clone(),
+                parent_index: None,
+                cost: 0.0,
+            });
         }
-        path_states.reverse();
+    }
 
-        Path(path_states)
+    /// Adds `q_new`, which can be reached from `nearest_node_index` with a valid motion, to the
+    /// tree with the best parent among its neighbours, and rewires the neighbours through it.
+    fn insert(
+        &mut self,
+        q_new: S,
+        nearest_node_index: usize,
+        space: &SP,
+        motion_valid: impl Fn(&S, &S) -> bool,
+    ) {
+        let neighbours = self
+            .stats
+            .nearest(|| find_neighbours(&self.tree, &q_new, space, self.resolved_search_radius));
+
+        // Choose parent
+        let (best_parent_index, min_cost) = choose_parent(
+            &self.tree,
+            &q_new,
+            nearest_node_index,
+            &neighbours,
+            space,
+            &motion_valid,
+        );
+
+        // Add the new node to the tree with the optimal parent and cost.
+        let stats = &*self.stats;
+        stats.node_added(&q_new, Some(&self.tree[best_parent_index].state));
+        self.tree.push(Node {
+            state: q_new,
+            parent_index: Some(best_parent_index),
+            cost: min_cost,
+        });
+        let new_node_index = self.tree.len() - 1;
+
+        // Rewire tree
+        rewire(
+            &mut self.tree,
+            new_node_index,
+            &neighbours,
+            space,
+            stats,
+            motion_valid,
+        );
+    }
+
+    /// Returns the index of the lowest-cost node that satisfies the goal, if any.
+    fn best_goal_node(&self, goal: &G) -> Option<usize> {
+        self.tree
+            .iter()
+            .enumerate()
+            .filter(|(_, node)| goal.is_satisfied(&node.state))
+            .min_by(|(_, a), (_, b)| a.cost.total_cmp(&b.cost))
+            .map(|(index, _)| index)
     }
 }
 
@@ -166,141 +284,281 @@ where
         problem_def: Arc<ProblemDefinition<S, SP, G>>,
         validity_checker: Arc<dyn StateValidityChecker<S>>,
     ) {
+        self.resolved_max_distance = resolve_max_distance(self.max_distance, &*problem_def.space);
+        self.resolved_search_radius =
+            resolve_search_radius(self.search_radius, self.resolved_max_distance);
+        if !can_keep_tree(
+            self.problem_def.as_ref(),
+            self.validity_checker.as_ref(),
+            self.tree.first().map(|node| &node.state),
+            &problem_def,
+            &validity_checker,
+        ) {
+            self.tree.clear();
+        }
+        self.initialise_tree(&problem_def);
         self.problem_def = Some(problem_def);
         self.validity_checker = Some(validity_checker);
-        self.tree.clear();
-
-        // Initialise the tree with the start state.
-        let start_state = self.problem_def.as_ref().unwrap().start_states[0].clone();
-        let start_node = Node {
-            state: start_state,
-            parent_index: None,
-            cost: 0.0,
-        };
-        self.tree.push(start_node);
+        self.stats.reset();
     }
 
     fn solve(&mut self, timeout: Duration) -> Result<Path<S>, PlanningError> {
         let pd = self
             .problem_def
-            .as_ref()
+            .clone()
+            .ok_or(PlanningError::PlannerUninitialised)?;
+        let vc = self
+            .validity_checker
+            .clone()
             .ok_or(PlanningError::PlannerUninitialised)?;
         let goal = &pd.goal;
+        let space = &*pd.space;
+        let stats = self.stats.clone();
+        let vc = stats.checker(&*vc);
+        let motion_valid = |from: &S, to: &S| stats.check_motion(space, &vc, from, to);
+
+        self.initialise_tree(&pd);
 
         let start_time = Instant::now();
         let mut rng = self
             .rng
             .take()
-            .unwrap_or_else(|| Box::new(StdRng::from_os_rng()));
+            .unwrap_or_else(|| Box::new(PlannerRng::from_os_rng()));
 
         // Main Loop
-        loop {
-            // 1. Check for timeout
-            if start_time.elapsed() > timeout {
-                return Err(PlanningError::Timeout);
-            }
-
-            // 2. Sample a state (q_rand)
-            let q_rand = if rng.random_bool(self.goal_bias) {
-                // TODO: assume sample_goal can't fail here for simplicity, but a real
-                // implementation would handle the Result.
-                goal.sample_goal(&mut rng).unwrap()
-            } else {
-                // TODO: assume uniform sampling can't fail if bounds are set correctly.
-                pd.space.sample_uniform(&mut rng).unwrap()
-            };
-
-            // 3. Find the nearest node in the tree (q_near)
-            let mut nearest_node_index = 0;
-            let mut min_dist = pd.space.distance(&self.tree[0].state, &q_rand);
-
-            for i in 1..self.tree.len() {
-                let dist = pd.space.distance(&self.tree[i].state, &q_rand);
-                if dist < min_dist {
-                    min_dist = dist;
-                    nearest_node_index = i;
+        while start_time.elapsed() <= timeout {
+            stats.iteration();
+
+            // 1. Sample a state (q_rand)
+            let q_rand = stats.sample(|| {
+                if rng.random_bool(self.goal_bias) {
+                    // TODO: assume sample_goal can't fail here for simplicity, but a real
+                    // implementation would handle the Result.
+                    goal.sample_goal(&mut rng).unwrap()
+                } else {
+                    // TODO: assume uniform sampling can't fail if bounds are set correctly.
+                    pd.space.sample_uniform(&mut rng).unwrap()
                 }
-            }
+            });
+
+            // 2. Find the nearest node in the tree (q_near) and steer from it towards q_rand to
+            //    get q_new
+            let (nearest_node_index, q_new, _) = stats.nearest(|| {
+                steer(
+                    self.tree.iter().map(|n| &n.state),
+                    &q_rand,
+                    space,
+                    self.resolved_max_distance,
+                )
+            });
             let q_near = &self.tree[nearest_node_index].state;
 
-            // 4. Steer from q_near towards q_rand to get q_new
-            let mut q_new = q_near.clone();
-            if min_dist > self.max_distance {
-                let t = self.max_distance / min_dist;
-                pd.space.interpolate(q_near, &q_rand, t, &mut q_new);
-            } else {
-                q_new = q_rand;
-            }
-
-            // 5. Check if the motion to q_new is valid
-            if !self.check_motion(q_near, &q_new) {
+            // 3. Check if the motion to q_new is valid
+            if !motion_valid(q_near, &q_new) {
                 continue;
             }
 
-            let temp_node = Node {
-                state: q_new.clone(),
-                parent_index: None,
-                cost: 0.0,
-            };
-            let neighbours: Vec<usize> = self.find_neighbours(&temp_node);
-
-            // 6. Choose parent
-            let mut best_parent_index = nearest_node_index;
-            let q_near_node = &self.tree[nearest_node_index];
-            let mut min_cost = self.cost(&temp_node, q_near_node);
-
-            // Iterate through neighbors to find a cheaper path. If a neighbor offers a cheaper
-            // path and the motion from that neighbor is collision-free we have found a new,
-            // better parent.
-            for &neighbour_idx in &neighbours {
-                let neighbour_node = &self.tree[neighbour_idx];
-                let cost_via_neighbour = self.cost(&temp_node, neighbour_node);
-
-                if cost_via_neighbour < min_cost && self.check_motion(&neighbour_node.state, &q_new)
-                {
-                    min_cost = cost_via_neighbour;
-                    best_parent_index = neighbour_idx;
-                }
+            // 4. Choose the parent of q_new, add it to the tree and rewire its neighbours
+            let reached_goal = goal.is_satisfied(&q_new);
+            self.insert(q_new, nearest_node_index, space, motion_valid);
+
+            // 5. Stop once the new node satisfies the goal
+            if reached_goal {
+                break;
             }
+        }
+        // Keep the random number generator, so a seeded planner stays reproducible across calls.
+        self.rng = Some(rng);
+
+        // Return the best path found so far, which may come from an earlier call.
+        let path = self
+            .best_goal_node(goal)
+            .map(|goal_node_index| reconstruct_path(&self.tree, goal_node_index))
+            .ok_or(PlanningError::Timeout)?;
+        stats.solution(space, &path);
+        Ok(path)
+    }
 
-            // 7. Add the new node to the tree with the optimal parent and cost.
-            let new_node = Node {
-                state: q_new.clone(),
-                parent_index: Some(best_parent_index),
-                cost: min_cost,
-            };
-            self.tree.push(new_node);
-            let new_node_index = self.tree.len() - 1;
-
-            // 8. Rewire tree
-            for &neighbour_idx in &neighbours {
-                let new_node_ref = &self.tree[new_node_index];
-                let neighbour_node = &self.tree[neighbour_idx];
-
-                // Don't try to rewire the new node's own parent.
-                if new_node_ref.parent_index == Some(neighbour_idx) {
-                    continue;
-                }
+    fn stats(&self) -> PlannerStats {
+        self.stats.snapshot()
+    }
 
-                // Calculate the cost to reach the neighbor if it were routed through the new
-                // node. If the path through the new node is shorter and the motion is valid
-                // update the neighbor's parent and cost. This is the "rewire" operation.
-                let cost_via_new_node = self.cost(neighbour_node, new_node_ref);
-
-                if cost_via_new_node < neighbour_node.cost
-                    && self.check_motion(&new_node_ref.state, &neighbour_node.state)
-                {
-                    let mutable_neighbour_node = &mut self.tree[neighbour_idx];
-                    mutable_neighbour_node.parent_index = Some(new_node_index);
-                    mutable_neighbour_node.cost = cost_via_new_node;
-                }
-            }
+    fn set_observer(&mut self, observer: Arc<dyn PlannerObserver<S>>) {
+        stats::set_observer(&mut self.stats, observer);
+    }
+
+    fn params(&self) -> ParamSet {
+        ParamSet::default()
+            .with_real(
+                "max_distance",
+                "The maximum length of a motion. 0 uses a fifth of the space's maximum extent.",
+                self.max_distance,
+                0.0..=f64::INFINITY,
+            )
+            .with_real(
+                "goal_bias",
+                "The probability of sampling the goal region instead of the whole space.",
+                self.goal_bias,
+                0.0..=1.0,
+            )
+            .with_real(
+                "search_radius",
+                "The radius for choosing parents and rewiring. 0 uses twice max_distance.",
+                self.search_radius,
+                0.0..=f64::INFINITY,
+            )
+    }
+
+    fn set_params(&mut self, params: &ParamSet) {
+        if let Some(max_distance) = params.real("max_distance") {
+            self.max_distance = max_distance;
+        }
+        if let Some(goal_bias) = params.real("goal_bias") {
+            self.goal_bias = goal_bias;
+        }
+        if let Some(search_radius) = params.real("search_radius") {
+            self.search_radius = search_radius;
+        }
+    }
+}
+
+/// How many times `max_distance` the default `search_radius` is.
+const DEFAULT_SEARCH_RADIUS_FACTOR: f64 = 2.0;
+
+/// Returns `search_radius` if it is positive, or otherwise twice `max_distance`.
+///
+/// Optimising planners call this in `setup`, after resolving `max_distance`.
+pub(crate) fn resolve_search_radius(search_radius: f64, max_distance: f64) -> f64 {
+    if search_radius > 0.0 {
+        search_radius
+    } else {
+        DEFAULT_SEARCH_RADIUS_FACTOR * max_distance
+    }
+}
+
+/// Finds all nodes in the tree that are within `search_radius` of `state`.
+///
+/// This is a simple linear scan;
+/// TODO: Consider using kd-trees.
+pub(crate) fn find_neighbours<S: State, SP: StateSpace<StateType = S>>(
+    tree: &[Node<S>],
+    state: &S,
+    space: &SP,
+    search_radius: f64,
+) -> Vec<usize> {
+    let mut neighbours: Vec<usize> = Vec::new();
+    for (i, node) in tree.iter().enumerate() {
+        if space.distance(state, &node.state) < search_radius {
+            neighbours.push(i);
+        }
+    }
+    neighbours
+}
 
-            // 9. Check if the new node satisfies the goal
-            if goal.is_satisfied(&q_new) {
-                println!("Solution found after {} nodes.", self.tree.len());
-                return Ok(self.reconstruct_path(self.tree.len() - 1));
+/// The "Choose Parent" step of RRT*.
+///
+/// Starting from `nearest_node_index`, picks the node among `neighbours` that gives `q_new` the
+/// lowest cost and can reach it with a valid motion. The motion from the nearest node is assumed
+/// to have been checked already.
+///
+/// Returns the index of the chosen parent and the resulting cost of `q_new`.
+pub(crate) fn choose_parent<S: State, SP: StateSpace<StateType = S>>(
+    tree: &[Node<S>],
+    q_new: &S,
+    nearest_node_index: usize,
+    neighbours: &[usize],
+    space: &SP,
+    check_motion: impl Fn(&S, &S) -> bool,
+) -> (usize, f64) {
+    let mut best_parent_index = nearest_node_index;
+    let nearest_node = &tree[nearest_node_index];
+    let mut min_cost = nearest_node.cost + space.distance(q_new, &nearest_node.state);
+
+    // Iterate through neighbors to find a cheaper path. If a neighbor offers a cheaper path and
+    // the motion from that neighbor is collision-free we have found a new, better parent.
+    for &neighbour_idx in neighbours {
+        let neighbour_node = &tree[neighbour_idx];
+        let cost_via_neighbour = neighbour_node.cost + space.distance(q_new, &neighbour_node.state);
+
+        if cost_via_neighbour < min_cost && check_motion(&neighbour_node.state, q_new) {
+            min_cost = cost_via_neighbour;
+            best_parent_index = neighbour_idx;
+        }
+    }
+
+    (best_parent_index, min_cost)
+}
+
+/// The "Rewire" step of RRT*.
+///
+/// Re-parents every node in `neighbours` through `new_node_index` if doing so lowers its cost and
+/// the motion is valid. Returns the indices of the nodes that were rewired.
+///
+/// The removed and added edges of every rewired node are reported to the observer of `stats`.
+///
+/// > [!NOTE]
+/// > Only the rewired neighbours have their costs updated here; their descendants keep their
+/// > previous (now overestimated) costs until they are rewired themselves.
+pub(crate) fn rewire<S: State, SP: StateSpace<StateType = S>>(
+    tree: &mut [Node<S>],
+    new_node_index: usize,
+    neighbours: &[usize],
+    space: &SP,
+    stats: &StatsRecorder<S>,
+    check_motion: impl Fn(&S, &S) -> bool,
+) -> Vec<usize> {
+    let mut rewired = Vec::new();
+    for &neighbour_idx in neighbours {
+        let new_node_ref = &tree[new_node_index];
+        let neighbour_node = &tree[neighbour_idx];
+
+        // Don't try to rewire the new node's own parent.
+        if new_node_ref.parent_index == Some(neighbour_idx) || neighbour_idx == new_node_index {
+            continue;
+        }
+
+        // Calculate the cost to reach the neighbor if it were routed through the new node. If the
+        // path through the new node is shorter and the motion is valid update the neighbor's
+        // parent and cost. This is the "rewire" operation.
+        let cost_via_new_node =
+            new_node_ref.cost + space.distance(&neighbour_node.state, &new_node_ref.state);
+
+        if cost_via_new_node < neighbour_node.cost
+            && check_motion(&new_node_ref.state, &neighbour_node.state)
+        {
+            if let Some(old_parent_idx) = neighbour_node.parent_index {
+                stats.edge_removed(&tree[old_parent_idx].state, &neighbour_node.state);
             }
+            stats.edge_added(&new_node_ref.state, &neighbour_node.state);
+            let mutable_neighbour_node = &mut tree[neighbour_idx];
+            mutable_neighbour_node.parent_index = Some(new_node_index);
+            mutable_neighbour_node.cost = cost_via_new_node;
+            rewired.push(neighbour_idx);
         }
     }
+    rewired
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base::{goal::GoalState, space::RealVectorStateSpace, state::RealVectorState};
+
+    #[test]
+    fn test_tree_states_puts_parents_first() {
+        let mut planner: RRTStar<_, RealVectorStateSpace, GoalState<RealVectorStateSpace>> =
+            RRTStar::new(0.5, 0.05, 1.0, &PlannerConfig::default());
+        // Node 1 has been rewired through node 2, which was added after it.
+        planner.tree = [(0.0, None), (2.0, Some(2)), (1.0, Some(0))]
+            .into_iter()
+            .map(|(x, parent_index)| Node {
+                state: RealVectorState::new(vec![x]),
+                parent_index,
+                cost: x,
+            })
+            .collect();
+
+        let order: Vec<f64> = planner.tree_states().map(|state| state.values[0]).collect();
+        assert_eq!(order, vec![0.0, 1.0, 2.0]);
+    }
 }
@@ -0,0 +1,606 @@
+// Copyright (c) 2025 Junior Sundar
+//
+// SPDX-License-Identifier: BSD-3-Clause
+
+use std::{
+    collections::{BinaryHeap, HashSet},
+    sync::Arc,
+};
+
+use crate::{
+    base::planner::PlannerConfig,
+    time::{Duration, Instant},
+};
+
+use rand::{Rng, SeedableRng};
+
+use crate::base::{
+    error::PlanningError,
+    goal::{Goal, GoalSampleableRegion},
+    observer::PlannerObserver,
+    param::ParamSet,
+    planner::{Path, Planner, PlannerStats},
+    problem_definition::ProblemDefinition,
+    rng::PlannerRng,
+    space::StateSpace,
+    state::State,
+    validity::StateValidityChecker,
+};
+
+use super::{
+    common::{ordered_pair, reconstruct_path, QueueEntry, TreeNode},
+    rrt::resolve_max_distance,
+    rrt_star::resolve_search_radius,
+    stats::{self, StatsRecorder},
+};
+
+/// The number of attempts made to sample a valid goal state for the root of the tree.
+const ROOT_SAMPLE_ATTEMPTS: usize = 100;
+
+// A helper struct to build the tree. Unlike the other tree planners, RRTX keeps every edge to
+// nearby nodes (not just the parent edge) so that the tree can be repaired when edges become
+// blocked or unblocked. `cost` is the cost-to-goal, and is infinite for orphaned nodes.
+#[derive(Clone)]
+struct Node<S: State> {
+    state: S,
+    parent_index: Option<usize>,
+    children: Vec<usize>,
+    neighbours: Vec<usize>,
+    cost: f64,
+}
+
+impl<S: State> TreeNode<S> for Node<S> {
+    fn state(&self) -> &S {
+        &self.state
+    }
+
+    fn parent_index(&self) -> Option<usize> {
+        self.parent_index
+    }
+}
+
+/// An implementation of the RRTX algorithm for replanning in changing environments.
+///
+/// RRTX grows an RRT*-style shortest-path tree rooted at the goal, so every node stores its
+/// cost-to-goal and the robot can be connected to the tree from wherever it currently is. When
+/// the environment changes the tree is repaired in place instead of being rebuilt:
+///
+/// 1.  **Invalidation**: [`RRTX::notify_region_invalidated`] re-checks every edge passing through
+///     the changed region. Nodes whose parent edge is now blocked are orphaned along with their
+///     subtrees, and are then reconnected through their remaining neighbours.
+/// 2.  **Validation**: [`RRTX::notify_region_validated`] re-checks the blocked edges passing
+///     through the region, unblocks those that are valid again, and propagates any cost
+///     improvements through the tree.
+///
+/// The validity checker passed to `setup` is expected to reflect the current environment (for
+/// instance through interior mutability) by the time a notification is sent.
+///
+/// `solve` returns a path from the robot's current state (see [`RRTX::set_robot_state`]) to the
+/// goal as soon as the robot can be connected to the tree, growing the tree only if needed.
+pub struct RRTX<S: State, SP: StateSpace<StateType = S>, G: Goal<S>> {
+    /// The maximum distance between nodes in the tree. This is the "step size". If it is 0,
+    /// each call to `setup` uses a fifth of the maximum extent of the space.
+    pub max_distance: f64,
+    /// The probability of sampling the robot's current state instead of the whole space.
+    pub robot_bias: f64,
+    /// The radius within which nodes are connected as neighbours. If it is 0, each call to `setup`
+    /// uses twice `max_distance`.
+    pub search_radius: f64,
+
+    // The parameters above, with any that are 0 resolved by `setup`.
+    resolved_max_distance: f64,
+    resolved_search_radius: f64,
+    problem_def: Option<Arc<ProblemDefinition<S, SP, G>>>,
+    validity_checker: Option<Arc<dyn StateValidityChecker<S>>>,
+    tree: Vec<Node<S>>,
+    blocked_edges: HashSet<(usize, usize)>,
+    robot_state: Option<S>,
+    rng: Option<Box<PlannerRng>>,
+    stats: Arc<StatsRecorder<S>>,
+}
+
+impl<S, SP, G> RRTX<S, SP, G>
+where
+    S: State + Clone,
+    SP: StateSpace<StateType = S>,
+    G: Goal<S> + GoalSampleableRegion<S>,
+{
+    /// Creates a new `RRTX` planner with the specified parameters.
+    ///
+    /// # Parameters
+    /// * `max_distance` - The maximum length of a single branch in the tree.
+    /// * `robot_bias` - The probability (0.0 to 1.0) of sampling the robot's current state.
+    /// * `search_radius` - The radius for connecting neighbours and rewiring.
+    /// * `config` - The planner configuration, for planner-specific parameters.
+    pub fn new(
+        max_distance: f64,
+        robot_bias: f64,
+        search_radius: f64,
+        config: &PlannerConfig,
+    ) -> Self {
+        let rng = config.seed.map(|s| Box::new(PlannerRng::seed_from_u64(s)));
+        RRTX {
+            max_distance,
+            robot_bias,
+            search_radius,
+            resolved_max_distance: max_distance,
+            resolved_search_radius: search_radius,
+            problem_def: None,
+            validity_checker: None,
+            tree: Vec::new(),
+            blocked_edges: HashSet::new(),
+            robot_state: None,
+            rng,
+            stats: Arc::default(),
+        }
+    }
+
+    /// Updates the robot's current state. The next call to `solve` returns a path from here.
+    ///
+    /// It can be called before `setup`, which otherwise starts the robot at the first start state.
+    pub fn set_robot_state(&mut self, state: S) {
+        self.robot_state = Some(state);
+    }
+
+    /// Returns the number of nodes in the tree.
+    pub fn tree_size(&self) -> usize {
+        self.tree.len()
+    }
+
+    /// Notifies the planner that part of the space may have become invalid.
+    ///
+    /// Every edge with a state inside `region` is re-checked against the validity checker. Edges
+    /// that are now in collision are blocked, their dependent subtrees are orphaned, and the
+    /// orphans are reconnected to the rest of the tree where possible.
+    ///
+    /// # Parameters
+    /// * `region` - Returns `true` for states inside the changed region.
+    pub fn notify_region_invalidated(&mut self, region: impl Fn(&S) -> bool) {
+        let (Some(pd), Some(vc)) = (self.problem_def.clone(), self.validity_checker.clone()) else {
+            return;
+        };
+        let space = &*pd.space;
+        let stats = self.stats.clone();
+        let vc = stats.checker(&*vc);
+
+        let mut newly_blocked = Vec::new();
+        for a in 0..self.tree.len() {
+            for &b in &self.tree[a].neighbours {
+                let edge = ordered_pair(a, b);
+                if a > b || self.blocked_edges.contains(&edge) {
+                    continue;
+                }
+                let (from, to) = (&self.tree[a].state, &self.tree[b].state);
+                if edge_in_region(space, from, to, &region)
+                    && !(vc.is_valid(from) && stats.check_motion(space, &vc, from, to))
+                {
+                    newly_blocked.push(edge);
+                }
+            }
+        }
+
+        let mut orphans = Vec::new();
+        for (a, b) in newly_blocked {
+            self.blocked_edges.insert((a, b));
+            if self.tree[a].parent_index == Some(b) {
+                orphans.push(a);
+            } else if self.tree[b].parent_index == Some(a) {
+                orphans.push(b);
+            }
+        }
+
+        let mut orphaned = HashSet::new();
+        let mut stack = orphans;
+        while let Some(idx) = stack.pop() {
+            if !orphaned.insert(idx) {
+                continue;
+            }
+            self.set_parent(idx, None);
+            self.tree[idx].cost = f64::INFINITY;
+            stack.extend(std::mem::take(&mut self.tree[idx].children));
+        }
+
+        // Reconnect the orphans from every intact node that neighbours them.
+        let mut orphaned: Vec<usize> = orphaned.into_iter().collect();
+        orphaned.sort_unstable();
+        let mut queue = BinaryHeap::new();
+        for &idx in &orphaned {
+            for &n in &self.tree[idx].neighbours {
+                if self.tree[n].cost.is_finite() {
+                    queue.push(QueueEntry {
+                        cost: self.tree[n].cost,
+                        index: n,
+                    });
+                }
+            }
+        }
+        self.propagate(queue, space);
+    }
+
+    /// Notifies the planner that part of the space may have become valid again.
+    ///
+    /// Every blocked edge with a state inside `region` is re-checked against the validity checker.
+    /// Edges that are valid again are unblocked, and any resulting cost improvements are propagated
+    /// through the tree.
+    ///
+    /// # Parameters
+    /// * `region` - Returns `true` for states inside the changed region.
+    pub fn notify_region_validated(&mut self, region: impl Fn(&S) -> bool) {
+        let (Some(pd), Some(vc)) = (self.problem_def.clone(), self.validity_checker.clone()) else {
+            return;
+        };
+        let space = &*pd.space;
+        let stats = self.stats.clone();
+        let vc = stats.checker(&*vc);
+
+        let mut blocked: Vec<(usize, usize)> = self.blocked_edges.iter().copied().collect();
+        blocked.sort_unstable();
+
+        let mut queue = BinaryHeap::new();
+        for (a, b) in blocked {
+            let (from, to) = (&self.tree[a].state, &self.tree[b].state);
+            if edge_in_region(space, from, to, &region)
+                && vc.is_valid(from)
+                && stats.check_motion(space, &vc, from, to)
+            {
+                self.blocked_edges.remove(&(a, b));
+                for idx in [a, b] {
+                    if self.tree[idx].cost.is_finite() {
+                        queue.push(QueueEntry {
+                            cost: self.tree[idx].cost,
+                            index: idx,
+                        });
+                    }
+                }
+            }
+        }
+        self.propagate(queue, space);
+    }
+
+    /// Relaxes the costs of the neighbours of every queued node, Dijkstra style, re-parenting
+    /// nodes whenever a cheaper route to the goal is found. This performs both the rewiring
+    /// cascade after an insertion and the repair after a notification.
+    fn propagate(&mut self, mut queue: BinaryHeap<QueueEntry>, space: &SP) {
+        while let Some(QueueEntry { cost, index }) = queue.pop() {
+            if cost > self.tree[index].cost {
+                continue;
+            }
+
+            for n in self.tree[index].neighbours.clone() {
+                if self.blocked_edges.contains(&ordered_pair(index, n)) {
+                    continue;
+                }
+                let cost_via_index =
+                    cost + space.distance(&self.tree[index].state, &self.tree[n].state);
+                if cost_via_index < self.tree[n].cost {
+                    self.set_parent(n, Some(index));
+                    self.tree[n].cost = cost_via_index;
+                    queue.push(QueueEntry {
+                        cost: cost_via_index,
+                        index: n,
+                    });
+                }
+            }
+        }
+    }
+
+    /// Moves `child` to `parent` in the tree, reporting the changed edges to the observer.
+    fn set_parent(&mut self, child: usize, parent: Option<usize>) {
+        if let Some(old_parent) = self.tree[child].parent_index {
+            self.stats
+                .edge_removed(&self.tree[old_parent].state, &self.tree[child].state);
+            self.tree[old_parent].children.retain(|&c| c != child);
+        }
+        if let Some(new_parent) = parent {
+            self.stats
+                .edge_added(&self.tree[new_parent].state, &self.tree[child].state);
+            self.tree[new_parent].children.push(child);
+        }
+        self.tree[child].parent_index = parent;
+    }
+
+    /// Finds the cheapest connected node among the robot's neighbours.
+    fn connect_robot(&self, robot_neighbours: &[usize], robot: &S, space: &SP) -> Option<usize> {
+        robot_neighbours
+            .iter()
+            .copied()
+            .filter(|&i| self.tree[i].cost.is_finite())
+            .min_by(|&a, &b| {
+                let cost_a = self.tree[a].cost + space.distance(robot, &self.tree[a].state);
+                let cost_b = self.tree[b].cost + space.distance(robot, &self.tree[b].state);
+                cost_a.total_cmp(&cost_b)
+            })
+    }
+}
+
+impl<S, SP, G> Planner<S, SP, G> for RRTX<S, SP, G>
+where
+    S: State + Clone,
+    SP: StateSpace<StateType = S>,
+    G: Goal<S> + GoalSampleableRegion<S>,
+{
+    fn setup(
+        &mut self,
+        problem_def: Arc<ProblemDefinition<S, SP, G>>,
+        validity_checker: Arc<dyn StateValidityChecker<S>>,
+    ) {
+        self.resolved_max_distance = resolve_max_distance(self.max_distance, &*problem_def.space);
+        self.resolved_search_radius =
+            resolve_search_radius(self.search_radius, self.resolved_max_distance);
+        self.tree.clear();
+        self.blocked_edges.clear();
+        self.stats.reset();
+        if self.robot_state.is_none() {
+            self.robot_state = Some(problem_def.start_states[0].clone());
+        }
+
+        // Initialise the tree with a sample from the goal region as its root.
+        let mut rng = self
+            .rng
+            .take()
+            .unwrap_or_else(|| Box::new(PlannerRng::from_os_rng()));
+        let stats = &*self.stats;
+        let vc = stats.checker(&*validity_checker);
+        let root = (0..ROOT_SAMPLE_ATTEMPTS)
+            .filter_map(|_| stats.sample(|| problem_def.goal.sample_goal(&mut rng)).ok())
+            .find(|state| {
+                let valid = vc.is_valid(state);
+                if !valid {
+                    stats.reject_sample(Some(state));
+                }
+                valid
+            });
+        if let Some(state) = root {
+            stats.node_added(&state, None);
+            self.tree.push(Node {
+                state,
+                parent_index: None,
+                children: Vec::new(),
+                neighbours: Vec::new(),
+                cost: 0.0,
+            });
+        }
+        self.rng = Some(rng);
+
+        self.problem_def = Some(problem_def);
+        self.validity_checker = Some(validity_checker);
+    }
+
+    fn solve(&mut self, timeout: Duration) -> Result<Path<S>, PlanningError> {
+        let pd = self
+            .problem_def
+            .clone()
+            .ok_or(PlanningError::PlannerUninitialised)?;
+        let vc = self
+            .validity_checker
+            .clone()
+            .ok_or(PlanningError::PlannerUninitialised)?;
+        let space = &*pd.space;
+        let stats = self.stats.clone();
+        let vc = stats.checker(&*vc);
+
+        let robot = self
+            .robot_state
+            .clone()
+            .ok_or(PlanningError::PlannerUninitialised)?;
+        if !vc.is_valid(&robot) {
+            return Err(PlanningError::InvalidStartState);
+        }
+        if self.tree.is_empty() {
+            return Err(PlanningError::NoSolutionFound);
+        }
+
+        let start_time = Instant::now();
+        let mut rng = self
+            .rng
+            .take()
+            .unwrap_or_else(|| Box::new(PlannerRng::from_os_rng()));
+
+        // Nodes the robot can currently see, kept up to date as the tree grows.
+        let mut robot_neighbours: Vec<usize> = stats
+            .nearest(|| {
+                (0..self.tree.len())
+                    .filter(|&i| {
+                        space.distance(&robot, &self.tree[i].state) < self.resolved_search_radius
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .into_iter()
+            .filter(|&i| stats.check_motion(space, &vc, &robot, &self.tree[i].state))
+            .collect();
+
+        // Main Loop
+        let result = loop {
+            // 1. Return as soon as the robot can be connected to the tree.
+            if let Some(idx) = self.connect_robot(&robot_neighbours, &robot, space) {
+                // The tree is rooted at the goal, so its path runs from the goal to the robot.
+                let mut path_states = reconstruct_path(&self.tree, idx).0;
+                path_states.push(robot.clone());
+                path_states.reverse();
+                let path = Path(path_states);
+                stats.solution(space, &path);
+                break Ok(path);
+            }
+
+            // 2. Check for timeout
+            if start_time.elapsed() > timeout {
+                break Err(PlanningError::Timeout);
+            }
+
+            stats.iteration();
+
+            // 3. Sample a state (q_rand)
+            let q_rand = stats.sample(|| {
+                if rng.random_bool(self.robot_bias) {
+                    robot.clone()
+                } else {
+                    // TODO: assume uniform sampling can't fail if bounds are set correctly.
+                    space.sample_uniform(&mut rng).unwrap()
+                }
+            });
+
+            // 4. Find the nearest node in the tree (q_near) and steer towards q_rand
+            let (nearest_node_index, min_dist) = stats.nearest(|| {
+                let mut nearest_node_index = 0;
+                let mut min_dist = space.distance(&self.tree[0].state, &q_rand);
+                for i in 1..self.tree.len() {
+                    let dist = space.distance(&self.tree[i].state, &q_rand);
+                    if dist < min_dist {
+                        min_dist = dist;
+                        nearest_node_index = i;
+                    }
+                }
+                (nearest_node_index, min_dist)
+            });
+            let q_near = &self.tree[nearest_node_index].state;
+
+            let mut q_new = q_near.clone();
+            if min_dist > self.resolved_max_distance {
+                let t = self.resolved_max_distance / min_dist;
+                space.interpolate(q_near, &q_rand, t, &mut q_new);
+            } else {
+                q_new = q_rand;
+            }
+
+            if !vc.is_valid(&q_new) {
+                stats.reject_sample(Some(&q_new));
+                continue;
+            }
+
+            // 5. Connect to every neighbour, remembering which edges are blocked, and choose the
+            //    parent with the lowest cost-to-goal.
+            let mut neighbours: Vec<usize> = stats.nearest(|| {
+                (0..self.tree.len())
+                    .filter(|&i| {
+                        space.distance(&q_new, &self.tree[i].state) < self.resolved_search_radius
+                    })
+                    .collect()
+            });
+            if !neighbours.contains(&nearest_node_index) {
+                neighbours.push(nearest_node_index);
+            }
+
+            let mut blocked = Vec::new();
+            let mut best_parent = None;
+            let mut min_cost = f64::INFINITY;
+            for &n in &neighbours {
+                if !stats.check_motion(space, &vc, &q_new, &self.tree[n].state) {
+                    blocked.push(n);
+                    continue;
+                }
+                let cost_via_neighbour =
+                    self.tree[n].cost + space.distance(&q_new, &self.tree[n].state);
+                if cost_via_neighbour < min_cost {
+                    min_cost = cost_via_neighbour;
+                    best_parent = Some(n);
+                }
+            }
+            let Some(best_parent) = best_parent else {
+                continue;
+            };
+
+            // 6. Add the new node to the tree.
+            let new_node_index = self.tree.len();
+            stats.node_added(&q_new, None);
+            self.tree.push(Node {
+                state: q_new.clone(),
+                parent_index: None,
+                children: Vec::new(),
+                neighbours: neighbours.clone(),
+                cost: min_cost,
+            });
+            self.set_parent(new_node_index, Some(best_parent));
+            for &n in &neighbours {
+                self.tree[n].neighbours.push(new_node_index);
+            }
+            for n in blocked {
+                self.blocked_edges.insert(ordered_pair(n, new_node_index));
+            }
+
+            if space.distance(&robot, &q_new) < self.resolved_search_radius
+                && stats.check_motion(space, &vc, &robot, &q_new)
+            {
+                robot_neighbours.push(new_node_index);
+            }
+
+            // 7. Rewire the neighbourhood, cascading cost improvements through the tree.
+            let mut queue = BinaryHeap::new();
+            queue.push(QueueEntry {
+                cost: min_cost,
+                index: new_node_index,
+            });
+            self.propagate(queue, space);
+        };
+        self.rng = Some(rng);
+
+        result
+    }
+
+    fn stats(&self) -> PlannerStats {
+        self.stats.snapshot()
+    }
+
+    fn set_observer(&mut self, observer: Arc<dyn PlannerObserver<S>>) {
+        stats::set_observer(&mut self.stats, observer);
+    }
+
+    fn params(&self) -> ParamSet {
+        ParamSet::default()
+            .with_real(
+                "max_distance",
+                "The maximum length of a motion. 0 uses a fifth of the space's maximum extent.",
+                self.max_distance,
+                0.0..=f64::INFINITY,
+            )
+            .with_real(
+                "robot_bias",
+                "The probability of sampling the robot's current state instead of the whole space.",
+                self.robot_bias,
+                0.0..=1.0,
+            )
+            .with_real(
+                "search_radius",
+                "The radius within which nodes are neighbours. 0 uses twice max_distance.",
+                self.search_radius,
+                0.0..=f64::INFINITY,
+            )
+    }
+
+    fn set_params(&mut self, params: &ParamSet) {
+        if let Some(max_distance) = params.real("max_distance") {
+            self.max_distance = max_distance;
+        }
+        if let Some(robot_bias) = params.real("robot_bias") {
+            self.robot_bias = robot_bias;
+        }
+        if let Some(search_radius) = params.real("search_radius") {
+            self.search_radius = search_radius;
+        }
+    }
+}
+
+/// Returns `true` if any state along the straight-line motion between `from` and `to` lies in
+/// `region`.
+fn edge_in_region<S: State + Clone, SP: StateSpace<StateType = S>>(
+    space: &SP,
+    from: &S,
+    to: &S,
+    region: &impl Fn(&S) -> bool,
+) -> bool {
+    if region(from) || region(to) {
+        return true;
+    }
+
+    let dist = space.distance(from, to);
+    let num_steps = (dist / (space.get_longest_valid_segment_length() * 0.1)).ceil() as usize;
+    let mut interpolated_state = from.clone();
+    for i in 1..num_steps {
+        let t = i as f64 / num_steps as f64;
+        space.interpolate(from, to, t, &mut interpolated_state);
+        if region(&interpolated_state) {
+            return true;
+        }
+    }
+
+    false
+}
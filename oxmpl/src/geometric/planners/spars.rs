@@ -0,0 +1,649 @@
+// Copyright (c) 2025 Junior Sundar
+//
+// SPDX-License-Identifier: BSD-3-Clause
+
+use std::{collections::HashSet, sync::Arc};
+
+use rand::{Rng, SeedableRng};
+
+use crate::time::{Duration, Instant};
+
+use crate::base::{
+    error::PlanningError,
+    goal::{Goal, GoalSampleableRegion},
+    observer::PlannerObserver,
+    param::ParamSet,
+    planner::{Path, Planner, PlannerConfig, PlannerStats},
+    problem_definition::ProblemDefinition,
+    rng::PlannerRng,
+    space::StateSpace,
+    state::State,
+    validity::StateValidityChecker,
+};
+
+use super::{
+    common::{ordered_pair, shortest_path},
+    prm::Node,
+    rrt::resolve_distance,
+    stats::{self, StatsRecorder},
+    union_find::UnionFind,
+};
+
+/// The number of goal samples drawn when connecting the goal region to a roadmap during a query.
+const GOAL_SAMPLE_ATTEMPTS: usize = 10;
+
+/// The fraction of the space's maximum extent used as the default `sparse_delta`.
+pub(crate) const DEFAULT_SPARSE_DELTA_FRACTION: f64 = 0.25;
+
+/// The fraction of `sparse_delta` used as the default `dense_delta`.
+pub(crate) const DEFAULT_DENSE_DELTA_FACTOR: f64 = 0.1;
+
+/// An implementation of the SPArse Roadmap Spanner (SPARS) algorithm.
+///
+/// SPARS builds a compact roadmap that is an asymptotically near-optimal spanner of the dense
+/// roadmap `PRM` would produce. Alongside the sparse roadmap it maintains a dense graph of every
+/// valid sample, which is used to detect interfaces between sparse nodes and to measure the
+/// quality of paths through the sparse roadmap.
+///
+/// # Algorithm Overview
+///
+/// Every valid sample is added to the dense graph, but only becomes a sparse node if it is needed
+/// for one of the following criteria:
+/// 1.  **Coverage**: No sparse node is visible within `sparse_delta`, so the sample becomes a new
+///     guard.
+/// 2.  **Connectivity**: The sample sees sparse nodes from two or more disconnected components, so
+///     it is added to join them.
+/// 3.  **Interface**: The sample neighbours (in the dense graph) a sample with a different
+///     representative, and those two representatives are not yet connected by an edge.
+/// 4.  **Quality**: A newly discovered interface reveals a dense path between two sparse nodes
+///     that is more than `stretch_factor` times shorter than their path in the sparse roadmap. The
+///     dense path is shortcut and added to the sparse roadmap.
+///
+/// Construction stops once `max_failures` consecutive samples have been rejected, or when
+/// `timeout` elapses. The resulting sparse roadmap is queried in the same way as `PRM`'s.
+pub struct SPARS<S: State, SP: StateSpace<StateType = S>, G: Goal<S>> {
+    /// The time allocated for roadmap construction, in seconds.
+    pub timeout: f64,
+    /// The visibility radius of the sparse roadmap nodes. If it is 0, each call to `setup` uses a
+    /// quarter of the maximum extent of the space.
+    pub sparse_delta: f64,
+    /// The connection radius of the dense graph. If it is 0, each call to `setup` uses a tenth of
+    /// `sparse_delta`.
+    pub dense_delta: f64,
+    /// The multiplicative bound on sparse path lengths relative to dense path lengths.
+    pub stretch_factor: f64,
+    /// The number of consecutive rejected samples after which construction is considered done.
+    pub max_failures: usize,
+
+    // The parameters above, with any that are 0 resolved by `setup`.
+    resolved_sparse_delta: f64,
+    resolved_dense_delta: f64,
+    problem_def: Option<Arc<ProblemDefinition<S, SP, G>>>,
+    validity_checker: Option<Arc<dyn StateValidityChecker<S>>>,
+    roadmap: Vec<Node<S>>,
+    components: UnionFind,
+    /// For every sparse node, the index of its counterpart in the dense graph.
+    dense_indices: Vec<usize>,
+    dense: Vec<Node<S>>,
+    /// For every dense node, the index of the sparse node that represents it.
+    representatives: Vec<usize>,
+    /// Unordered pairs of sparse nodes whose regions have been found to share an interface.
+    interfaces: HashSet<(usize, usize)>,
+    rng: Option<Box<PlannerRng>>,
+    stats: Arc<StatsRecorder<S>>,
+}
+
+impl<S, SP, G> SPARS<S, SP, G>
+where
+    S: State + Clone,
+    SP: StateSpace<StateType = S>,
+    G: Goal<S>,
+{
+    /// Creates a new `SPARS` planner with the specified parameters.
+    ///
+    /// # Parameters
+    /// * `timeout` - The time in seconds to spend building the roadmap.
+    /// * `sparse_delta` - The visibility radius of sparse roadmap nodes.
+    /// * `dense_delta` - The connection radius of the underlying dense graph. Should be a small
+    ///   fraction of `sparse_delta`.
+    /// * `stretch_factor` - The allowed ratio between sparse and dense path lengths. Must be
+    ///   greater than 1.
+    /// * `max_failures` - The number of consecutive useless samples before construction stops.
+    /// * `config` - The planner configuration, for planner-specific parameters.
+    pub fn new(
+        timeout: f64,
+        sparse_delta: f64,
+        dense_delta: f64,
+        stretch_factor: f64,
+        max_failures: usize,
+        config: &PlannerConfig,
+    ) -> Self {
+        let rng = config.seed.map(|s| Box::new(PlannerRng::seed_from_u64(s)));
+        SPARS {
+            timeout,
+            sparse_delta,
+            dense_delta,
+            stretch_factor,
+            max_failures,
+            resolved_sparse_delta: sparse_delta,
+            resolved_dense_delta: dense_delta,
+            problem_def: None,
+            validity_checker: None,
+            roadmap: Vec::new(),
+            components: UnionFind::default(),
+            dense_indices: Vec::new(),
+            dense: Vec::new(),
+            representatives: Vec::new(),
+            interfaces: HashSet::new(),
+            rng,
+            stats: Arc::default(),
+        }
+    }
+
+    /// Get private variable `roadmap` (the sparse roadmap) as a clone.
+    pub fn get_roadmap(&self) -> Vec<Node<S>> {
+        self.roadmap.clone()
+    }
+
+    /// Returns the number of nodes in the dense graph used during construction.
+    pub fn dense_node_count(&self) -> usize {
+        self.dense.len()
+    }
+
+    /// Update ProblemDefinition. This is so that you can use an already constructed roadmap but
+    /// just change the start and goal states.
+    pub fn set_problem_definition(&mut self, pd: Arc<ProblemDefinition<S, SP, G>>) {
+        self.problem_def = Some(pd);
+    }
+
+    /// Constructs the sparse roadmap.
+    ///
+    /// Samples are drawn until either `max_failures` consecutive samples have been rejected by
+    /// every criterion or `timeout` seconds have elapsed.
+    pub fn construct_roadmap(&mut self) -> Result<(), PlanningError> {
+        let pd = self
+            .problem_def
+            .clone()
+            .ok_or(PlanningError::PlannerUninitialised)?;
+        let vc = self
+            .validity_checker
+            .clone()
+            .ok_or(PlanningError::PlannerUninitialised)?;
+
+        if !self.roadmap.is_empty() {
+            log::info!(
+                "SPARS: Roadmap already constructed with {} milestones.",
+                self.roadmap.len()
+            );
+
+            return Ok(());
+        }
+
+        let mut rng = self
+            .rng
+            .take()
+            .unwrap_or_else(|| Box::new(PlannerRng::from_os_rng()));
+        let stats = self.stats.clone();
+        let vc = stats.checker(&*vc);
+        let start_time = Instant::now();
+        let mut failures = 0;
+        while failures < self.max_failures && start_time.elapsed().as_secs_f64() <= self.timeout {
+            stats.iteration();
+            let q_rand = stats.sample(|| pd.space.sample_uniform(&mut *rng).unwrap());
+            if !vc.is_valid(&q_rand) {
+                stats.reject_sample(Some(&q_rand));
+                continue;
+            }
+
+            if self.add_sample(q_rand, &*pd.space, &vc, &stats) {
+                failures = 0;
+            } else {
+                failures += 1;
+            }
+        }
+        self.rng = Some(rng);
+
+        log::info!(
+            "SPARS: Roadmap constructed with {} milestones from {} dense samples.",
+            self.roadmap.len(),
+            self.dense.len()
+        );
+
+        Ok(())
+    }
+
+    /// Inserts a valid sample into the dense graph and applies the sparse roadmap criteria.
+    ///
+    /// Returns `true` if the sparse roadmap was modified.
+    fn add_sample(
+        &mut self,
+        q: S,
+        space: &SP,
+        vc: &dyn StateValidityChecker<S>,
+        stats: &StatsRecorder<S>,
+    ) -> bool {
+        let dense_idx = self.dense.len();
+        let dense_neighbours: Vec<usize> = stats
+            .nearest(|| {
+                self.dense
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, node)| space.distance(&q, &node.state) < self.resolved_dense_delta)
+                    .map(|(i, _)| i)
+                    .collect::<Vec<_>>()
+            })
+            .into_iter()
+            .filter(|&i| stats.check_motion(space, vc, &q, &self.dense[i].state))
+            .collect();
+        for &i in &dense_neighbours {
+            self.dense[i].edges.push(dense_idx);
+        }
+        self.dense.push(Node {
+            state: q.clone(),
+            edges: dense_neighbours.clone(),
+        });
+
+        let visible = visible_nodes(
+            &self.roadmap,
+            space,
+            vc,
+            stats,
+            &q,
+            self.resolved_sparse_delta,
+        );
+
+        // Coverage
+        if visible.is_empty() {
+            let new_idx = self.add_sparse_node(dense_idx);
+            self.representatives.push(new_idx);
+            return true;
+        }
+
+        // Connectivity
+        let mut seen_components = HashSet::new();
+        let bridges: Vec<usize> = visible
+            .iter()
+            .copied()
+            .filter(|&v| seen_components.insert(self.components.find(v)))
+            .collect();
+        if bridges.len() > 1 {
+            let new_idx = self.add_sparse_node(dense_idx);
+            for v in bridges {
+                self.add_sparse_edge(new_idx, v);
+            }
+            self.representatives.push(new_idx);
+            return true;
+        }
+
+        let rep = visible[0];
+        self.representatives.push(rep);
+
+        // Interface
+        let mut new_interfaces = Vec::new();
+        for &n in &dense_neighbours {
+            let other = self.representatives[n];
+            if other == rep {
+                continue;
+            }
+            if self.interfaces.insert(ordered_pair(rep, other)) {
+                new_interfaces.push(other);
+            }
+            if self.roadmap[rep].edges.contains(&other) {
+                continue;
+            }
+
+            if stats.check_motion(
+                space,
+                vc,
+                &self.roadmap[rep].state,
+                &self.roadmap[other].state,
+            ) {
+                self.add_sparse_edge(rep, other);
+            } else {
+                let q_idx = self.add_sparse_node(dense_idx);
+                let n_idx = self.add_sparse_node(n);
+                self.add_sparse_edge(rep, q_idx);
+                self.add_sparse_edge(q_idx, n_idx);
+                self.add_sparse_edge(n_idx, other);
+            }
+            return true;
+        }
+
+        // Quality
+        let mut added = false;
+        for v in new_interfaces {
+            let mut others: Vec<usize> = self
+                .interfaces
+                .iter()
+                .filter_map(|&(a, b)| match (a == rep, b == rep) {
+                    (true, _) if b != v => Some(b),
+                    (_, true) if a != v => Some(a),
+                    _ => None,
+                })
+                .collect();
+            others.sort_unstable();
+            for w in others {
+                added |= self.check_quality(v, w, space, vc, stats);
+            }
+        }
+        added
+    }
+
+    /// Compares the sparse path between sparse nodes `v` and `w` against their shortest dense
+    /// path, adding a shortcut version of the dense path if the stretch bound is violated.
+    fn check_quality(
+        &mut self,
+        v: usize,
+        w: usize,
+        space: &SP,
+        vc: &dyn StateValidityChecker<S>,
+        stats: &StatsRecorder<S>,
+    ) -> bool {
+        let (dense_v, dense_w) = (self.dense_indices[v], self.dense_indices[w]);
+        let Some((dense_cost, dense_path)) = shortest_path(
+            &self.dense,
+            &[(dense_v, 0.0)],
+            |i| i == dense_w,
+            |a, b| space.distance(&self.dense[a].state, &self.dense[b].state),
+        ) else {
+            return false;
+        };
+
+        let sparse_cost = shortest_path(
+            &self.roadmap,
+            &[(v, 0.0)],
+            |i| i == w,
+            |a, b| space.distance(&self.roadmap[a].state, &self.roadmap[b].state),
+        )
+        .map_or(f64::INFINITY, |(cost, _)| cost);
+
+        if sparse_cost <= self.stretch_factor * dense_cost {
+            return false;
+        }
+
+        // Greedily shortcut the dense path before adding it to the sparse roadmap.
+        let mut waypoints = vec![dense_path[0]];
+        let mut current = 0;
+        while current < dense_path.len() - 1 {
+            let mut next = current + 1;
+            for candidate in (current + 2..dense_path.len()).rev() {
+                if stats.check_motion(
+                    space,
+                    vc,
+                    &self.dense[dense_path[current]].state,
+                    &self.dense[dense_path[candidate]].state,
+                ) {
+                    next = candidate;
+                    break;
+                }
+            }
+            waypoints.push(dense_path[next]);
+            current = next;
+        }
+
+        let mut previous = v;
+        for &dense_idx in &waypoints[1..waypoints.len() - 1] {
+            let new_idx = self.add_sparse_node(dense_idx);
+            self.add_sparse_edge(previous, new_idx);
+            previous = new_idx;
+        }
+        self.add_sparse_edge(previous, w);
+
+        true
+    }
+
+    /// Adds the dense node `dense_idx` to the sparse roadmap, reporting it to the observer.
+    fn add_sparse_node(&mut self, dense_idx: usize) -> usize {
+        let idx = self.roadmap.len();
+        self.stats.node_added(&self.dense[dense_idx].state, None);
+        self.roadmap.push(Node {
+            state: self.dense[dense_idx].state.clone(),
+            edges: Vec::new(),
+        });
+        self.dense_indices.push(dense_idx);
+        self.components.make_set();
+        idx
+    }
+
+    fn add_sparse_edge(&mut self, a: usize, b: usize) {
+        if a == b || self.roadmap[a].edges.contains(&b) {
+            return;
+        }
+        self.stats
+            .edge_added(&self.roadmap[a].state, &self.roadmap[b].state);
+        self.roadmap[a].edges.push(b);
+        self.roadmap[b].edges.push(a);
+        self.components.union(a, b);
+    }
+}
+
+impl<S, SP, G> Planner<S, SP, G> for SPARS<S, SP, G>
+where
+    S: State + Clone,
+    SP: StateSpace<StateType = S>,
+    G: Goal<S> + GoalSampleableRegion<S>,
+{
+    fn setup(
+        &mut self,
+        problem_def: Arc<ProblemDefinition<S, SP, G>>,
+        validity_checker: Arc<dyn StateValidityChecker<S>>,
+    ) {
+        self.resolved_sparse_delta = resolve_distance(
+            self.sparse_delta,
+            DEFAULT_SPARSE_DELTA_FRACTION,
+            &*problem_def.space,
+        );
+        self.resolved_dense_delta = if self.dense_delta > 0.0 {
+            self.dense_delta
+        } else {
+            DEFAULT_DENSE_DELTA_FACTOR * self.resolved_sparse_delta
+        };
+        self.problem_def = Some(problem_def);
+        self.validity_checker = Some(validity_checker);
+        self.roadmap.clear();
+        self.components.clear();
+        self.dense_indices.clear();
+        self.dense.clear();
+        self.representatives.clear();
+        self.interfaces.clear();
+        self.stats.reset();
+    }
+
+    fn solve(&mut self, timeout: Duration) -> Result<Path<S>, PlanningError> {
+        let pd = self
+            .problem_def
+            .as_ref()
+            .ok_or(PlanningError::PlannerUninitialised)?;
+        let vc = self
+            .validity_checker
+            .as_ref()
+            .ok_or(PlanningError::PlannerUninitialised)?;
+
+        if self.roadmap.is_empty() {
+            return Err(PlanningError::UnsampledStateSpace);
+        }
+
+        let mut rng = self
+            .rng
+            .take()
+            .unwrap_or_else(|| Box::new(PlannerRng::from_os_rng()));
+        let result = query_roadmap(
+            &self.roadmap,
+            pd,
+            &self.stats.checker(&**vc),
+            &self.stats,
+            self.resolved_sparse_delta,
+            &mut *rng,
+            timeout,
+        );
+        self.rng = Some(rng);
+
+        result
+    }
+
+    fn stats(&self) -> PlannerStats {
+        self.stats.snapshot()
+    }
+
+    fn set_observer(&mut self, observer: Arc<dyn PlannerObserver<S>>) {
+        stats::set_observer(&mut self.stats, observer);
+    }
+
+    fn params(&self) -> ParamSet {
+        ParamSet::default()
+            .with_real(
+                "timeout",
+                "The time allocated for roadmap construction, in seconds.",
+                self.timeout,
+                0.0..=f64::INFINITY,
+            )
+            .with_real(
+                "sparse_delta",
+                "The visibility radius of sparse nodes. 0 uses a quarter of the maximum extent.",
+                self.sparse_delta,
+                0.0..=f64::INFINITY,
+            )
+            .with_real(
+                "dense_delta",
+                "The connection radius of the dense graph. 0 uses a tenth of sparse_delta.",
+                self.dense_delta,
+                0.0..=f64::INFINITY,
+            )
+            .with_real(
+                "stretch_factor",
+                "The bound on sparse path lengths relative to dense path lengths.",
+                self.stretch_factor,
+                1.0..=f64::INFINITY,
+            )
+            .with_integer(
+                "max_failures",
+                "The number of consecutive rejected samples after which construction is done.",
+                self.max_failures,
+                1.0..=f64::INFINITY,
+            )
+    }
+
+    fn set_params(&mut self, params: &ParamSet) {
+        if let Some(timeout) = params.real("timeout") {
+            self.timeout = timeout;
+        }
+        if let Some(sparse_delta) = params.real("sparse_delta") {
+            self.sparse_delta = sparse_delta;
+        }
+        if let Some(dense_delta) = params.real("dense_delta") {
+            self.dense_delta = dense_delta;
+        }
+        if let Some(stretch_factor) = params.real("stretch_factor") {
+            self.stretch_factor = stretch_factor;
+        }
+        if let Some(max_failures) = params.integer("max_failures") {
+            self.max_failures = max_failures;
+        }
+    }
+}
+
+/// Returns the indices of the roadmap nodes within `radius` of `state` that can be reached with a
+/// valid motion, sorted by increasing distance.
+pub(crate) fn visible_nodes<S: State + Clone, SP: StateSpace<StateType = S>>(
+    roadmap: &[Node<S>],
+    space: &SP,
+    vc: &dyn StateValidityChecker<S>,
+    stats: &StatsRecorder<S>,
+    state: &S,
+    radius: f64,
+) -> Vec<usize> {
+    let candidates = stats.nearest(|| {
+        let mut candidates: Vec<(f64, usize)> = roadmap
+            .iter()
+            .enumerate()
+            .map(|(i, node)| (space.distance(state, &node.state), i))
+            .filter(|&(dist, _)| dist < radius)
+            .collect();
+        candidates.sort_by(|a, b| a.0.total_cmp(&b.0));
+        candidates
+    });
+
+    candidates
+        .into_iter()
+        .filter(|&(_, i)| stats.check_motion(space, vc, state, &roadmap[i].state))
+        .map(|(_, i)| i)
+        .collect()
+}
+
+/// Answers a query on a sparse roadmap.
+///
+/// The start state is connected to every visible roadmap node within `connection_radius`. A
+/// handful of goal samples are drawn and connected in the same way, and any roadmap node that
+/// already satisfies the goal is accepted directly. Dijkstra's algorithm then finds the shortest
+/// path through the roadmap.
+pub(crate) fn query_roadmap<S, SP, G>(
+    roadmap: &[Node<S>],
+    pd: &ProblemDefinition<S, SP, G>,
+    vc: &dyn StateValidityChecker<S>,
+    stats: &StatsRecorder<S>,
+    connection_radius: f64,
+    rng: &mut impl Rng,
+    timeout: Duration,
+) -> Result<Path<S>, PlanningError>
+where
+    S: State + Clone,
+    SP: StateSpace<StateType = S>,
+    G: Goal<S> + GoalSampleableRegion<S>,
+{
+    let start_time = Instant::now();
+    let space = &*pd.space;
+
+    let start_state = &pd.start_states[0];
+    if !vc.is_valid(start_state) {
+        return Err(PlanningError::InvalidStartState);
+    }
+
+    let mut graph = roadmap.to_vec();
+    let start_idx = graph.len();
+    graph.push(Node {
+        state: start_state.clone(),
+        edges: visible_nodes(roadmap, space, vc, stats, start_state, connection_radius),
+    });
+
+    let first_goal_idx = graph.len();
+    for _ in 0..GOAL_SAMPLE_ATTEMPTS {
+        let Ok(goal_state) = stats.sample(|| pd.goal.sample_goal(rng)) else {
+            stats.reject_sample(None);
+            continue;
+        };
+        if !vc.is_valid(&goal_state) {
+            stats.reject_sample(Some(&goal_state));
+            continue;
+        }
+
+        let goal_idx = graph.len();
+        for i in visible_nodes(roadmap, space, vc, stats, &goal_state, connection_radius) {
+            graph[i].edges.push(goal_idx);
+        }
+        graph.push(Node {
+            state: goal_state,
+            edges: Vec::new(),
+        });
+    }
+
+    if start_time.elapsed() > timeout {
+        return Err(PlanningError::Timeout);
+    }
+
+    let (_, indices) = shortest_path(
+        &graph,
+        &[(start_idx, 0.0)],
+        |i| i >= first_goal_idx || (i != start_idx && pd.goal.is_satisfied(&graph[i].state)),
+        |a, b| space.distance(&graph[a].state, &graph[b].state),
+    )
+    .ok_or(PlanningError::NoSolutionFound)?;
+
+    let path = Path(
+        indices
+            .into_iter()
+            .map(|i| graph[i].state.clone())
+            .collect(),
+    );
+    stats.solution(space, &path);
+    Ok(path)
+}
@@ -0,0 +1,548 @@
+// Copyright (c) 2025 Junior Sundar
+//
+// SPDX-License-Identifier: BSD-3-Clause
+
+use std::{collections::HashMap, sync::Arc};
+
+use rand::{Rng, SeedableRng};
+
+use crate::time::{Duration, Instant};
+
+use crate::base::{
+    error::PlanningError,
+    goal::{Goal, GoalSampleableRegion},
+    observer::PlannerObserver,
+    param::ParamSet,
+    planner::{Path, Planner, PlannerConfig, PlannerStats},
+    problem_definition::ProblemDefinition,
+    rng::PlannerRng,
+    space::StateSpace,
+    state::State,
+    validity::StateValidityChecker,
+};
+
+use super::{
+    common::{ordered_pair, shortest_path},
+    prm::Node,
+    rrt::resolve_distance,
+    spars::{
+        query_roadmap, visible_nodes, DEFAULT_DENSE_DELTA_FACTOR, DEFAULT_SPARSE_DELTA_FRACTION,
+    },
+    stats::{self, StatsRecorder},
+    union_find::UnionFind,
+};
+
+/// The closest pair of samples found so far across the interface between two sparse nodes.
+///
+/// `first` lies in the region of the lower-indexed node and `second` in the region of the
+/// higher-indexed one.
+#[derive(Clone)]
+struct InterfaceData<S: State> {
+    first: S,
+    second: S,
+    distance: f64,
+}
+
+impl<S: State + Clone> InterfaceData<S> {
+    /// Returns the point on `node`'s side of the interface keyed by `key`.
+    fn point_for(&self, key: (usize, usize), node: usize) -> &S {
+        if key.0 == node {
+            &self.first
+        } else {
+            &self.second
+        }
+    }
+}
+
+/// An implementation of the SPARS2 algorithm.
+///
+/// SPARS2 builds the same kind of compact roadmap spanner as `SPARS`, but without keeping a dense
+/// graph in memory. Interfaces between sparse nodes are instead discovered by sampling a state
+/// within `dense_delta` of each new sample, and the closest pair of states seen across every
+/// interface is stored to estimate path quality.
+///
+/// # Algorithm Overview
+///
+/// Each valid sample is tested against the following criteria, in order, and the first one that
+/// applies modifies the roadmap:
+/// 1.  **Coverage**: No sparse node is visible within `sparse_delta`, so the sample becomes a new
+///     guard.
+/// 2.  **Connectivity**: The sample sees sparse nodes from two or more disconnected components, so
+///     it is added to join them.
+/// 3.  **Interface**: The two closest visible sparse nodes are not connected by an edge, so they
+///     are joined either directly or through the sample.
+/// 4.  **Quality**: An interface point pair between the sample's representative `r` and a
+///     neighbouring representative `v` is recorded. If the roadmap path from `v` to some other
+///     neighbour `w` of `r` is longer than `stretch_factor` times the path through the recorded
+///     interface points, that shorter path is added.
+///
+/// Construction stops once `max_failures` consecutive samples have been rejected, or when
+/// `timeout` elapses. The resulting sparse roadmap is queried in the same way as `PRM`'s.
+pub struct SPARS2<S: State, SP: StateSpace<StateType = S>, G: Goal<S>> {
+    /// The time allocated for roadmap construction, in seconds.
+    pub timeout: f64,
+    /// The visibility radius of the sparse roadmap nodes. If it is 0, each call to `setup` uses a
+    /// quarter of the maximum extent of the space.
+    pub sparse_delta: f64,
+    /// The radius used to sample states across interfaces. If it is 0, each call to `setup` uses a
+    /// tenth of `sparse_delta`.
+    pub dense_delta: f64,
+    /// The multiplicative bound on roadmap path lengths relative to interface path lengths.
+    pub stretch_factor: f64,
+    /// The number of consecutive rejected samples after which construction is considered done.
+    pub max_failures: usize,
+
+    // The parameters above, with any that are 0 resolved by `setup`.
+    resolved_sparse_delta: f64,
+    resolved_dense_delta: f64,
+    problem_def: Option<Arc<ProblemDefinition<S, SP, G>>>,
+    validity_checker: Option<Arc<dyn StateValidityChecker<S>>>,
+    roadmap: Vec<Node<S>>,
+    components: UnionFind,
+    interfaces: HashMap<(usize, usize), InterfaceData<S>>,
+    rng: Option<Box<PlannerRng>>,
+    stats: Arc<StatsRecorder<S>>,
+}
+
+impl<S, SP, G> SPARS2<S, SP, G>
+where
+    S: State + Clone,
+    SP: StateSpace<StateType = S>,
+    G: Goal<S>,
+{
+    /// Creates a new `SPARS2` planner with the specified parameters.
+    ///
+    /// # Parameters
+    /// * `timeout` - The time in seconds to spend building the roadmap.
+    /// * `sparse_delta` - The visibility radius of sparse roadmap nodes.
+    /// * `dense_delta` - The radius used to sample states across interfaces. Should be a small
+    ///   fraction of `sparse_delta`.
+    /// * `stretch_factor` - The allowed ratio between roadmap and interface path lengths. Must be
+    ///   greater than 1.
+    /// * `max_failures` - The number of consecutive useless samples before construction stops.
+    /// * `config` - The planner configuration, for planner-specific parameters.
+    pub fn new(
+        timeout: f64,
+        sparse_delta: f64,
+        dense_delta: f64,
+        stretch_factor: f64,
+        max_failures: usize,
+        config: &PlannerConfig,
+    ) -> Self {
+        let rng = config.seed.map(|s| Box::new(PlannerRng::seed_from_u64(s)));
+        SPARS2 {
+            timeout,
+            sparse_delta,
+            dense_delta,
+            stretch_factor,
+            max_failures,
+            resolved_sparse_delta: sparse_delta,
+            resolved_dense_delta: dense_delta,
+            problem_def: None,
+            validity_checker: None,
+            roadmap: Vec::new(),
+            components: UnionFind::default(),
+            interfaces: HashMap::new(),
+            rng,
+            stats: Arc::default(),
+        }
+    }
+
+    /// Get private variable `roadmap` as a clone.
+    pub fn get_roadmap(&self) -> Vec<Node<S>> {
+        self.roadmap.clone()
+    }
+
+    /// Update ProblemDefinition. This is so that you can use an already constructed roadmap but
+    /// just change the start and goal states.
+    pub fn set_problem_definition(&mut self, pd: Arc<ProblemDefinition<S, SP, G>>) {
+        self.problem_def = Some(pd);
+    }
+
+    /// Constructs the sparse roadmap.
+    ///
+    /// Samples are drawn until either `max_failures` consecutive samples have been rejected by
+    /// every criterion or `timeout` seconds have elapsed.
+    pub fn construct_roadmap(&mut self) -> Result<(), PlanningError> {
+        let pd = self
+            .problem_def
+            .clone()
+            .ok_or(PlanningError::PlannerUninitialised)?;
+        let vc = self
+            .validity_checker
+            .clone()
+            .ok_or(PlanningError::PlannerUninitialised)?;
+
+        if !self.roadmap.is_empty() {
+            log::info!(
+                "SPARS2: Roadmap already constructed with {} milestones.",
+                self.roadmap.len()
+            );
+
+            return Ok(());
+        }
+
+        let mut rng = self
+            .rng
+            .take()
+            .unwrap_or_else(|| Box::new(PlannerRng::from_os_rng()));
+        let stats = self.stats.clone();
+        let vc = stats.checker(&*vc);
+        let start_time = Instant::now();
+        let mut failures = 0;
+        while failures < self.max_failures && start_time.elapsed().as_secs_f64() <= self.timeout {
+            stats.iteration();
+            let q_rand = stats.sample(|| pd.space.sample_uniform(&mut *rng).unwrap());
+            if !vc.is_valid(&q_rand) {
+                stats.reject_sample(Some(&q_rand));
+                continue;
+            }
+
+            if self.add_sample(q_rand, &*pd.space, &vc, &stats, &mut *rng) {
+                failures = 0;
+            } else {
+                failures += 1;
+            }
+        }
+        self.rng = Some(rng);
+
+        log::info!(
+            "SPARS2: Roadmap constructed with {} milestones.",
+            self.roadmap.len()
+        );
+
+        Ok(())
+    }
+
+    /// Applies the sparse roadmap criteria to a valid sample.
+    ///
+    /// Returns `true` if the roadmap was modified.
+    fn add_sample(
+        &mut self,
+        q: S,
+        space: &SP,
+        vc: &dyn StateValidityChecker<S>,
+        stats: &StatsRecorder<S>,
+        rng: &mut impl Rng,
+    ) -> bool {
+        let visible = visible_nodes(
+            &self.roadmap,
+            space,
+            vc,
+            stats,
+            &q,
+            self.resolved_sparse_delta,
+        );
+
+        // Coverage
+        if visible.is_empty() {
+            self.add_node(q);
+            return true;
+        }
+
+        // Connectivity
+        let mut bridges: Vec<usize> = Vec::new();
+        for &v in &visible {
+            if !bridges.iter().any(|&b| self.components.same_set(b, v)) {
+                bridges.push(v);
+            }
+        }
+        if bridges.len() > 1 {
+            let new_idx = self.add_node(q);
+            for v in bridges {
+                self.add_edge(new_idx, v);
+            }
+            return true;
+        }
+
+        // Interface
+        let rep = visible[0];
+        if let Some(&other) = visible.get(1) {
+            if !self.roadmap[rep].edges.contains(&other) {
+                if stats.check_motion(
+                    space,
+                    vc,
+                    &self.roadmap[rep].state,
+                    &self.roadmap[other].state,
+                ) {
+                    self.add_edge(rep, other);
+                } else {
+                    let new_idx = self.add_node(q);
+                    self.add_edge(new_idx, rep);
+                    self.add_edge(new_idx, other);
+                }
+                return true;
+            }
+        }
+
+        // Quality
+        let q_near = stats.sample(|| sample_near(space, &q, self.resolved_dense_delta, rng));
+        if !stats.check_motion(space, vc, &q, &q_near) {
+            stats.reject_sample(Some(&q_near));
+            return false;
+        }
+        let Some(&near_rep) = visible_nodes(
+            &self.roadmap,
+            space,
+            vc,
+            stats,
+            &q_near,
+            self.resolved_sparse_delta,
+        )
+        .first() else {
+            return false;
+        };
+        if near_rep == rep {
+            return false;
+        }
+
+        let key = ordered_pair(rep, near_rep);
+        let distance = space.distance(&q, &q_near);
+        let (first, second) = if key.0 == rep {
+            (q, q_near)
+        } else {
+            (q_near, q)
+        };
+        match self.interfaces.get(&key) {
+            Some(data) if data.distance <= distance => return false,
+            _ => {
+                self.interfaces.insert(
+                    key,
+                    InterfaceData {
+                        first,
+                        second,
+                        distance,
+                    },
+                );
+            }
+        }
+
+        self.check_quality(rep, near_rep, space, vc, stats)
+            | self.check_quality(near_rep, rep, space, vc, stats)
+    }
+
+    /// Checks the roadmap paths from `v` to every other neighbour `w` of `r` against the path
+    /// through the recorded interface points of `r`, adding the latter if the stretch bound is
+    /// violated.
+    fn check_quality(
+        &mut self,
+        r: usize,
+        v: usize,
+        space: &SP,
+        vc: &dyn StateValidityChecker<S>,
+        stats: &StatsRecorder<S>,
+    ) -> bool {
+        let key_v = ordered_pair(r, v);
+        let point_v = self.interfaces[&key_v].point_for(key_v, v).clone();
+
+        let mut others: Vec<usize> = self
+            .interfaces
+            .keys()
+            .filter_map(|&(a, b)| match (a == r, b == r) {
+                (true, _) if b != v => Some(b),
+                (_, true) if a != v => Some(a),
+                _ => None,
+            })
+            .collect();
+        others.sort_unstable();
+
+        let mut added = false;
+        for w in others {
+            let key_w = ordered_pair(r, w);
+            let point_w = self.interfaces[&key_w].point_for(key_w, w).clone();
+
+            let interface_cost = space.distance(&self.roadmap[v].state, &point_v)
+                + space.distance(&point_v, &point_w)
+                + space.distance(&point_w, &self.roadmap[w].state);
+            let roadmap_cost = shortest_path(
+                &self.roadmap,
+                &[(v, 0.0)],
+                |i| i == w,
+                |a, b| space.distance(&self.roadmap[a].state, &self.roadmap[b].state),
+            )
+            .map_or(f64::INFINITY, |(cost, _)| cost);
+
+            if roadmap_cost <= self.stretch_factor * interface_cost
+                || !stats.check_motion(space, vc, &point_v, &point_w)
+                || !stats.check_motion(space, vc, &self.roadmap[v].state, &point_v)
+                || !stats.check_motion(space, vc, &point_w, &self.roadmap[w].state)
+            {
+                continue;
+            }
+
+            let idx_v = self.add_node(point_v.clone());
+            let idx_w = self.add_node(point_w);
+            self.add_edge(v, idx_v);
+            self.add_edge(idx_v, idx_w);
+            self.add_edge(idx_w, w);
+            added = true;
+        }
+        added
+    }
+
+    /// Adds `state` to the roadmap, reporting it to the observer.
+    fn add_node(&mut self, state: S) -> usize {
+        let idx = self.roadmap.len();
+        self.stats.node_added(&state, None);
+        self.roadmap.push(Node {
+            state,
+            edges: Vec::new(),
+        });
+        self.components.make_set();
+        idx
+    }
+
+    fn add_edge(&mut self, a: usize, b: usize) {
+        if a == b || self.roadmap[a].edges.contains(&b) {
+            return;
+        }
+        self.stats
+            .edge_added(&self.roadmap[a].state, &self.roadmap[b].state);
+        self.roadmap[a].edges.push(b);
+        self.roadmap[b].edges.push(a);
+        self.components.union(a, b);
+    }
+}
+
+impl<S, SP, G> Planner<S, SP, G> for SPARS2<S, SP, G>
+where
+    S: State + Clone,
+    SP: StateSpace<StateType = S>,
+    G: Goal<S> + GoalSampleableRegion<S>,
+{
+    fn setup(
+        &mut self,
+        problem_def: Arc<ProblemDefinition<S, SP, G>>,
+        validity_checker: Arc<dyn StateValidityChecker<S>>,
+    ) {
+        self.resolved_sparse_delta = resolve_distance(
+            self.sparse_delta,
+            DEFAULT_SPARSE_DELTA_FRACTION,
+            &*problem_def.space,
+        );
+        self.resolved_dense_delta = if self.dense_delta > 0.0 {
+            self.dense_delta
+        } else {
+            DEFAULT_DENSE_DELTA_FACTOR * self.resolved_sparse_delta
+        };
+        self.problem_def = Some(problem_def);
+        self.validity_checker = Some(validity_checker);
+        self.roadmap.clear();
+        self.components.clear();
+        self.interfaces.clear();
+        self.stats.reset();
+    }
+
+    fn solve(&mut self, timeout: Duration) -> Result<Path<S>, PlanningError> {
+        let pd = self
+            .problem_def
+            .as_ref()
+            .ok_or(PlanningError::PlannerUninitialised)?;
+        let vc = self
+            .validity_checker
+            .as_ref()
+            .ok_or(PlanningError::PlannerUninitialised)?;
+
+        if self.roadmap.is_empty() {
+            return Err(PlanningError::UnsampledStateSpace);
+        }
+
+        let mut rng = self
+            .rng
+            .take()
+            .unwrap_or_else(|| Box::new(PlannerRng::from_os_rng()));
+        let result = query_roadmap(
+            &self.roadmap,
+            pd,
+            &self.stats.checker(&**vc),
+            &self.stats,
+            self.resolved_sparse_delta,
+            &mut *rng,
+            timeout,
+        );
+        self.rng = Some(rng);
+
+        result
+    }
+
+    fn stats(&self) -> PlannerStats {
+        self.stats.snapshot()
+    }
+
+    fn set_observer(&mut self, observer: Arc<dyn PlannerObserver<S>>) {
+        stats::set_observer(&mut self.stats, observer);
+    }
+
+    fn params(&self) -> ParamSet {
+        ParamSet::default()
+            .with_real(
+                "timeout",
+                "The time allocated for roadmap construction, in seconds.",
+                self.timeout,
+                0.0..=f64::INFINITY,
+            )
+            .with_real(
+                "sparse_delta",
+                "The visibility radius of sparse nodes. 0 uses a quarter of the maximum extent.",
+                self.sparse_delta,
+                0.0..=f64::INFINITY,
+            )
+            .with_real(
+                "dense_delta",
+                "The radius used to sample states across interfaces. 0 uses a tenth of sparse_delta.",
+                self.dense_delta,
+                0.0..=f64::INFINITY,
+            )
+            .with_real(
+                "stretch_factor",
+                "The bound on roadmap path lengths relative to interface path lengths.",
+                self.stretch_factor,
+                1.0..=f64::INFINITY,
+            )
+            .with_integer(
+                "max_failures",
+                "The number of consecutive rejected samples after which construction is done.",
+                self.max_failures,
+                1.0..=f64::INFINITY,
+            )
+    }
+
+    fn set_params(&mut self, params: &ParamSet) {
+        if let Some(timeout) = params.real("timeout") {
+            self.timeout = timeout;
+        }
+        if let Some(sparse_delta) = params.real("sparse_delta") {
+            self.sparse_delta = sparse_delta;
+        }
+        if let Some(dense_delta) = params.real("dense_delta") {
+            self.dense_delta = dense_delta;
+        }
+        if let Some(stretch_factor) = params.real("stretch_factor") {
+            self.stretch_factor = stretch_factor;
+        }
+        if let Some(max_failures) = params.integer("max_failures") {
+            self.max_failures = max_failures;
+        }
+    }
+}
+
+/// Samples a state within `radius` of `near` by moving a random distance towards a uniform sample.
+fn sample_near<S: State + Clone, SP: StateSpace<StateType = S>>(
+    space: &SP,
+    near: &S,
+    radius: f64,
+    rng: &mut impl Rng,
+) -> S {
+    let Ok(target) = space.sample_uniform(rng) else {
+        return near.clone();
+    };
+    let dist = space.distance(near, &target);
+    if dist <= f64::EPSILON {
+        return target;
+    }
+
+    let mut sample = near.clone();
+    let t = (radius / dist).min(1.0) * rng.random::<f64>();
+    space.interpolate(near, &target, t, &mut sample);
+    sample
+}
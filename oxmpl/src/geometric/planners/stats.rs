@@ -0,0 +1,260 @@
+// Copyright (c) 2025 Junior Sundar
+//
+// SPDX-License-Identifier: BSD-3-Clause
+
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc, Mutex,
+};
+
+use crate::{
+    base::{
+        observer::{LogObserver, PlannerObserver},
+        planner::{Path, PlannerStats},
+        space::StateSpace,
+        state::State,
+        validity::StateValidityChecker,
+    },
+    time::{Duration, Instant},
+};
+
+use super::common::check_motion;
+
+/// The phase in which states are sampled.
+pub(crate) const SAMPLING: &str = "sampling";
+/// The phase in which nearest-neighbour and radius queries are answered.
+pub(crate) const NEAREST_NEIGHBOURS: &str = "nearest neighbours";
+/// The phase in which motions are checked.
+pub(crate) const MOTION_CHECKING: &str = "motion checking";
+
+/// Collects `PlannerStats` for a planner and reports its progress to a `PlannerObserver`.
+///
+/// Planners make their samples, nearest-neighbour queries and motion checks through the recorder,
+/// which counts and times them, and check states with the validity checker returned by `checker`,
+/// which counts those checks. Iterations, rejected samples, new nodes and edges and solutions are
+/// passed on to the observer. The recorder only needs `&self`, so it can be shared between
+/// threads.
+pub(crate) struct StatsRecorder<S: State> {
+    iterations: AtomicU64,
+    samples_drawn: AtomicU64,
+    samples_rejected: AtomicU64,
+    validity_checks: AtomicU64,
+    motion_checks: AtomicU64,
+    nearest_neighbour_queries: AtomicU64,
+    sampling_nanos: AtomicU64,
+    nearest_neighbour_nanos: AtomicU64,
+    motion_checking_nanos: AtomicU64,
+    other_phases: Mutex<Vec<(&'static str, Duration)>>,
+    observer: Arc<dyn PlannerObserver<S>>,
+}
+
+impl<S: State> Default for StatsRecorder<S> {
+    fn default() -> Self {
+        StatsRecorder {
+            iterations: AtomicU64::default(),
+            samples_drawn: AtomicU64::default(),
+            samples_rejected: AtomicU64::default(),
+            validity_checks: AtomicU64::default(),
+            motion_checks: AtomicU64::default(),
+            nearest_neighbour_queries: AtomicU64::default(),
+            sampling_nanos: AtomicU64::default(),
+            nearest_neighbour_nanos: AtomicU64::default(),
+            motion_checking_nanos: AtomicU64::default(),
+            other_phases: Mutex::default(),
+            observer: Arc::new(LogObserver),
+        }
+    }
+}
+
+/// A validity checker that counts the states it checks in a `StatsRecorder`.
+pub(crate) struct CountingChecker<'a, S: State> {
+    inner: &'a dyn StateValidityChecker<S>,
+    count: &'a AtomicU64,
+}
+
+impl<S: State> StateValidityChecker<S> for CountingChecker<'_, S> {
+    fn is_valid(&self, state: &S) -> bool {
+        self.count.fetch_add(1, Ordering::Relaxed);
+        self.inner.is_valid(state)
+    }
+
+    fn clearance(&self, state: &S) -> Option<f64> {
+        self.inner.clearance(state)
+    }
+}
+
+/// Adds the time elapsed since `start` to `total`, in nanoseconds.
+fn add_elapsed(total: &AtomicU64, start: Instant) {
+    let nanos = u64::try_from(start.elapsed().as_nanos()).unwrap_or(u64::MAX);
+    total.fetch_add(nanos, Ordering::Relaxed);
+}
+
+/// Replaces the observer of the recorder shared through `stats`.
+///
+/// # Panics
+/// Panics if `stats` is shared, which it only is while the planner is solving.
+pub(crate) fn set_observer<S: State>(
+    stats: &mut Arc<StatsRecorder<S>>,
+    observer: Arc<dyn PlannerObserver<S>>,
+) {
+    Arc::get_mut(stats)
+        .expect("the observer cannot be replaced while the planner is solving")
+        .observer = observer;
+}
+
+impl<S: State> StatsRecorder<S> {
+    /// Clears all counts and phase times.
+    pub(crate) fn reset(&self) {
+        for counter in [
+            &self.iterations,
+            &self.samples_drawn,
+            &self.samples_rejected,
+            &self.validity_checks,
+            &self.motion_checks,
+            &self.nearest_neighbour_queries,
+            &self.sampling_nanos,
+            &self.nearest_neighbour_nanos,
+            &self.motion_checking_nanos,
+        ] {
+            counter.store(0, Ordering::Relaxed);
+        }
+        self.other_phases.lock().unwrap().clear();
+    }
+
+    /// Returns the statistics collected so far.
+    pub(crate) fn snapshot(&self) -> PlannerStats {
+        let mut phase_times = Vec::new();
+        for (phase, nanos) in [
+            (SAMPLING, &self.sampling_nanos),
+            (NEAREST_NEIGHBOURS, &self.nearest_neighbour_nanos),
+            (MOTION_CHECKING, &self.motion_checking_nanos),
+        ] {
+            let nanos = nanos.load(Ordering::Relaxed);
+            if nanos > 0 {
+                phase_times.push((phase.to_string(), Duration::from_nanos(nanos)));
+            }
+        }
+        phase_times.extend(
+            self.other_phases
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|(phase, time)| (phase.to_string(), *time)),
+        );
+        PlannerStats {
+            iterations: self.iterations.load(Ordering::Relaxed),
+            samples_drawn: self.samples_drawn.load(Ordering::Relaxed),
+            samples_rejected: self.samples_rejected.load(Ordering::Relaxed),
+            validity_checks: self.validity_checks.load(Ordering::Relaxed),
+            motion_checks: self.motion_checks.load(Ordering::Relaxed),
+            nearest_neighbour_queries: self.nearest_neighbour_queries.load(Ordering::Relaxed),
+            phase_times,
+        }
+    }
+
+    /// Counts an iteration of the planner's main loop.
+    pub(crate) fn iteration(&self) {
+        let iteration = self.iterations.fetch_add(1, Ordering::Relaxed) + 1;
+        self.observer.on_iteration(iteration);
+    }
+
+    /// Draws a sample with `sample`, counting and timing it.
+    pub(crate) fn sample<T>(&self, sample: impl FnOnce() -> T) -> T {
+        let start = Instant::now();
+        let result = sample();
+        add_elapsed(&self.sampling_nanos, start);
+        self.samples_drawn.fetch_add(1, Ordering::Relaxed);
+        result
+    }
+
+    /// Counts a sample that was discarded. `sample` is `None` if sampling failed.
+    pub(crate) fn reject_sample(&self, sample: Option<&S>) {
+        self.samples_rejected.fetch_add(1, Ordering::Relaxed);
+        self.observer.on_sample_rejected(sample);
+    }
+
+    /// Reports a state added to the tree or roadmap, along with the edge from `parent` if it has
+    /// one.
+    pub(crate) fn node_added(&self, state: &S, parent: Option<&S>) {
+        self.observer.on_node_added(state);
+        if let Some(parent) = parent {
+            self.observer.on_edge_added(parent, state);
+        }
+    }
+
+    /// Reports an edge added between two states already in the tree or roadmap.
+    pub(crate) fn edge_added(&self, from: &S, to: &S) {
+        self.observer.on_edge_added(from, to);
+    }
+
+    /// Reports an edge removed from the tree.
+    pub(crate) fn edge_removed(&self, from: &S, to: &S) {
+        self.observer.on_edge_removed(from, to);
+    }
+
+    /// Reports a new or improved solution, measuring its length in `space`.
+    pub(crate) fn solution<SP: StateSpace<StateType = S>>(&self, space: &SP, path: &Path<S>) {
+        let length = path
+            .0
+            .windows(2)
+            .map(|pair| space.distance(&pair[0], &pair[1]))
+            .sum();
+        self.observer.on_solution(path, length);
+    }
+
+    /// Answers a nearest-neighbour or radius query with `query`, counting and timing it.
+    pub(crate) fn nearest<T>(&self, query: impl FnOnce() -> T) -> T {
+        let start = Instant::now();
+        let result = query();
+        add_elapsed(&self.nearest_neighbour_nanos, start);
+        self.nearest_neighbour_queries
+            .fetch_add(1, Ordering::Relaxed);
+        result
+    }
+
+    /// Returns a validity checker that counts the states checked by `validity_checker`.
+    pub(crate) fn checker<'a>(
+        &'a self,
+        validity_checker: &'a dyn StateValidityChecker<S>,
+    ) -> CountingChecker<'a, S> {
+        CountingChecker {
+            inner: validity_checker,
+            count: &self.validity_checks,
+        }
+    }
+
+    /// Checks the motion from `from` to `to` with `check_motion`, counting and timing it.
+    ///
+    /// The states along the motion are only counted as validity checks if `validity_checker` was
+    /// returned by `checker`.
+    pub(crate) fn check_motion<SP>(
+        &self,
+        space: &SP,
+        validity_checker: &dyn StateValidityChecker<S>,
+        from: &S,
+        to: &S,
+    ) -> bool
+    where
+        S: Clone,
+        SP: StateSpace<StateType = S>,
+    {
+        let start = Instant::now();
+        let valid = check_motion(space, validity_checker, from, to);
+        add_elapsed(&self.motion_checking_nanos, start);
+        self.motion_checks.fetch_add(1, Ordering::Relaxed);
+        valid
+    }
+
+    /// Runs `f`, adding the time it takes to a planner-specific `phase`.
+    pub(crate) fn time<T>(&self, phase: &'static str, f: impl FnOnce() -> T) -> T {
+        let start = Instant::now();
+        let result = f();
+        let elapsed = start.elapsed();
+        let mut phases = self.other_phases.lock().unwrap();
+        match phases.iter_mut().find(|(name, _)| *name == phase) {
+            Some((_, total)) => *total += elapsed,
+            None => phases.push((phase, elapsed)),
+        }
+        result
+    }
+}
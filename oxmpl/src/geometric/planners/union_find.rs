@@ -0,0 +1,75 @@
+// Copyright (c) 2025 Junior Sundar
+//
+// SPDX-License-Identifier: BSD-3-Clause
+
+/// A disjoint-set forest used by the roadmap planners to track connected components.
+///
+/// Uses path halving and union by size, so both operations run in amortised near-constant time.
+#[derive(Clone, Default)]
+pub(crate) struct UnionFind {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+}
+
+impl UnionFind {
+    /// Adds a new singleton set and returns its index.
+    pub(crate) fn make_set(&mut self) -> usize {
+        let idx = self.parent.len();
+        self.parent.push(idx);
+        self.size.push(1);
+        idx
+    }
+
+    /// Returns the representative element of the set containing `idx`.
+    pub(crate) fn find(&mut self, mut idx: usize) -> usize {
+        while self.parent[idx] != idx {
+            self.parent[idx] = self.parent[self.parent[idx]];
+            idx = self.parent[idx];
+        }
+        idx
+    }
+
+    /// Merges the sets containing `a` and `b`. Returns `false` if they were already joined.
+    pub(crate) fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut root_a, mut root_b) = (self.find(a), self.find(b));
+        if root_a == root_b {
+            return false;
+        }
+        if self.size[root_a] < self.size[root_b] {
+            std::mem::swap(&mut root_a, &mut root_b);
+        }
+        self.parent[root_b] = root_a;
+        self.size[root_a] += self.size[root_b];
+        true
+    }
+
+    /// Returns `true` if `a` and `b` belong to the same set.
+    pub(crate) fn same_set(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.parent.clear();
+        self.size.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_union_find_merges_sets() {
+        let mut uf = UnionFind::default();
+        let a = uf.make_set();
+        let b = uf.make_set();
+        let c = uf.make_set();
+
+        assert!(!uf.same_set(a, b));
+        assert!(uf.union(a, b));
+        assert!(uf.same_set(a, b));
+        assert!(!uf.union(b, a));
+        assert!(!uf.same_set(a, c));
+        assert_eq!(uf.find(b), uf.find(a));
+    }
+}
@@ -0,0 +1,287 @@
+// Copyright (c) 2025 Junior Sundar
+//
+// SPDX-License-Identifier: BSD-3-Clause
+
+use std::sync::Arc;
+
+use crate::{
+    base::{
+        error::PlanningError,
+        goal::GoalSampleableRegion,
+        planner::{Path, Planner, PlannerConfig, PlannerStats},
+        problem_definition::ProblemDefinition,
+        rng::{planner_rng, PlannerRng},
+        space::StateSpace,
+        state::State,
+        validity::StateValidityChecker,
+    },
+    geometric::{path_simplifier::PathSimplifier, RRTConnect},
+    time::{Duration, Instant},
+    tools::PreparedPlanner,
+};
+
+/// The range of the default planner. As 0, the planner uses a fifth of the space's extent each
+/// time it is set up.
+const DEFAULT_RANGE: f64 = 0.0;
+
+/// The goal bias of the default planner.
+const DEFAULT_GOAL_BIAS: f64 = 0.05;
+
+/// A single place to set up and solve motion planning problems.
+///
+/// `SimpleSetup` owns the state space, validity checker, start states, goal and planner, and
+/// takes care of the steps that are otherwise easy to get wrong:
+///
+/// 1. The problem is checked with `ProblemDefinition::validate` before solving, so a missing or
+///    invalid start state or an unreachable goal is reported as such.
+/// 2. The planner is set up with a fresh `ProblemDefinition` for every query. If no planner has
+///    been set, an `RRTConnect` planner is used, with a range of a fifth of the space's extent.
+/// 3. The solution is simplified with a `PathSimplifier`, unless `simplify_solution` is `false`.
+/// 4. The time spent planning and simplifying is recorded.
+///
+/// The same `SimpleSetup` can answer any number of queries. Change the start states or the goal
+/// and call `solve` again.
+///
+/// # Example
+///
+/// ```
+/// use std::{sync::Arc, time::Duration};
+/// use oxmpl::base::{
+///     goal::GoalState,
+///     planner::PlannerConfig,
+///     space::RealVectorStateSpace,
+///     state::RealVectorState,
+///     validity::StateValidityChecker,
+/// };
+/// use oxmpl::geometric::SimpleSetup;
+///
+/// struct NoObstacles;
+/// impl StateValidityChecker<RealVectorState> for NoObstacles {
+///     fn is_valid(&self, _state: &RealVectorState) -> bool {
+///         true
+///     }
+/// }
+///
+/// let space = RealVectorStateSpace::new(2, Some(vec![(0.0, 10.0), (0.0, 10.0)])).unwrap();
+/// let mut setup = SimpleSetup::new(space, NoObstacles, &PlannerConfig { seed: Some(1) });
+///
+/// setup.set_start_state(RealVectorState::new(vec![1.0, 1.0]));
+/// setup.set_goal(GoalState::new(
+///     setup.space().clone(),
+///     RealVectorState::new(vec![9.0, 9.0]),
+///     0.5,
+/// ));
+///
+/// let path = setup.solve(Duration::from_secs(1)).unwrap();
+/// assert!(path.0.len() >= 2);
+/// println!("Planned in {:?}", setup.last_plan_time());
+/// ```
+pub struct SimpleSetup<S: State, SP: StateSpace<StateType = S>, G: GoalSampleableRegion<S>> {
+    /// Whether solutions are simplified after planning. Defaults to `true`.
+    pub simplify_solution: bool,
+    /// The longest time spent simplifying a solution. Defaults to one second.
+    pub simplification_timeout: Duration,
+
+    space: Arc<SP>,
+    validity_checker: Arc<dyn StateValidityChecker<S>>,
+    start_states: Vec<S>,
+    goal: Option<Arc<G>>,
+    planner: Option<Box<dyn Planner<S, SP, G>>>,
+    simplifier: PathSimplifier<S, SP>,
+    seed: Option<u64>,
+    rng: PlannerRng,
+    solution: Option<Path<S>>,
+    last_plan_time: Duration,
+    last_simplification_time: Duration,
+}
+
+impl<S, SP, G> SimpleSetup<S, SP, G>
+where
+    S: State + Clone + 'static,
+    SP: StateSpace<StateType = S> + 'static,
+    G: GoalSampleableRegion<S> + 'static,
+{
+    /// Creates a new `SimpleSetup` with no start states, goal or planner.
+    ///
+    /// # Parameters
+    /// * `space` - The space to plan in.
+    /// * `validity_checker` - Decides which states are valid.
+    /// * `config` - The configuration, for the seed of the default planner and the simplifier.
+    pub fn new(
+        space: SP,
+        validity_checker: impl StateValidityChecker<S> + 'static,
+        config: &PlannerConfig,
+    ) -> Self {
+        let space = Arc::new(space);
+        let validity_checker: Arc<dyn StateValidityChecker<S>> = Arc::new(validity_checker);
+        SimpleSetup {
+            simplify_solution: true,
+            simplification_timeout: Duration::from_secs(1),
+            simplifier: PathSimplifier::new(space.clone(), validity_checker.clone(), config),
+            space,
+            validity_checker,
+            start_states: Vec::new(),
+            goal: None,
+            planner: None,
+            seed: config.seed,
+            rng: planner_rng(config.seed),
+            solution: None,
+            last_plan_time: Duration::ZERO,
+            last_simplification_time: Duration::ZERO,
+        }
+    }
+
+    /// Returns the space being planned in, for building goals that need it.
+    pub fn space(&self) -> &Arc<SP> {
+        &self.space
+    }
+
+    /// Returns the validity checker.
+    pub fn validity_checker(&self) -> &Arc<dyn StateValidityChecker<S>> {
+        &self.validity_checker
+    }
+
+    /// Replaces the start states with a single start state.
+    pub fn set_start_state(&mut self, state: S) {
+        self.start_states = vec![state];
+    }
+
+    /// Adds another start state.
+    pub fn add_start_state(&mut self, state: S) {
+        self.start_states.push(state);
+    }
+
+    /// Removes every start state.
+    pub fn clear_start_states(&mut self) {
+        self.start_states.clear();
+    }
+
+    /// Returns the start states.
+    pub fn start_states(&self) -> &[S] {
+        &self.start_states
+    }
+
+    /// Sets the goal.
+    pub fn set_goal(&mut self, goal: G) {
+        self.goal = Some(Arc::new(goal));
+    }
+
+    /// Returns the goal, if one has been set.
+    pub fn goal(&self) -> Option<&Arc<G>> {
+        self.goal.as_ref()
+    }
+
+    /// Sets the planner used by `solve`, in place of the default `RRTConnect`.
+    pub fn set_planner(&mut self, planner: impl Planner<S, SP, G> + 'static) {
+        self.planner = Some(Box::new(planner));
+    }
+
+    /// Sets the planner used by `solve`, together with a preparation step that is run after it is
+    /// set up and before it solves, such as `PRM::construct_roadmap`.
+    pub fn set_planner_with_preparation<P, F>(&mut self, planner: P, prepare: F)
+    where
+        P: Planner<S, SP, G> + 'static,
+        F: FnMut(&mut P) -> Result<(), PlanningError> + 'static,
+    {
+        self.planner = Some(Box::new(PreparedPlanner::new(planner, prepare)));
+    }
+
+    /// Checks that the problem can be solved as it stands, using `ProblemDefinition::validate`.
+    ///
+    /// Warnings, such as none of the goal samples being valid, are logged rather than returned, as
+    /// the planner may still find a solution.
+    ///
+    /// # Errors
+    /// * `PlanningError::NoGoal` if no goal has been set.
+    /// * `PlanningError::InvalidProblem` with the first error found by
+    ///   `ProblemDefinition::validate`, such as a missing or invalid start state.
+    pub fn validate(&mut self) -> Result<(), PlanningError> {
+        let problem_def = self.problem_definition()?;
+        let report = problem_def.validate(&*self.validity_checker, &mut self.rng);
+        for warning in &report.warnings {
+            log::warn!("SimpleSetup: {warning}");
+        }
+        match report.errors.into_iter().next() {
+            Some(error) => Err(PlanningError::InvalidProblem(error)),
+            None => Ok(()),
+        }
+    }
+
+    /// Validates the problem, plans a path within `timeout` and, if `simplify_solution` is set,
+    /// simplifies it.
+    ///
+    /// The solution is also kept, and can be retrieved later with `solution`.
+    ///
+    /// # Errors
+    /// Returns the error from `validate` if the problem is malformed, or the planner's error if it
+    /// fails to find a solution.
+    pub fn solve(&mut self, timeout: Duration) -> Result<Path<S>, PlanningError> {
+        self.solution = None;
+        self.last_plan_time = Duration::ZERO;
+        self.last_simplification_time = Duration::ZERO;
+        self.validate()?;
+
+        let problem_def = self.problem_definition()?;
+        let seed = self.seed;
+        let planner = self.planner.get_or_insert_with(|| {
+            Box::new(RRTConnect::new(
+                DEFAULT_RANGE,
+                DEFAULT_GOAL_BIAS,
+                &PlannerConfig { seed },
+            ))
+        });
+
+        let start_time = Instant::now();
+        planner.setup(problem_def, self.validity_checker.clone());
+        let result = planner.solve(timeout);
+        self.last_plan_time = start_time.elapsed();
+        let mut path = result?;
+
+        if self.simplify_solution {
+            let start_time = Instant::now();
+            self.simplifier
+                .simplify(&mut path, self.simplification_timeout);
+            self.last_simplification_time = start_time.elapsed();
+        }
+
+        self.solution = Some(path.clone());
+        Ok(path)
+    }
+
+    /// Returns the solution found by the last call to `solve`, if it succeeded.
+    pub fn solution(&self) -> Option<&Path<S>> {
+        self.solution.as_ref()
+    }
+
+    /// Returns the time the planner spent on the last call to `solve`, including setting it up.
+    pub fn last_plan_time(&self) -> Duration {
+        self.last_plan_time
+    }
+
+    /// Returns the time spent simplifying the solution on the last call to `solve`.
+    pub fn last_simplification_time(&self) -> Duration {
+        self.last_simplification_time
+    }
+
+    /// Returns the statistics the planner collected on the last call to `solve`, or empty
+    /// statistics if `solve` has not been called.
+    pub fn planner_stats(&self) -> PlannerStats {
+        self.planner
+            .as_ref()
+            .map_or_else(PlannerStats::default, |planner| planner.stats())
+    }
+
+    /// Returns the path simplifier, to simplify paths further by hand.
+    pub fn simplifier(&mut self) -> &mut PathSimplifier<S, SP> {
+        &mut self.simplifier
+    }
+
+    /// Builds a `ProblemDefinition` from the current start states and goal.
+    fn problem_definition(&self) -> Result<Arc<ProblemDefinition<S, SP, G>>, PlanningError> {
+        Ok(Arc::new(ProblemDefinition {
+            space: self.space.clone(),
+            start_states: self.start_states.clone(),
+            goal: self.goal.clone().ok_or(PlanningError::NoGoal)?,
+        }))
+    }
+}
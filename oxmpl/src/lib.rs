@@ -1,3 +1,4 @@
 pub mod base;
 pub mod geometric;
 pub mod time;
+pub mod tools;
@@ -0,0 +1,735 @@
+// Copyright (c) 2025 Junior Sundar
+//
+// SPDX-License-Identifier: BSD-3-Clause
+
+use std::{
+    fmt,
+    io::{self, Write},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+use crate::{
+    base::{
+        error::PlanningError,
+        goal::Goal,
+        param::json_string,
+        planner::{Path, Planner, PlannerConfig, PlannerStats},
+        problem_definition::ProblemDefinition,
+        space::StateSpace,
+        state::State,
+        validity::StateValidityChecker,
+    },
+    geometric::PathSimplifier,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+/// Runs one benchmark run of a planner and returns its result.
+type RunFn<S, SP, G> = Box<
+    dyn FnMut(
+        &BenchmarkRequest,
+        &Arc<ProblemDefinition<S, SP, G>>,
+        &Arc<CountingValidityChecker<S>>,
+        Option<u64>,
+    ) -> RunResult,
+>;
+
+/// The properties recorded for every run, with their OMPL types, before any planner-specific ones.
+const RUN_PROPERTIES: [(&str, &str); 7] = [
+    ("time", "REAL"),
+    ("solved", "BOOLEAN"),
+    ("solution length", "REAL"),
+    ("solution vertices", "INTEGER"),
+    ("simplification time", "REAL"),
+    ("simplified solution length", "REAL"),
+    ("validity checks", "INTEGER"),
+];
+
+/// A property recorded for a run.
+#[derive(Clone, Debug, PartialEq)]
+pub enum BenchmarkValue {
+    Boolean(bool),
+    Integer(i64),
+    Real(f64),
+    Text(String),
+}
+
+impl BenchmarkValue {
+    /// The name of the type in OMPL's benchmark log format.
+    fn ompl_type(&self) -> &'static str {
+        match self {
+            Self::Boolean(_) => "BOOLEAN",
+            Self::Integer(_) => "INTEGER",
+            Self::Real(_) => "REAL",
+            Self::Text(_) => "STRING",
+        }
+    }
+
+    fn write_json(&self, out: &mut impl Write) -> io::Result<()> {
+        match self {
+            Self::Boolean(value) => write!(out, "{value}"),
+            Self::Integer(value) => write!(out, "{value}"),
+            Self::Real(value) => write_json_number(out, *value),
+            Self::Text(value) => write_json_string(out, value),
+        }
+    }
+}
+
+impl fmt::Display for BenchmarkValue {
+    /// Formats the value as OMPL does, with booleans written as `0` or `1`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Boolean(value) => write!(f, "{}", u8::from(*value)),
+            Self::Integer(value) => write!(f, "{value}"),
+            Self::Real(value) => write!(f, "{value}"),
+            Self::Text(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+/// The settings of a benchmark.
+#[derive(Clone, Debug)]
+pub struct BenchmarkRequest {
+    /// The number of times each planner is run.
+    pub runs: usize,
+    /// The time each run may take to solve the problem, including any preparation such as
+    /// `PRM::construct_roadmap`. The preparation is not interrupted, but a run that takes longer
+    /// than this is recorded as unsolved. Simplification is given the same time again.
+    pub time_limit: Duration,
+    /// Whether to simplify each solution with `PathSimplifier`.
+    pub simplify: bool,
+    /// The seed of the first run. Run `i` of every planner is seeded with `seed + i`, so that
+    /// benchmarks can be repeated. If `None`, every run is seeded randomly.
+    pub seed: Option<u64>,
+}
+
+impl Default for BenchmarkRequest {
+    fn default() -> Self {
+        BenchmarkRequest {
+            runs: 100,
+            time_limit: Duration::from_secs(5),
+            simplify: true,
+            seed: None,
+        }
+    }
+}
+
+/// The result of a single run of a planner.
+#[derive(Clone, Debug)]
+pub struct RunResult {
+    /// The time taken to prepare the planner and solve the problem.
+    pub time: Duration,
+    /// Whether a solution was found.
+    pub solved: bool,
+    /// The length of the solution, if one was found.
+    pub solution_length: Option<f64>,
+    /// The number of states in the solution, if one was found.
+    pub solution_vertices: Option<usize>,
+    /// The time taken to simplify the solution, if it was simplified.
+    pub simplification_time: Option<Duration>,
+    /// The length of the simplified solution, if it was simplified.
+    pub simplified_length: Option<f64>,
+    /// The number of states checked by the validity checker while solving, not counting
+    /// simplification.
+    pub validity_checks: usize,
+    /// Planner-specific properties, such as the size of a tree or roadmap.
+    pub properties: Vec<(String, BenchmarkValue)>,
+    /// The statistics collected by the planner. Only written to the JSON output.
+    pub stats: PlannerStats,
+}
+
+impl RunResult {
+    /// Returns the properties common to every run, in the order of `RUN_PROPERTIES`.
+    fn common_values(&self) -> [Option<BenchmarkValue>; 7] {
+        [
+            Some(BenchmarkValue::Real(self.time.as_secs_f64())),
+            Some(BenchmarkValue::Boolean(self.solved)),
+            self.solution_length.map(BenchmarkValue::Real),
+            self.solution_vertices
+                .map(|vertices| BenchmarkValue::Integer(vertices as i64)),
+            self.simplification_time
+                .map(|time| BenchmarkValue::Real(time.as_secs_f64())),
+            self.simplified_length.map(BenchmarkValue::Real),
+            Some(BenchmarkValue::Integer(self.validity_checks as i64)),
+        ]
+    }
+
+    fn property(&self, name: &str) -> Option<&BenchmarkValue> {
+        self.properties
+            .iter()
+            .find(|(property, _)| property == name)
+            .map(|(_, value)| value)
+    }
+}
+
+/// The results of every run of one planner.
+#[derive(Clone, Debug)]
+pub struct PlannerResults {
+    /// The name the planner was added with.
+    pub name: String,
+    /// The result of each run, in order.
+    pub runs: Vec<RunResult>,
+}
+
+impl PlannerResults {
+    /// Returns the fraction of runs that found a solution.
+    pub fn success_rate(&self) -> f64 {
+        if self.runs.is_empty() {
+            return 0.0;
+        }
+        self.runs.iter().filter(|run| run.solved).count() as f64 / self.runs.len() as f64
+    }
+
+    /// Returns the names and OMPL types of the planner-specific properties, in the order they were
+    /// first recorded.
+    fn property_columns(&self) -> Vec<(&str, &'static str)> {
+        let mut columns: Vec<(&str, &'static str)> = Vec::new();
+        for (name, value) in self.runs.iter().flat_map(|run| &run.properties) {
+            if !columns.iter().any(|(column, _)| column == name) {
+                columns.push((name, value.ompl_type()));
+            }
+        }
+        columns
+    }
+}
+
+/// The results of a benchmark, which can be written as an OMPL benchmark log, CSV or JSON.
+#[derive(Clone, Debug)]
+pub struct BenchmarkResults {
+    /// The name of the experiment.
+    pub experiment_name: String,
+    /// The name of the machine the benchmark was run on.
+    pub host: String,
+    /// When the benchmark was started.
+    pub start_time: SystemTime,
+    /// The time taken to run the whole benchmark.
+    pub total_time: Duration,
+    /// The settings the benchmark was run with.
+    pub request: BenchmarkRequest,
+    /// The results of each planner, in the order they were added.
+    pub planners: Vec<PlannerResults>,
+}
+
+impl BenchmarkResults {
+    /// Writes the results in OMPL's benchmark log format.
+    ///
+    /// The log can be turned into a database with OMPL's `ompl_benchmark_statistics.py` and
+    /// explored in Planner Arena, alongside logs written by OMPL itself.
+    pub fn write_ompl_log(&self, mut out: impl Write) -> io::Result<()> {
+        writeln!(out, "OMPL version oxmpl-{}", env!("CARGO_PKG_VERSION"))?;
+        writeln!(out, "Experiment {}", self.experiment_name)?;
+        writeln!(out, "Running on {}", self.host)?;
+        writeln!(out, "Starting at {}", format_utc(self.start_time))?;
+        writeln!(out, "<<<|")?;
+        writeln!(
+            out,
+            "Solutions simplified: {}",
+            if self.request.simplify { "yes" } else { "no" }
+        )?;
+        writeln!(out, "|>>>")?;
+        writeln!(out, "<<<|")?;
+        writeln!(out, "{} {}", std::env::consts::OS, std::env::consts::ARCH)?;
+        writeln!(out, "|>>>")?;
+        writeln!(
+            out,
+            "{} is the random seed",
+            self.request.seed.unwrap_or_default()
+        )?;
+        writeln!(
+            out,
+            "{} seconds per run",
+            self.request.time_limit.as_secs_f64()
+        )?;
+        // Memory limits are not supported, but OMPL's format requires one, so an unlimited amount
+        // is given.
+        writeln!(out, "inf MB per run")?;
+        writeln!(out, "{} runs per planner", self.request.runs)?;
+        writeln!(
+            out,
+            "{} seconds spent to collect the data",
+            self.total_time.as_secs_f64()
+        )?;
+        writeln!(out, "0 enum types")?;
+        writeln!(out, "{} planners", self.planners.len())?;
+
+        for planner in &self.planners {
+            let columns = planner.property_columns();
+            writeln!(out, "{}", planner.name)?;
+            writeln!(out, "0 common properties")?;
+            writeln!(
+                out,
+                "{} properties for each run",
+                RUN_PROPERTIES.len() + columns.len()
+            )?;
+            for (name, kind) in RUN_PROPERTIES.iter().chain(&columns) {
+                writeln!(out, "{name} {kind}")?;
+            }
+            writeln!(out, "{} runs", planner.runs.len())?;
+            for run in &planner.runs {
+                let values = run
+                    .common_values()
+                    .into_iter()
+                    .chain(columns.iter().map(|(name, _)| run.property(name).cloned()));
+                for value in values {
+                    match value {
+                        Some(value) => write!(out, "{value}; ")?,
+                        None => write!(out, "; ")?,
+                    }
+                }
+                writeln!(out)?;
+            }
+            writeln!(out, ".")?;
+        }
+        Ok(())
+    }
+
+    /// Writes the results as CSV, with one row per run.
+    ///
+    /// The columns are the planner name, the run index, the properties common to every run and
+    /// then the planner-specific properties of every planner. Missing values are left empty.
+    pub fn write_csv(&self, mut out: impl Write) -> io::Result<()> {
+        let mut columns: Vec<&str> = Vec::new();
+        for planner in &self.planners {
+            for (name, _) in planner.property_columns() {
+                if !columns.contains(&name) {
+                    columns.push(name);
+                }
+            }
+        }
+
+        let header = ["planner", "run"]
+            .into_iter()
+            .chain(RUN_PROPERTIES.iter().map(|(name, _)| *name))
+            .chain(columns.iter().copied())
+            .map(csv_field)
+            .collect::<Vec<_>>();
+        writeln!(out, "{}", header.join(","))?;
+
+        for planner in &self.planners {
+            for (index, run) in planner.runs.iter().enumerate() {
+                let row = [Some(planner.name.clone()), Some(index.to_string())]
+                    .into_iter()
+                    .chain(
+                        run.common_values()
+                            .into_iter()
+                            .map(|value| value.map(|value| value.to_string())),
+                    )
+                    .chain(
+                        columns
+                            .iter()
+                            .map(|name| run.property(name).map(ToString::to_string)),
+                    )
+                    .map(|value| value.as_deref().map(csv_field).unwrap_or_default())
+                    .collect::<Vec<_>>();
+                writeln!(out, "{}", row.join(","))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes the results as a JSON object.
+    ///
+    /// Durations are written in seconds and the start time as seconds since the Unix epoch.
+    /// Missing values are written as `null`. Every run also includes the planner's
+    /// `PlannerStats` under `"stats"`.
+    pub fn write_json(&self, mut out: impl Write) -> io::Result<()> {
+        write!(out, "{{\"experiment\":")?;
+        write_json_string(&mut out, &self.experiment_name)?;
+        write!(out, ",\"host\":")?;
+        write_json_string(&mut out, &self.host)?;
+        write!(out, ",\"start_time\":")?;
+        let since_epoch = self
+            .start_time
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        write_json_number(&mut out, since_epoch.as_secs_f64())?;
+        write!(out, ",\"total_time\":")?;
+        write_json_number(&mut out, self.total_time.as_secs_f64())?;
+        write!(out, ",\"runs\":{}", self.request.runs)?;
+        write!(out, ",\"time_limit\":")?;
+        write_json_number(&mut out, self.request.time_limit.as_secs_f64())?;
+        write!(out, ",\"simplify\":{}", self.request.simplify)?;
+        match self.request.seed {
+            Some(seed) => write!(out, ",\"seed\":{seed}")?,
+            None => write!(out, ",\"seed\":null")?,
+        }
+
+        write!(out, ",\"planners\":[")?;
+        for (i, planner) in self.planners.iter().enumerate() {
+            if i > 0 {
+                write!(out, ",")?;
+            }
+            write!(out, "{{\"name\":")?;
+            write_json_string(&mut out, &planner.name)?;
+            write!(out, ",\"runs\":[")?;
+            for (j, run) in planner.runs.iter().enumerate() {
+                if j > 0 {
+                    write!(out, ",")?;
+                }
+                write!(out, "{{")?;
+                for (k, ((name, _), value)) in
+                    RUN_PROPERTIES.iter().zip(run.common_values()).enumerate()
+                {
+                    if k > 0 {
+                        write!(out, ",")?;
+                    }
+                    write_json_string(&mut out, name)?;
+                    write!(out, ":")?;
+                    match value {
+                        Some(value) => value.write_json(&mut out)?,
+                        None => write!(out, "null")?,
+                    }
+                }
+                write!(out, ",\"properties\":{{")?;
+                for (k, (name, value)) in run.properties.iter().enumerate() {
+                    if k > 0 {
+                        write!(out, ",")?;
+                    }
+                    write_json_string(&mut out, name)?;
+                    write!(out, ":")?;
+                    value.write_json(&mut out)?;
+                }
+                write!(out, "}},\"stats\":{}}}", run.stats.to_json())?;
+            }
+            write!(out, "]}}")?;
+        }
+        writeln!(out, "]}}")
+    }
+}
+
+/// Wraps a validity checker and counts the states it checks.
+struct CountingValidityChecker<S: State> {
+    inner: Arc<dyn StateValidityChecker<S>>,
+    checks: AtomicUsize,
+}
+
+impl<S: State> StateValidityChecker<S> for CountingValidityChecker<S> {
+    fn is_valid(&self, state: &S) -> bool {
+        self.checks.fetch_add(1, Ordering::Relaxed);
+        self.inner.is_valid(state)
+    }
+
+    fn clearance(&self, state: &S) -> Option<f64> {
+        self.inner.clearance(state)
+    }
+}
+
+/// Runs several planners on the same problem many times and records how they perform.
+///
+/// Each planner is added with a factory that builds a fresh planner for every run, so that no run
+/// benefits from the work of an earlier one. Every run records the time taken to solve, whether a
+/// solution was found, its length, the time taken to simplify it and the number of validity
+/// checks made. Planners can also record their own properties, such as the size of their tree.
+///
+/// The results can be written in OMPL's benchmark log format, to be compared with OMPL planners
+/// in Planner Arena, or as CSV or JSON.
+///
+/// # Example
+///
+/// ```
+/// use std::{sync::Arc, time::Duration};
+/// use oxmpl::base::{
+///     goal::GoalState,
+///     problem_definition::ProblemDefinition,
+///     space::RealVectorStateSpace,
+///     state::RealVectorState,
+///     validity::StateValidityChecker,
+/// };
+/// use oxmpl::geometric::{RRTConnect, RRT};
+/// use oxmpl::tools::{Benchmark, BenchmarkRequest, BenchmarkValue};
+///
+/// struct NoObstacles;
+/// impl StateValidityChecker<RealVectorState> for NoObstacles {
+///     fn is_valid(&self, _state: &RealVectorState) -> bool {
+///         true
+///     }
+/// }
+///
+/// let space = Arc::new(RealVectorStateSpace::new(2, Some(vec![(0.0, 10.0), (0.0, 10.0)])).unwrap());
+/// let problem_def = Arc::new(ProblemDefinition {
+///     space: space.clone(),
+///     start_states: vec![RealVectorState::new(vec![1.0, 1.0])],
+///     goal: Arc::new(GoalState::new(space.clone(), RealVectorState::new(vec![9.0, 9.0]), 0.5)),
+/// });
+///
+/// let mut benchmark = Benchmark::new("open_space", problem_def, Arc::new(NoObstacles));
+/// benchmark.add_planner_with_properties(
+///     "RRT",
+///     |config| RRT::new(0.5, 0.05, config),
+///     |_| Ok(()),
+///     |rrt| vec![("tree states".to_string(), BenchmarkValue::Integer(rrt.tree_states().count() as i64))],
+/// );
+/// benchmark.add_planner("RRTConnect", |config| RRTConnect::new(0.5, 0.05, config));
+///
+/// let results = benchmark.run(&BenchmarkRequest {
+///     runs: 3,
+///     time_limit: Duration::from_secs(1),
+///     seed: Some(0),
+///     ..Default::default()
+/// });
+/// assert_eq!(results.planners[0].success_rate(), 1.0);
+///
+/// let mut log = Vec::new();
+/// results.write_ompl_log(&mut log).unwrap();
+/// ```
+pub struct Benchmark<S: State, SP: StateSpace<StateType = S>, G: Goal<S>> {
+    experiment_name: String,
+    problem_def: Arc<ProblemDefinition<S, SP, G>>,
+    validity_checker: Arc<dyn StateValidityChecker<S>>,
+    planners: Vec<(String, RunFn<S, SP, G>)>,
+}
+
+impl<S, SP, G> Benchmark<S, SP, G>
+where
+    S: State + Clone + 'static,
+    SP: StateSpace<StateType = S> + 'static,
+    G: Goal<S> + 'static,
+{
+    /// Creates a new `Benchmark` with no planners.
+    ///
+    /// # Parameters
+    /// * `experiment_name` - The name of the experiment, written to the results.
+    /// * `problem_def` - The problem every planner is run on.
+    /// * `validity_checker` - The validity checker every planner is given.
+    pub fn new(
+        experiment_name: impl Into<String>,
+        problem_def: Arc<ProblemDefinition<S, SP, G>>,
+        validity_checker: Arc<dyn StateValidityChecker<S>>,
+    ) -> Self {
+        Benchmark {
+            experiment_name: experiment_name.into(),
+            problem_def,
+            validity_checker,
+            planners: Vec::new(),
+        }
+    }
+
+    /// Adds a planner to the benchmark.
+    ///
+    /// # Parameters
+    /// * `name` - The name the planner's results are recorded under.
+    /// * `factory` - Builds a new planner for each run from the run's configuration.
+    pub fn add_planner<P, F>(&mut self, name: impl Into<String>, factory: F)
+    where
+        P: Planner<S, SP, G> + 'static,
+        F: FnMut(&PlannerConfig) -> P + 'static,
+    {
+        self.add_planner_with_properties(name, factory, |_| Ok(()), |_| Vec::new());
+    }
+
+    /// Adds a planner together with a preparation step that is run after `setup` and before
+    /// `solve`, such as `PRM::construct_roadmap`. The preparation counts towards the run's time, so
+    /// a run whose preparation overruns `time_limit` is recorded as unsolved.
+    pub fn add_planner_with_preparation<P, F, R>(
+        &mut self,
+        name: impl Into<String>,
+        factory: F,
+        prepare: R,
+    ) where
+        P: Planner<S, SP, G> + 'static,
+        F: FnMut(&PlannerConfig) -> P + 'static,
+        R: FnMut(&mut P) -> Result<(), PlanningError> + 'static,
+    {
+        self.add_planner_with_properties(name, factory, prepare, |_| Vec::new());
+    }
+
+    /// Adds a planner with a preparation step and a function that reads planner-specific
+    /// properties from it after each run.
+    ///
+    /// Pass `|_| Ok(())` as `prepare` if the planner needs no preparation.
+    pub fn add_planner_with_properties<P, F, R, Q>(
+        &mut self,
+        name: impl Into<String>,
+        mut factory: F,
+        mut prepare: R,
+        properties: Q,
+    ) where
+        P: Planner<S, SP, G> + 'static,
+        F: FnMut(&PlannerConfig) -> P + 'static,
+        R: FnMut(&mut P) -> Result<(), PlanningError> + 'static,
+        Q: Fn(&P) -> Vec<(String, BenchmarkValue)> + 'static,
+    {
+        let run = move |request: &BenchmarkRequest,
+                        problem_def: &Arc<ProblemDefinition<S, SP, G>>,
+                        checker: &Arc<CountingValidityChecker<S>>,
+                        seed: Option<u64>| {
+            let config = PlannerConfig { seed };
+            let mut planner = factory(&config);
+            checker.checks.store(0, Ordering::Relaxed);
+
+            let start = Instant::now();
+            planner.setup(problem_def.clone(), checker.clone());
+            let result = prepare(&mut planner)
+                .and_then(|()| planner.solve(request.time_limit.saturating_sub(start.elapsed())));
+            let time = start.elapsed();
+            // Preparation is not bounded by the time limit, so a run may still overrun it.
+            let result = result.and_then(|path| {
+                if time <= request.time_limit {
+                    Ok(path)
+                } else {
+                    Err(PlanningError::Timeout)
+                }
+            });
+            let validity_checks = checker.checks.load(Ordering::Relaxed);
+
+            let mut run = RunResult {
+                time,
+                solved: false,
+                solution_length: None,
+                solution_vertices: None,
+                simplification_time: None,
+                simplified_length: None,
+                validity_checks,
+                properties: properties(&planner),
+                stats: planner.stats(),
+            };
+            if let Ok(path) = result {
+                let mut simplifier =
+                    PathSimplifier::new(problem_def.space.clone(), checker.clone(), &config);
+                run.solved = true;
+                run.solution_length = Some(simplifier.path_length(&path));
+                run.solution_vertices = Some(path.0.len());
+                if request.simplify {
+                    let mut simplified: Path<S> = path;
+                    let start = Instant::now();
+                    simplifier.simplify(&mut simplified, request.time_limit);
+                    run.simplification_time = Some(start.elapsed());
+                    run.simplified_length = Some(simplifier.path_length(&simplified));
+                }
+            }
+            run
+        };
+        self.planners.push((name.into(), Box::new(run)));
+    }
+
+    /// Runs every planner `request.runs` times and returns the results.
+    ///
+    /// The planners take turns, so that run `i` of every planner is made before run `i + 1` of
+    /// any, which spreads any slowdown of the machine evenly between them.
+    pub fn run(&mut self, request: &BenchmarkRequest) -> BenchmarkResults {
+        let start_time = SystemTime::now();
+        let start = Instant::now();
+        let checker = Arc::new(CountingValidityChecker {
+            inner: self.validity_checker.clone(),
+            checks: AtomicUsize::new(0),
+        });
+
+        let mut planners: Vec<PlannerResults> = self
+            .planners
+            .iter()
+            .map(|(name, _)| PlannerResults {
+                name: name.clone(),
+                runs: Vec::with_capacity(request.runs),
+            })
+            .collect();
+        for index in 0..request.runs {
+            let seed = request.seed.map(|seed| seed.wrapping_add(index as u64));
+            for ((_, run), results) in self.planners.iter_mut().zip(&mut planners) {
+                results
+                    .runs
+                    .push(run(request, &self.problem_def, &checker, seed));
+            }
+        }
+
+        BenchmarkResults {
+            experiment_name: self.experiment_name.clone(),
+            host: host_name(),
+            start_time,
+            total_time: start.elapsed(),
+            request: request.clone(),
+            planners,
+        }
+    }
+}
+
+/// Returns the name of this machine, or `unknown` if it cannot be found.
+fn host_name() -> String {
+    std::env::var("HOSTNAME")
+        .ok()
+        .or_else(|| std::fs::read_to_string("/etc/hostname").ok())
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+/// Formats a time as `YYYY-MM-DD HH:MM:SS` in UTC.
+fn format_utc(time: SystemTime) -> String {
+    let seconds = time
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let (days, seconds_of_day) = (seconds / 86_400, seconds % 86_400);
+
+    // Converts days since the epoch to a civil date, following Howard Hinnant's algorithm.
+    let z = days as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02} {:02}:{:02}:{:02}",
+        seconds_of_day / 3600,
+        seconds_of_day / 60 % 60,
+        seconds_of_day % 60
+    )
+}
+
+/// Quotes a CSV field if it contains a comma, quote or line break.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn write_json_string(out: &mut impl Write, value: &str) -> io::Result<()> {
+    write!(out, "{}", json_string(value))
+}
+
+/// Writes a number, or `null` if it is not finite, as JSON has no representation for those.
+fn write_json_number(out: &mut impl Write, value: f64) -> io::Result<()> {
+    if value.is_finite() {
+        write!(out, "{value}")
+    } else {
+        write!(out, "null")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_utc() {
+        assert_eq!(format_utc(UNIX_EPOCH), "1970-01-01 00:00:00");
+        let leap_day = UNIX_EPOCH + Duration::from_secs(951_782_400 + 3_723);
+        assert_eq!(format_utc(leap_day), "2000-02-29 01:02:03");
+    }
+
+    #[test]
+    fn test_escaping() {
+        assert_eq!(csv_field("RRT"), "RRT");
+        assert_eq!(csv_field("RRT, \"fast\""), "\"RRT, \"\"fast\"\"\"");
+
+        let mut json = Vec::new();
+        write_json_string(&mut json, "a\"b\\c\nd\u{1}").unwrap();
+        assert_eq!(String::from_utf8(json).unwrap(), r#""a\"b\\c\nd\u0001""#);
+
+        let mut json = Vec::new();
+        write_json_number(&mut json, f64::INFINITY).unwrap();
+        assert_eq!(json, b"null");
+    }
+}
@@ -0,0 +1,19 @@
+// Copyright (c) 2025 Junior Sundar
+//
+// SPDX-License-Identifier: BSD-3-Clause
+
+//! Utilities that build on top of the planners, such as running several of them at once or
+//! benchmarking them.
+
+mod benchmark;
+mod parallel_plan;
+mod time_parameterisation;
+
+pub use self::benchmark::{
+    Benchmark, BenchmarkRequest, BenchmarkResults, BenchmarkValue, PlannerResults, RunResult,
+};
+pub(crate) use self::parallel_plan::PreparedPlanner;
+pub use self::parallel_plan::{ParallelPlan, ParallelPlanMode};
+pub use self::time_parameterisation::{
+    JointKind, JointLimits, JointState, Trajectory, TrajectoryPoint,
+};
@@ -0,0 +1,430 @@
+// Copyright (c) 2025 Junior Sundar
+//
+// SPDX-License-Identifier: BSD-3-Clause
+
+use std::{
+    panic,
+    sync::{mpsc, Arc},
+    thread::{self, JoinHandle},
+};
+
+use crate::{
+    base::{
+        error::PlanningError,
+        goal::Goal,
+        observer::PlannerObserver,
+        param::ParamSet,
+        planner::{Path, Planner, PlannerStats},
+        problem_definition::ProblemDefinition,
+        space::StateSpace,
+        state::State,
+        validity::StateValidityChecker,
+    },
+    geometric::{
+        planners::{
+            common::{check_motion, shortest_path},
+            prm::Node,
+        },
+        BoxedPlanner,
+    },
+    time::Duration,
+};
+
+/// A running planner thread, which hands back the planner and its index when joined.
+type PlannerHandle<S, SP, G> = JoinHandle<(usize, BoxedPlanner<S, SP, G>)>;
+
+/// How `ParallelPlan` combines the results of the planners it runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParallelPlanMode {
+    /// Return the first solution found by any planner.
+    FirstSolution,
+    /// Wait for every planner to finish and return the shortest solution.
+    BestSolution,
+    /// Wait for every planner to finish and splice their solutions together at near-identical
+    /// states, returning the shortest path through the combined solutions.
+    Hybridise,
+}
+
+/// Wraps a planner together with a preparation step that must run before it can solve, such as
+/// `PRM::construct_roadmap`.
+pub(crate) struct PreparedPlanner<P, F> {
+    planner: P,
+    prepare: F,
+    prepared: bool,
+}
+
+impl<P, F> PreparedPlanner<P, F> {
+    pub(crate) fn new(planner: P, prepare: F) -> Self {
+        PreparedPlanner {
+            planner,
+            prepare,
+            prepared: false,
+        }
+    }
+}
+
+impl<S, SP, G, P, F> Planner<S, SP, G> for PreparedPlanner<P, F>
+where
+    S: State,
+    SP: StateSpace<StateType = S>,
+    G: Goal<S>,
+    P: Planner<S, SP, G>,
+    F: FnMut(&mut P) -> Result<(), PlanningError>,
+{
+    fn setup(
+        &mut self,
+        problem_def: Arc<ProblemDefinition<S, SP, G>>,
+        validity_checker: Arc<dyn StateValidityChecker<S>>,
+    ) {
+        self.planner.setup(problem_def, validity_checker);
+        self.prepared = false;
+    }
+
+    fn solve(&mut self, timeout: Duration) -> Result<Path<S>, PlanningError> {
+        if !self.prepared {
+            (self.prepare)(&mut self.planner)?;
+            self.prepared = true;
+        }
+        self.planner.solve(timeout)
+    }
+
+    fn stats(&self) -> PlannerStats {
+        self.planner.stats()
+    }
+
+    fn set_observer(&mut self, observer: Arc<dyn PlannerObserver<S>>) {
+        self.planner.set_observer(observer);
+    }
+
+    fn params(&self) -> ParamSet {
+        self.planner.params()
+    }
+
+    fn set_params(&mut self, params: &ParamSet) {
+        self.planner.set_params(params);
+    }
+}
+
+/// Runs several planners concurrently on the same problem.
+///
+/// Every planner is given the same `Arc<ProblemDefinition>` and `StateValidityChecker` and is run
+/// on its own thread when `solve` is called. Depending on the `mode`, either the first solution
+/// found or the best of all solutions is returned. In `ParallelPlanMode::Hybridise` mode the
+/// solutions are joined into a single graph wherever two of them pass within
+/// `hybridisation_tolerance` of each other, and the shortest path through that graph is returned.
+///
+/// `ParallelPlan` is itself a `Planner`, so it can be used anywhere a single planner can.
+///
+/// # Thread Safety
+///
+/// This relies on `StateSpace` and `StateValidityChecker` being `Send + Sync`. The goal must also
+/// be `Send + Sync`, as it is shared through the `ProblemDefinition`.
+///
+/// > [!NOTE]
+/// > There is no way to cancel a running planner. In `ParallelPlanMode::FirstSolution` mode the
+/// > remaining planners keep running until they finish or reach their own timeout, and are
+/// > waited for at the next call to `setup` or `solve`.
+///
+/// > [!WARNING]
+/// > Threads cannot be spawned on `wasm32-unknown-unknown`, so this is not available in the
+/// > JavaScript bindings.
+pub struct ParallelPlan<S: State, SP: StateSpace<StateType = S>, G: Goal<S>> {
+    /// How the results of the planners are combined.
+    pub mode: ParallelPlanMode,
+    /// The maximum distance between two states of different solutions for them to be spliced
+    /// together. Only used in `ParallelPlanMode::Hybridise` mode.
+    pub hybridisation_tolerance: f64,
+
+    planners: Vec<Option<BoxedPlanner<S, SP, G>>>,
+    pending: Vec<PlannerHandle<S, SP, G>>,
+    problem_def: Option<Arc<ProblemDefinition<S, SP, G>>>,
+    validity_checker: Option<Arc<dyn StateValidityChecker<S>>>,
+    solutions: Vec<(usize, Path<S>)>,
+}
+
+impl<S, SP, G> ParallelPlan<S, SP, G>
+where
+    S: State + Clone,
+    SP: StateSpace<StateType = S> + 'static,
+    G: Goal<S> + Send + Sync + 'static,
+{
+    /// Creates a new `ParallelPlan` with no planners.
+    ///
+    /// # Parameters
+    /// * `mode` - How the results of the planners are combined.
+    /// * `hybridisation_tolerance` - The distance within which states of different solutions are
+    ///   spliced together in `ParallelPlanMode::Hybridise` mode.
+    pub fn new(mode: ParallelPlanMode, hybridisation_tolerance: f64) -> Self {
+        ParallelPlan {
+            mode,
+            hybridisation_tolerance,
+            planners: Vec::new(),
+            pending: Vec::new(),
+            problem_def: None,
+            validity_checker: None,
+            solutions: Vec::new(),
+        }
+    }
+
+    /// Adds a planner to be run. Planners added after `setup` will only be set up at the next call
+    /// to `setup`.
+    pub fn add_planner(&mut self, planner: impl Planner<S, SP, G> + Send + 'static) {
+        self.planners.push(Some(Box::new(planner)));
+    }
+
+    /// Adds a planner together with a preparation step that is run on its thread, after `setup`
+    /// and before its first `solve`.
+    ///
+    /// This is needed for planners such as `PRM`, which must construct their roadmap before they
+    /// can answer queries.
+    pub fn add_planner_with_preparation<P, F>(&mut self, planner: P, prepare: F)
+    where
+        P: Planner<S, SP, G> + Send + 'static,
+        F: FnMut(&mut P) -> Result<(), PlanningError> + Send + 'static,
+    {
+        self.planners
+            .push(Some(Box::new(PreparedPlanner::new(planner, prepare))));
+    }
+
+    /// Returns the solutions found during the last call to `solve`, each with the index of the
+    /// planner that found it, in the order the planners were added.
+    pub fn solutions(&self) -> &[(usize, Path<S>)] {
+        &self.solutions
+    }
+
+    /// Waits for planners still running from a previous `solve` and takes them back.
+    fn reclaim_pending(&mut self) {
+        for handle in self.pending.drain(..) {
+            match handle.join() {
+                Ok((index, planner)) => self.planners[index] = Some(planner),
+                Err(payload) => panic::resume_unwind(payload),
+            }
+        }
+    }
+
+    fn path_length(space: &SP, path: &Path<S>) -> f64 {
+        path.0
+            .windows(2)
+            .map(|pair| space.distance(&pair[0], &pair[1]))
+            .sum()
+    }
+
+    /// Joins all solutions into one graph and returns the shortest path through it.
+    ///
+    /// Consecutive states of each solution are connected as they are. States of different
+    /// solutions that are within `hybridisation_tolerance` of each other are connected too, if the
+    /// motion between them is valid.
+    fn hybridise(
+        &self,
+        pd: &ProblemDefinition<S, SP, G>,
+        vc: &dyn StateValidityChecker<S>,
+    ) -> Option<Path<S>> {
+        let space = &*pd.space;
+        let mut graph: Vec<Node<S>> = Vec::new();
+        let mut owners = Vec::new();
+        let mut sources = Vec::new();
+        let mut targets = Vec::new();
+
+        for (solution_idx, (_, path)) in self.solutions.iter().enumerate() {
+            let first = graph.len();
+            for (i, state) in path.0.iter().enumerate() {
+                let index = graph.len();
+                let edges = if i > 0 { vec![index - 1] } else { Vec::new() };
+                if i > 0 {
+                    graph[index - 1].edges.push(index);
+                }
+                graph.push(Node {
+                    state: state.clone(),
+                    edges,
+                });
+                owners.push(solution_idx);
+            }
+            if graph.len() > first {
+                sources.push((first, 0.0));
+                targets.push(graph.len() - 1);
+            }
+        }
+
+        for a in 0..graph.len() {
+            for b in a + 1..graph.len() {
+                if owners[a] == owners[b] {
+                    continue;
+                }
+                let dist = space.distance(&graph[a].state, &graph[b].state);
+                if dist <= self.hybridisation_tolerance
+                    && (dist == 0.0 || check_motion(space, vc, &graph[a].state, &graph[b].state))
+                {
+                    graph[a].edges.push(b);
+                    graph[b].edges.push(a);
+                }
+            }
+        }
+
+        let (_, indices) = shortest_path(
+            &graph,
+            &sources,
+            |index| targets.contains(&index),
+            |a, b| space.distance(&graph[a].state, &graph[b].state),
+        )?;
+
+        let mut states: Vec<S> = Vec::with_capacity(indices.len());
+        for index in indices {
+            // Spliced states that coincide exactly would otherwise appear twice.
+            let state = &graph[index].state;
+            if states
+                .last()
+                .is_none_or(|last| space.distance(last, state) > 0.0)
+            {
+                states.push(state.clone());
+            }
+        }
+        Some(Path(states))
+    }
+}
+
+impl<S, SP, G> Planner<S, SP, G> for ParallelPlan<S, SP, G>
+where
+    S: State + Clone,
+    SP: StateSpace<StateType = S> + 'static,
+    G: Goal<S> + Send + Sync + 'static,
+{
+    fn setup(
+        &mut self,
+        problem_def: Arc<ProblemDefinition<S, SP, G>>,
+        validity_checker: Arc<dyn StateValidityChecker<S>>,
+    ) {
+        self.reclaim_pending();
+        self.solutions.clear();
+
+        for planner in self.planners.iter_mut().flatten() {
+            planner.setup(problem_def.clone(), validity_checker.clone());
+        }
+
+        self.problem_def = Some(problem_def);
+        self.validity_checker = Some(validity_checker);
+    }
+
+    fn solve(&mut self, timeout: Duration) -> Result<Path<S>, PlanningError> {
+        let pd = self
+            .problem_def
+            .clone()
+            .ok_or(PlanningError::PlannerUninitialised)?;
+        let vc = self
+            .validity_checker
+            .clone()
+            .ok_or(PlanningError::PlannerUninitialised)?;
+
+        self.reclaim_pending();
+        self.solutions.clear();
+        if self.planners.is_empty() {
+            return Err(PlanningError::NoSolutionFound);
+        }
+
+        // 1. Start every planner on its own thread. Each thread reports its result over the
+        //    channel and hands its planner back when joined.
+        let (sender, receiver) = mpsc::channel();
+        for (index, slot) in self.planners.iter_mut().enumerate() {
+            let Some(mut planner) = slot.take() else {
+                continue;
+            };
+            let sender = sender.clone();
+            self.pending.push(thread::spawn(move || {
+                let result = planner.solve(timeout);
+                // The receiver is gone if an earlier solution was already returned.
+                let _ = sender.send((index, result));
+                (index, planner)
+            }));
+        }
+        drop(sender);
+
+        // 2. Collect the results. The loop ends once every thread has reported, or stops early
+        //    with the first solution in `FirstSolution` mode.
+        let mut first_error = None;
+        for (index, result) in receiver.iter() {
+            match result {
+                Ok(path) => {
+                    self.solutions.push((index, path));
+                    if self.mode == ParallelPlanMode::FirstSolution {
+                        break;
+                    }
+                }
+                Err(e) => {
+                    if first_error.as_ref().is_none_or(|(i, _)| index < *i) {
+                        first_error = Some((index, e));
+                    }
+                }
+            }
+        }
+        if self.mode != ParallelPlanMode::FirstSolution {
+            self.reclaim_pending();
+        }
+        self.solutions.sort_by_key(|(index, _)| *index);
+
+        if self.solutions.is_empty() {
+            return Err(first_error.map_or(PlanningError::NoSolutionFound, |(_, e)| e));
+        }
+
+        // 3. Combine the solutions according to the mode.
+        let space = &*pd.space;
+        let best = self
+            .solutions
+            .iter()
+            .min_by(|(_, a), (_, b)| {
+                Self::path_length(space, a).total_cmp(&Self::path_length(space, b))
+            })
+            .map(|(_, path)| path.clone())
+            .expect("solutions is not empty");
+
+        match self.mode {
+            ParallelPlanMode::FirstSolution | ParallelPlanMode::BestSolution => Ok(best),
+            ParallelPlanMode::Hybridise => {
+                let hybrid = self.hybridise(&pd, &*vc).unwrap_or_else(|| best.clone());
+                log::info!(
+                    "Hybridised {} solutions from length {:.4} to {:.4}.",
+                    self.solutions.len(),
+                    Self::path_length(space, &best),
+                    Self::path_length(space, &hybrid)
+                );
+                Ok(hybrid)
+            }
+        }
+    }
+
+    /// Returns the statistics of every planner added together.
+    ///
+    /// Planners still running after a `ParallelPlanMode::FirstSolution` solve are not included.
+    fn stats(&self) -> PlannerStats {
+        let mut stats = PlannerStats::default();
+        for planner in self.planners.iter().flatten() {
+            stats.merge(&planner.stats());
+        }
+        stats
+    }
+
+    /// Sets the observer of every planner added so far, waiting for any that are still running.
+    ///
+    /// Events from the planners may interleave, as they run on their own threads.
+    fn set_observer(&mut self, observer: Arc<dyn PlannerObserver<S>>) {
+        self.reclaim_pending();
+        for planner in self.planners.iter_mut().flatten() {
+            planner.set_observer(observer.clone());
+        }
+    }
+
+    /// Returns the parameters of `ParallelPlan` itself. Those of the planners it runs are set on
+    /// each planner before it is added.
+    fn params(&self) -> ParamSet {
+        ParamSet::default().with_real(
+            "hybridisation_tolerance",
+            "The maximum distance between two solutions for them to be spliced together.",
+            self.hybridisation_tolerance,
+            0.0..=f64::INFINITY,
+        )
+    }
+
+    fn set_params(&mut self, params: &ParamSet) {
+        if let Some(hybridisation_tolerance) = params.real("hybridisation_tolerance") {
+            self.hybridisation_tolerance = hybridisation_tolerance;
+        }
+    }
+}
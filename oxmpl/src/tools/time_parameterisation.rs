@@ -0,0 +1,648 @@
+// Copyright (c) 2025 Junior Sundar
+//
+// SPDX-License-Identifier: BSD-3-Clause
+
+use std::f64::consts::PI;
+
+use crate::base::{
+    error::TrajectoryError,
+    planner::Path,
+    state::{CompoundState, RealVectorState, SO2State, State},
+};
+
+/// Two consecutive segments whose directions differ by less than this (as `1 - cos(angle)`) are
+/// treated as collinear, so the trajectory does not stop between them.
+const COLLINEAR_TOLERANCE: f64 = 1e-12;
+
+/// How the position of a joint behaves.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JointKind {
+    /// A position on the real line, such as a dimension of a `RealVectorState`.
+    Linear,
+    /// An angle that wraps around at `±PI`, such as an `SO2State`. Motions between two angles take
+    /// the shorter way around.
+    Wrapping,
+}
+
+/// A state that can be described by the positions of a list of joints, so that paths of it can be
+/// turned into trajectories.
+///
+/// Implemented for `RealVectorState`, `SO2State` and `CompoundState`s made of them.
+pub trait JointState: State + Clone {
+    /// Appends the kind and position of each joint of the state to `joints`.
+    ///
+    /// # Errors
+    /// Returns `TrajectoryError::UnsupportedState` if the state has no joint positions.
+    fn push_joints(&self, joints: &mut Vec<(JointKind, f64)>) -> Result<(), TrajectoryError>;
+
+    /// Returns a copy of the state with its joint positions taken from the front of `positions`,
+    /// and advances `positions` past them.
+    fn with_joints(&self, positions: &mut &[f64]) -> Self;
+}
+
+impl JointState for RealVectorState {
+    fn push_joints(&self, joints: &mut Vec<(JointKind, f64)>) -> Result<(), TrajectoryError> {
+        joints.extend(self.values.iter().map(|&value| (JointKind::Linear, value)));
+        Ok(())
+    }
+
+    fn with_joints(&self, positions: &mut &[f64]) -> Self {
+        let (values, rest) = positions.split_at(self.values.len());
+        *positions = rest;
+        RealVectorState::new(values.to_vec())
+    }
+}
+
+impl JointState for SO2State {
+    fn push_joints(&self, joints: &mut Vec<(JointKind, f64)>) -> Result<(), TrajectoryError> {
+        joints.push((JointKind::Wrapping, self.value));
+        Ok(())
+    }
+
+    fn with_joints(&self, positions: &mut &[f64]) -> Self {
+        let (value, rest) = positions.split_first().expect("too few joint positions");
+        *positions = rest;
+        SO2State::new(*value)
+    }
+}
+
+impl JointState for CompoundState {
+    fn push_joints(&self, joints: &mut Vec<(JointKind, f64)>) -> Result<(), TrajectoryError> {
+        for component in &self.components {
+            let any = component.as_ref().as_any();
+            if let Some(state) = any.downcast_ref::<RealVectorState>() {
+                state.push_joints(joints)?;
+            } else if let Some(state) = any.downcast_ref::<SO2State>() {
+                state.push_joints(joints)?;
+            } else if let Some(state) = any.downcast_ref::<CompoundState>() {
+                state.push_joints(joints)?;
+            } else {
+                return Err(TrajectoryError::UnsupportedState);
+            }
+        }
+        Ok(())
+    }
+
+    fn with_joints(&self, positions: &mut &[f64]) -> Self {
+        let components = self
+            .components
+            .iter()
+            .map(|component| -> Box<dyn State> {
+                let any = component.as_ref().as_any();
+                if let Some(state) = any.downcast_ref::<RealVectorState>() {
+                    Box::new(state.with_joints(positions))
+                } else if let Some(state) = any.downcast_ref::<SO2State>() {
+                    Box::new(state.with_joints(positions))
+                } else if let Some(state) = any.downcast_ref::<CompoundState>() {
+                    Box::new(state.with_joints(positions))
+                } else {
+                    component.clone()
+                }
+            })
+            .collect();
+        CompoundState::new(components)
+    }
+}
+
+/// The velocity and acceleration limits of each joint.
+#[derive(Clone, Debug, PartialEq)]
+pub struct JointLimits {
+    /// The largest speed of each joint, in units (or radians) per second.
+    pub max_velocity: Vec<f64>,
+    /// The largest acceleration of each joint, in units (or radians) per second squared.
+    pub max_acceleration: Vec<f64>,
+}
+
+impl JointLimits {
+    /// Creates limits with the given maximum velocity and acceleration of each joint.
+    pub fn new(max_velocity: Vec<f64>, max_acceleration: Vec<f64>) -> Self {
+        JointLimits {
+            max_velocity,
+            max_acceleration,
+        }
+    }
+
+    /// Creates limits with the same maximum velocity and acceleration for all `joints`.
+    pub fn uniform(joints: usize, max_velocity: f64, max_acceleration: f64) -> Self {
+        Self::new(vec![max_velocity; joints], vec![max_acceleration; joints])
+    }
+
+    /// Checks that there are limits for exactly `joints` joints, and that they are all positive
+    /// and finite.
+    fn validate(&self, joints: usize) -> Result<(), TrajectoryError> {
+        for found in [self.max_velocity.len(), self.max_acceleration.len()] {
+            if found != joints {
+                return Err(TrajectoryError::DimensionMismatch {
+                    expected: joints,
+                    found,
+                });
+            }
+        }
+        let is_valid = |limit: f64| limit > 0.0 && limit.is_finite();
+        match (0..joints)
+            .find(|&j| !is_valid(self.max_velocity[j]) || !is_valid(self.max_acceleration[j]))
+        {
+            Some(joint) => Err(TrajectoryError::InvalidLimit { joint }),
+            None => Ok(()),
+        }
+    }
+}
+
+/// A single sample of a `Trajectory`.
+#[derive(Clone, Debug)]
+pub struct TrajectoryPoint<S> {
+    /// The time since the start of the trajectory, in seconds.
+    pub time: f64,
+    /// The state at this time.
+    pub state: S,
+    /// The position of each joint. Wrapping joints are not wrapped back into `[-PI, PI)`, so the
+    /// positions change continuously along the trajectory.
+    pub positions: Vec<f64>,
+    /// The velocity of each joint.
+    pub velocities: Vec<f64>,
+    /// The acceleration of each joint.
+    pub accelerations: Vec<f64>,
+}
+
+/// One straight segment of a trajectory, with a trapezoidal speed profile along it.
+#[derive(Clone, Debug)]
+struct Segment {
+    /// The time at which the segment starts.
+    start_time: f64,
+    /// The joint positions at the start of the segment.
+    start: Vec<f64>,
+    /// The unit vector from the start to the end of the segment, in joint space.
+    direction: Vec<f64>,
+    /// The speed along the segment at its start.
+    entry_speed: f64,
+    /// The highest speed along the segment.
+    cruise_speed: f64,
+    /// The acceleration along the segment while speeding up and slowing down.
+    acceleration: f64,
+    /// How long the segment spends speeding up, cruising and slowing down.
+    accelerate_time: f64,
+    cruise_time: f64,
+    decelerate_time: f64,
+}
+
+impl Segment {
+    /// Creates a segment of `length` from `start` in `direction`, that is entered at `entry_speed`
+    /// and left at `exit_speed`, and moves as fast as `max_speed` and `acceleration` allow.
+    ///
+    /// The speeds must be reachable from each other within `length`.
+    fn new(
+        start: Vec<f64>,
+        direction: Vec<f64>,
+        length: f64,
+        entry_speed: f64,
+        exit_speed: f64,
+        max_speed: f64,
+        acceleration: f64,
+    ) -> Self {
+        let peak_speed = (acceleration * length
+            + (entry_speed * entry_speed + exit_speed * exit_speed) / 2.0)
+            .sqrt();
+        let cruise_speed = peak_speed.min(max_speed);
+        let accelerate_time = ((cruise_speed - entry_speed) / acceleration).max(0.0);
+        let decelerate_time = ((cruise_speed - exit_speed) / acceleration).max(0.0);
+        let accelerate_distance = (entry_speed + cruise_speed) / 2.0 * accelerate_time;
+        let decelerate_distance = (exit_speed + cruise_speed) / 2.0 * decelerate_time;
+        let cruise_distance = (length - accelerate_distance - decelerate_distance).max(0.0);
+        let cruise_time = if cruise_speed > 0.0 {
+            cruise_distance / cruise_speed
+        } else {
+            0.0
+        };
+        Segment {
+            start_time: 0.0,
+            start,
+            direction,
+            entry_speed,
+            cruise_speed,
+            acceleration,
+            accelerate_time,
+            cruise_time,
+            decelerate_time,
+        }
+    }
+
+    /// Returns how long the segment takes.
+    fn duration(&self) -> f64 {
+        self.accelerate_time + self.cruise_time + self.decelerate_time
+    }
+
+    /// Returns the distance travelled along the segment, and the speed and acceleration along it,
+    /// `time` seconds after it starts.
+    fn evaluate(&self, time: f64) -> (f64, f64, f64) {
+        let (v0, vc, a) = (self.entry_speed, self.cruise_speed, self.acceleration);
+        if time < self.accelerate_time {
+            return (v0 * time + a * time * time / 2.0, v0 + a * time, a);
+        }
+        let accelerate_distance = (v0 + vc) / 2.0 * self.accelerate_time;
+        let time = time - self.accelerate_time;
+        if time < self.cruise_time {
+            return (accelerate_distance + vc * time, vc, 0.0);
+        }
+        let cruise_distance = vc * self.cruise_time;
+        let time = (time - self.cruise_time).min(self.decelerate_time);
+        (
+            accelerate_distance + cruise_distance + vc * time - a * time * time / 2.0,
+            vc - a * time,
+            -a,
+        )
+    }
+}
+
+/// A path timed to respect the velocity and acceleration limits of each joint.
+///
+/// The trajectory follows the path exactly, along straight lines in joint space between its
+/// states. Along each line the speed follows a trapezoidal profile: it accelerates at the largest
+/// rate the limits allow, cruises at the largest speed they allow, and decelerates. As a path
+/// cannot change direction at speed without leaving it, the trajectory comes to rest at every
+/// corner of the path; it only keeps moving through states where the path carries straight on,
+/// such as those added by `PathGeometric::densify`. Under these rules the trajectory is
+/// time-optimal.
+///
+/// # Example
+///
+/// ```
+/// use oxmpl::base::{planner::Path, state::RealVectorState};
+/// use oxmpl::tools::{JointLimits, Trajectory};
+///
+/// let path = Path(vec![
+///     RealVectorState::new(vec![0.0, 0.0]),
+///     RealVectorState::new(vec![10.0, 0.0]),
+/// ]);
+/// let trajectory = Trajectory::from_path(&path, &JointLimits::uniform(2, 2.0, 1.0)).unwrap();
+///
+/// // Two seconds to reach 2 units/s, three seconds of cruising, and two seconds to stop.
+/// assert!((trajectory.duration() - 7.0).abs() < 1e-9);
+///
+/// // Sample the trajectory at 100 Hz for a controller.
+/// for point in trajectory.sample(100.0) {
+///     assert!(point.velocities[0] <= 2.0 + 1e-9);
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Trajectory<S: JointState> {
+    /// The first state of the path, used as a template for the sampled states.
+    template: S,
+    segments: Vec<Segment>,
+    /// The joint positions at the end of the trajectory.
+    end: Vec<f64>,
+}
+
+impl<S: JointState> Trajectory<S> {
+    /// Times `path` so that no joint exceeds `limits`, as quickly as possible.
+    ///
+    /// # Errors
+    /// * `TrajectoryError::EmptyPath` if the path has no states.
+    /// * `TrajectoryError::UnsupportedState` if its states have no joint positions.
+    /// * `TrajectoryError::DimensionMismatch` if its states, or the limits, have different numbers
+    ///   of joints.
+    /// * `TrajectoryError::InvalidLimit` if a limit is not positive and finite.
+    pub fn from_path(path: &Path<S>, limits: &JointLimits) -> Result<Self, TrajectoryError> {
+        let template = path.0.first().ok_or(TrajectoryError::EmptyPath)?.clone();
+        let waypoints = Self::unwrapped_positions(path)?;
+        let joints = waypoints[0].len();
+        limits.validate(joints)?;
+
+        // The direction, length and largest speed and acceleration along each segment.
+        let mut lines = Vec::new();
+        for pair in waypoints.windows(2) {
+            let delta: Vec<f64> = pair[1].iter().zip(&pair[0]).map(|(b, a)| b - a).collect();
+            let length = delta.iter().map(|d| d * d).sum::<f64>().sqrt();
+            if length == 0.0 {
+                continue;
+            }
+            let direction: Vec<f64> = delta.iter().map(|d| d / length).collect();
+            let limit = |joint_limits: &[f64]| {
+                direction
+                    .iter()
+                    .zip(joint_limits)
+                    .filter(|(d, _)| **d != 0.0)
+                    .map(|(d, limit)| limit / d.abs())
+                    .fold(f64::INFINITY, f64::min)
+            };
+            let max_speed = limit(&limits.max_velocity);
+            let acceleration = limit(&limits.max_acceleration);
+            lines.push((pair[0].clone(), direction, length, max_speed, acceleration));
+        }
+
+        // The speed at each junction between segments: zero at the ends and at corners, and as
+        // high as both segments allow where the path carries straight on. It is then lowered
+        // where there is not enough room to speed up or slow down.
+        let mut speeds = vec![0.0; lines.len() + 1];
+        for k in 1..lines.len() {
+            let (before, after) = (&lines[k - 1], &lines[k]);
+            let cos_angle: f64 = before.1.iter().zip(&after.1).map(|(a, b)| a * b).sum();
+            if cos_angle >= 1.0 - COLLINEAR_TOLERANCE {
+                speeds[k] = before.3.min(after.3);
+            }
+        }
+        for (k, line) in lines.iter().enumerate().rev() {
+            let reachable = (speeds[k + 1] * speeds[k + 1] + 2.0 * line.4 * line.2).sqrt();
+            speeds[k] = speeds[k].min(reachable);
+        }
+        for (k, line) in lines.iter().enumerate() {
+            let reachable = (speeds[k] * speeds[k] + 2.0 * line.4 * line.2).sqrt();
+            speeds[k + 1] = speeds[k + 1].min(reachable);
+        }
+
+        let mut segments = Vec::with_capacity(lines.len());
+        let mut time = 0.0;
+        for (k, (start, direction, length, max_speed, acceleration)) in
+            lines.into_iter().enumerate()
+        {
+            let mut segment = Segment::new(
+                start,
+                direction,
+                length,
+                speeds[k],
+                speeds[k + 1],
+                max_speed,
+                acceleration,
+            );
+            segment.start_time = time;
+            time += segment.duration();
+            segments.push(segment);
+        }
+
+        Ok(Trajectory {
+            template,
+            segments,
+            end: waypoints.last().unwrap().clone(),
+        })
+    }
+
+    /// Returns how long the trajectory takes, in seconds.
+    pub fn duration(&self) -> f64 {
+        self.segments
+            .last()
+            .map_or(0.0, |segment| segment.start_time + segment.duration())
+    }
+
+    /// Returns the trajectory at `time` seconds after it starts. Times outside the trajectory are
+    /// clamped to its start or end.
+    pub fn sample_at(&self, time: f64) -> TrajectoryPoint<S> {
+        let time = time.clamp(0.0, self.duration());
+        let index = self
+            .segments
+            .partition_point(|segment| segment.start_time <= time);
+
+        let (positions, velocities, accelerations) = match index
+            .checked_sub(1)
+            .map(|i| &self.segments[i])
+            .filter(|_| time < self.duration())
+        {
+            Some(segment) => {
+                let (distance, speed, acceleration) = segment.evaluate(time - segment.start_time);
+                let along = |scale: f64| -> Vec<f64> {
+                    segment.direction.iter().map(|d| d * scale).collect()
+                };
+                let positions = segment
+                    .start
+                    .iter()
+                    .zip(along(distance))
+                    .map(|(start, offset)| start + offset)
+                    .collect();
+                (positions, along(speed), along(acceleration))
+            }
+            None => (
+                self.end.clone(),
+                vec![0.0; self.end.len()],
+                vec![0.0; self.end.len()],
+            ),
+        };
+
+        TrajectoryPoint {
+            time,
+            state: self.template.with_joints(&mut positions.as_slice()),
+            positions,
+            velocities,
+            accelerations,
+        }
+    }
+
+    /// Samples the trajectory at a fixed `rate`, in hertz, from its start to its end.
+    ///
+    /// The last sample is always at the end of the trajectory, even if it falls between two
+    /// periods.
+    ///
+    /// # Panics
+    /// Panics if `rate` is not positive.
+    pub fn sample(&self, rate: f64) -> Vec<TrajectoryPoint<S>> {
+        assert!(rate > 0.0, "the sampling rate must be positive");
+        let duration = self.duration();
+        let count = (duration * rate).floor() as usize;
+        let mut points: Vec<_> = (0..=count)
+            .map(|k| self.sample_at(k as f64 / rate))
+            .collect();
+        if (count as f64) / rate < duration {
+            points.push(self.sample_at(duration));
+        }
+        points
+    }
+
+    /// Returns the joint positions of each state of `path`, with wrapping joints unwrapped so
+    /// that each step between states takes the shorter way around.
+    fn unwrapped_positions(path: &Path<S>) -> Result<Vec<Vec<f64>>, TrajectoryError> {
+        let mut waypoints: Vec<Vec<f64>> = Vec::with_capacity(path.0.len());
+        let mut previous_joints: Vec<(JointKind, f64)> = Vec::new();
+        for state in &path.0 {
+            let mut joints = Vec::new();
+            state.push_joints(&mut joints)?;
+            let Some(previous) = waypoints.last() else {
+                waypoints.push(joints.iter().map(|&(_, position)| position).collect());
+                previous_joints = joints;
+                continue;
+            };
+            if joints.len() != previous_joints.len() {
+                return Err(TrajectoryError::DimensionMismatch {
+                    expected: previous_joints.len(),
+                    found: joints.len(),
+                });
+            }
+            let positions = joints
+                .iter()
+                .zip(&previous_joints)
+                .zip(previous)
+                .map(
+                    |((&(kind, raw), &(_, previous_raw)), &previous)| match kind {
+                        JointKind::Linear => raw,
+                        JointKind::Wrapping => {
+                            previous + ((raw - previous_raw + PI).rem_euclid(2.0 * PI) - PI)
+                        }
+                    },
+                )
+                .collect();
+            waypoints.push(positions);
+            previous_joints = joints;
+        }
+        Ok(waypoints)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base::state::SO3State;
+
+    const TOLERANCE: f64 = 1e-9;
+
+    fn path(points: &[&[f64]]) -> Path<RealVectorState> {
+        Path(
+            points
+                .iter()
+                .map(|p| RealVectorState::new(p.to_vec()))
+                .collect(),
+        )
+    }
+
+    /// Checks that every sample respects the limits, that the velocities and accelerations match
+    /// the changes in position and velocity, and that the trajectory starts and ends at rest.
+    fn assert_complies<S: JointState>(trajectory: &Trajectory<S>, limits: &JointLimits) {
+        let rate = 1000.0;
+        let points = trajectory.sample(rate);
+        for point in &points {
+            for j in 0..point.positions.len() {
+                assert!(point.velocities[j].abs() <= limits.max_velocity[j] * (1.0 + TOLERANCE));
+                assert!(
+                    point.accelerations[j].abs() <= limits.max_acceleration[j] * (1.0 + TOLERANCE)
+                );
+            }
+        }
+        for pair in points.windows(2) {
+            let dt = pair[1].time - pair[0].time;
+            for j in 0..pair[0].positions.len() {
+                let mean_velocity = (pair[1].positions[j] - pair[0].positions[j]) / dt;
+                let (v0, v1) = (pair[0].velocities[j], pair[1].velocities[j]);
+                assert!(mean_velocity >= v0.min(v1) - 1e-6 && mean_velocity <= v0.max(v1) + 1e-6);
+                let mean_acceleration = (v1 - v0) / dt;
+                assert!(mean_acceleration.abs() <= limits.max_acceleration[j] * (1.0 + 1e-6));
+            }
+        }
+        for point in [points.first().unwrap(), points.last().unwrap()] {
+            assert!(point.velocities.iter().all(|v| v.abs() < TOLERANCE));
+        }
+    }
+
+    #[test]
+    fn test_trajectory_durations() {
+        let limits = JointLimits::uniform(1, 2.0, 1.0);
+
+        // Accelerate for 2 s over 2 units, cruise for 3 s over 6, and decelerate for 2 s.
+        let trajectory = Trajectory::from_path(&path(&[&[0.0], &[10.0]]), &limits).unwrap();
+        assert!((trajectory.duration() - 7.0).abs() < TOLERANCE);
+        assert_complies(&trajectory, &limits);
+
+        // Too short to reach full speed: 1 s to accelerate and 1 s to decelerate.
+        let trajectory = Trajectory::from_path(&path(&[&[0.0], &[1.0]]), &limits).unwrap();
+        assert!((trajectory.duration() - 2.0).abs() < TOLERANCE);
+
+        // States where the path carries straight on are passed without stopping.
+        let trajectory =
+            Trajectory::from_path(&path(&[&[0.0], &[3.0], &[3.0], &[10.0]]), &limits).unwrap();
+        assert!((trajectory.duration() - 7.0).abs() < TOLERANCE);
+        assert_complies(&trajectory, &limits);
+
+        // A single state gives an empty trajectory.
+        let trajectory = Trajectory::from_path(&path(&[&[4.0]]), &limits).unwrap();
+        assert_eq!(trajectory.duration(), 0.0);
+        assert_eq!(trajectory.sample(10.0).len(), 1);
+    }
+
+    #[test]
+    fn test_trajectory_respects_limits_through_corners() {
+        let limits = JointLimits::new(vec![1.0, 0.5, 2.0], vec![0.8, 2.0, 0.3]);
+        let path = path(&[
+            &[0.0, 0.0, 0.0],
+            &[2.0, 1.0, -1.0],
+            &[2.5, -3.0, 0.5],
+            &[4.0, -3.0, 0.5],
+        ]);
+        let trajectory = Trajectory::from_path(&path, &limits).unwrap();
+        assert_complies(&trajectory, &limits);
+
+        // The trajectory stops at, and so passes exactly through, every corner.
+        let mut waypoint = 1;
+        for point in trajectory.sample(1000.0) {
+            if waypoint < path.0.len() - 1
+                && point
+                    .positions
+                    .iter()
+                    .zip(&path.0[waypoint].values)
+                    .all(|(a, b)| (a - b).abs() < 1e-6)
+            {
+                assert!(point.velocities.iter().all(|v| v.abs() < 1e-2));
+                waypoint += 1;
+            }
+        }
+        assert_eq!(waypoint, path.0.len() - 1);
+
+        let end = trajectory.sample_at(trajectory.duration() + 1.0);
+        assert_eq!(end.state.values, vec![4.0, -3.0, 0.5]);
+    }
+
+    #[test]
+    fn test_trajectory_compound_state_wraps_angles() {
+        let state = |x: f64, angle: f64| {
+            CompoundState::new(vec![
+                Box::new(RealVectorState::new(vec![x])),
+                Box::new(SO2State::new(angle)),
+            ])
+        };
+        let path = Path(vec![state(0.0, 3.0), state(0.1, -3.0)]);
+        let limits = JointLimits::uniform(2, 1.0, 1.0);
+        let trajectory = Trajectory::from_path(&path, &limits).unwrap();
+        assert_complies(&trajectory, &limits);
+
+        // The angle turns by 2 * PI - 6 the short way, rather than by 6 the long way.
+        let end = trajectory.sample_at(trajectory.duration());
+        assert!((end.positions[1] - (2.0 * PI - 3.0)).abs() < TOLERANCE);
+        let angle = end.state.components[1]
+            .as_ref()
+            .as_any()
+            .downcast_ref::<SO2State>()
+            .unwrap();
+        assert!((angle.value - -3.0).abs() < TOLERANCE);
+    }
+
+    #[test]
+    fn test_trajectory_errors() {
+        let limits = JointLimits::uniform(2, 1.0, 1.0);
+        assert_eq!(
+            Trajectory::from_path(&path(&[]), &limits).err(),
+            Some(TrajectoryError::EmptyPath)
+        );
+        assert_eq!(
+            Trajectory::from_path(&path(&[&[0.0], &[1.0]]), &limits).err(),
+            Some(TrajectoryError::DimensionMismatch {
+                expected: 1,
+                found: 2
+            })
+        );
+        assert_eq!(
+            Trajectory::from_path(&path(&[&[0.0, 0.0], &[1.0]]), &limits).err(),
+            Some(TrajectoryError::DimensionMismatch {
+                expected: 2,
+                found: 1
+            })
+        );
+        assert_eq!(
+            Trajectory::from_path(
+                &path(&[&[0.0, 0.0], &[1.0, 1.0]]),
+                &JointLimits::new(vec![1.0, 1.0], vec![1.0, 0.0])
+            )
+            .err(),
+            Some(TrajectoryError::InvalidLimit { joint: 1 })
+        );
+        let unsupported = Path(vec![CompoundState::new(vec![Box::new(
+            SO3State::identity(),
+        )])]);
+        assert_eq!(
+            Trajectory::from_path(&unsupported, &limits).err(),
+            Some(TrajectoryError::UnsupportedState)
+        );
+    }
+}
@@ -0,0 +1,209 @@
+use std::{sync::Arc, time::Duration};
+
+use oxmpl::base::{
+    goal::GoalState, problem_definition::ProblemDefinition, space::RealVectorStateSpace,
+    state::RealVectorState, validity::StateValidityChecker,
+};
+use oxmpl::geometric::{RRTConnect, PRM, RRT};
+use oxmpl::tools::{Benchmark, BenchmarkRequest, BenchmarkResults, BenchmarkValue};
+
+struct WallObstacleChecker {
+    wall_x_pos: f64,
+    wall_y_min: f64,
+    wall_y_max: f64,
+    wall_thickness: f64,
+}
+
+impl StateValidityChecker<RealVectorState> for WallObstacleChecker {
+    fn is_valid(&self, state: &RealVectorState) -> bool {
+        let x = state.values[0];
+        let y = state.values[1];
+
+        let is_in_wall = x >= self.wall_x_pos - self.wall_thickness / 2.0
+            && x <= self.wall_x_pos + self.wall_thickness / 2.0
+            && y >= self.wall_y_min
+            && y <= self.wall_y_max;
+
+        !is_in_wall
+    }
+}
+
+fn run_benchmark(runs: usize) -> BenchmarkResults {
+    let space = Arc::new(
+        RealVectorStateSpace::new(2, Some(vec![(0.0, 10.0), (0.0, 10.0)]))
+            .expect("Failed to create state space for test."),
+    );
+    let problem_def = Arc::new(ProblemDefinition {
+        space: space.clone(),
+        start_states: vec![RealVectorState::new(vec![1.0, 5.0])],
+        goal: Arc::new(GoalState::new(
+            space.clone(),
+            RealVectorState::new(vec![9.0, 5.0]),
+            0.5,
+        )),
+    });
+    let checker = Arc::new(WallObstacleChecker {
+        wall_x_pos: 5.0,
+        wall_y_min: 2.0,
+        wall_y_max: 8.0,
+        wall_thickness: 0.5,
+    });
+
+    let mut benchmark = Benchmark::new("wall", problem_def, checker);
+    benchmark.add_planner_with_properties(
+        "geometric_RRT",
+        |config| RRT::new(0.5, 0.05, config),
+        |_| Ok(()),
+        |rrt| {
+            vec![(
+                "graph states".to_string(),
+                BenchmarkValue::Integer(rrt.tree_states().count() as i64),
+            )]
+        },
+    );
+    benchmark.add_planner("geometric_RRTConnect", |config| {
+        RRTConnect::new(0.5, 0.05, config)
+    });
+    benchmark.add_planner_with_preparation(
+        "geometric_PRM",
+        |config| PRM::new(0.1, 2.0, config),
+        |prm| prm.construct_roadmap(),
+    );
+
+    benchmark.run(&BenchmarkRequest {
+        runs,
+        time_limit: Duration::from_secs(2),
+        seed: Some(42),
+        ..Default::default()
+    })
+}
+
+#[test]
+fn test_benchmark_records_every_run() {
+    let results = run_benchmark(3);
+
+    assert_eq!(results.experiment_name, "wall");
+    assert_eq!(results.planners.len(), 3);
+    for planner in &results.planners {
+        assert_eq!(planner.runs.len(), 3);
+        assert_eq!(planner.success_rate(), 1.0, "{} failed", planner.name);
+        for run in &planner.runs {
+            assert!(run.time <= Duration::from_secs(3));
+            assert!(run.validity_checks > 0);
+            assert!(run.solution_vertices.unwrap() >= 2);
+            assert!(run.simplified_length.unwrap() <= run.solution_length.unwrap() + 1e-9);
+        }
+    }
+    assert!(matches!(
+        results.planners[0].runs[0].properties[..],
+        [(ref name, BenchmarkValue::Integer(states))] if name == "graph states" && states > 1
+    ));
+    assert!(results.planners[1].runs[0].properties.is_empty());
+}
+
+#[test]
+fn test_benchmark_writes_ompl_log() {
+    let results = run_benchmark(2);
+    let mut log = Vec::new();
+    results.write_ompl_log(&mut log).unwrap();
+    let log = String::from_utf8(log).unwrap();
+    let lines: Vec<&str> = log.lines().collect();
+
+    assert!(lines[0].starts_with("OMPL version"));
+    assert_eq!(lines[1], "Experiment wall");
+    assert!(lines.contains(&"42 is the random seed"));
+    assert!(lines.contains(&"2 seconds per run"));
+    assert!(lines.contains(&"inf MB per run"));
+    assert!(lines.contains(&"2 runs per planner"));
+    assert!(lines.contains(&"3 planners"));
+
+    // The RRT section has its planner-specific property after the common ones.
+    let start = lines
+        .iter()
+        .position(|line| *line == "geometric_RRT")
+        .unwrap();
+    assert_eq!(lines[start + 1], "0 common properties");
+    assert_eq!(lines[start + 2], "8 properties for each run");
+    assert_eq!(lines[start + 3], "time REAL");
+    assert_eq!(lines[start + 10], "graph states INTEGER");
+    assert_eq!(lines[start + 11], "2 runs");
+    for run in &lines[start + 12..start + 14] {
+        assert_eq!(run.matches("; ").count(), 8);
+        assert!(run.split("; ").nth(1) == Some("1"));
+    }
+    assert_eq!(lines[start + 14], ".");
+    assert_eq!(lines.iter().filter(|line| **line == ".").count(), 3);
+}
+
+#[test]
+fn test_benchmark_writes_csv_and_json() {
+    let results = run_benchmark(2);
+
+    let mut csv = Vec::new();
+    results.write_csv(&mut csv).unwrap();
+    let csv = String::from_utf8(csv).unwrap();
+    let rows: Vec<&str> = csv.lines().collect();
+    assert_eq!(rows.len(), 1 + 3 * 2);
+    assert_eq!(
+        rows[0],
+        "planner,run,time,solved,solution length,solution vertices,simplification time,\
+         simplified solution length,validity checks,graph states"
+    );
+    assert!(rows[1].starts_with("geometric_RRT,0,"));
+    // Planners without the property leave its column empty.
+    assert!(rows[3].starts_with("geometric_RRTConnect,0,"));
+    assert!(rows[3].ends_with(','));
+
+    let mut json = Vec::new();
+    results.write_json(&mut json).unwrap();
+    let json = String::from_utf8(json).unwrap();
+    assert!(json.starts_with("{\"experiment\":\"wall\""));
+    assert!(json.contains("\"seed\":42"));
+    assert!(!json.contains("memory"));
+    assert!(json.contains("\"name\":\"geometric_PRM\""));
+    assert!(json.contains("\"properties\":{\"graph states\":"));
+    assert_eq!(json.matches("\"solved\":true").count(), 6);
+}
+
+#[test]
+fn test_benchmark_fails_runs_whose_preparation_overruns() {
+    let space = Arc::new(
+        RealVectorStateSpace::new(2, Some(vec![(0.0, 10.0), (0.0, 10.0)]))
+            .expect("Failed to create state space for test."),
+    );
+    let problem_def = Arc::new(ProblemDefinition {
+        space: space.clone(),
+        start_states: vec![RealVectorState::new(vec![1.0, 5.0])],
+        goal: Arc::new(GoalState::new(
+            space.clone(),
+            RealVectorState::new(vec![9.0, 5.0]),
+            0.5,
+        )),
+    });
+    let checker = Arc::new(WallObstacleChecker {
+        wall_x_pos: 5.0,
+        wall_y_min: 2.0,
+        wall_y_max: 8.0,
+        wall_thickness: 0.5,
+    });
+
+    // The roadmap takes longer to construct than the time limit allows.
+    let mut benchmark = Benchmark::new("overrun", problem_def, checker);
+    benchmark.add_planner_with_preparation(
+        "geometric_PRM",
+        |config| PRM::new(0.3, 2.0, config),
+        |prm| prm.construct_roadmap(),
+    );
+    let results = benchmark.run(&BenchmarkRequest {
+        runs: 2,
+        time_limit: Duration::from_millis(100),
+        seed: Some(42),
+        ..Default::default()
+    });
+
+    for run in &results.planners[0].runs {
+        assert!(run.time > Duration::from_millis(100));
+        assert!(!run.solved, "A run that overran its time limit was solved");
+        assert!(run.solution_length.is_none());
+    }
+}
@@ -0,0 +1,156 @@
+use std::{f64::consts::PI, sync::Arc, time::Duration};
+
+use oxmpl::base::{
+    error::StateSamplingError,
+    goal::{DynGoal, Goal, GoalRegion, GoalSampleableRegion, GoalState},
+    planner::{Planner, PlannerConfig},
+    problem_definition::ProblemDefinition,
+    space::{DynStateSpace, RealVectorStateSpace, StateSpace},
+    state::RealVectorState,
+    validity::StateValidityChecker,
+};
+use oxmpl::geometric::{PlannerRegistry, RRTConnect, RRTStar, RRT};
+
+use rand::{rngs::StdRng, Rng, RngCore, SeedableRng};
+
+/// A StateValidityChecker that defines a simple vertical wall obstacle.
+struct WallObstacleChecker {
+    wall_x_pos: f64,
+    wall_y_min: f64,
+    wall_y_max: f64,
+    wall_thickness: f64,
+}
+
+impl StateValidityChecker<RealVectorState> for WallObstacleChecker {
+    fn is_valid(&self, state: &RealVectorState) -> bool {
+        let x = state.values[0];
+        let y = state.values[1];
+
+        let is_in_wall = x >= self.wall_x_pos - self.wall_thickness / 2.0
+            && x <= self.wall_x_pos + self.wall_thickness / 2.0
+            && y >= self.wall_y_min
+            && y <= self.wall_y_max;
+
+        !is_in_wall
+    }
+}
+
+struct CircularGoalRegion {
+    target: RealVectorState,
+    radius: f64,
+    space: DynStateSpace<RealVectorState>,
+}
+
+impl Goal<RealVectorState> for CircularGoalRegion {
+    fn is_satisfied(&self, state: &RealVectorState) -> bool {
+        self.space.distance(state, &self.target) <= self.radius
+    }
+}
+
+impl GoalRegion<RealVectorState> for CircularGoalRegion {
+    fn distance_goal(&self, state: &RealVectorState) -> f64 {
+        let dist_to_center = self.space.distance(state, &self.target);
+        (dist_to_center - self.radius).max(0.0)
+    }
+}
+
+impl GoalSampleableRegion<RealVectorState> for CircularGoalRegion {
+    fn sample_goal(&self, rng: &mut dyn RngCore) -> Result<RealVectorState, StateSamplingError> {
+        let angle = rng.random_range(0.0..2.0 * PI);
+
+        let radius = self.radius * rng.random::<f64>().sqrt();
+
+        let x = self.target.values[0] + radius * angle.cos();
+        let y = self.target.values[1] + radius * angle.sin();
+
+        Ok(RealVectorState { values: vec![x, y] })
+    }
+}
+
+/// Builds the wall problem with its space and goal behind trait objects, choosing the goal by name.
+fn create_problem(
+    goal_name: &str,
+) -> (
+    Arc<ProblemDefinition<RealVectorState>>,
+    Arc<WallObstacleChecker>,
+) {
+    let space: DynStateSpace<RealVectorState> =
+        Arc::new(RealVectorStateSpace::new(2, Some(vec![(0.0, 10.0), (0.0, 10.0)])).unwrap());
+    // `ProblemDefinition` and `GoalState` share their space through an `Arc` of their own.
+    let space = Arc::new(space);
+    let target = RealVectorState {
+        values: vec![9.0, 5.0],
+    };
+    let goal: DynGoal<RealVectorState> = match goal_name {
+        "circle" => Arc::new(CircularGoalRegion {
+            target,
+            radius: 0.5,
+            space: (*space).clone(),
+        }),
+        "state" => Arc::new(GoalState::new(space.clone(), target, 0.5)),
+        _ => unreachable!(),
+    };
+    let problem_definition = Arc::new(ProblemDefinition {
+        space,
+        start_states: vec![RealVectorState {
+            values: vec![1.0, 5.0],
+        }],
+        goal: Arc::new(goal),
+    });
+    let validity_checker = Arc::new(WallObstacleChecker {
+        wall_x_pos: 5.0,
+        wall_y_min: 2.0,
+        wall_y_max: 8.0,
+        wall_thickness: 0.5,
+    });
+    (problem_definition, validity_checker)
+}
+
+#[test]
+fn test_boxed_planners_solve_problems_with_goals_chosen_at_runtime() {
+    let config = PlannerConfig { seed: Some(0) };
+    for goal_name in ["circle", "state"] {
+        let planners: Vec<Box<dyn Planner<RealVectorState>>> = vec![
+            Box::new(RRT::new(0.5, 0.05, &config)),
+            Box::new(RRTConnect::new(0.5, 0.05, &config)),
+            Box::new(RRTStar::new(0.5, 0.05, 1.0, &config)),
+        ];
+        for mut planner in planners {
+            let (problem_def, validity_checker) = create_problem(goal_name);
+            planner.setup(problem_def.clone(), validity_checker.clone());
+
+            let path = planner.solve(Duration::from_secs(5)).unwrap();
+            assert!(problem_def.goal.is_satisfied(path.0.last().unwrap()));
+            assert!(path.0.iter().all(|state| validity_checker.is_valid(state)));
+        }
+    }
+}
+
+#[test]
+fn test_registry_builds_planners_for_dynamic_problems() {
+    let registry = PlannerRegistry::<RealVectorState>::with_geometric_planners();
+    let (problem_def, validity_checker) = create_problem("state");
+    let mut planner = registry
+        .create("PRM", &PlannerConfig { seed: Some(0) })
+        .unwrap();
+    planner.setup(problem_def.clone(), validity_checker);
+
+    let path = planner.solve(Duration::from_secs(5)).unwrap();
+    assert!(problem_def.goal.is_satisfied(path.0.last().unwrap()));
+}
+
+#[test]
+fn test_dynamic_space_and_goal_sample_through_trait_objects() {
+    let (problem_def, _) = create_problem("circle");
+    let mut rng = StdRng::seed_from_u64(0);
+    let rng: &mut dyn RngCore = &mut rng;
+
+    let state = problem_def.space.sample_uniform(rng).unwrap();
+    assert!(problem_def.space.satisfies_bounds(&state));
+    assert!((problem_def.space.get_maximum_extent() - 200f64.sqrt()).abs() < 1e-9);
+
+    let goal: &dyn GoalSampleableRegion<RealVectorState> = &*problem_def.goal;
+    let sample = goal.sample_goal(rng).unwrap();
+    assert!(goal.is_satisfied(&sample));
+    assert_eq!(goal.distance_goal(&sample), 0.0);
+}
@@ -0,0 +1,137 @@
+use std::{env, fmt::Write, fs, path::PathBuf, sync::Arc, time::Duration};
+
+use oxmpl::base::{
+    goal::{DynGoal, GoalSpace, GoalState},
+    planner::{Path, Planner, PlannerConfig},
+    problem_definition::ProblemDefinition,
+    space::{DynStateSpace, RealVectorStateSpace},
+    state::RealVectorState,
+    validity::StateValidityChecker,
+};
+use oxmpl::geometric::PlannerRegistry;
+
+/// The paths seeded planners must find, shared with the golden tests of `oxmpl-py` and
+/// `oxmpl-js`. Run with `OXMPL_UPDATE_GOLDEN=1` to rewrite it after a change that is meant to
+/// alter the paths.
+const GOLDEN_FILE: &str = "tests/golden/paths_rvss.json";
+
+/// The seed every case is planned with.
+const SEED: u64 = 42;
+
+/// The planner and goal of each case, which the bindings' golden tests repeat in the same order.
+const CASES: [(&str, &str); 5] = [
+    ("RRT", "GoalState"),
+    ("RRTConnect", "GoalState"),
+    ("RRTStar", "GoalState"),
+    ("RRT", "GoalSpace"),
+    ("RRTConnect", "GoalSpace"),
+];
+
+/// A StateValidityChecker that defines a simple vertical wall obstacle.
+struct WallObstacleChecker {
+    wall_x_pos: f64,
+    wall_y_min: f64,
+    wall_y_max: f64,
+    wall_thickness: f64,
+}
+
+impl StateValidityChecker<RealVectorState> for WallObstacleChecker {
+    fn is_valid(&self, state: &RealVectorState) -> bool {
+        let x = state.values[0];
+        let y = state.values[1];
+
+        let is_in_wall = x >= self.wall_x_pos - self.wall_thickness / 2.0
+            && x <= self.wall_x_pos + self.wall_thickness / 2.0
+            && y >= self.wall_y_min
+            && y <= self.wall_y_max;
+
+        !is_in_wall
+    }
+}
+
+fn create_problem(goal_name: &str) -> Arc<ProblemDefinition<RealVectorState>> {
+    let space = RealVectorStateSpace::new(2, Some(vec![(0.0, 10.0), (0.0, 10.0)])).unwrap();
+    let goal: DynGoal<RealVectorState> = match goal_name {
+        "GoalState" => Arc::new(GoalState::new(
+            Arc::new(space.clone()),
+            RealVectorState::new(vec![9.0, 5.0]),
+            0.5,
+        )),
+        "GoalSpace" => Arc::new(GoalSpace::new(
+            RealVectorStateSpace::new(2, Some(vec![(8.5, 9.5), (4.5, 5.5)])).unwrap(),
+        )),
+        _ => unreachable!("unknown goal {goal_name}"),
+    };
+    let space: DynStateSpace<RealVectorState> = Arc::new(space);
+    Arc::new(ProblemDefinition {
+        space: Arc::new(space),
+        start_states: vec![RealVectorState::new(vec![1.0, 5.0])],
+        goal: Arc::new(goal),
+    })
+}
+
+fn plan(planner_name: &str, goal_name: &str) -> Path<RealVectorState> {
+    let registry = PlannerRegistry::with_geometric_planners();
+    let mut planner = registry
+        .create(planner_name, &PlannerConfig { seed: Some(SEED) })
+        .unwrap();
+    planner.set_param("max_distance", "0.5").unwrap();
+    planner.set_param("goal_bias", "0.05").unwrap();
+    planner.setup(
+        create_problem(goal_name),
+        Arc::new(WallObstacleChecker {
+            wall_x_pos: 5.0,
+            wall_y_min: 2.0,
+            wall_y_max: 8.0,
+            wall_thickness: 0.5,
+        }),
+    );
+    planner
+        .solve(Duration::from_secs(10))
+        .unwrap_or_else(|e| panic!("{planner_name} with {goal_name} failed to solve: {e}"))
+}
+
+/// Writes the paths as JSON, one state per line. Coordinates are written with the fewest digits
+/// that read back as the same `f64`, so the comparison is exact.
+fn paths_to_json(paths: &[(String, Path<RealVectorState>)]) -> String {
+    let mut json = String::from("{\n");
+    for (i, (case, path)) in paths.iter().enumerate() {
+        writeln!(json, "  \"{case}\": [").unwrap();
+        for (j, state) in path.0.iter().enumerate() {
+            let values: Vec<_> = state.values.iter().map(f64::to_string).collect();
+            let separator = if j + 1 < path.0.len() { "," } else { "" };
+            writeln!(json, "    [{}]{separator}", values.join(", ")).unwrap();
+        }
+        let separator = if i + 1 < paths.len() { "," } else { "" };
+        writeln!(json, "  ]{separator}").unwrap();
+    }
+    json.push_str("}\n");
+    json
+}
+
+#[test]
+fn test_seeded_planners_find_the_golden_paths() {
+    let paths: Vec<_> = CASES
+        .iter()
+        .map(|(planner, goal)| (format!("{planner}/{goal}"), plan(planner, goal)))
+        .collect();
+    let json = paths_to_json(&paths);
+
+    let golden_file = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(GOLDEN_FILE);
+    if env::var_os("OXMPL_UPDATE_GOLDEN").is_some() {
+        fs::write(&golden_file, &json).unwrap();
+    }
+    let golden = fs::read_to_string(&golden_file).unwrap();
+    assert!(
+        json == golden,
+        "the paths differ from {GOLDEN_FILE}. If the change is intended, rerun with \
+         OXMPL_UPDATE_GOLDEN=1 and check the new paths in."
+    );
+}
+
+#[test]
+fn test_seeded_planners_repeat_their_paths() {
+    for (planner, goal) in CASES {
+        assert_eq!(plan(planner, goal).0, plan(planner, goal).0);
+    }
+}
@@ -0,0 +1,248 @@
+use std::{f64::consts::PI, sync::Arc, time::Duration};
+
+use oxmpl::base::{
+    error::StateSamplingError,
+    goal::{Goal, GoalRegion, GoalSampleableRegion},
+    planner::{Path, Planner, PlannerConfig},
+    problem_definition::ProblemDefinition,
+    space::{RealVectorStateSpace, StateSpace},
+    state::RealVectorState,
+    validity::StateValidityChecker,
+};
+use oxmpl::geometric::{RRTConnect, RRTStar, PRM, RRT};
+use oxmpl::tools::{ParallelPlan, ParallelPlanMode};
+
+use rand::{Rng, RngCore};
+
+struct WallObstacleChecker {
+    wall_x_pos: f64,
+    wall_y_min: f64,
+    wall_y_max: f64,
+    wall_thickness: f64,
+}
+
+impl StateValidityChecker<RealVectorState> for WallObstacleChecker {
+    fn is_valid(&self, state: &RealVectorState) -> bool {
+        let x = state.values[0];
+        let y = state.values[1];
+
+        let is_in_wall = x >= self.wall_x_pos - self.wall_thickness / 2.0
+            && x <= self.wall_x_pos + self.wall_thickness / 2.0
+            && y >= self.wall_y_min
+            && y <= self.wall_y_max;
+
+        !is_in_wall
+    }
+}
+
+struct CircularGoalRegion {
+    target: RealVectorState,
+    radius: f64,
+    space: Arc<RealVectorStateSpace>,
+}
+
+impl Goal<RealVectorState> for CircularGoalRegion {
+    fn is_satisfied(&self, state: &RealVectorState) -> bool {
+        self.space.distance(state, &self.target) <= self.radius
+    }
+}
+
+impl GoalRegion<RealVectorState> for CircularGoalRegion {
+    fn distance_goal(&self, state: &RealVectorState) -> f64 {
+        let dist_to_center = self.space.distance(state, &self.target);
+        (dist_to_center - self.radius).max(0.0)
+    }
+}
+
+impl GoalSampleableRegion<RealVectorState> for CircularGoalRegion {
+    fn sample_goal(&self, rng: &mut dyn RngCore) -> Result<RealVectorState, StateSamplingError> {
+        let angle = rng.random_range(0.0..2.0 * PI);
+
+        let radius = self.radius * rng.random::<f64>().sqrt();
+
+        let x = self.target.values[0] + radius * angle.cos();
+        let y = self.target.values[1] + radius * angle.sin();
+
+        Ok(RealVectorState { values: vec![x, y] })
+    }
+}
+
+fn is_path_valid(
+    path: &Path<RealVectorState>,
+    space: &RealVectorStateSpace,
+    checker: &dyn StateValidityChecker<RealVectorState>,
+) -> bool {
+    for i in 0..path.0.len() - 1 {
+        let state_a = &path.0[i];
+        let state_b = &path.0[i + 1];
+
+        if !checker.is_valid(state_a) {
+            println!("Path invalid: State {state_a:?} is in collision.");
+            return false;
+        }
+        if (i + 1 == path.0.len() - 1) && !checker.is_valid(state_b) {
+            println!("Path invalid: Final state {state_b:?} is in collision.");
+            return false;
+        }
+
+        let dist = space.distance(state_a, state_b);
+        let num_steps = (dist / space.get_longest_valid_segment_length()).ceil() as usize;
+        if num_steps > 1 {
+            let mut interpolated_state = state_a.clone();
+            for j in 1..=num_steps {
+                let t = j as f64 / num_steps as f64;
+                space.interpolate(state_a, state_b, t, &mut interpolated_state);
+                if !checker.is_valid(&interpolated_state) {
+                    println!(
+                        "Path invalid: Motion between {state_a:?} and {state_b:?} is in collision at {interpolated_state:?}."
+                    );
+                    return false;
+                }
+            }
+        }
+    }
+    true
+}
+
+fn path_length(path: &Path<RealVectorState>, space: &RealVectorStateSpace) -> f64 {
+    path.0
+        .windows(2)
+        .map(|pair| space.distance(&pair[0], &pair[1]))
+        .sum()
+}
+
+fn solve_in_parallel(
+    mode: ParallelPlanMode,
+) -> (
+    ParallelPlan<RealVectorState, RealVectorStateSpace, CircularGoalRegion>,
+    Path<RealVectorState>,
+) {
+    let space = Arc::new(
+        RealVectorStateSpace::new(2, Some(vec![(0.0, 10.0), (0.0, 10.0)]))
+            .expect("Error creating new RealVectorState!"),
+    );
+
+    let start_state = RealVectorState {
+        values: vec![1.0, 5.0],
+    };
+    let goal_definition = Arc::new(CircularGoalRegion {
+        target: RealVectorState {
+            values: vec![9.0, 5.0],
+        },
+        radius: 0.5,
+        space: space.clone(),
+    });
+
+    let problem_definition = Arc::new(ProblemDefinition {
+        space: space.clone(),
+        start_states: vec![start_state.clone()],
+        goal: goal_definition.clone(),
+    });
+
+    let validity_checker = Arc::new(WallObstacleChecker {
+        wall_x_pos: 5.0,
+        wall_y_min: 2.0,
+        wall_y_max: 8.0,
+        wall_thickness: 0.5,
+    });
+
+    let mut planner = ParallelPlan::new(mode, 0.5);
+    planner.add_planner(RRT::new(0.5, 0.05, &PlannerConfig { seed: Some(0) }));
+    planner.add_planner(RRTConnect::new(0.5, 0.0, &PlannerConfig { seed: Some(1) }));
+    planner.add_planner(RRTStar::new(
+        0.5,
+        0.05,
+        1.0,
+        &PlannerConfig { seed: Some(2) },
+    ));
+    planner.add_planner_with_preparation(
+        PRM::new(1.0, 0.5, &PlannerConfig { seed: Some(3) }),
+        |prm| prm.construct_roadmap(),
+    );
+
+    planner.setup(problem_definition, validity_checker.clone());
+
+    let timeout = Duration::from_secs(5);
+    let result = planner.solve(timeout);
+
+    assert!(
+        result.is_ok(),
+        "Planner failed to find a solution when one should exist. Error: {:?}",
+        result.err()
+    );
+
+    let path = result.unwrap();
+    println!("Found path with {} states.", path.0.len());
+
+    assert!(!path.0.is_empty(), "Path should not be empty");
+
+    assert!(
+        space.distance(path.0.first().unwrap(), &start_state) < 1e-9,
+        "Path should start at the start state"
+    );
+
+    assert!(
+        goal_definition.is_satisfied(path.0.last().unwrap()),
+        "Path should end in the goal region"
+    );
+
+    assert!(
+        is_path_valid(&path, &space, &*validity_checker),
+        "The returned path was found to be invalid."
+    );
+
+    (planner, path)
+}
+
+#[test]
+fn test_parallel_plan_first_solution_in_rvss() {
+    let (planner, _) = solve_in_parallel(ParallelPlanMode::FirstSolution);
+
+    assert_eq!(
+        planner.solutions().len(),
+        1,
+        "Only the first solution should be kept"
+    );
+
+    println!("ParallelPlan first solution test passed!");
+}
+
+#[test]
+fn test_parallel_plan_best_solution_in_rvss() {
+    let (planner, path) = solve_in_parallel(ParallelPlanMode::BestSolution);
+    let space = RealVectorStateSpace::new(2, Some(vec![(0.0, 10.0), (0.0, 10.0)])).unwrap();
+
+    assert_eq!(
+        planner.solutions().len(),
+        4,
+        "Every planner should have found a solution"
+    );
+
+    let best_length = path_length(&path, &space);
+    for (index, solution) in planner.solutions() {
+        assert!(
+            best_length <= path_length(solution, &space) + 1e-9,
+            "Planner {index} found a shorter path than the one returned"
+        );
+    }
+
+    println!("ParallelPlan best solution test passed!");
+}
+
+#[test]
+fn test_parallel_plan_hybridise_in_rvss() {
+    let (planner, path) = solve_in_parallel(ParallelPlanMode::Hybridise);
+    let space = RealVectorStateSpace::new(2, Some(vec![(0.0, 10.0), (0.0, 10.0)])).unwrap();
+
+    assert!(!planner.solutions().is_empty());
+
+    let hybrid_length = path_length(&path, &space);
+    for (index, solution) in planner.solutions() {
+        assert!(
+            hybrid_length <= path_length(solution, &space) + 1e-9,
+            "The hybrid path should be no longer than the solution of planner {index}"
+        );
+    }
+
+    println!("ParallelPlan hybridise test passed!");
+}
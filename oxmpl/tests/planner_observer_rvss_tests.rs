@@ -0,0 +1,284 @@
+use std::{
+    f64::consts::PI,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use oxmpl::base::{
+    error::StateSamplingError,
+    goal::{Goal, GoalRegion, GoalSampleableRegion},
+    observer::PlannerObserver,
+    planner::{Path, Planner, PlannerConfig},
+    problem_definition::ProblemDefinition,
+    space::{RealVectorStateSpace, StateSpace},
+    state::RealVectorState,
+    validity::StateValidityChecker,
+};
+use oxmpl::geometric::{BiRRTStar, LazyRRT, RRTStar, PRM, RRT};
+
+use rand::{Rng, RngCore};
+
+/// A StateValidityChecker that defines a simple vertical wall obstacle.
+struct WallObstacleChecker {
+    wall_x_pos: f64,
+    wall_y_min: f64,
+    wall_y_max: f64,
+    wall_thickness: f64,
+}
+
+impl StateValidityChecker<RealVectorState> for WallObstacleChecker {
+    fn is_valid(&self, state: &RealVectorState) -> bool {
+        let x = state.values[0];
+        let y = state.values[1];
+
+        let is_in_wall = x >= self.wall_x_pos - self.wall_thickness / 2.0
+            && x <= self.wall_x_pos + self.wall_thickness / 2.0
+            && y >= self.wall_y_min
+            && y <= self.wall_y_max;
+
+        !is_in_wall
+    }
+}
+
+struct CircularGoalRegion {
+    target: RealVectorState,
+    radius: f64,
+    space: Arc<RealVectorStateSpace>,
+}
+
+impl Goal<RealVectorState> for CircularGoalRegion {
+    fn is_satisfied(&self, state: &RealVectorState) -> bool {
+        self.space.distance(state, &self.target) <= self.radius
+    }
+}
+
+impl GoalRegion<RealVectorState> for CircularGoalRegion {
+    fn distance_goal(&self, state: &RealVectorState) -> f64 {
+        let dist_to_center = self.space.distance(state, &self.target);
+        (dist_to_center - self.radius).max(0.0)
+    }
+}
+
+impl GoalSampleableRegion<RealVectorState> for CircularGoalRegion {
+    fn sample_goal(&self, rng: &mut dyn RngCore) -> Result<RealVectorState, StateSamplingError> {
+        let angle = rng.random_range(0.0..2.0 * PI);
+
+        let radius = self.radius * rng.random::<f64>().sqrt();
+
+        let x = self.target.values[0] + radius * angle.cos();
+        let y = self.target.values[1] + radius * angle.sin();
+
+        Ok(RealVectorState { values: vec![x, y] })
+    }
+}
+
+type RvProblemDefinition =
+    ProblemDefinition<RealVectorState, RealVectorStateSpace, CircularGoalRegion>;
+
+fn create_problem() -> (Arc<RvProblemDefinition>, Arc<WallObstacleChecker>) {
+    let space =
+        Arc::new(RealVectorStateSpace::new(2, Some(vec![(0.0, 10.0), (0.0, 10.0)])).unwrap());
+    let goal = Arc::new(CircularGoalRegion {
+        target: RealVectorState {
+            values: vec![9.0, 5.0],
+        },
+        radius: 0.5,
+        space: space.clone(),
+    });
+    let problem_definition = Arc::new(ProblemDefinition {
+        space,
+        start_states: vec![RealVectorState {
+            values: vec![1.0, 5.0],
+        }],
+        goal,
+    });
+    let validity_checker = Arc::new(WallObstacleChecker {
+        wall_x_pos: 5.0,
+        wall_y_min: 2.0,
+        wall_y_max: 8.0,
+        wall_thickness: 0.5,
+    });
+    (problem_definition, validity_checker)
+}
+
+/// Records every event it is told about.
+#[derive(Default)]
+struct RecordingObserver {
+    iterations: Mutex<Vec<u64>>,
+    nodes: Mutex<Vec<RealVectorState>>,
+    edges_added: Mutex<Vec<(RealVectorState, RealVectorState)>>,
+    edges_removed: Mutex<Vec<(RealVectorState, RealVectorState)>>,
+    rejected_samples: Mutex<Vec<Option<RealVectorState>>>,
+    solutions: Mutex<Vec<(Path<RealVectorState>, f64)>>,
+}
+
+impl PlannerObserver<RealVectorState> for RecordingObserver {
+    fn on_iteration(&self, iteration: u64) {
+        self.iterations.lock().unwrap().push(iteration);
+    }
+
+    fn on_node_added(&self, state: &RealVectorState) {
+        self.nodes.lock().unwrap().push(state.clone());
+    }
+
+    fn on_edge_added(&self, from: &RealVectorState, to: &RealVectorState) {
+        self.edges_added
+            .lock()
+            .unwrap()
+            .push((from.clone(), to.clone()));
+    }
+
+    fn on_edge_removed(&self, from: &RealVectorState, to: &RealVectorState) {
+        self.edges_removed
+            .lock()
+            .unwrap()
+            .push((from.clone(), to.clone()));
+    }
+
+    fn on_sample_rejected(&self, sample: Option<&RealVectorState>) {
+        self.rejected_samples.lock().unwrap().push(sample.cloned());
+    }
+
+    fn on_solution(&self, path: &Path<RealVectorState>, length: f64) {
+        self.solutions.lock().unwrap().push((path.clone(), length));
+    }
+}
+
+/// Sets `observer` on `planner` and sets it up.
+fn observe<P>(planner: &mut P, observer: &Arc<RecordingObserver>)
+where
+    P: Planner<RealVectorState, RealVectorStateSpace, CircularGoalRegion>,
+{
+    let (problem_definition, validity_checker) = create_problem();
+    planner.set_observer(observer.clone());
+    planner.setup(problem_definition, validity_checker);
+}
+
+#[test]
+fn test_rrt_streams_its_tree_to_the_observer_in_rvss() {
+    let observer = Arc::new(RecordingObserver::default());
+    let mut planner = RRT::new(0.5, 0.05, &PlannerConfig { seed: Some(0) });
+    observe(&mut planner, &observer);
+
+    let path = planner
+        .solve(Duration::from_secs(5))
+        .expect("Planner failed to find a solution.");
+
+    let iterations = observer.iterations.lock().unwrap();
+    let expected: Vec<u64> = (1..=planner.stats().iterations).collect();
+    assert_eq!(*iterations, expected, "Every iteration should be reported.");
+
+    let nodes = observer.nodes.lock().unwrap();
+    let tree: Vec<_> = planner.tree_states().cloned().collect();
+    assert_eq!(*nodes, tree, "Nodes should be reported as they are added.");
+
+    let edges = observer.edges_added.lock().unwrap();
+    assert_eq!(edges.len(), tree.len() - 1, "The root has no parent edge.");
+    for (from, to) in edges.iter() {
+        assert!(nodes.contains(from) && nodes.contains(to));
+    }
+
+    let solutions = observer.solutions.lock().unwrap();
+    assert_eq!(solutions.len(), 1, "RRT should report a single solution.");
+    assert_eq!(solutions[0].0 .0, path.0);
+    assert!(observer.edges_removed.lock().unwrap().is_empty());
+}
+
+#[test]
+fn test_rrt_star_reports_rewired_edges_in_rvss() {
+    let observer = Arc::new(RecordingObserver::default());
+    let mut planner = RRTStar::new(0.5, 0.05, 2.0, &PlannerConfig { seed: Some(1) });
+    observe(&mut planner, &observer);
+
+    let _ = planner.solve(Duration::from_millis(500));
+
+    let nodes = observer.nodes.lock().unwrap().len();
+    let added = observer.edges_added.lock().unwrap().len();
+    let removed = observer.edges_removed.lock().unwrap().len();
+    assert!(removed > 0, "RRTStar should have rewired some nodes.");
+    assert_eq!(
+        added - removed,
+        nodes - 1,
+        "Every node but the root should be left with exactly one parent edge."
+    );
+    assert_eq!(nodes, planner.tree_states().count());
+}
+
+#[test]
+fn test_bi_rrt_star_reports_improving_solutions_in_rvss() {
+    let observer = Arc::new(RecordingObserver::default());
+    let mut planner = BiRRTStar::new(0.5, 0.05, 2.0, &PlannerConfig { seed: Some(2) });
+    observe(&mut planner, &observer);
+
+    let path = planner
+        .solve(Duration::from_millis(500))
+        .expect("Planner failed to find a solution.");
+
+    let solutions = observer.solutions.lock().unwrap();
+    assert!(!solutions.is_empty(), "No solution was reported.");
+    for pair in solutions.windows(2) {
+        assert!(
+            pair[1].1 < pair[0].1,
+            "Each reported solution should improve on the last."
+        );
+    }
+    // Rewiring may shorten the best solution further after it was reported.
+    let (_, last_length) = solutions.last().unwrap();
+    let space = RealVectorStateSpace::new(2, None).unwrap();
+    let length: f64 = path
+        .0
+        .windows(2)
+        .map(|pair| space.distance(&pair[0], &pair[1]))
+        .sum();
+    assert!(length <= last_length + 1e-9);
+}
+
+#[test]
+fn test_rejected_samples_are_reported_in_rvss() {
+    let observer = Arc::new(RecordingObserver::default());
+    let mut planner = PRM::new(0.5, 1.0, &PlannerConfig { seed: Some(3) });
+    observe(&mut planner, &observer);
+    planner.construct_roadmap().unwrap();
+
+    let rejected = observer.rejected_samples.lock().unwrap();
+    assert!(
+        !rejected.is_empty(),
+        "Samples inside the wall should be rejected."
+    );
+    assert_eq!(rejected.len() as u64, planner.stats().samples_rejected);
+    let (_, validity_checker) = create_problem();
+    for sample in rejected.iter() {
+        let sample = sample
+            .as_ref()
+            .expect("Sampling the space should not fail.");
+        assert!(!validity_checker.is_valid(sample));
+    }
+
+    assert_eq!(
+        observer.nodes.lock().unwrap().len(),
+        planner.get_roadmap().len()
+    );
+    assert!(!observer.edges_added.lock().unwrap().is_empty());
+}
+
+#[test]
+fn test_lazy_rrt_reports_pruned_edges_in_rvss() {
+    let observer = Arc::new(RecordingObserver::default());
+    let mut planner = LazyRRT::new(0.5, 0.2, &PlannerConfig { seed: Some(4) });
+    observe(&mut planner, &observer);
+
+    let path = planner
+        .solve(Duration::from_secs(5))
+        .expect("Planner failed to find a solution.");
+
+    let removed = observer.edges_removed.lock().unwrap();
+    assert!(
+        !removed.is_empty(),
+        "Branches through the wall should have been pruned."
+    );
+    let (_, validity_checker) = create_problem();
+    for state in &path.0 {
+        assert!(validity_checker.is_valid(state));
+    }
+    assert_eq!(observer.solutions.lock().unwrap().len(), 1);
+}
@@ -0,0 +1,273 @@
+use std::{
+    f64::consts::PI,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use oxmpl::base::{
+    error::{ParamError, StateSamplingError},
+    goal::{Goal, GoalRegion, GoalSampleableRegion},
+    observer::PlannerObserver,
+    param::ParamValue,
+    planner::{Planner, PlannerConfig},
+    problem_definition::ProblemDefinition,
+    space::{RealVectorStateSpace, StateSpace},
+    state::RealVectorState,
+    validity::StateValidityChecker,
+};
+use oxmpl::geometric::{RRTConnect, RRTStar, PRM, RRT, SPARS};
+
+use rand::{Rng, RngCore};
+
+/// A StateValidityChecker that defines a simple vertical wall obstacle.
+struct WallObstacleChecker {
+    wall_x_pos: f64,
+    wall_y_min: f64,
+    wall_y_max: f64,
+    wall_thickness: f64,
+}
+
+impl StateValidityChecker<RealVectorState> for WallObstacleChecker {
+    fn is_valid(&self, state: &RealVectorState) -> bool {
+        let x = state.values[0];
+        let y = state.values[1];
+
+        let is_in_wall = x >= self.wall_x_pos - self.wall_thickness / 2.0
+            && x <= self.wall_x_pos + self.wall_thickness / 2.0
+            && y >= self.wall_y_min
+            && y <= self.wall_y_max;
+
+        !is_in_wall
+    }
+}
+
+struct CircularGoalRegion {
+    target: RealVectorState,
+    radius: f64,
+    space: Arc<RealVectorStateSpace>,
+}
+
+impl Goal<RealVectorState> for CircularGoalRegion {
+    fn is_satisfied(&self, state: &RealVectorState) -> bool {
+        self.space.distance(state, &self.target) <= self.radius
+    }
+}
+
+impl GoalRegion<RealVectorState> for CircularGoalRegion {
+    fn distance_goal(&self, state: &RealVectorState) -> f64 {
+        let dist_to_center = self.space.distance(state, &self.target);
+        (dist_to_center - self.radius).max(0.0)
+    }
+}
+
+impl GoalSampleableRegion<RealVectorState> for CircularGoalRegion {
+    fn sample_goal(&self, rng: &mut dyn RngCore) -> Result<RealVectorState, StateSamplingError> {
+        let angle = rng.random_range(0.0..2.0 * PI);
+
+        let radius = self.radius * rng.random::<f64>().sqrt();
+
+        let x = self.target.values[0] + radius * angle.cos();
+        let y = self.target.values[1] + radius * angle.sin();
+
+        Ok(RealVectorState { values: vec![x, y] })
+    }
+}
+
+type RvProblemDefinition =
+    ProblemDefinition<RealVectorState, RealVectorStateSpace, CircularGoalRegion>;
+
+fn create_problem() -> (Arc<RvProblemDefinition>, Arc<WallObstacleChecker>) {
+    let space =
+        Arc::new(RealVectorStateSpace::new(2, Some(vec![(0.0, 10.0), (0.0, 10.0)])).unwrap());
+    let goal = Arc::new(CircularGoalRegion {
+        target: RealVectorState {
+            values: vec![9.0, 5.0],
+        },
+        radius: 0.5,
+        space: space.clone(),
+    });
+    let problem_definition = Arc::new(ProblemDefinition {
+        space,
+        start_states: vec![RealVectorState {
+            values: vec![1.0, 5.0],
+        }],
+        goal,
+    });
+    let validity_checker = Arc::new(WallObstacleChecker {
+        wall_x_pos: 5.0,
+        wall_y_min: 2.0,
+        wall_y_max: 8.0,
+        wall_thickness: 0.5,
+    });
+    (problem_definition, validity_checker)
+}
+
+#[test]
+fn test_rrt_params_are_listed_with_current_values() {
+    let planner: RRT<RealVectorState, RealVectorStateSpace, CircularGoalRegion> =
+        RRT::new(0.5, 0.05, &PlannerConfig::default());
+    let params = planner.params();
+
+    let names: Vec<_> = params.iter().map(|param| param.name()).collect();
+    assert_eq!(names, ["max_distance", "goal_bias", "num_threads"]);
+    assert_eq!(params.real("max_distance"), Some(0.5));
+    assert_eq!(params.real("goal_bias"), Some(0.05));
+    assert_eq!(
+        params.get("num_threads").unwrap().value(),
+        ParamValue::Integer(1)
+    );
+    assert_eq!(*params.get("goal_bias").unwrap().range(), 0.0..=1.0);
+    assert!(params.iter().all(|param| !param.description().is_empty()));
+}
+
+#[test]
+fn test_set_param_from_strings_updates_planner() {
+    let mut planner: PRM<RealVectorState, RealVectorStateSpace, CircularGoalRegion> =
+        PRM::new(1.0, 1.0, &PlannerConfig::default());
+    planner.set_param("connection_radius", "2.5").unwrap();
+    planner.set_param("num_threads", "2").unwrap();
+    planner.set_param("goal_bias", "0").unwrap();
+
+    assert_eq!(planner.connection_radius, 2.5);
+    assert_eq!(planner.num_threads, 2);
+    assert_eq!(planner.goal_bias, 0.0);
+}
+
+#[test]
+fn test_set_param_rejects_bad_values_and_leaves_planner_unchanged() {
+    let mut planner: SPARS<RealVectorState, RealVectorStateSpace, CircularGoalRegion> =
+        SPARS::new(1.0, 2.0, 0.2, 3.0, 100, &PlannerConfig::default());
+    let before = planner.params();
+
+    assert!(matches!(
+        planner.set_param("stretch_factor", "0.5"),
+        Err(ParamError::OutOfRange { .. })
+    ));
+    assert!(matches!(
+        planner.set_param("max_failures", "many"),
+        Err(ParamError::InvalidValue { .. })
+    ));
+    assert_eq!(
+        planner.set_param("goal_bias", "0.1"),
+        Err(ParamError::UnknownParam {
+            name: "goal_bias".to_string()
+        })
+    );
+    assert_eq!(planner.params(), before);
+}
+
+#[test]
+fn test_params_copy_between_planners() {
+    let mut source: RRTConnect<RealVectorState, RealVectorStateSpace, CircularGoalRegion> =
+        RRTConnect::new(0.25, 0.2, &PlannerConfig::default());
+    source.set_param("max_distance", "0.75").unwrap();
+    let mut target: RRT<RealVectorState, RealVectorStateSpace, CircularGoalRegion> =
+        RRT::new(0.5, 0.05, &PlannerConfig::default());
+    target.set_params(&source.params());
+
+    assert_eq!(target.max_distance, 0.75);
+    assert_eq!(target.goal_bias, 0.2);
+    assert_eq!(target.num_threads, 1);
+}
+
+/// Records the length of the longest edge it is told about.
+#[derive(Default)]
+struct LongestEdgeObserver {
+    longest: Mutex<f64>,
+}
+
+impl PlannerObserver<RealVectorState> for LongestEdgeObserver {
+    fn on_edge_added(&self, from: &RealVectorState, to: &RealVectorState) {
+        let length = RealVectorStateSpace::new(2, None)
+            .unwrap()
+            .distance(from, to);
+        let mut longest = self.longest.lock().unwrap();
+        *longest = longest.max(length);
+    }
+}
+
+/// Creates a problem in a `size` by `size` square, with no obstacle in the way.
+fn create_open_problem(size: f64) -> (Arc<RvProblemDefinition>, Arc<WallObstacleChecker>) {
+    let space =
+        Arc::new(RealVectorStateSpace::new(2, Some(vec![(0.0, size), (0.0, size)])).unwrap());
+    let goal = Arc::new(CircularGoalRegion {
+        target: RealVectorState {
+            values: vec![0.9 * size, 0.5 * size],
+        },
+        radius: 0.05 * size,
+        space: space.clone(),
+    });
+    let problem_definition = Arc::new(ProblemDefinition {
+        space,
+        start_states: vec![RealVectorState {
+            values: vec![0.1 * size, 0.5 * size],
+        }],
+        goal,
+    });
+    let validity_checker = Arc::new(WallObstacleChecker {
+        wall_x_pos: 2.0 * size,
+        wall_y_min: 0.0,
+        wall_y_max: 0.0,
+        wall_thickness: 0.0,
+    });
+    (problem_definition, validity_checker)
+}
+
+#[test]
+fn test_zero_max_distance_defaults_to_fraction_of_maximum_extent() {
+    let (problem_def, validity_checker) = create_problem();
+    let observer = Arc::new(LongestEdgeObserver::default());
+    let mut planner = RRT::new(0.0, 0.05, &PlannerConfig { seed: Some(0) });
+    planner.set_observer(observer.clone());
+    planner.setup(problem_def.clone(), validity_checker);
+    assert!(planner.solve(Duration::from_secs(5)).is_ok());
+
+    let extent = problem_def.space.get_maximum_extent();
+    assert!((extent - 200f64.sqrt()).abs() < 1e-9);
+    assert!((*observer.longest.lock().unwrap() - 0.2 * extent).abs() < 1e-9);
+    // The user's value is kept.
+    assert_eq!(planner.max_distance, 0.0);
+    assert_eq!(planner.params().real("max_distance"), Some(0.0));
+}
+
+#[test]
+fn test_zero_max_distance_is_resolved_again_on_each_setup() {
+    let mut planner = RRT::new(0.0, 0.05, &PlannerConfig { seed: Some(0) });
+
+    for size in [10.0, 1.0] {
+        let (problem_def, validity_checker) = create_open_problem(size);
+        let observer = Arc::new(LongestEdgeObserver::default());
+        planner.set_observer(observer.clone());
+        planner.setup(problem_def.clone(), validity_checker);
+        assert!(planner.solve(Duration::from_secs(5)).is_ok());
+
+        let extent = problem_def.space.get_maximum_extent();
+        assert!(*observer.longest.lock().unwrap() <= 0.2 * extent + 1e-9);
+        assert_eq!(planner.max_distance, 0.0);
+    }
+}
+
+#[test]
+fn test_zero_radii_default_from_maximum_extent() {
+    let (problem_def, validity_checker) = create_problem();
+
+    let mut prm = PRM::new(1.0, 0.0, &PlannerConfig { seed: Some(0) });
+    prm.setup(problem_def.clone(), validity_checker.clone());
+    prm.construct_roadmap().unwrap();
+    assert!(prm.solve(Duration::from_secs(5)).is_ok());
+    assert_eq!(prm.connection_radius, 0.0);
+
+    let mut spars = SPARS::new(1.0, 0.0, 0.0, 3.0, 100, &PlannerConfig { seed: Some(0) });
+    spars.setup(problem_def.clone(), validity_checker.clone());
+    spars.construct_roadmap().unwrap();
+    assert!(spars.solve(Duration::from_secs(5)).is_ok());
+    assert_eq!(spars.sparse_delta, 0.0);
+    assert_eq!(spars.dense_delta, 0.0);
+
+    let mut rrt_star = RRTStar::new(0.0, 0.05, 0.0, &PlannerConfig { seed: Some(0) });
+    rrt_star.setup(problem_def, validity_checker);
+    assert!(rrt_star.solve(Duration::from_secs(5)).is_ok());
+    assert_eq!(rrt_star.max_distance, 0.0);
+    assert_eq!(rrt_star.search_radius, 0.0);
+    assert_eq!(rrt_star.params().real("search_radius"), Some(0.0));
+}
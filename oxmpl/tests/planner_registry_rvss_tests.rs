@@ -0,0 +1,208 @@
+use std::{f64::consts::PI, sync::Arc, time::Duration};
+
+use oxmpl::base::{
+    error::{PlannerRegistryError, StateSamplingError},
+    goal::{Goal, GoalRegion, GoalSampleableRegion},
+    planner::{Path, Planner, PlannerConfig},
+    problem_definition::ProblemDefinition,
+    space::{RealVectorStateSpace, StateSpace},
+    state::RealVectorState,
+    validity::StateValidityChecker,
+};
+use oxmpl::geometric::{PlannerRegistry, RRT};
+use oxmpl::tools::{ParallelPlan, ParallelPlanMode};
+
+use rand::{Rng, RngCore};
+
+/// A StateValidityChecker that defines a simple vertical wall obstacle.
+struct WallObstacleChecker {
+    wall_x_pos: f64,
+    wall_y_min: f64,
+    wall_y_max: f64,
+    wall_thickness: f64,
+}
+
+impl StateValidityChecker<RealVectorState> for WallObstacleChecker {
+    fn is_valid(&self, state: &RealVectorState) -> bool {
+        let x = state.values[0];
+        let y = state.values[1];
+
+        let is_in_wall = x >= self.wall_x_pos - self.wall_thickness / 2.0
+            && x <= self.wall_x_pos + self.wall_thickness / 2.0
+            && y >= self.wall_y_min
+            && y <= self.wall_y_max;
+
+        !is_in_wall
+    }
+}
+
+struct CircularGoalRegion {
+    target: RealVectorState,
+    radius: f64,
+    space: Arc<RealVectorStateSpace>,
+}
+
+impl Goal<RealVectorState> for CircularGoalRegion {
+    fn is_satisfied(&self, state: &RealVectorState) -> bool {
+        self.space.distance(state, &self.target) <= self.radius
+    }
+}
+
+impl GoalRegion<RealVectorState> for CircularGoalRegion {
+    fn distance_goal(&self, state: &RealVectorState) -> f64 {
+        let dist_to_center = self.space.distance(state, &self.target);
+        (dist_to_center - self.radius).max(0.0)
+    }
+}
+
+impl GoalSampleableRegion<RealVectorState> for CircularGoalRegion {
+    fn sample_goal(&self, rng: &mut dyn RngCore) -> Result<RealVectorState, StateSamplingError> {
+        let angle = rng.random_range(0.0..2.0 * PI);
+
+        let radius = self.radius * rng.random::<f64>().sqrt();
+
+        let x = self.target.values[0] + radius * angle.cos();
+        let y = self.target.values[1] + radius * angle.sin();
+
+        Ok(RealVectorState { values: vec![x, y] })
+    }
+}
+
+type RvProblemDefinition =
+    ProblemDefinition<RealVectorState, RealVectorStateSpace, CircularGoalRegion>;
+
+fn create_problem() -> (Arc<RvProblemDefinition>, Arc<WallObstacleChecker>) {
+    let space =
+        Arc::new(RealVectorStateSpace::new(2, Some(vec![(0.0, 10.0), (0.0, 10.0)])).unwrap());
+    let goal = Arc::new(CircularGoalRegion {
+        target: RealVectorState {
+            values: vec![9.0, 5.0],
+        },
+        radius: 0.5,
+        space: space.clone(),
+    });
+    let problem_definition = Arc::new(ProblemDefinition {
+        space,
+        start_states: vec![RealVectorState {
+            values: vec![1.0, 5.0],
+        }],
+        goal,
+    });
+    let validity_checker = Arc::new(WallObstacleChecker {
+        wall_x_pos: 5.0,
+        wall_y_min: 2.0,
+        wall_y_max: 8.0,
+        wall_thickness: 0.5,
+    });
+    (problem_definition, validity_checker)
+}
+
+type RvPlannerRegistry = PlannerRegistry<RealVectorState, RealVectorStateSpace, CircularGoalRegion>;
+
+fn assert_path_is_valid(
+    path: &Path<RealVectorState>,
+    problem_def: &RvProblemDefinition,
+    validity_checker: &WallObstacleChecker,
+) {
+    assert_eq!(path.0.first(), problem_def.start_states.first());
+    assert!(problem_def.goal.is_satisfied(path.0.last().unwrap()));
+    assert!(path.0.iter().all(|state| validity_checker.is_valid(state)));
+}
+
+#[test]
+fn test_geometric_planners_are_registered_by_name() {
+    let registry = RvPlannerRegistry::with_geometric_planners();
+    let names: Vec<_> = registry.names().collect();
+    assert_eq!(
+        names,
+        [
+            "RRT",
+            "RRTConnect",
+            "RRTStar",
+            "BiRRTStar",
+            "LazyRRT",
+            "LazyRRTConnect",
+            "RRTX",
+            "CForest",
+            "PRM",
+            "SPARS",
+            "SPARS2",
+        ]
+    );
+    assert!(registry.contains("PRM"));
+    assert!(!registry.contains("prm"));
+}
+
+#[test]
+fn test_every_registered_planner_solves_with_its_defaults() {
+    let registry = RvPlannerRegistry::with_geometric_planners();
+    for name in registry.names() {
+        let (problem_def, validity_checker) = create_problem();
+        let mut planner = registry
+            .create(name, &PlannerConfig { seed: Some(0) })
+            .unwrap();
+        planner.setup(problem_def.clone(), validity_checker.clone());
+
+        let path = planner
+            .solve(Duration::from_secs(2))
+            .unwrap_or_else(|e| panic!("{name} failed to solve: {e}"));
+        assert_path_is_valid(&path, &problem_def, &validity_checker);
+    }
+}
+
+#[test]
+fn test_unknown_planner_is_an_error() {
+    let registry = RvPlannerRegistry::with_geometric_planners();
+    assert_eq!(
+        registry.create("RRTSharp", &PlannerConfig::default()).err(),
+        Some(PlannerRegistryError::UnknownPlanner {
+            name: "RRTSharp".to_string()
+        })
+    );
+    assert!(registry.params("RRTSharp").is_err());
+}
+
+#[test]
+fn test_registered_planner_params_can_be_listed_and_set() {
+    let registry = RvPlannerRegistry::with_geometric_planners();
+    let params = registry.params("PRM").unwrap();
+    assert_eq!(params.real("connection_radius"), Some(0.0));
+
+    let mut planner = registry.create("PRM", &PlannerConfig::default()).unwrap();
+    planner.set_param("connection_radius", "2.5").unwrap();
+    assert_eq!(planner.params().real("connection_radius"), Some(2.5));
+    assert!(planner.set_param("max_distance", "1").is_err());
+}
+
+#[test]
+fn test_register_replaces_and_remove_deletes_planners() {
+    let mut registry = RvPlannerRegistry::new();
+    registry.register("RRT", |config| RRT::new(0.5, 0.05, config));
+    registry.register("RRT", |config| RRT::new(0.25, 0.1, config));
+    assert_eq!(registry.names().count(), 1);
+    assert_eq!(
+        registry.params("RRT").unwrap().real("max_distance"),
+        Some(0.25)
+    );
+
+    assert!(registry.remove("RRT"));
+    assert!(!registry.remove("RRT"));
+    assert_eq!(registry.names().count(), 0);
+}
+
+#[test]
+fn test_registered_planners_can_be_run_in_parallel() {
+    let registry = RvPlannerRegistry::with_geometric_planners();
+    let (problem_def, validity_checker) = create_problem();
+    let mut parallel = ParallelPlan::new(ParallelPlanMode::BestSolution, 0.1);
+    for (seed, name) in ["RRTConnect", "PRM"].into_iter().enumerate() {
+        let config = PlannerConfig {
+            seed: Some(seed as u64),
+        };
+        parallel.add_planner(registry.create(name, &config).unwrap());
+    }
+    parallel.setup(problem_def.clone(), validity_checker.clone());
+
+    let path = parallel.solve(Duration::from_secs(10)).unwrap();
+    assert_path_is_valid(&path, &problem_def, &validity_checker);
+}
@@ -0,0 +1,274 @@
+use std::{
+    f64::consts::PI,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use oxmpl::base::{
+    error::StateSamplingError,
+    goal::{Goal, GoalRegion, GoalSampleableRegion},
+    planner::{Planner, PlannerConfig, PlannerStats},
+    problem_definition::ProblemDefinition,
+    space::{RealVectorStateSpace, StateSpace},
+    state::RealVectorState,
+    validity::StateValidityChecker,
+};
+use oxmpl::geometric::{
+    BiRRTStar, CForest, LazyRRT, LazyRRTConnect, RRTConnect, RRTStar, PRM, RRT, RRTX, SPARS, SPARS2,
+};
+
+use rand::{Rng, RngCore};
+
+/// A StateValidityChecker that defines a simple vertical wall obstacle and counts the states it
+/// checks.
+struct WallObstacleChecker {
+    wall_x_pos: f64,
+    wall_y_min: f64,
+    wall_y_max: f64,
+    wall_thickness: f64,
+    checks: AtomicU64,
+}
+
+impl StateValidityChecker<RealVectorState> for WallObstacleChecker {
+    fn is_valid(&self, state: &RealVectorState) -> bool {
+        self.checks.fetch_add(1, Ordering::Relaxed);
+        let x = state.values[0];
+        let y = state.values[1];
+
+        let is_in_wall = x >= self.wall_x_pos - self.wall_thickness / 2.0
+            && x <= self.wall_x_pos + self.wall_thickness / 2.0
+            && y >= self.wall_y_min
+            && y <= self.wall_y_max;
+
+        !is_in_wall
+    }
+}
+
+struct CircularGoalRegion {
+    target: RealVectorState,
+    radius: f64,
+    space: Arc<RealVectorStateSpace>,
+}
+
+impl Goal<RealVectorState> for CircularGoalRegion {
+    fn is_satisfied(&self, state: &RealVectorState) -> bool {
+        self.space.distance(state, &self.target) <= self.radius
+    }
+}
+
+impl GoalRegion<RealVectorState> for CircularGoalRegion {
+    fn distance_goal(&self, state: &RealVectorState) -> f64 {
+        let dist_to_center = self.space.distance(state, &self.target);
+        (dist_to_center - self.radius).max(0.0)
+    }
+}
+
+impl GoalSampleableRegion<RealVectorState> for CircularGoalRegion {
+    fn sample_goal(&self, rng: &mut dyn RngCore) -> Result<RealVectorState, StateSamplingError> {
+        let angle = rng.random_range(0.0..2.0 * PI);
+
+        let radius = self.radius * rng.random::<f64>().sqrt();
+
+        let x = self.target.values[0] + radius * angle.cos();
+        let y = self.target.values[1] + radius * angle.sin();
+
+        Ok(RealVectorState { values: vec![x, y] })
+    }
+}
+
+type RvProblemDefinition =
+    ProblemDefinition<RealVectorState, RealVectorStateSpace, CircularGoalRegion>;
+
+fn create_problem() -> (Arc<RvProblemDefinition>, Arc<WallObstacleChecker>) {
+    let space =
+        Arc::new(RealVectorStateSpace::new(2, Some(vec![(0.0, 10.0), (0.0, 10.0)])).unwrap());
+    let goal = Arc::new(CircularGoalRegion {
+        target: RealVectorState {
+            values: vec![9.0, 5.0],
+        },
+        radius: 0.5,
+        space: space.clone(),
+    });
+    let problem_definition = Arc::new(ProblemDefinition {
+        space,
+        start_states: vec![RealVectorState {
+            values: vec![1.0, 5.0],
+        }],
+        goal,
+    });
+    let validity_checker = Arc::new(WallObstacleChecker {
+        wall_x_pos: 5.0,
+        wall_y_min: 2.0,
+        wall_y_max: 8.0,
+        wall_thickness: 0.5,
+        checks: AtomicU64::new(0),
+    });
+    (problem_definition, validity_checker)
+}
+
+/// Sets up `planner`, prepares it with `prepare` and runs it, returning its statistics.
+fn run<P>(mut planner: P, prepare: impl FnOnce(&mut P)) -> PlannerStats
+where
+    P: Planner<RealVectorState, RealVectorStateSpace, CircularGoalRegion>,
+{
+    let (problem_definition, validity_checker) = create_problem();
+    planner.setup(problem_definition, validity_checker);
+    prepare(&mut planner);
+    let _ = planner.solve(Duration::from_millis(500));
+    planner.stats()
+}
+
+fn assert_work_recorded(name: &str, stats: &PlannerStats) {
+    assert!(stats.iterations > 0, "{name} counted no iterations.");
+    assert!(stats.samples_drawn > 0, "{name} counted no samples.");
+    assert!(
+        stats.validity_checks > 0,
+        "{name} counted no validity checks."
+    );
+    assert!(stats.motion_checks > 0, "{name} counted no motion checks.");
+    assert!(
+        stats.nearest_neighbour_queries > 0,
+        "{name} counted no nearest-neighbour queries."
+    );
+    assert!(
+        stats.validity_checks >= stats.motion_checks,
+        "{name} checked motions without checking any states."
+    );
+    for phase in ["sampling", "nearest neighbours", "motion checking"] {
+        assert!(
+            stats.phase_time(phase) > Duration::ZERO,
+            "{name} spent no time in the {phase} phase."
+        );
+    }
+}
+
+#[test]
+fn test_every_planner_reports_stats_in_rvss() {
+    let config = PlannerConfig { seed: Some(0) };
+
+    assert_work_recorded("RRT", &run(RRT::new(0.5, 0.05, &config), |_| {}));
+    assert_work_recorded(
+        "RRTConnect",
+        &run(RRTConnect::new(0.5, 0.05, &config), |_| {}),
+    );
+    assert_work_recorded(
+        "RRTStar",
+        &run(RRTStar::new(0.5, 0.05, 1.0, &config), |_| {}),
+    );
+    assert_work_recorded("LazyRRT", &run(LazyRRT::new(0.5, 0.05, &config), |_| {}));
+    assert_work_recorded(
+        "LazyRRTConnect",
+        &run(LazyRRTConnect::new(0.5, 0.05, &config), |_| {}),
+    );
+    assert_work_recorded(
+        "BiRRTStar",
+        &run(BiRRTStar::new(0.5, 0.05, 1.0, &config), |_| {}),
+    );
+    assert_work_recorded(
+        "CForest",
+        &run(CForest::new(0.5, 0.05, 1.0, 2, &config), |_| {}),
+    );
+    assert_work_recorded("RRTX", &run(RRTX::new(0.5, 0.05, 1.0, &config), |_| {}));
+    assert_work_recorded(
+        "PRM",
+        &run(PRM::new(0.2, 1.0, &config), |planner| {
+            planner.construct_roadmap().unwrap()
+        }),
+    );
+    assert_work_recorded(
+        "SPARS",
+        &run(SPARS::new(0.2, 2.0, 0.2, 3.0, 100, &config), |planner| {
+            planner.construct_roadmap().unwrap()
+        }),
+    );
+    assert_work_recorded(
+        "SPARS2",
+        &run(SPARS2::new(0.2, 2.0, 0.2, 3.0, 100, &config), |planner| {
+            planner.construct_roadmap().unwrap()
+        }),
+    );
+}
+
+#[test]
+fn test_validity_checks_match_checker_in_rvss() {
+    let (problem_definition, validity_checker) = create_problem();
+    let mut planner = RRTConnect::new(0.5, 0.05, &PlannerConfig { seed: Some(1) });
+
+    planner.setup(problem_definition, validity_checker.clone());
+    let result = planner.solve(Duration::from_secs(5));
+    assert!(result.is_ok(), "Planner failed to find a solution.");
+
+    let stats = planner.stats();
+    assert_eq!(
+        stats.validity_checks,
+        validity_checker.checks.load(Ordering::Relaxed),
+        "Every state checked should be counted exactly once."
+    );
+    assert!(stats.samples_rejected <= stats.samples_drawn);
+}
+
+#[test]
+fn test_stats_accumulate_until_setup_in_rvss() {
+    let (problem_definition, validity_checker) = create_problem();
+    let mut planner = PRM::new(0.2, 1.0, &PlannerConfig { seed: Some(2) });
+
+    planner.setup(problem_definition.clone(), validity_checker.clone());
+    planner.construct_roadmap().unwrap();
+    assert!(
+        planner.stats().phase_time("roadmap construction") > Duration::ZERO,
+        "Roadmap construction should be timed."
+    );
+
+    let _ = planner.solve(Duration::from_secs(1));
+    let first = planner.stats();
+    let _ = planner.solve(Duration::from_secs(1));
+    let second = planner.stats();
+    assert!(second.nearest_neighbour_queries > first.nearest_neighbour_queries);
+    assert!(second.validity_checks > first.validity_checks);
+
+    planner.setup(problem_definition, validity_checker);
+    assert_eq!(planner.stats(), PlannerStats::default());
+}
+
+#[test]
+fn test_stats_serialise_to_json() {
+    let stats = run(
+        RRTConnect::new(0.5, 0.05, &PlannerConfig { seed: Some(3) }),
+        |_| {},
+    );
+    let json = stats.to_json();
+
+    assert!(json.starts_with(&format!("{{\"iterations\":{},", stats.iterations)));
+    assert!(json.contains(&format!("\"motion_checks\":{}", stats.motion_checks)));
+    assert!(json.contains("\"phase_times\":{\"sampling\":"));
+    assert!(json.ends_with("}}"));
+
+    let mut merged = stats.clone();
+    merged.merge(&stats);
+    assert_eq!(merged.iterations, 2 * stats.iterations);
+    assert_eq!(
+        merged.phase_time("sampling"),
+        2 * stats.phase_time("sampling")
+    );
+}
+
+#[test]
+fn test_stats_json_escapes_phase_names() {
+    let stats = PlannerStats {
+        phase_times: vec![
+            (
+                "a \"quoted\"\tphase".to_string(),
+                Duration::from_millis(500),
+            ),
+            ("bell\u{7}".to_string(), Duration::ZERO),
+        ],
+        ..Default::default()
+    };
+
+    assert!(stats
+        .to_json()
+        .ends_with("\"phase_times\":{\"a \\\"quoted\\\"\\tphase\":0.5,\"bell\\u0007\":0}}"));
+}
@@ -1,7 +1,7 @@
 use std::{f64::consts::PI, sync::Arc, time::Duration};
 
 use oxmpl::base::{
-    error::StateSamplingError,
+    error::{PlanningError, StateSamplingError},
     goal::{Goal, GoalRegion, GoalSampleableRegion},
     planner::{Path, Planner, PlannerConfig},
     problem_definition::ProblemDefinition,
@@ -11,7 +11,7 @@ use oxmpl::base::{
 };
 use oxmpl::geometric::PRM;
 
-use rand::Rng;
+use rand::{Rng, RngCore};
 
 /// A StateValidityChecker that defines a simple vertical wall obstacle.
 struct WallObstacleChecker {
@@ -35,6 +35,24 @@ impl StateValidityChecker<RealVectorState> for WallObstacleChecker {
     }
 }
 
+/// A StateValidityChecker where only the right of the space and a tiny pocket around `pocket` are
+/// free.
+struct PocketChecker {
+    pocket: RealVectorState,
+}
+
+impl StateValidityChecker<RealVectorState> for PocketChecker {
+    fn is_valid(&self, state: &RealVectorState) -> bool {
+        let x = state.values[0];
+        let y = state.values[1];
+
+        let in_pocket =
+            (x - self.pocket.values[0]).abs() < 1e-3 && (y - self.pocket.values[1]).abs() < 1e-3;
+
+        x > 8.0 || in_pocket
+    }
+}
+
 struct CircularGoalRegion {
     target: RealVectorState,
     radius: f64,
@@ -55,7 +73,7 @@ impl GoalRegion<RealVectorState> for CircularGoalRegion {
 }
 
 impl GoalSampleableRegion<RealVectorState> for CircularGoalRegion {
-    fn sample_goal(&self, rng: &mut impl Rng) -> Result<RealVectorState, StateSamplingError> {
+    fn sample_goal(&self, rng: &mut dyn RngCore) -> Result<RealVectorState, StateSamplingError> {
         let angle = rng.random_range(0.0..2.0 * PI);
 
         let radius = self.radius * rng.random::<f64>().sqrt();
@@ -190,3 +208,267 @@ fn test_prm_finds_path_in_rvss() {
 
     println!("PRM planner test passed!");
 }
+
+#[test]
+fn test_parallel_prm_is_reproducible_in_rvss() {
+    let space = Arc::new(
+        RealVectorStateSpace::new(2, Some(vec![(0.0, 10.0), (0.0, 10.0)]))
+            .expect("Error creating new RealVectorState!"),
+    );
+
+    let start_state = RealVectorState {
+        values: vec![1.0, 5.0],
+    };
+    let goal_definition = Arc::new(CircularGoalRegion {
+        target: RealVectorState {
+            values: vec![9.0, 5.0],
+        },
+        radius: 0.5,
+        space: space.clone(),
+    });
+
+    let problem_definition = Arc::new(ProblemDefinition {
+        space: space.clone(),
+        start_states: vec![start_state.clone()],
+        goal: goal_definition.clone(),
+    });
+
+    let validity_checker = Arc::new(WallObstacleChecker {
+        wall_x_pos: 5.0,
+        wall_y_min: 2.0,
+        wall_y_max: 8.0,
+        wall_thickness: 0.5,
+    });
+
+    let mut planners = Vec::new();
+    for build_time in [0.5, 1.0] {
+        let mut planner = PRM::new(build_time, 0.5, &PlannerConfig { seed: Some(0) });
+        planner.num_threads = 4;
+        planner.setup(problem_definition.clone(), validity_checker.clone());
+        planner
+            .construct_roadmap()
+            .expect("Issue constructing roadmap!");
+        planners.push(planner);
+    }
+
+    // Seeded parallel builds differ only in how many rounds fit in the time given, so the shorter
+    // roadmap must be a prefix of the longer one.
+    let short_roadmap = planners[0].get_roadmap();
+    let long_roadmap = planners[1].get_roadmap();
+    assert!(!short_roadmap.is_empty(), "Roadmap was not populated.");
+    for (short_node, long_node) in short_roadmap.iter().zip(&long_roadmap) {
+        assert_eq!(
+            short_node.state(),
+            long_node.state(),
+            "Seeded parallel roadmaps should agree on the states they share"
+        );
+    }
+
+    // Edges are only added between components, so the roadmap is a forest.
+    let num_edges: usize = long_roadmap
+        .iter()
+        .map(|node| node.edges().len())
+        .sum::<usize>()
+        / 2;
+    assert!(
+        num_edges < long_roadmap.len(),
+        "Parallel roadmap should not connect nodes that are already in the same component"
+    );
+
+    let result = planners[1].solve(Duration::from_secs(5));
+    assert!(
+        result.is_ok(),
+        "Planner failed to find a solution when one should exist. Error: {:?}",
+        result.err()
+    );
+
+    let path = result.unwrap();
+    assert!(
+        space.distance(path.0.first().unwrap(), &start_state) < 1e-9,
+        "Path should start at the start state"
+    );
+    assert!(
+        goal_definition.is_satisfied(path.0.last().unwrap()),
+        "Path should end in the goal region"
+    );
+    assert!(
+        is_path_valid(&path, &space, &*validity_checker),
+        "The returned path was found to be invalid."
+    );
+
+    println!("Parallel PRM planner test passed!");
+}
+
+#[test]
+fn test_prm_samples_small_goal_region_in_rvss() {
+    let space = Arc::new(
+        RealVectorStateSpace::new(2, Some(vec![(0.0, 10.0), (0.0, 10.0)]))
+            .expect("Error creating new RealVectorState!"),
+    );
+
+    let start_state = RealVectorState {
+        values: vec![1.0, 5.0],
+    };
+    // A goal region this small is practically never hit by uniform samples.
+    let goal_definition = Arc::new(CircularGoalRegion {
+        target: RealVectorState {
+            values: vec![9.0, 5.0],
+        },
+        radius: 0.001,
+        space: space.clone(),
+    });
+
+    let problem_definition = Arc::new(ProblemDefinition {
+        space: space.clone(),
+        start_states: vec![start_state.clone()],
+        goal: goal_definition.clone(),
+    });
+
+    let validity_checker = Arc::new(WallObstacleChecker {
+        wall_x_pos: 5.0,
+        wall_y_min: 2.0,
+        wall_y_max: 8.0,
+        wall_thickness: 0.5,
+    });
+
+    // Disable goal samples during construction, so the goal can only be reached by sampling it
+    // during the query.
+    let mut planner = PRM::new(1.0, 0.5, &PlannerConfig { seed: Some(0) });
+    planner.goal_bias = 0.0;
+
+    planner.setup(problem_definition, validity_checker.clone());
+    planner
+        .construct_roadmap()
+        .expect("Issue constructing roadmap!");
+
+    let result = planner.solve(Duration::from_secs(5));
+    assert!(
+        result.is_ok(),
+        "Planner failed to find a solution when one should exist. Error: {:?}",
+        result.err()
+    );
+
+    let path = result.unwrap();
+    assert!(
+        space.distance(path.0.first().unwrap(), &start_state) < 1e-9,
+        "Path should start at the start state"
+    );
+    assert!(
+        goal_definition.is_satisfied(path.0.last().unwrap()),
+        "Path should end in the goal region"
+    );
+    assert!(
+        is_path_valid(&path, &space, &*validity_checker),
+        "The returned path was found to be invalid."
+    );
+
+    println!("PRM small goal region test passed!");
+}
+
+#[test]
+fn test_prm_reports_goal_in_another_component_in_rvss() {
+    let space = Arc::new(
+        RealVectorStateSpace::new(2, Some(vec![(0.0, 10.0), (0.0, 10.0)]))
+            .expect("Error creating new RealVectorState!"),
+    );
+
+    let problem_definition = Arc::new(ProblemDefinition {
+        space: space.clone(),
+        start_states: vec![RealVectorState {
+            values: vec![1.0, 5.0],
+        }],
+        goal: Arc::new(CircularGoalRegion {
+            target: RealVectorState {
+                values: vec![9.0, 5.0],
+            },
+            radius: 0.5,
+            space: space.clone(),
+        }),
+    });
+
+    // The wall spans the whole space, so the start and the goal are in different components.
+    let validity_checker = Arc::new(WallObstacleChecker {
+        wall_x_pos: 5.0,
+        wall_y_min: 0.0,
+        wall_y_max: 10.0,
+        wall_thickness: 0.5,
+    });
+
+    let mut planner = PRM::new(0.5, 1.0, &PlannerConfig { seed: Some(0) });
+    planner.num_threads = 2;
+    planner.setup(problem_definition, validity_checker);
+    planner
+        .construct_roadmap()
+        .expect("Issue constructing roadmap!");
+
+    let result = planner.solve(Duration::from_millis(200));
+    assert!(
+        matches!(result, Err(PlanningError::NoSolutionFound)),
+        "Planner should not find a path through the wall. Error: {:?}",
+        result.err()
+    );
+}
+
+#[test]
+fn test_prm_connects_start_to_sampled_goal_state_in_rvss() {
+    let space = Arc::new(
+        RealVectorStateSpace::new(2, Some(vec![(0.0, 10.0), (0.0, 10.0)]))
+            .expect("Error creating new RealVectorState!"),
+    );
+
+    let start_state = RealVectorState {
+        values: vec![1.0, 5.0],
+    };
+    let goal_definition = Arc::new(CircularGoalRegion {
+        target: RealVectorState {
+            values: vec![1.0005, 5.0],
+        },
+        radius: 0.0002,
+        space: space.clone(),
+    });
+
+    let problem_definition = Arc::new(ProblemDefinition {
+        space: space.clone(),
+        start_states: vec![start_state.clone()],
+        goal: goal_definition.clone(),
+    });
+
+    // The start and goal share a pocket that uniform samples practically never hit, so the
+    // roadmap is built far from the start, which has no roadmap neighbours.
+    let validity_checker = Arc::new(PocketChecker {
+        pocket: start_state.clone(),
+    });
+
+    let mut planner = PRM::new(0.2, 1.0, &PlannerConfig { seed: Some(0) });
+    planner.goal_bias = 0.0;
+    planner.setup(problem_definition, validity_checker.clone());
+    planner
+        .construct_roadmap()
+        .expect("Issue constructing roadmap!");
+
+    let result = planner.solve(Duration::from_secs(5));
+    assert!(
+        result.is_ok(),
+        "Planner failed to find a solution when one should exist. Error: {:?}",
+        result.err()
+    );
+
+    let path = result.unwrap();
+    assert_eq!(
+        path.0.len(),
+        2,
+        "Path should go straight from the start to a sampled goal state"
+    );
+    assert!(
+        space.distance(path.0.first().unwrap(), &start_state) < 1e-9,
+        "Path should start at the start state"
+    );
+    assert!(
+        goal_definition.is_satisfied(path.0.last().unwrap()),
+        "Path should end in the goal region"
+    );
+    assert!(
+        is_path_valid(&path, &space, &*validity_checker),
+        "The returned path was found to be invalid."
+    );
+}
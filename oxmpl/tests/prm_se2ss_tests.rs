@@ -11,7 +11,7 @@ use oxmpl::base::{
 };
 use oxmpl::geometric::PRM;
 
-use rand::Rng;
+use rand::{Rng, RngCore};
 
 struct ObstacleChecker {
     x_min: f64,
@@ -48,7 +48,7 @@ impl GoalRegion<SE2State> for SE2GoalRegion {
 }
 
 impl GoalSampleableRegion<SE2State> for SE2GoalRegion {
-    fn sample_goal(&self, rng: &mut impl Rng) -> Result<SE2State, StateSamplingError> {
+    fn sample_goal(&self, rng: &mut dyn RngCore) -> Result<SE2State, StateSamplingError> {
         let angle = rng.random_range(0.0..2.0 * PI);
         let r = self.radius * rng.random::<f64>().sqrt();
         let x = self.target.get_x() + r * angle.cos();
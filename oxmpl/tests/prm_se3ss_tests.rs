@@ -11,7 +11,7 @@ use oxmpl::base::{
 };
 use oxmpl::geometric::PRM;
 
-use rand::Rng;
+use rand::{Rng, RngCore};
 use std::f64::consts::PI;
 
 struct ObstacleChecker;
@@ -79,7 +79,7 @@ impl GoalRegion<SE3State> for SE3GoalRegion {
 }
 
 impl GoalSampleableRegion<SE3State> for SE3GoalRegion {
-    fn sample_goal(&self, rng: &mut impl Rng) -> Result<SE3State, StateSamplingError> {
+    fn sample_goal(&self, rng: &mut dyn RngCore) -> Result<SE3State, StateSamplingError> {
         let u: f64 = rng.random();
         let v: f64 = rng.random();
         let theta = 2.0 * PI * u;
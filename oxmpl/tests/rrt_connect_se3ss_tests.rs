@@ -11,7 +11,7 @@ use oxmpl::base::{
 };
 use oxmpl::geometric::RRTConnect;
 
-use rand::Rng;
+use rand::RngCore;
 
 struct BoxObstacleChecker {
     x_min: f64,
@@ -56,7 +56,7 @@ impl GoalRegion<SE3State> for SE3GoalRegion {
 }
 
 impl GoalSampleableRegion<SE3State> for SE3GoalRegion {
-    fn sample_goal(&self, _rng: &mut impl Rng) -> Result<SE3State, StateSamplingError> {
+    fn sample_goal(&self, _rng: &mut dyn RngCore) -> Result<SE3State, StateSamplingError> {
         let t = &self.target;
         Ok(SE3State::new(
             t.get_x(),
@@ -11,7 +11,7 @@ use oxmpl::base::{
 };
 use oxmpl::geometric::RRTConnect;
 
-use rand::Rng;
+use rand::{Rng, RngCore};
 
 fn quaternion_from_axis_angle(axis: [f64; 3], angle: f64) -> SO3State {
     let norm = (axis[0].powi(2) + axis[1].powi(2) + axis[2].powi(2)).sqrt();
@@ -73,7 +73,7 @@ impl GoalRegion<SO3State> for SO3GoalRegion {
 }
 
 impl GoalSampleableRegion<SO3State> for SO3GoalRegion {
-    fn sample_goal(&self, rng: &mut impl Rng) -> Result<SO3State, StateSamplingError> {
+    fn sample_goal(&self, rng: &mut dyn RngCore) -> Result<SO3State, StateSamplingError> {
         loop {
             let x: f64 = rng.random_range(-1.0..1.0);
             let y: f64 = rng.random_range(-1.0..1.0);
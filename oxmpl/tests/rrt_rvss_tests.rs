@@ -11,7 +11,7 @@ use oxmpl::base::{
 };
 use oxmpl::geometric::RRT;
 
-use rand::Rng;
+use rand::{Rng, RngCore};
 
 struct WallObstacleChecker {
     wall_x_pos: f64,
@@ -54,7 +54,7 @@ impl GoalRegion<RealVectorState> for CircularGoalRegion {
 }
 
 impl GoalSampleableRegion<RealVectorState> for CircularGoalRegion {
-    fn sample_goal(&self, rng: &mut impl Rng) -> Result<RealVectorState, StateSamplingError> {
+    fn sample_goal(&self, rng: &mut dyn RngCore) -> Result<RealVectorState, StateSamplingError> {
         let angle = rng.random_range(0.0..2.0 * PI);
 
         let radius = self.radius * rng.random::<f64>().sqrt();
@@ -182,3 +182,128 @@ fn test_rrt_finds_path_in_rvss() {
 
     println!("RRT planner test passed!");
 }
+
+#[test]
+fn test_parallel_rrt_is_deterministic_in_rvss() {
+    let space = Arc::new(
+        RealVectorStateSpace::new(2, Some(vec![(0.0, 10.0), (0.0, 10.0)]))
+            .expect("Error creating new RealVectorState!"),
+    );
+
+    let start_state = RealVectorState {
+        values: vec![1.0, 5.0],
+    };
+    let goal_definition = Arc::new(CircularGoalRegion {
+        target: RealVectorState {
+            values: vec![9.0, 5.0],
+        },
+        radius: 0.5,
+        space: space.clone(),
+    });
+
+    let problem_definition = Arc::new(ProblemDefinition {
+        space: space.clone(),
+        start_states: vec![start_state.clone()],
+        goal: goal_definition.clone(),
+    });
+
+    let validity_checker = Arc::new(WallObstacleChecker {
+        wall_x_pos: 5.0,
+        wall_y_min: 2.0,
+        wall_y_max: 8.0,
+        wall_thickness: 0.5,
+    });
+
+    let mut paths = Vec::new();
+    for _ in 0..2 {
+        let mut planner = RRT::new(0.5, 0.05, &PlannerConfig { seed: Some(0) });
+        planner.num_threads = 4;
+        planner.setup(problem_definition.clone(), validity_checker.clone());
+
+        let result = planner.solve(Duration::from_secs(5));
+        assert!(
+            result.is_ok(),
+            "Planner failed to find a solution when one should exist. Error: {:?}",
+            result.err()
+        );
+
+        let path = result.unwrap();
+        assert!(
+            space.distance(path.0.first().unwrap(), &start_state) < 1e-9,
+            "Path should start at the start state"
+        );
+        assert!(
+            goal_definition.is_satisfied(path.0.last().unwrap()),
+            "Path should end in the goal region"
+        );
+        assert!(
+            is_path_valid(&path, &space, &*validity_checker),
+            "The returned path was found to be invalid."
+        );
+        paths.push(path);
+    }
+
+    assert_eq!(
+        paths[0].0, paths[1].0,
+        "Seeded parallel runs should find the same path"
+    );
+
+    println!("Parallel RRT planner test passed!");
+}
+
+#[test]
+fn test_unseeded_parallel_rrt_finds_path_in_rvss() {
+    let space = Arc::new(
+        RealVectorStateSpace::new(2, Some(vec![(0.0, 10.0), (0.0, 10.0)]))
+            .expect("Error creating new RealVectorState!"),
+    );
+
+    let start_state = RealVectorState {
+        values: vec![1.0, 5.0],
+    };
+    let goal_definition = Arc::new(CircularGoalRegion {
+        target: RealVectorState {
+            values: vec![9.0, 5.0],
+        },
+        radius: 0.5,
+        space: space.clone(),
+    });
+
+    let problem_definition = Arc::new(ProblemDefinition {
+        space: space.clone(),
+        start_states: vec![start_state.clone()],
+        goal: goal_definition.clone(),
+    });
+
+    let validity_checker = Arc::new(WallObstacleChecker {
+        wall_x_pos: 5.0,
+        wall_y_min: 2.0,
+        wall_y_max: 8.0,
+        wall_thickness: 0.5,
+    });
+
+    let mut planner = RRT::new(0.5, 0.05, &PlannerConfig { seed: None });
+    planner.num_threads = 4;
+    planner.setup(problem_definition, validity_checker.clone());
+
+    let result = planner.solve(Duration::from_secs(5));
+    assert!(
+        result.is_ok(),
+        "Planner failed to find a solution when one should exist. Error: {:?}",
+        result.err()
+    );
+
+    let path = result.unwrap();
+    assert!(
+        space.distance(path.0.first().unwrap(), &start_state) < 1e-9,
+        "Path should start at the start state"
+    );
+    assert!(
+        goal_definition.is_satisfied(path.0.last().unwrap()),
+        "Path should end in the goal region"
+    );
+    assert!(
+        is_path_valid(&path, &space, &*validity_checker),
+        "The returned path was found to be invalid."
+    );
+}
@@ -11,7 +11,7 @@ use oxmpl::base::{
 };
 use oxmpl::geometric::RRT;
 
-use rand::Rng;
+use rand::{Rng, RngCore};
 
 struct ForbiddenAngleChecker {
     invalid_min: f64,
@@ -45,7 +45,7 @@ impl GoalRegion<SO2State> for AngleGoalRegion {
 }
 
 impl GoalSampleableRegion<SO2State> for AngleGoalRegion {
-    fn sample_goal(&self, rng: &mut impl Rng) -> Result<SO2State, StateSamplingError> {
+    fn sample_goal(&self, rng: &mut dyn RngCore) -> Result<SO2State, StateSamplingError> {
         let random_offset = rng.random_range(-self.radius..=self.radius);
         Ok(SO2State::new(self.target.value + random_offset))
     }
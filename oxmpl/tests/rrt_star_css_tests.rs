@@ -11,7 +11,7 @@ use oxmpl::base::{
 };
 use oxmpl::geometric::RRTStar;
 
-use rand::Rng;
+use rand::RngCore;
 
 struct BoxObstacleChecker {
     x_min: f64,
@@ -55,7 +55,7 @@ impl GoalRegion<CompoundState> for CompoundGoalRegion {
 }
 
 impl GoalSampleableRegion<CompoundState> for CompoundGoalRegion {
-    fn sample_goal(&self, _rng: &mut impl Rng) -> Result<CompoundState, StateSamplingError> {
+    fn sample_goal(&self, _rng: &mut dyn RngCore) -> Result<CompoundState, StateSamplingError> {
         let target_rv = self.target.components[0]
             .as_any()
             .downcast_ref::<RealVectorState>()
@@ -11,7 +11,7 @@ use oxmpl::base::{
 };
 use oxmpl::geometric::RRTStar;
 
-use rand::Rng;
+use rand::{Rng, RngCore};
 
 struct WallObstacleChecker {
     wall_x_pos: f64,
@@ -54,7 +54,7 @@ impl GoalRegion<RealVectorState> for CircularGoalRegion {
 }
 
 impl GoalSampleableRegion<RealVectorState> for CircularGoalRegion {
-    fn sample_goal(&self, rng: &mut impl Rng) -> Result<RealVectorState, StateSamplingError> {
+    fn sample_goal(&self, rng: &mut dyn RngCore) -> Result<RealVectorState, StateSamplingError> {
         let angle = rng.random_range(0.0..2.0 * PI);
 
         let radius = self.radius * rng.random::<f64>().sqrt();
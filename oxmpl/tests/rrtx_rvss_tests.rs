@@ -0,0 +1,295 @@
+use std::{
+    f64::consts::PI,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use oxmpl::base::{
+    error::StateSamplingError,
+    goal::{Goal, GoalRegion, GoalSampleableRegion},
+    planner::{Path, Planner, PlannerConfig},
+    problem_definition::ProblemDefinition,
+    space::{RealVectorStateSpace, StateSpace},
+    state::RealVectorState,
+    validity::StateValidityChecker,
+};
+use oxmpl::geometric::RRTX;
+
+use rand::{Rng, RngCore};
+
+/// A StateValidityChecker with a wall that has a door in it. The door can be opened and closed
+/// while planning, which is what RRTX is notified about.
+struct DoorObstacleChecker {
+    wall_x_pos: f64,
+    wall_thickness: f64,
+    door_y_min: f64,
+    door_y_max: f64,
+    door_closed: AtomicBool,
+}
+
+impl DoorObstacleChecker {
+    fn in_wall_column(&self, state: &RealVectorState) -> bool {
+        let x = state.values[0];
+        x >= self.wall_x_pos - self.wall_thickness / 2.0
+            && x <= self.wall_x_pos + self.wall_thickness / 2.0
+    }
+
+    fn in_door(&self, state: &RealVectorState) -> bool {
+        let y = state.values[1];
+        self.in_wall_column(state) && y >= self.door_y_min && y <= self.door_y_max
+    }
+}
+
+impl StateValidityChecker<RealVectorState> for DoorObstacleChecker {
+    fn is_valid(&self, state: &RealVectorState) -> bool {
+        let y = state.values[1];
+
+        // The wall spans y in [2, 10]; the bottom is always open.
+        let is_in_wall = self.in_wall_column(state) && y >= 2.0;
+        let is_in_door_gap = self.in_door(state);
+
+        !is_in_wall || (is_in_door_gap && !self.door_closed.load(Ordering::SeqCst))
+    }
+}
+
+fn path_length(path: &Path<RealVectorState>, space: &RealVectorStateSpace) -> f64 {
+    path.0
+        .windows(2)
+        .map(|pair| space.distance(&pair[0], &pair[1]))
+        .sum()
+}
+
+struct CircularGoalRegion {
+    target: RealVectorState,
+    radius: f64,
+    space: Arc<RealVectorStateSpace>,
+}
+
+impl Goal<RealVectorState> for CircularGoalRegion {
+    fn is_satisfied(&self, state: &RealVectorState) -> bool {
+        self.space.distance(state, &self.target) <= self.radius
+    }
+}
+
+impl GoalRegion<RealVectorState> for CircularGoalRegion {
+    fn distance_goal(&self, state: &RealVectorState) -> f64 {
+        let dist_to_center = self.space.distance(state, &self.target);
+        (dist_to_center - self.radius).max(0.0)
+    }
+}
+
+impl GoalSampleableRegion<RealVectorState> for CircularGoalRegion {
+    fn sample_goal(&self, rng: &mut dyn RngCore) -> Result<RealVectorState, StateSamplingError> {
+        let angle = rng.random_range(0.0..2.0 * PI);
+
+        let radius = self.radius * rng.random::<f64>().sqrt();
+
+        let x = self.target.values[0] + radius * angle.cos();
+        let y = self.target.values[1] + radius * angle.sin();
+
+        Ok(RealVectorState { values: vec![x, y] })
+    }
+}
+
+fn is_path_valid(
+    path: &Path<RealVectorState>,
+    space: &RealVectorStateSpace,
+    checker: &dyn StateValidityChecker<RealVectorState>,
+) -> bool {
+    for i in 0..path.0.len() - 1 {
+        let state_a = &path.0[i];
+        let state_b = &path.0[i + 1];
+
+        if !checker.is_valid(state_a) {
+            println!("Path invalid: State {state_a:?} is in collision.");
+            return false;
+        }
+        if (i + 1 == path.0.len() - 1) && !checker.is_valid(state_b) {
+            println!("Path invalid: Final state {state_b:?} is in collision.");
+            return false;
+        }
+
+        let dist = space.distance(state_a, state_b);
+        let num_steps = (dist / space.get_longest_valid_segment_length()).ceil() as usize;
+        if num_steps > 1 {
+            let mut interpolated_state = state_a.clone();
+            for j in 1..=num_steps {
+                let t = j as f64 / num_steps as f64;
+                space.interpolate(state_a, state_b, t, &mut interpolated_state);
+                if !checker.is_valid(&interpolated_state) {
+                    println!(
+                        "Path invalid: Motion between {state_a:?} and {state_b:?} is in collision at {interpolated_state:?}."
+                    );
+                    return false;
+                }
+            }
+        }
+    }
+    true
+}
+
+#[test]
+fn test_rrtx_replans_when_door_closes_and_opens() {
+    let space = Arc::new(
+        RealVectorStateSpace::new(2, Some(vec![(0.0, 10.0), (0.0, 10.0)]))
+            .expect("Failed to create state space for test."),
+    );
+
+    let start_state = RealVectorState {
+        values: vec![1.0, 5.0],
+    };
+    let goal_definition = Arc::new(CircularGoalRegion {
+        target: RealVectorState {
+            values: vec![9.0, 5.0],
+        },
+        radius: 0.5,
+        space: space.clone(),
+    });
+
+    let problem_definition = Arc::new(ProblemDefinition {
+        space: space.clone(),
+        start_states: vec![start_state.clone()],
+        goal: goal_definition.clone(),
+    });
+
+    let validity_checker = Arc::new(DoorObstacleChecker {
+        wall_x_pos: 5.0,
+        wall_thickness: 0.5,
+        door_y_min: 4.0,
+        door_y_max: 6.0,
+        door_closed: AtomicBool::new(false),
+    });
+    let door_checker = validity_checker.clone();
+    let door_region = move |state: &RealVectorState| door_checker.in_door(state);
+
+    let mut planner = RRTX::new(0.5, 0.05, 1.0, &PlannerConfig { seed: Some(0) });
+    planner.setup(problem_definition, validity_checker.clone());
+
+    let timeout = Duration::from_secs(5);
+
+    // 1. Plan through the open door.
+    let open_path = planner
+        .solve(timeout)
+        .expect("Planner failed to find a solution through the open door.");
+    assert!(
+        space.distance(open_path.0.first().unwrap(), &start_state) < 1e-9,
+        "Path should start at the start state"
+    );
+    assert!(
+        goal_definition.is_satisfied(open_path.0.last().unwrap()),
+        "Path should end in the goal region"
+    );
+    assert!(
+        is_path_valid(&open_path, &space, &*validity_checker),
+        "The returned path was found to be invalid."
+    );
+
+    // 2. Close the door. The repaired tree must route around the bottom of the wall.
+    validity_checker.door_closed.store(true, Ordering::SeqCst);
+    planner.notify_region_invalidated(&door_region);
+    let closed_path = planner
+        .solve(timeout)
+        .expect("Planner failed to replan after the door closed.");
+    assert!(
+        goal_definition.is_satisfied(closed_path.0.last().unwrap()),
+        "Replanned path should end in the goal region"
+    );
+    assert!(
+        is_path_valid(&closed_path, &space, &*validity_checker),
+        "The replanned path passes through the closed door."
+    );
+
+    // 3. Open the door again. The repaired tree should use the shorter route through it.
+    validity_checker.door_closed.store(false, Ordering::SeqCst);
+    planner.notify_region_validated(&door_region);
+    let reopened_path = planner
+        .solve(timeout)
+        .expect("Planner failed to replan after the door opened.");
+    assert!(
+        is_path_valid(&reopened_path, &space, &*validity_checker),
+        "The replanned path was found to be invalid."
+    );
+    assert!(
+        path_length(&reopened_path, &space) < path_length(&closed_path, &space),
+        "Reopening the door should shorten the path."
+    );
+
+    // 4. Move the robot along its path and plan from its new state.
+    let robot_state = reopened_path.0[reopened_path.0.len() / 2].clone();
+    planner.set_robot_state(robot_state.clone());
+    let moved_path = planner
+        .solve(timeout)
+        .expect("Planner failed to plan from the robot's new state.");
+    assert!(
+        space.distance(moved_path.0.first().unwrap(), &robot_state) < 1e-9,
+        "Path should start at the robot's current state"
+    );
+    assert!(
+        goal_definition.is_satisfied(moved_path.0.last().unwrap()),
+        "Path should end in the goal region"
+    );
+    assert!(
+        is_path_valid(&moved_path, &space, &*validity_checker),
+        "The returned path was found to be invalid."
+    );
+
+    println!("RRTX planner test passed!");
+}
+
+#[test]
+fn test_rrtx_keeps_robot_state_set_before_setup() {
+    let space = Arc::new(
+        RealVectorStateSpace::new(2, Some(vec![(0.0, 10.0), (0.0, 10.0)]))
+            .expect("Failed to create state space for test."),
+    );
+
+    let goal_definition = Arc::new(CircularGoalRegion {
+        target: RealVectorState {
+            values: vec![9.0, 5.0],
+        },
+        radius: 0.5,
+        space: space.clone(),
+    });
+
+    let problem_definition = Arc::new(ProblemDefinition {
+        space: space.clone(),
+        start_states: vec![RealVectorState {
+            values: vec![1.0, 5.0],
+        }],
+        goal: goal_definition.clone(),
+    });
+
+    let validity_checker = Arc::new(DoorObstacleChecker {
+        wall_x_pos: 5.0,
+        wall_thickness: 0.5,
+        door_y_min: 4.0,
+        door_y_max: 6.0,
+        door_closed: AtomicBool::new(false),
+    });
+
+    let robot_state = RealVectorState {
+        values: vec![2.0, 8.0],
+    };
+    let mut planner = RRTX::new(0.5, 0.05, 1.0, &PlannerConfig { seed: Some(0) });
+    planner.set_robot_state(robot_state.clone());
+    planner.setup(problem_definition, validity_checker.clone());
+
+    let path = planner
+        .solve(Duration::from_secs(5))
+        .expect("Planner failed to find a solution from the robot state.");
+    assert!(
+        space.distance(path.0.first().unwrap(), &robot_state) < 1e-9,
+        "Path should start at the robot state set before setup"
+    );
+    assert!(
+        goal_definition.is_satisfied(path.0.last().unwrap()),
+        "Path should end in the goal region"
+    );
+    assert!(
+        is_path_valid(&path, &space, &*validity_checker),
+        "The returned path was found to be invalid."
+    );
+}
@@ -0,0 +1,186 @@
+use std::time::Duration;
+
+use oxmpl::base::{
+    error::{PlanningError, ProblemDefinitionError},
+    goal::{Goal, GoalState},
+    planner::{Path, PlannerConfig},
+    space::{RealVectorStateSpace, StateSpace},
+    state::RealVectorState,
+    validity::StateValidityChecker,
+};
+use oxmpl::geometric::{SimpleSetup, PRM};
+
+struct WallObstacleChecker {
+    wall_x_pos: f64,
+    wall_y_min: f64,
+    wall_y_max: f64,
+    wall_thickness: f64,
+}
+
+impl StateValidityChecker<RealVectorState> for WallObstacleChecker {
+    fn is_valid(&self, state: &RealVectorState) -> bool {
+        let x = state.values[0];
+        let y = state.values[1];
+
+        let is_in_wall = x >= self.wall_x_pos - self.wall_thickness / 2.0
+            && x <= self.wall_x_pos + self.wall_thickness / 2.0
+            && y >= self.wall_y_min
+            && y <= self.wall_y_max;
+
+        !is_in_wall
+    }
+}
+
+type Setup = SimpleSetup<RealVectorState, RealVectorStateSpace, GoalState<RealVectorStateSpace>>;
+
+fn create_setup(seed: u64) -> Setup {
+    let space = RealVectorStateSpace::new(2, Some(vec![(0.0, 10.0), (0.0, 10.0)]))
+        .expect("Failed to create state space for test.");
+    let checker = WallObstacleChecker {
+        wall_x_pos: 5.0,
+        wall_y_min: 2.0,
+        wall_y_max: 8.0,
+        wall_thickness: 0.5,
+    };
+    SimpleSetup::new(space, checker, &PlannerConfig { seed: Some(seed) })
+}
+
+fn set_query(setup: &mut Setup, start: [f64; 2], goal: [f64; 2]) {
+    setup.set_start_state(RealVectorState::new(start.to_vec()));
+    setup.set_goal(GoalState::new(
+        setup.space().clone(),
+        RealVectorState::new(goal.to_vec()),
+        0.5,
+    ));
+}
+
+fn is_path_valid(path: &Path<RealVectorState>, setup: &Setup) -> bool {
+    let space = setup.space();
+    let checker = setup.validity_checker();
+    path.0.windows(2).all(|pair| {
+        let num_steps = (space.distance(&pair[0], &pair[1])
+            / space.get_longest_valid_segment_length())
+        .ceil()
+        .max(1.0) as usize;
+        let mut state = pair[0].clone();
+        (0..=num_steps).all(|j| {
+            space.interpolate(&pair[0], &pair[1], j as f64 / num_steps as f64, &mut state);
+            checker.is_valid(&state)
+        })
+    })
+}
+
+fn path_length(path: &Path<RealVectorState>, setup: &Setup) -> f64 {
+    path.0
+        .windows(2)
+        .map(|pair| setup.space().distance(&pair[0], &pair[1]))
+        .sum()
+}
+
+#[test]
+fn test_simple_setup_solves_and_simplifies() {
+    let mut raw_setup = create_setup(3);
+    raw_setup.simplify_solution = false;
+    set_query(&mut raw_setup, [1.0, 5.0], [9.0, 5.0]);
+    let raw_path = raw_setup
+        .solve(Duration::from_secs(5))
+        .expect("Planner failed to find a solution when one should exist.");
+
+    let mut setup = create_setup(3);
+    set_query(&mut setup, [1.0, 5.0], [9.0, 5.0]);
+    let path = setup
+        .solve(Duration::from_secs(5))
+        .expect("Planner failed to find a solution when one should exist.");
+
+    assert!(is_path_valid(&path, &setup));
+    assert_eq!(path.0.first().unwrap().values, vec![1.0, 5.0]);
+    assert!(setup.goal().unwrap().is_satisfied(path.0.last().unwrap()));
+    assert!(path_length(&path, &setup) <= path_length(&raw_path, &raw_setup) + 1e-9);
+    assert!(setup.solution().is_some());
+    assert!(setup.last_plan_time() > Duration::ZERO);
+}
+
+#[test]
+fn test_simple_setup_answers_several_queries() {
+    let mut setup = create_setup(5);
+
+    let queries = [
+        ([1.0, 5.0], [9.0, 5.0]),
+        ([9.0, 9.0], [1.0, 1.0]),
+        ([2.0, 1.0], [8.0, 9.0]),
+    ];
+    for (start, goal) in queries {
+        set_query(&mut setup, start, goal);
+        let path = setup
+            .solve(Duration::from_secs(5))
+            .expect("Planner failed to find a solution when one should exist.");
+        assert_eq!(path.0.first().unwrap().values, start.to_vec());
+        assert!(setup.goal().unwrap().is_satisfied(path.0.last().unwrap()));
+        assert!(is_path_valid(&path, &setup));
+    }
+}
+
+#[test]
+fn test_simple_setup_rejects_malformed_problems() {
+    let mut setup = create_setup(0);
+    setup.set_start_state(RealVectorState::new(vec![1.0, 5.0]));
+    assert_eq!(
+        setup.solve(Duration::from_secs(1)).err(),
+        Some(PlanningError::NoGoal)
+    );
+
+    set_query(&mut setup, [5.0, 5.0], [9.0, 5.0]);
+    assert_eq!(
+        setup.validate().err(),
+        Some(PlanningError::InvalidProblem(
+            ProblemDefinitionError::InvalidStartState { index: 0 }
+        ))
+    );
+
+    set_query(&mut setup, [11.0, 5.0], [9.0, 5.0]);
+    assert_eq!(
+        setup.validate().err(),
+        Some(PlanningError::InvalidProblem(
+            ProblemDefinitionError::StartStateOutOfBounds { index: 0 }
+        ))
+    );
+
+    setup.clear_start_states();
+    assert_eq!(
+        setup.solve(Duration::from_secs(1)).err(),
+        Some(PlanningError::InvalidProblem(
+            ProblemDefinitionError::NoStartStates
+        ))
+    );
+
+    // The goal lies inside the wall. Its samples are all invalid, which is only a warning, so the
+    // planner is run and times out.
+    set_query(&mut setup, [1.0, 5.0], [5.0, 5.0]);
+    setup.set_goal(GoalState::new(
+        setup.space().clone(),
+        RealVectorState::new(vec![5.0, 5.0]),
+        0.0,
+    ));
+    assert_eq!(setup.validate().err(), None);
+    assert_eq!(
+        setup.solve(Duration::from_millis(100)).err(),
+        Some(PlanningError::Timeout)
+    );
+    assert!(setup.solution().is_none());
+}
+
+#[test]
+fn test_simple_setup_with_prm() {
+    let mut setup = create_setup(11);
+    setup.set_planner_with_preparation(
+        PRM::new(1.0, 2.0, &PlannerConfig { seed: Some(11) }),
+        |prm| prm.construct_roadmap(),
+    );
+
+    set_query(&mut setup, [1.0, 5.0], [9.0, 5.0]);
+    let path = setup
+        .solve(Duration::from_secs(5))
+        .expect("Planner failed to find a solution when one should exist.");
+    assert!(is_path_valid(&path, &setup));
+    assert!(setup.goal().unwrap().is_satisfied(path.0.last().unwrap()));
+}
@@ -0,0 +1,230 @@
+use std::{sync::Arc, time::Duration};
+
+use oxmpl::base::{
+    error::PlanningError,
+    goal::{Goal, GoalState},
+    planner::{Path, Planner, PlannerConfig},
+    problem_definition::ProblemDefinition,
+    space::{RealVectorStateSpace, StateSpace},
+    state::RealVectorState,
+    validity::StateValidityChecker,
+};
+use oxmpl::geometric::{RRTStar, PRM, RRT};
+
+struct WallObstacleChecker {
+    wall_x_pos: f64,
+    wall_y_min: f64,
+    wall_y_max: f64,
+    wall_thickness: f64,
+}
+
+impl StateValidityChecker<RealVectorState> for WallObstacleChecker {
+    fn is_valid(&self, state: &RealVectorState) -> bool {
+        let x = state.values[0];
+        let y = state.values[1];
+
+        let is_in_wall = x >= self.wall_x_pos - self.wall_thickness / 2.0
+            && x <= self.wall_x_pos + self.wall_thickness / 2.0
+            && y >= self.wall_y_min
+            && y <= self.wall_y_max;
+
+        !is_in_wall
+    }
+}
+
+type Problem =
+    ProblemDefinition<RealVectorState, RealVectorStateSpace, GoalState<RealVectorStateSpace>>;
+
+struct World {
+    space: Arc<RealVectorStateSpace>,
+    checker: Arc<dyn StateValidityChecker<RealVectorState>>,
+}
+
+impl World {
+    fn new() -> Self {
+        let space = RealVectorStateSpace::new(2, Some(vec![(0.0, 10.0), (0.0, 10.0)]))
+            .expect("Failed to create state space for test.");
+        World {
+            space: Arc::new(space),
+            checker: Arc::new(WallObstacleChecker {
+                wall_x_pos: 5.0,
+                wall_y_min: 2.0,
+                wall_y_max: 8.0,
+                wall_thickness: 0.5,
+            }),
+        }
+    }
+
+    fn problem(&self, start: [f64; 2], goal: [f64; 2]) -> Arc<Problem> {
+        Arc::new(ProblemDefinition {
+            space: self.space.clone(),
+            start_states: vec![RealVectorState::new(start.to_vec())],
+            goal: Arc::new(GoalState::new(
+                self.space.clone(),
+                RealVectorState::new(goal.to_vec()),
+                0.5,
+            )),
+        })
+    }
+}
+
+fn path_length(path: &Path<RealVectorState>, space: &RealVectorStateSpace) -> f64 {
+    path.0
+        .windows(2)
+        .map(|pair| space.distance(&pair[0], &pair[1]))
+        .sum()
+}
+
+#[test]
+fn test_rrt_resumes_across_calls() {
+    let world = World::new();
+    let problem = world.problem([1.0, 5.0], [9.0, 5.0]);
+    let mut planner = RRT::new(0.5, 0.05, &PlannerConfig { seed: Some(1) });
+    planner.setup(problem.clone(), world.checker.clone());
+
+    // Plan in small slices of time, as a control loop would. The tree keeps growing between calls.
+    let mut tree_size = planner.tree_states().count();
+    let path = loop {
+        match planner.solve(Duration::from_micros(200)) {
+            Ok(path) => break path,
+            Err(e) => assert_eq!(e, PlanningError::Timeout),
+        }
+        let new_tree_size = planner.tree_states().count();
+        assert!(new_tree_size >= tree_size);
+        tree_size = new_tree_size;
+    };
+    assert!(problem.goal.is_satisfied(path.0.last().unwrap()));
+
+    // Once the tree reaches the goal, the same path is returned straight away.
+    let tree_size = planner.tree_states().count();
+    let again = planner.solve(Duration::ZERO).unwrap();
+    assert_eq!(again.0.len(), path.0.len());
+    assert_eq!(planner.tree_states().count(), tree_size);
+
+    planner.clear();
+    assert_eq!(planner.tree_states().count(), 0);
+    let path = planner.solve(Duration::from_secs(5)).unwrap();
+    assert!(problem.goal.is_satisfied(path.0.last().unwrap()));
+}
+
+#[test]
+fn test_rrt_setup_keeps_tree_for_same_start() {
+    let world = World::new();
+    let mut planner = RRT::new(0.5, 0.05, &PlannerConfig { seed: Some(2) });
+    planner.setup(world.problem([1.0, 5.0], [9.0, 5.0]), world.checker.clone());
+    planner.solve(Duration::from_secs(5)).unwrap();
+    let tree_size = planner.tree_states().count();
+
+    // A new goal from the same start keeps the tree.
+    let problem = world.problem([1.0, 5.0], [9.0, 9.0]);
+    planner.setup(problem.clone(), world.checker.clone());
+    assert_eq!(planner.tree_states().count(), tree_size);
+    let path = planner.solve(Duration::from_secs(5)).unwrap();
+    assert!(problem.goal.is_satisfied(path.0.last().unwrap()));
+
+    // A new start state does not.
+    planner.setup(world.problem([2.0, 2.0], [9.0, 9.0]), world.checker.clone());
+    assert_eq!(planner.tree_states().count(), 1);
+
+    // Nor does a new validity checker.
+    let checker: Arc<dyn StateValidityChecker<RealVectorState>> = Arc::new(WallObstacleChecker {
+        wall_x_pos: 5.0,
+        wall_y_min: 2.0,
+        wall_y_max: 8.0,
+        wall_thickness: 0.5,
+    });
+    planner.solve(Duration::from_secs(5)).unwrap();
+    planner.setup(world.problem([2.0, 2.0], [9.0, 9.0]), checker);
+    assert_eq!(planner.tree_states().count(), 1);
+}
+
+#[test]
+fn test_rrt_warm_start_from_path() {
+    let world = World::new();
+    let problem = world.problem([1.0, 5.0], [9.0, 5.0]);
+
+    let mut planner = RRT::new(0.5, 0.05, &PlannerConfig { seed: Some(3) });
+    assert_eq!(
+        planner.warm_start(Vec::new()),
+        Err(PlanningError::PlannerUninitialised)
+    );
+    planner.setup(problem.clone(), world.checker.clone());
+    let path = planner.solve(Duration::from_secs(5)).unwrap();
+
+    // A new planner seeded with the previous path has a solution without growing its tree.
+    let mut seeded = RRT::new(0.5, 0.05, &PlannerConfig { seed: Some(4) });
+    seeded.setup(problem.clone(), world.checker.clone());
+    let added = seeded.warm_start(path.0.clone()).unwrap();
+    assert_eq!(added, path.0.len() - 1);
+    let seeded_path = seeded.solve(Duration::ZERO).unwrap();
+    assert!(problem.goal.is_satisfied(seeded_path.0.last().unwrap()));
+    assert!(path_length(&seeded_path, &world.space) <= path_length(&path, &world.space) + 1e-9);
+
+    // A state inside the wall cannot be added.
+    let mut blocked = RRT::new(0.5, 0.05, &PlannerConfig { seed: Some(5) });
+    blocked.setup(problem, world.checker.clone());
+    assert_eq!(
+        blocked.warm_start(vec![RealVectorState::new(vec![5.0, 5.0])]),
+        Ok(0)
+    );
+
+    // A whole tree can be copied too.
+    let mut copy = RRT::new(0.5, 0.05, &PlannerConfig { seed: Some(6) });
+    copy.setup(world.problem([1.0, 5.0], [9.0, 5.0]), world.checker.clone());
+    let added = copy
+        .warm_start(planner.tree_states().cloned().collect::<Vec<_>>())
+        .unwrap();
+    assert_eq!(added, planner.tree_states().count() - 1);
+}
+
+#[test]
+fn test_rrt_star_improves_across_calls() {
+    let world = World::new();
+    let problem = world.problem([1.0, 5.0], [9.0, 5.0]);
+    let mut planner = RRTStar::new(0.5, 0.05, 1.5, &PlannerConfig { seed: Some(7) });
+    planner.setup(problem.clone(), world.checker.clone());
+
+    let mut best = f64::INFINITY;
+    for _ in 0..5 {
+        let path = planner.solve(Duration::from_secs(5)).unwrap();
+        assert!(problem.goal.is_satisfied(path.0.last().unwrap()));
+        let length = path_length(&path, &world.space);
+        assert!(length <= best + 1e-9);
+        best = length;
+    }
+
+    // Warm starting a new planner with the best path gives it that solution immediately.
+    let path = planner.solve(Duration::ZERO).unwrap();
+    let mut seeded = RRTStar::new(0.5, 0.05, 1.5, &PlannerConfig { seed: Some(8) });
+    seeded.setup(problem.clone(), world.checker.clone());
+    assert_eq!(seeded.warm_start(path.0.clone()), Ok(path.0.len() - 1));
+    let seeded_path = seeded.solve(Duration::ZERO).unwrap();
+    assert!(path_length(&seeded_path, &world.space) <= best + 1e-9);
+
+    planner.clear();
+    assert_eq!(planner.tree_states().count(), 0);
+    assert_eq!(
+        planner.solve(Duration::ZERO).err(),
+        Some(PlanningError::Timeout)
+    );
+}
+
+#[test]
+fn test_prm_setup_keeps_roadmap() {
+    let world = World::new();
+    let mut planner = PRM::new(0.2, 1.5, &PlannerConfig { seed: Some(9) });
+    planner.setup(world.problem([1.0, 5.0], [9.0, 5.0]), world.checker.clone());
+    planner.construct_roadmap().unwrap();
+    let roadmap_size = planner.get_roadmap().len();
+    assert!(roadmap_size > 0);
+
+    // A new query in the same world reuses the roadmap.
+    let problem = world.problem([9.0, 9.0], [1.0, 1.0]);
+    planner.setup(problem.clone(), world.checker.clone());
+    assert!(planner.get_roadmap().len() >= roadmap_size);
+    let path = planner.solve(Duration::from_secs(5)).unwrap();
+    assert!(problem.goal.is_satisfied(path.0.last().unwrap()));
+
+    planner.clear();
+    assert!(planner.get_roadmap().is_empty());
+}